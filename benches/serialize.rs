@@ -0,0 +1,51 @@
+//! Times writing a document with a large table, the case the text writer is
+//! tuned for.
+//!
+//! Run with `cargo bench --bench serialize`.
+
+use std::time::{Duration, Instant};
+
+use docx_rust::{
+    document::{Paragraph, Table, TableRow},
+    Docx, DocxResult,
+};
+
+const ROWS: usize = 10_000;
+const COLUMNS: usize = 10;
+const ITERATIONS: u32 = 5;
+
+fn main() -> DocxResult<()> {
+    let mut table = Table::default();
+    for row in 0..ROWS {
+        let mut cells = TableRow::default();
+        for column in 0..COLUMNS {
+            cells = cells.push_cell(Paragraph::default().push_text(format!(
+                "R{} & C{} <{}>",
+                row,
+                column,
+                row * column
+            )));
+        }
+        table = table.push_row(cells);
+    }
+
+    let mut docx = Docx::default();
+    docx.document.push(table);
+
+    let mut total = Duration::ZERO;
+    let mut size = 0;
+    for _ in 0..ITERATIONS {
+        let start = Instant::now();
+        size = docx.write_to_vec()?.len();
+        total += start.elapsed();
+    }
+
+    println!(
+        "serialize {} cells: {:?} per iteration ({} bytes)",
+        ROWS * COLUMNS,
+        total / ITERATIONS,
+        size
+    );
+
+    Ok(())
+}
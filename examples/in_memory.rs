@@ -0,0 +1,33 @@
+//! Generates and reads back a docx without touching the file system.
+//!
+//! This is the path to use on `wasm32-unknown-unknown`, where the crate is
+//! built with `default-features = false`. With `wasm-bindgen`, a function like
+//! `generate` can be exported to JavaScript and its bytes wrapped in a `Blob`
+//! for download.
+
+use docx_rust::{
+    document::{BodyContent, Paragraph},
+    Docx, DocxFile, DocxResult,
+};
+
+fn generate(text: &str) -> DocxResult<Vec<u8>> {
+    let mut docx = Docx::default();
+    docx.document.push(Paragraph::default().push_text(text));
+    docx.write_to_vec()
+}
+
+fn main() -> DocxResult<()> {
+    let bytes = generate("hello, world")?;
+    println!("generated {} bytes", bytes.len());
+
+    let file = DocxFile::from_bytes(&bytes)?;
+    let docx = file.parse()?;
+
+    for content in &docx.document.body.content {
+        if let BodyContent::Paragraph(para) = content {
+            println!("{}", para.text());
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(written) = docx_rust::fuzz::parse_and_rewrite(data) {
+        // whatever the crate writes, it must be able to read back
+        docx_rust::fuzz::parse_and_rewrite(&written).expect("rewritten package doesn't parse");
+    }
+});
@@ -0,0 +1,1066 @@
+//! Assembling a document from parts built separately
+//!
+//! [`Docx`] is `Send` and `Sync`, so chapters can be built by worker threads,
+//! each into a document of its own, and then appended to the final one.
+
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::ops::RangeBounds;
+
+use crate::{
+    chart::ChartPart,
+    content_type::{chart_content_type, embedding_content_type},
+    document::{BodyContent, ParagraphContent, RunContent},
+    formatting::ParagraphProperty,
+    media::MediaType,
+    rsid::{new_para_id, random},
+    schema::{
+        SCHEMA_CHART, SCHEMA_HYPERLINK, SCHEMA_IMAGE, SCHEMA_OLE_OBJECT, SCHEMA_PACKAGE,
+        SCHEMA_SUBDOCUMENT,
+    },
+    walk::{map_relationship_ids, relationship_ids, runs, runs_mut},
+    Docx,
+};
+
+impl<'a> Docx<'a> {
+    /// Creates a document from chapters, in order, see
+    /// [`Docx::append_document`].
+    ///
+    /// The first chapter gives the properties of the document, like its
+    /// section properties and settings.
+    ///
+    /// ```rust
+    /// use docx_rust::Docx;
+    /// use std::thread;
+    ///
+    /// let titles = ["Introduction", "Installation", "Usage"];
+    /// let chapters: Vec<Docx> = thread::scope(|scope| {
+    ///     let workers: Vec<_> = titles
+    ///         .iter()
+    ///         .map(|title| {
+    ///             scope.spawn(move || {
+    ///                 let mut chapter = Docx::default();
+    ///                 chapter.add_heading(1, *title);
+    ///                 chapter.add_paragraph("Lorem ipsum");
+    ///                 chapter
+    ///             })
+    ///         })
+    ///         .collect();
+    ///     workers.into_iter().map(|w| w.join().unwrap()).collect()
+    /// });
+    ///
+    /// let docx = Docx::assemble(chapters);
+    /// assert_eq!(docx.document.body.content.len(), 6);
+    /// ```
+    pub fn assemble<I: IntoIterator<Item = Docx<'a>>>(chapters: I) -> Docx<'a> {
+        let mut chapters = chapters.into_iter();
+        let mut docx = chapters.next().unwrap_or_default();
+        for chapter in chapters {
+            docx.append_document(chapter);
+        }
+        docx
+    }
+
+    /// Appends the body of another document, with the styles, numbering
+    /// definitions, pictures, charts, embedded objects, links, notes and
+    /// comments it uses.
+    ///
+    /// Styles are added unless the document has a style with the same id.
+    /// Lists, relationships, notes and comments get new ids, and so do
+    /// bookmarks and paragraph ids that are already used. Bookmarks whose
+    /// name is used are renamed, and the links and `REF`, `PAGEREF` and
+    /// `NOTEREF` fields referring to them updated. Headers and footers of
+    /// `chapter` aren't copied, nor are its section properties at the end of
+    /// the body.
+    pub fn append_document(&mut self, chapter: Docx<'a>) -> &mut Self {
+        let body = self.import_body(chapter);
+        let content = &mut self.document.body.content;
+        let at = match content.last() {
+            Some(BodyContent::SectionProperty(_)) => content.len() - 1,
+            _ => content.len(),
+        };
+        content.splice(at..at, body);
+        self
+    }
+
+    /// Copies body blocks to another document, with the styles, numbering
+    /// definitions, pictures, charts, embedded objects, links, notes and
+    /// comments they use, like [`Docx::append_document`] does. Returns the
+    /// copied blocks, referring to the ids of `target`, to be inserted where
+    /// needed.
+    ///
+    /// ```rust
+    /// use docx_rust::Docx;
+    ///
+    /// let mut source = Docx::default();
+    /// source.add_heading(1, "Terms");
+    /// source.add_paragraph("Not copied");
+    /// let link = source.hyperlink("https://example.com/terms", "Full terms");
+    /// source.add_paragraph("See ").content.push(link.into());
+    ///
+    /// let mut target = Docx::default();
+    /// target.add_paragraph("Contract");
+    /// let copied = source.clone_into(2..3, &mut target);
+    /// target.document.body.content.splice(0..0, copied);
+    ///
+    /// assert_eq!(target.document.body.content.len(), 2);
+    /// assert!(target.styles.get("Heading1").is_none());
+    /// assert_eq!(target.document_rels.unwrap().relationships.len(), 1);
+    /// ```
+    pub fn clone_into<R: RangeBounds<usize>>(
+        &self,
+        blocks: R,
+        target: &mut Docx<'a>,
+    ) -> Vec<BodyContent<'a>> {
+        let mut part = self.fragment(blocks);
+        part.headers.clear();
+        part.footers.clear();
+        part.prune_unused_notes();
+        part.prune_unused_styles();
+        part.prune_unused_numbering();
+
+        let used = part.relationship_ids();
+        if let Some(rels) = &mut part.document_rels {
+            rels.relationships
+                .retain(|rel| used.contains(rel.id.as_ref()));
+        }
+        target.import_body(part)
+    }
+
+    /// Moves the styles, numbering definitions and relationships of
+    /// `chapter` to this document, returning its body blocks with the ids of
+    /// this document, without the section properties at the end.
+    fn import_body(&mut self, mut chapter: Docx<'a>) -> Vec<BodyContent<'a>> {
+        self.append_numbering(&mut chapter);
+        self.append_relationships(&mut chapter);
+        self.renumber_bookmarks(&mut chapter);
+        self.renumber_para_ids(&mut chapter);
+        self.append_notes(&mut chapter);
+        self.append_comments(&mut chapter);
+
+        for style in chapter.styles.styles {
+            if self.styles.get(&style.style_id).is_none() {
+                self.styles.push(style);
+            }
+        }
+
+        let mut body = chapter.document.body.content;
+        if let Some(BodyContent::SectionProperty(_)) = body.last() {
+            body.pop();
+        }
+        body
+    }
+
+    /// Returns the relationship ids the paragraphs refer to, in links,
+    /// subdocuments, pictures, charts, embedded objects and VML.
+    fn relationship_ids(&self) -> HashSet<String> {
+        self.paragraphs()
+            .into_iter()
+            .flat_map(relationship_ids)
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Removes the footnotes, endnotes and comments the body doesn't refer
+    /// to, with the extended information of the removed comments.
+    fn prune_unused_notes(&mut self) {
+        let mut footnotes = HashSet::new();
+        let mut endnotes = HashSet::new();
+        for para in self.body_paragraphs() {
+            for run in runs(para) {
+                for content in &run.content {
+                    match content {
+                        RunContent::FootnoteReference(reference) => {
+                            footnotes.extend(reference.id.as_deref().map(str::to_string))
+                        }
+                        RunContent::EndnoteReference(reference) => {
+                            endnotes.extend(reference.id.as_deref().map(str::to_string))
+                        }
+                        _ => (),
+                    }
+                }
+            }
+        }
+        if let Some(notes) = &mut self.footnotes {
+            notes.content.retain(|note| {
+                note.ty.is_some()
+                    || note
+                        .id
+                        .is_some_and(|id| footnotes.contains(&id.to_string()))
+            });
+        }
+        if let Some(notes) = &mut self.endnotes {
+            notes.content.retain(|note| {
+                note.ty.is_some() || note.id.is_some_and(|id| endnotes.contains(&id.to_string()))
+            });
+        }
+
+        let mut comments = HashSet::new();
+        for para in self.paragraphs() {
+            for content in &para.content {
+                if let ParagraphContent::CommentRangeStart(start) = content {
+                    comments.insert(start.id.to_string());
+                }
+            }
+            for run in runs(para) {
+                for content in &run.content {
+                    if let RunContent::CommentReference(reference) = content {
+                        comments.extend(reference.id.as_deref().map(str::to_string));
+                    }
+                }
+            }
+        }
+        let kept: HashSet<String> = match &mut self.comments {
+            Some(part) => {
+                part.comments.retain(|comment| {
+                    comment
+                        .id
+                        .is_some_and(|id| comments.contains(&id.to_string()))
+                });
+                part.comments
+                    .iter()
+                    .filter_map(|comment| comment.content.id.as_deref())
+                    .map(str::to_string)
+                    .collect()
+            }
+            None => HashSet::new(),
+        };
+        if let Some(extended) = &mut self.comments_extended {
+            extended
+                .comments
+                .retain(|comment| kept.contains(&*comment.para_id));
+        }
+    }
+
+    /// Moves the numbering definitions of `chapter` to this document, giving
+    /// them unused ids, and new nsids where theirs are used.
+    fn append_numbering(&mut self, chapter: &mut Docx<'a>) {
+        let numbering = match chapter.numbering.take() {
+            Some(numbering) => numbering,
+            None => return,
+        };
+        let target = self.numbering.get_or_insert_with(Default::default);
+        let abstract_offset = target
+            .abstract_numberings
+            .iter()
+            .filter_map(|an| an.abstract_num_id)
+            .max()
+            .map_or(0, |id| id + 1);
+        let num_offset = target
+            .numberings
+            .iter()
+            .filter_map(|n| n.num_id)
+            .max()
+            .unwrap_or(0);
+        let mut nsids: HashSet<String> = target
+            .abstract_numberings
+            .iter()
+            .map(|an| an.nsid.value.to_string())
+            .collect();
+
+        for mut abstract_num in numbering.abstract_numberings {
+            let id = abstract_num.abstract_num_id.unwrap_or(0) + abstract_offset;
+            abstract_num.abstract_num_id = Some(id);
+            if abstract_num.nsid.value.is_empty()
+                || !nsids.insert(abstract_num.nsid.value.to_string())
+            {
+                let nsid = loop {
+                    let nsid = format!("{:08X}", random());
+                    if nsids.insert(nsid.clone()) {
+                        break nsid;
+                    }
+                };
+                abstract_num.nsid.value = nsid.into();
+            }
+            target.abstract_numberings.push(abstract_num);
+        }
+        for mut num in numbering.numberings {
+            num.num_id = num.num_id.map(|id| id + num_offset);
+            if let Some(abstract_num_id) = &mut num.abstract_num_id {
+                abstract_num_id.value = abstract_num_id.value.map(|id| id + abstract_offset);
+            }
+            target.numberings.push(num);
+        }
+
+        let remap = |property: &mut ParagraphProperty| {
+            if let Some(id) = property
+                .numbering
+                .as_mut()
+                .and_then(|numbering| numbering.id.as_mut())
+            {
+                // numId 0 removes the numbering inherited from the style
+                if id.value != 0 {
+                    id.value += num_offset;
+                }
+            }
+        };
+        for para in chapter.paragraphs_mut() {
+            if let Some(property) = &mut para.property {
+                remap(property);
+            }
+        }
+        for style in chapter.styles.styles.iter_mut() {
+            if let Some(property) = &mut style.paragraph {
+                remap(property);
+            }
+        }
+    }
+
+    /// Moves the pictures, charts, embedded objects, links and subdocuments
+    /// of `chapter` to this document, giving their relationships the ids of
+    /// this document.
+    fn append_relationships(&mut self, chapter: &mut Docx<'a>) {
+        let rels = match chapter.document_rels.take() {
+            Some(rels) => rels,
+            None => return,
+        };
+
+        let mut ids = HashMap::new();
+        for rel in rels.relationships {
+            let (ty, target) = match &*rel.ty {
+                SCHEMA_IMAGE => match chapter.media.remove(&*rel.target) {
+                    Some(media) => (SCHEMA_IMAGE, self.insert_media(&rel.target, media)),
+                    None => continue,
+                },
+                SCHEMA_CHART => match chapter.charts.remove(&*rel.target) {
+                    Some(chart) => (SCHEMA_CHART, self.insert_chart(&rel.target, chart, chapter)),
+                    None => continue,
+                },
+                SCHEMA_OLE_OBJECT => match chapter.embeddings.remove(&*rel.target) {
+                    Some(data) => (SCHEMA_OLE_OBJECT, self.insert_embedding(&rel.target, data)),
+                    None => continue,
+                },
+                SCHEMA_PACKAGE => match chapter.embeddings.remove(&*rel.target) {
+                    Some(data) => (SCHEMA_PACKAGE, self.insert_embedding(&rel.target, data)),
+                    None => continue,
+                },
+                SCHEMA_HYPERLINK => (SCHEMA_HYPERLINK, rel.target.to_string()),
+                SCHEMA_SUBDOCUMENT => (SCHEMA_SUBDOCUMENT, rel.target.to_string()),
+                _ => continue,
+            };
+            let target_rels = self.document_rels.get_or_insert_with(Default::default);
+            match rel.target_mode {
+                Some(_) => {
+                    target_rels.add_rel_with_target_mode(ty, target.clone(), Some("External"))
+                }
+                None => target_rels.add_rel(ty, target.clone()),
+            };
+            if let Some(id) = target_rels.get_id(&target) {
+                ids.insert(rel.id.to_string(), id.to_string());
+            }
+        }
+
+        for para in chapter.paragraphs_mut() {
+            map_relationship_ids(para, |id| ids.get(id).cloned());
+        }
+    }
+
+    /// Adds a chart part under `name`, or a free name like
+    /// `charts/chart1-2.xml`, together with the parts its relationships point
+    /// to, like its style and the workbook holding its data, moved from
+    /// `chapter`. Returns the name.
+    fn insert_chart(
+        &mut self,
+        name: &str,
+        mut chart: ChartPart<'a>,
+        chapter: &mut Docx<'a>,
+    ) -> String {
+        for rel in chart
+            .rels
+            .iter_mut()
+            .flat_map(|rels| &mut rels.relationships)
+        {
+            if rel.target_mode.is_some() {
+                continue;
+            }
+            // targets are relative to `word/charts/`
+            let part = match rel.target.strip_prefix("../") {
+                Some(part) => part.to_string(),
+                None => format!("charts/{}", rel.target),
+            };
+            let new_part = if let Some(data) = chapter.embeddings.remove(&part) {
+                self.insert_embedding(&part, data)
+            } else if let Some(media) = chapter.media.remove(&part) {
+                self.insert_media(&part, media)
+            } else if let Some(part_of_chart) = chapter.charts.remove(&part) {
+                let new_part = free_name(&part, |name| self.charts.contains_key(name));
+                self.content_types
+                    .add_override(format!("/word/{}", new_part), chart_content_type(&new_part));
+                self.charts.insert(new_part.clone(), part_of_chart);
+                new_part
+            } else {
+                continue;
+            };
+            rel.target = match new_part.strip_prefix("charts/") {
+                Some(file) => file.to_string().into(),
+                None => format!("../{}", new_part).into(),
+            };
+        }
+
+        let name = free_name(name, |name| self.charts.contains_key(name));
+        self.content_types
+            .add_override(format!("/word/{}", name), chart_content_type(&name));
+        self.charts.insert(name.clone(), chart);
+        name
+    }
+
+    /// Adds an embedded package or object under `name`, or a free name like
+    /// `embeddings/oleObject1-2.bin`. Returns the name.
+    fn insert_embedding(&mut self, name: &str, data: Cow<'a, [u8]>) -> String {
+        let name = free_name(name, |name| self.embeddings.contains_key(name));
+        if let Some((_, ext)) = name.rsplit_once('.') {
+            self.content_types
+                .add_default(ext.to_ascii_lowercase(), embedding_content_type(&name));
+        }
+        self.embeddings.insert(name.clone(), data);
+        name
+    }
+
+    /// Gives the bookmarks of `chapter` ids that aren't used in this
+    /// document, and renames those whose name is, updating the links and
+    /// fields that refer to them.
+    fn renumber_bookmarks(&self, chapter: &mut Docx<'a>) {
+        let mut names = HashSet::new();
+        let mut offset = 0;
+        for para in self.paragraphs() {
+            for content in &para.content {
+                let id = match content {
+                    ParagraphContent::BookmarkStart(start) => {
+                        names.extend(start.name.as_deref().map(str::to_string));
+                        &start.id
+                    }
+                    ParagraphContent::BookmarkEnd(end) => &end.id,
+                    _ => continue,
+                };
+                if let Some(id) = id.as_deref().and_then(|id| id.parse::<usize>().ok()) {
+                    offset = offset.max(id + 1);
+                }
+            }
+        }
+
+        let mut renamed = HashMap::new();
+        for para in chapter.paragraphs_mut() {
+            for content in para.content.iter_mut() {
+                let id = match content {
+                    ParagraphContent::BookmarkStart(start) => {
+                        if let Some(name) = &mut start.name {
+                            if !names.insert(name.to_string()) {
+                                let mut n = 2;
+                                let new_name = loop {
+                                    let new_name = format!("{}_{}", name, n);
+                                    if names.insert(new_name.clone()) {
+                                        break new_name;
+                                    }
+                                    n += 1;
+                                };
+                                renamed.insert(name.to_string(), new_name.clone());
+                                *name = new_name.into();
+                            }
+                        }
+                        &mut start.id
+                    }
+                    ParagraphContent::BookmarkEnd(end) => &mut end.id,
+                    _ => continue,
+                };
+                if let Some(id) = id {
+                    if let Ok(n) = id.parse::<usize>() {
+                        *id = (n + offset).to_string().into();
+                    }
+                }
+            }
+        }
+
+        if renamed.is_empty() {
+            return;
+        }
+        for para in chapter.paragraphs_mut() {
+            for content in para.content.iter_mut() {
+                if let ParagraphContent::Link(link) = content {
+                    if let Some(anchor) = &mut link.anchor {
+                        if let Some(name) = renamed.get(&**anchor) {
+                            *anchor = name.clone().into();
+                        }
+                    }
+                }
+            }
+            for run in runs_mut(para) {
+                for content in run.content.iter_mut() {
+                    if let RunContent::InstrText(instr) = content {
+                        if let Some(text) = rename_bookmark_reference(&instr.text, &renamed) {
+                            instr.text = text.into();
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Gives the paragraphs of `chapter` whose paragraph or text id is used
+    /// in this document a new one, updating the comments extended that refer
+    /// to them.
+    fn renumber_para_ids(&self, chapter: &mut Docx<'a>) {
+        let mut used: HashSet<String> = self
+            .paragraphs()
+            .into_iter()
+            .flat_map(|para| [&para.id, &para.text_id])
+            .flatten()
+            .map(|id| id.to_string())
+            .collect();
+
+        let mut renamed = HashMap::new();
+        for para in chapter.paragraphs_mut() {
+            if let Some(id) = &mut para.id {
+                if !used.insert(id.to_string()) {
+                    let new_id = new_para_id(&mut used, random);
+                    renamed.insert(id.to_string(), new_id.clone());
+                    *id = new_id.into();
+                }
+            }
+            if let Some(id) = &mut para.text_id {
+                if !used.insert(id.to_string()) {
+                    *id = new_para_id(&mut used, random).into();
+                }
+            }
+        }
+
+        if let Some(extended) = &mut chapter.comments_extended {
+            for comment in extended.comments.iter_mut() {
+                let ids =
+                    std::iter::once(&mut comment.para_id).chain(comment.para_id_parent.as_mut());
+                for id in ids {
+                    if let Some(new_id) = renamed.get(&**id) {
+                        *id = new_id.clone().into();
+                    }
+                }
+            }
+        }
+    }
+
+    /// Moves the footnotes and endnotes of `chapter` to this document, giving
+    /// them unused ids. The separators are only moved when this document has
+    /// none.
+    fn append_notes(&mut self, chapter: &mut Docx<'a>) {
+        macro_rules! move_notes {
+            ($notes:ident) => {{
+                let mut ids = HashMap::new();
+                if let Some(notes) = chapter.$notes.take() {
+                    let target = self.$notes.get_or_insert_with(Default::default);
+                    let (separators, notes): (Vec<_>, Vec<_>) = notes
+                        .content
+                        .into_iter()
+                        .partition(|note| note.ty.is_some());
+                    if !target.content.iter().any(|note| note.ty.is_some()) {
+                        target.content.splice(0..0, separators);
+                    }
+                    let next = target
+                        .content
+                        .iter()
+                        .filter_map(|note| note.id)
+                        .max()
+                        .map_or(1, |id| id + 1);
+                    for (mut note, new_id) in notes.into_iter().zip(next..) {
+                        if let Some(id) = note.id {
+                            ids.insert(id.to_string(), new_id.to_string());
+                        }
+                        note.id = Some(new_id);
+                        target.content.push(note);
+                    }
+                }
+                ids
+            }};
+        }
+
+        let footnotes = move_notes!(footnotes);
+        let endnotes = move_notes!(endnotes);
+        if footnotes.is_empty() && endnotes.is_empty() {
+            return;
+        }
+        for para in chapter.paragraphs_mut() {
+            for run in runs_mut(para) {
+                for content in run.content.iter_mut() {
+                    let (id, ids) = match content {
+                        RunContent::FootnoteReference(reference) => (&mut reference.id, &footnotes),
+                        RunContent::EndnoteReference(reference) => (&mut reference.id, &endnotes),
+                        _ => continue,
+                    };
+                    if let Some(id) = id {
+                        if let Some(new_id) = ids.get(&**id) {
+                            *id = new_id.clone().into();
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Moves the comments of `chapter` to this document, giving them unused
+    /// ids, with their extended information and authors.
+    fn append_comments(&mut self, chapter: &mut Docx<'a>) {
+        if let Some(extended) = chapter.comments_extended.take() {
+            self.comments_extended
+                .get_or_insert_with(Default::default)
+                .comments
+                .extend(extended.comments);
+        }
+        if let Some(people) = chapter.people.take() {
+            let target = self.people.get_or_insert_with(Default::default);
+            for person in people.persons {
+                if !target.persons.iter().any(|p| p.author == person.author) {
+                    target.persons.push(person);
+                }
+            }
+        }
+        let comments = match chapter.comments.take() {
+            Some(comments) => comments,
+            None => return,
+        };
+
+        let target = self.comments.get_or_insert_with(Default::default);
+        let next = target
+            .comments
+            .iter()
+            .filter_map(|comment| comment.id)
+            .max()
+            .map_or(0, |id| id + 1);
+        let mut ids = HashMap::new();
+        for (mut comment, new_id) in comments.comments.into_iter().zip(next..) {
+            if let Some(id) = comment.id {
+                ids.insert(id.to_string(), new_id.to_string());
+            }
+            comment.id = Some(new_id);
+            target.comments.push(comment);
+        }
+
+        for para in chapter.paragraphs_mut() {
+            for content in para.content.iter_mut() {
+                let id = match content {
+                    ParagraphContent::CommentRangeStart(start) => &mut start.id,
+                    ParagraphContent::CommentRangeEnd(end) => &mut end.id,
+                    _ => continue,
+                };
+                if let Some(new_id) = ids.get(&**id) {
+                    *id = new_id.clone().into();
+                }
+            }
+            for run in runs_mut(para) {
+                for content in run.content.iter_mut() {
+                    if let RunContent::CommentReference(reference) = content {
+                        if let Some(id) = &mut reference.id {
+                            if let Some(new_id) = ids.get(&**id) {
+                                *id = new_id.clone().into();
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Adds a media file under `name`, or a free name like
+    /// `media/image1-2.png` if another file has that name. Returns the name.
+    pub(crate) fn insert_media(&mut self, name: &str, media: (MediaType, &'a Vec<u8>)) -> String {
+        let (stem, extension) = name.rsplit_once('.').unwrap_or((name, ""));
+        let mut candidate = name.to_string();
+        let mut n = 1;
+        loop {
+            match self.media.get(&candidate) {
+                Some((_, data)) if *data != media.1 => {
+                    n += 1;
+                    candidate = format!("{}-{}.{}", stem, n, extension);
+                }
+                _ => break,
+            }
+        }
+        self.media.insert(candidate.clone(), media);
+        candidate
+    }
+}
+
+/// Returns `name`, or a free name like `charts/chart1-2.xml` if `taken`
+/// says it is taken.
+fn free_name<F: Fn(&str) -> bool>(name: &str, taken: F) -> String {
+    let (stem, extension) = name.rsplit_once('.').unwrap_or((name, ""));
+    let mut candidate = name.to_string();
+    let mut n = 1;
+    while taken(&candidate) {
+        n += 1;
+        candidate = format!("{}-{}.{}", stem, n, extension);
+    }
+    candidate
+}
+
+/// Returns the instruction of a `REF`, `PAGEREF` or `NOTEREF` field with the
+/// bookmark it refers to renamed, if it is.
+fn rename_bookmark_reference(
+    instruction: &str,
+    renamed: &HashMap<String, String>,
+) -> Option<String> {
+    let mut words = instruction.split_whitespace();
+    match words.next()?.to_ascii_uppercase().as_str() {
+        "REF" | "PAGEREF" | "NOTEREF" => {}
+        _ => return None,
+    }
+    let word = words.next()?;
+    let name = word.trim_matches('"');
+    let new_name = renamed.get(name)?;
+    // the offset of the word, which is a slice of the instruction
+    let start = word.as_ptr() as usize - instruction.as_ptr() as usize;
+    Some(format!(
+        "{}{}{}",
+        &instruction[..start],
+        word.replace(name, new_name),
+        &instruction[start + word.len()..]
+    ))
+}
+
+#[test]
+fn append_document() {
+    use crate::document::{AbstractNum, Numbering, Paragraph};
+    use crate::formatting::ParagraphProperty;
+
+    fn chapter<'a>(title: &'a str) -> Docx<'a> {
+        let mut docx = Docx::default();
+        let mut numbering = Numbering::default();
+        let id = numbering.push_list(AbstractNum::bullet());
+        docx.numbering = Some(numbering);
+        docx.add_heading(1, title);
+        let link = docx.hyperlink(format!("https://example.com/{}", title), title);
+        docx.document.push(
+            Paragraph::default()
+                .property(ParagraphProperty::default().numbering((id, 0)))
+                .push(link),
+        );
+        docx
+    }
+
+    let docx = Docx::assemble([chapter("a"), chapter("b")]);
+    assert_eq!(docx.document.body.content.len(), 4);
+    assert_eq!(
+        docx.styles
+            .styles
+            .iter()
+            .filter(|s| s.style_id == "Heading1")
+            .count(),
+        1
+    );
+
+    let numbering = docx.numbering.as_ref().unwrap();
+    assert_eq!(numbering.numberings.len(), 2);
+    assert_eq!(numbering.numberings[1].num_id, Some(2));
+
+    let targets: Vec<_> = docx
+        .paragraphs()
+        .into_iter()
+        .flat_map(|para| &para.content)
+        .filter_map(|content| match content {
+            ParagraphContent::Link(link) => docx.hyperlink_target(link),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(targets, ["https://example.com/a", "https://example.com/b"]);
+}
+
+#[test]
+fn append_notes_comments_and_bookmarks() {
+    use crate::document::{
+        AbstractNum, BookmarkEnd, BookmarkStart, Comment, CommentRangeEnd, CommentRangeStart,
+        CommentReference, Comments, FootNote, FootNotes, FootnoteReference, Numbering, Paragraph,
+        Run,
+    };
+
+    fn chapter() -> Docx<'static> {
+        let mut docx = Docx::default();
+        docx.numbering
+            .get_or_insert_with(Numbering::default)
+            .push_list(AbstractNum::bullet());
+        docx.footnotes = Some(FootNotes {
+            content: vec![FootNote {
+                id: Some(1),
+                content: vec![Paragraph::default().push_text("note").into()],
+                ..Default::default()
+            }],
+        });
+        docx.comments = Some(Comments {
+            comments: vec![Comment {
+                id: Some(0),
+                author: "A".into(),
+                content: Paragraph::default().push_text("comment"),
+            }],
+        });
+        let mut para = Paragraph::default()
+            .push(BookmarkStart::default().id("0").name("intro"))
+            .push(CommentRangeStart::default().id("0"))
+            .push(
+                Run::default()
+                    .push_text("Intro")
+                    .push(FootnoteReference {
+                        id: Some("1".into()),
+                        ..Default::default()
+                    })
+                    .push(CommentReference {
+                        id: Some("0".into()),
+                    }),
+            )
+            .push(CommentRangeEnd::default().id("0"))
+            .push(BookmarkEnd::default().id("0"))
+            .push_field("PAGEREF intro \\h", "1");
+        para.id = Some("00000001".into());
+        docx.document.push(para);
+        docx
+    }
+
+    let docx = Docx::assemble([chapter(), chapter()]);
+
+    let notes: Vec<_> = docx
+        .footnotes
+        .as_ref()
+        .unwrap()
+        .content
+        .iter()
+        .map(|n| n.id)
+        .collect();
+    assert_eq!(notes, [Some(1), Some(2)]);
+    let comments: Vec<_> = docx
+        .comments
+        .as_ref()
+        .unwrap()
+        .comments
+        .iter()
+        .map(|c| c.id)
+        .collect();
+    assert_eq!(comments, [Some(0), Some(1)]);
+
+    let numbering = docx.numbering.as_ref().unwrap();
+    assert_ne!(
+        numbering.abstract_numberings[0].nsid.value,
+        numbering.abstract_numberings[1].nsid.value
+    );
+
+    let paras = docx.body_paragraphs();
+    assert_ne!(paras[0].id, paras[1].id);
+    let second = &paras[1];
+    let ids: Vec<_> = second
+        .content
+        .iter()
+        .filter_map(|content| match content {
+            ParagraphContent::BookmarkStart(start) => {
+                Some(format!("{:?} {:?}", start.id, start.name))
+            }
+            ParagraphContent::CommentRangeStart(start) => Some(start.id.to_string()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(ids, [r#"Some("1") Some("intro_2")"#, "1"]);
+    let references: Vec<_> = runs(second)
+        .into_iter()
+        .flat_map(|run| &run.content)
+        .filter_map(|content| match content {
+            RunContent::FootnoteReference(r) => r.id.as_deref().map(str::to_string),
+            RunContent::CommentReference(r) => r.id.as_deref().map(str::to_string),
+            RunContent::InstrText(instr) => Some(instr.text.to_string()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(references, ["2", "1", " PAGEREF intro_2 \\h "]);
+}
+
+#[test]
+fn append_charts() {
+    use crate::rels::Relationships;
+    use crate::schema::SCHEMA_CHART;
+
+    fn chapter() -> Docx<'static> {
+        let mut docx = Docx::default();
+        let mut rels = Relationships::default();
+        rels.add_rel(SCHEMA_PACKAGE, "../embeddings/Workbook.xlsx");
+        rels.add_rel(
+            "http://schemas.microsoft.com/office/2011/relationships/chartStyle",
+            "style1.xml",
+        );
+        docx.charts.insert(
+            "charts/chart1.xml".to_string(),
+            ChartPart {
+                xml: "<c:chartSpace/>".into(),
+                rels: Some(rels),
+            },
+        );
+        docx.charts.insert(
+            "charts/style1.xml".to_string(),
+            ChartPart {
+                xml: "<cs:chartStyle/>".into(),
+                rels: None,
+            },
+        );
+        docx.embeddings.insert(
+            "embeddings/Workbook.xlsx".to_string(),
+            Cow::Borrowed(&[0x50, 0x4B]),
+        );
+        docx.document_rels
+            .get_or_insert_with(Default::default)
+            .add_rel(SCHEMA_CHART, "charts/chart1.xml");
+        docx
+    }
+
+    let mut docx = Docx::default();
+    docx.append_document(chapter()).append_document(chapter());
+
+    let mut charts: Vec<_> = docx.charts.keys().cloned().collect();
+    charts.sort();
+    assert_eq!(
+        charts,
+        [
+            "charts/chart1-2.xml",
+            "charts/chart1.xml",
+            "charts/style1-2.xml",
+            "charts/style1.xml"
+        ]
+    );
+    let targets: Vec<_> = docx.charts["charts/chart1-2.xml"]
+        .rels
+        .as_ref()
+        .unwrap()
+        .relationships
+        .iter()
+        .map(|rel| rel.target.to_string())
+        .collect();
+    assert_eq!(targets, ["../embeddings/Workbook-2.xlsx", "style1-2.xml"]);
+    assert_eq!(docx.embeddings.len(), 2);
+    let rels = docx.document_rels.as_ref().unwrap();
+    assert!(rels.get_id("charts/chart1-2.xml").is_some());
+    assert_eq!(
+        docx.content_types.get("/word/charts/style1-2.xml"),
+        Some(crate::content_type::CONTENT_TYPE_CHART_STYLE)
+    );
+    assert!(docx
+        .content_types
+        .contains("/word/embeddings/Workbook-2.xlsx"));
+}
+
+#[test]
+fn clone_into() {
+    use crate::document::{AbstractNum, Numbering, Paragraph};
+    use crate::formatting::ParagraphProperty;
+
+    let list = |docx: &mut Docx<'static>, text: &'static str| {
+        let numbering = docx.numbering.get_or_insert_with(Numbering::default);
+        let id = numbering.push_list(AbstractNum::decimal());
+        docx.document.push(
+            Paragraph::default()
+                .property(ParagraphProperty::default().numbering((id, 0)))
+                .push_text(text),
+        );
+    };
+
+    let mut source = Docx::default();
+    list(&mut source, "not copied");
+    list(&mut source, "copied");
+    let mut target = Docx::default();
+    list(&mut target, "existing");
+
+    let copied = source.clone_into(1.., &mut target);
+    let numbering = target.numbering.as_ref().unwrap();
+    assert_eq!(numbering.numberings.len(), 2);
+    assert_eq!(numbering.abstract_numberings.len(), 2);
+    let id = match &copied[0] {
+        BodyContent::Paragraph(para) => para.property.as_ref().unwrap().numbering.clone(),
+        _ => None,
+    };
+    assert_eq!(id.and_then(|n| n.id).map(|id| id.value), Some(3));
+}
+
+#[test]
+fn clone_into_notes_comments_and_objects() {
+    use crate::document::{
+        Comment, CommentReference, Comments, FootNote, FootNotes, FootnoteReference, Object,
+        Paragraph, Run,
+    };
+
+    let mut source = Docx::default();
+    let rels = source.document_rels.get_or_insert_with(Default::default);
+    rels.add_rel(SCHEMA_OLE_OBJECT, "embeddings/oleObject1.bin");
+    let id = rels
+        .get_id("embeddings/oleObject1.bin")
+        .unwrap()
+        .to_string();
+    source.embeddings.insert(
+        "embeddings/oleObject1.bin".to_string(),
+        Cow::Borrowed(&[0xD0, 0xCF]),
+    );
+    source.footnotes = Some(FootNotes {
+        content: [1, 2]
+            .into_iter()
+            .map(|id| FootNote {
+                id: Some(id),
+                content: vec![Paragraph::default().push_text("note").into()],
+                ..Default::default()
+            })
+            .collect(),
+    });
+    source.comments = Some(Comments {
+        comments: vec![Comment {
+            id: Some(0),
+            author: "A".into(),
+            content: Paragraph::default().push_text("comment"),
+        }],
+    });
+    source.document.push(
+        Paragraph::default().push(
+            Run::default()
+                .push(FootnoteReference {
+                    id: Some("1".into()),
+                    ..Default::default()
+                })
+                .push(CommentReference {
+                    id: Some("0".into()),
+                }),
+        ),
+    );
+    let object = format!(r#"<w:object><o:OLEObject Type="Embed" r:id="{id}"/></w:object>"#);
+    source.document.push(
+        Paragraph::default().push(
+            Run::default()
+                .push(FootnoteReference {
+                    id: Some("2".into()),
+                    ..Default::default()
+                })
+                .push(RunContent::Object(Object { xml: object.into() })),
+        ),
+    );
+
+    let mut target = Docx::default();
+    let copied = source.clone_into(1.., &mut target);
+
+    let notes: Vec<_> = target
+        .footnotes
+        .as_ref()
+        .unwrap()
+        .content
+        .iter()
+        .map(|note| note.id)
+        .collect();
+    assert_eq!(notes, [Some(1)]);
+    assert!(target.comments.unwrap().comments.is_empty());
+    assert_eq!(target.embeddings.len(), 1);
+    let new_id = target
+        .document_rels
+        .as_ref()
+        .unwrap()
+        .get_id("embeddings/oleObject1.bin")
+        .unwrap();
+    let ids = match &copied[0] {
+        BodyContent::Paragraph(para) => relationship_ids(para)
+            .into_iter()
+            .map(str::to_string)
+            .collect(),
+        _ => Vec::new(),
+    };
+    assert_eq!(ids, [new_id]);
+}
+
+#[test]
+fn send_and_sync() {
+    fn check<T: Send + Sync>() {}
+
+    check::<Docx>();
+    check::<crate::DocxFile>();
+}
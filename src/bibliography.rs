@@ -0,0 +1,564 @@
+//! Bibliography Sources part
+//!
+//! The corresponding ZIP item is `/customXml/item{n}.xml`, a custom XML data
+//! part Word recognizes by the bibliography namespace of its root element.
+//!
+//! Citations and the bibliography itself are `CITATION` and `BIBLIOGRAPHY`
+//! fields, see [`Paragraph::push_citation`] and [`Docx::add_bibliography`].
+//!
+//! ```rust
+//! use docx_rust::bibliography::{Source, SourceType};
+//! use docx_rust::document::Paragraph;
+//! use docx_rust::Docx;
+//!
+//! let mut docx = Docx::default();
+//! docx.add_source(
+//!     Source::new("Knu84", SourceType::Book, "The TeXbook")
+//!         .person("Knuth", "Donald")
+//!         .year("1984")
+//!         .publisher("Addison-Wesley"),
+//! );
+//!
+//! let citation = docx.bibliography.as_ref().and_then(|b| b.citation("Knu84"));
+//! assert_eq!(citation.as_deref(), Some("(Knuth, 1984)"));
+//!
+//! docx.document.push(
+//!     Paragraph::default()
+//!         .push_text("As shown in ")
+//!         .push_citation("Knu84", citation.unwrap_or_default()),
+//! );
+//! docx.add_bibliography();
+//! ```
+//!
+//! [`Paragraph::push_citation`]: crate::document::Paragraph::push_citation
+//! [`Docx::add_bibliography`]: crate::Docx::add_bibliography
+
+use hard_xml::{XmlRead, XmlReader, XmlResult, XmlWrite, XmlWriter};
+use std::borrow::Cow;
+use std::io::Write;
+use xmlparser::{ElementEnd, Token, Tokenizer};
+
+use crate::document::read_element;
+use crate::schema::{write_raw, SCHEMA_BIBLIOGRAPHY, SCHEMA_XML};
+use crate::{__setter, __string_enum, __xml_test_suites};
+
+/// Returns whether a custom XML data part holds bibliography sources, from
+/// its root element.
+pub(crate) fn is_bibliography(xml: &str) -> bool {
+    let mut tokens = Tokenizer::from(xml).filter_map(Result::ok);
+    match tokens.find(|token| matches!(token, Token::ElementStart { .. })) {
+        Some(Token::ElementStart { prefix, local, .. })
+            if prefix.as_str() == "b" && local.as_str() == "Sources" => {}
+        _ => return false,
+    }
+    tokens
+        .take_while(|token| matches!(token, Token::Attribute { .. }))
+        .any(|token| {
+            matches!(token, Token::Attribute { prefix, local, value, .. }
+                if prefix.as_str() == "xmlns"
+                    && local.as_str() == "b"
+                    && value.as_str() == SCHEMA_BIBLIOGRAPHY)
+        })
+}
+
+/// The root element of the bibliography sources part.
+#[derive(Debug, Default, XmlRead, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "b:Sources")]
+pub struct Sources<'a> {
+    /// Specifies the style sheet of the citations, e.g. `\APASixthEditionOfficeOnline.xsl`
+    #[xml(attr = "SelectedStyle")]
+    pub selected_style: Option<Cow<'a, str>>,
+    /// Specifies the name of the citation style, e.g. `APA`
+    #[xml(attr = "StyleName")]
+    pub style_name: Option<Cow<'a, str>>,
+    /// Specifies the version of the citation style
+    #[xml(attr = "Version")]
+    pub version: Option<Cow<'a, str>>,
+    #[xml(child = "b:Source")]
+    pub sources: Vec<Source<'a>>,
+}
+
+impl<'a> Sources<'a> {
+    /// Returns the source with the given tag.
+    pub fn get(&self, tag: &str) -> Option<&Source<'a>> {
+        self.sources
+            .iter()
+            .find(|source| source.tag.as_deref() == Some(tag))
+    }
+
+    /// Adds a source, replacing the one with the same tag.
+    pub fn push(&mut self, source: Source<'a>) -> &mut Self {
+        let existing = self
+            .sources
+            .iter_mut()
+            .find(|s| s.tag.is_some() && s.tag == source.tag);
+        match existing {
+            Some(existing) => *existing = source,
+            None => self.sources.push(source),
+        }
+        self
+    }
+
+    /// Returns an author-date citation of the source with the given tag,
+    /// like `(Knuth, 1984)`, to show until Word updates the citation field.
+    pub fn citation(&self, tag: &str) -> Option<String> {
+        let source = self.get(tag)?;
+        let names: Vec<&str> = source
+            .author
+            .iter()
+            .filter_map(|author| author.author.as_ref())
+            .flat_map(|contributor| {
+                let people = contributor
+                    .names
+                    .iter()
+                    .flat_map(|names| names.people.iter())
+                    .filter_map(|person| person.last.as_deref());
+                contributor.corporate.as_deref().into_iter().chain(people)
+            })
+            .collect();
+
+        let who = match names[..] {
+            [] => source.title.as_deref().unwrap_or(tag).to_string(),
+            [name] => name.to_string(),
+            [first, second] => format!("{} and {}", first, second),
+            [first, ..] => format!("{} et al.", first),
+        };
+        Some(format!(
+            "({}, {})",
+            who,
+            source.year.as_deref().unwrap_or("n.d.")
+        ))
+    }
+}
+
+impl<'a> XmlWrite for Sources<'a> {
+    fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
+        let Sources {
+            selected_style,
+            style_name,
+            version,
+            sources,
+        } = self;
+
+        log::debug!("[Sources] Started writing.");
+        let _ = write!(writer.inner, "{}", SCHEMA_XML);
+
+        writer.write_element_start("b:Sources")?;
+        writer.write_attribute("xmlns:b", SCHEMA_BIBLIOGRAPHY)?;
+        writer.write_attribute("xmlns", SCHEMA_BIBLIOGRAPHY)?;
+        if let Some(selected_style) = selected_style {
+            writer.write_attribute("SelectedStyle", selected_style)?;
+        }
+        if let Some(style_name) = style_name {
+            writer.write_attribute("StyleName", style_name)?;
+        }
+        if let Some(version) = version {
+            writer.write_attribute("Version", version)?;
+        }
+
+        if sources.is_empty() {
+            writer.write_element_end_empty()?;
+        } else {
+            writer.write_element_end_open()?;
+            for source in sources {
+                source.to_writer(writer)?;
+            }
+            writer.write_element_end_close("b:Sources")?;
+        }
+
+        log::debug!("[Sources] Finished writing.");
+
+        Ok(())
+    }
+}
+
+/// A source cited in the document
+///
+/// The children that aren't modelled, e.g. `b:LCID` or `b:ConferenceName`,
+/// are kept as they were read, see [`Source::unknown`].
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+pub struct Source<'a> {
+    /// Specifies the tag citation fields refer to the source by
+    pub tag: Option<Cow<'a, str>>,
+    /// Specifies the kind of source, see [`SourceType`]
+    pub source_type: Option<Cow<'a, str>>,
+    pub guid: Option<Cow<'a, str>>,
+    pub author: Option<Contributors<'a>>,
+    pub title: Option<Cow<'a, str>>,
+    pub journal_name: Option<Cow<'a, str>>,
+    pub year: Option<Cow<'a, str>>,
+    pub month: Option<Cow<'a, str>>,
+    pub day: Option<Cow<'a, str>>,
+    pub city: Option<Cow<'a, str>>,
+    pub publisher: Option<Cow<'a, str>>,
+    pub edition: Option<Cow<'a, str>>,
+    pub volume: Option<Cow<'a, str>>,
+    pub issue: Option<Cow<'a, str>>,
+    pub pages: Option<Cow<'a, str>>,
+    pub url: Option<Cow<'a, str>>,
+    /// Specifies the position of the source in numbered citation styles
+    pub ref_order: Option<Cow<'a, str>>,
+    /// The markup of the children that aren't modelled, written as is after
+    /// the others
+    pub unknown: Vec<Cow<'a, str>>,
+}
+
+impl<'a> Source<'a> {
+    __setter!(guid: Option<Cow<'a, str>>);
+    __setter!(title: Option<Cow<'a, str>>);
+    __setter!(journal_name: Option<Cow<'a, str>>);
+    __setter!(year: Option<Cow<'a, str>>);
+    __setter!(month: Option<Cow<'a, str>>);
+    __setter!(day: Option<Cow<'a, str>>);
+    __setter!(city: Option<Cow<'a, str>>);
+    __setter!(publisher: Option<Cow<'a, str>>);
+    __setter!(edition: Option<Cow<'a, str>>);
+    __setter!(volume: Option<Cow<'a, str>>);
+    __setter!(issue: Option<Cow<'a, str>>);
+    __setter!(pages: Option<Cow<'a, str>>);
+    __setter!(url: Option<Cow<'a, str>>);
+
+    pub fn new<T, S>(tag: T, source_type: SourceType, title: S) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+        S: Into<Cow<'a, str>>,
+    {
+        Source {
+            tag: Some(tag.into()),
+            source_type: Some(source_type.to_string().into()),
+            title: Some(title.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Adds an author after the existing ones.
+    pub fn person<L, F>(mut self, last: L, first: F) -> Self
+    where
+        L: Into<Cow<'a, str>>,
+        F: Into<Cow<'a, str>>,
+    {
+        self.author
+            .get_or_insert_with(Default::default)
+            .author
+            .get_or_insert_with(Default::default)
+            .names
+            .get_or_insert_with(Default::default)
+            .people
+            .push(Person {
+                last: Some(last.into()),
+                first: Some(first.into()),
+                middle: None,
+            });
+        self
+    }
+
+    /// Sets an organization as the author.
+    pub fn corporate<T: Into<Cow<'a, str>>>(mut self, name: T) -> Self {
+        self.author = Some(Contributors {
+            author: Some(Contributor {
+                names: None,
+                corporate: Some(name.into()),
+            }),
+        });
+        self
+    }
+}
+
+impl<'a> XmlRead<'a> for Source<'a> {
+    fn from_reader(reader: &mut XmlReader<'a>) -> XmlResult<Self> {
+        let mut source = Source::default();
+
+        reader.read_till_element_start("b:Source")?;
+        while reader.find_attribute()?.is_some() {}
+        if let Some(Token::ElementEnd {
+            end: ElementEnd::Empty,
+            ..
+        }) = reader.next().transpose()?
+        {
+            return Ok(source);
+        }
+
+        while let Some(tag) = reader.find_element_start(Some("b:Source"))? {
+            let field = match tag {
+                "b:Author" => {
+                    source.author = Some(Contributors::from_reader(reader)?);
+                    continue;
+                }
+                "b:Tag" => &mut source.tag,
+                "b:SourceType" => &mut source.source_type,
+                "b:Guid" => &mut source.guid,
+                "b:Title" => &mut source.title,
+                "b:JournalName" => &mut source.journal_name,
+                "b:Year" => &mut source.year,
+                "b:Month" => &mut source.month,
+                "b:Day" => &mut source.day,
+                "b:City" => &mut source.city,
+                "b:Publisher" => &mut source.publisher,
+                "b:Edition" => &mut source.edition,
+                "b:Volume" => &mut source.volume,
+                "b:Issue" => &mut source.issue,
+                "b:Pages" => &mut source.pages,
+                "b:URL" => &mut source.url,
+                "b:RefOrder" => &mut source.ref_order,
+                _ => {
+                    source.unknown.push(read_element(reader)?.into());
+                    continue;
+                }
+            };
+            *field = Some(reader.read_text(tag)?);
+        }
+
+        Ok(source)
+    }
+}
+
+impl<'a> XmlWrite for Source<'a> {
+    fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
+        let Source {
+            tag,
+            source_type,
+            guid,
+            author,
+            title,
+            journal_name,
+            year,
+            month,
+            day,
+            city,
+            publisher,
+            edition,
+            volume,
+            issue,
+            pages,
+            url,
+            ref_order,
+            unknown,
+        } = self;
+
+        let before_author = [
+            ("b:Tag", tag),
+            ("b:SourceType", source_type),
+            ("b:Guid", guid),
+        ];
+        let after_author = [
+            ("b:Title", title),
+            ("b:JournalName", journal_name),
+            ("b:Year", year),
+            ("b:Month", month),
+            ("b:Day", day),
+            ("b:City", city),
+            ("b:Publisher", publisher),
+            ("b:Edition", edition),
+            ("b:Volume", volume),
+            ("b:Issue", issue),
+            ("b:Pages", pages),
+            ("b:URL", url),
+            ("b:RefOrder", ref_order),
+        ];
+
+        writer.write_element_start("b:Source")?;
+
+        if author.is_none()
+            && unknown.is_empty()
+            && before_author
+                .iter()
+                .chain(after_author.iter())
+                .all(|(_, text)| text.is_none())
+        {
+            return writer.write_element_end_empty();
+        }
+
+        writer.write_element_end_open()?;
+        for (name, text) in before_author {
+            if let Some(text) = text {
+                writer.write_flatten_text(name, text, false)?;
+            }
+        }
+        if let Some(author) = author {
+            author.to_writer(writer)?;
+        }
+        for (name, text) in after_author {
+            if let Some(text) = text {
+                writer.write_flatten_text(name, text, false)?;
+            }
+        }
+        for xml in unknown {
+            write_raw(writer, xml)?;
+        }
+        writer.write_element_end_close("b:Source")
+    }
+}
+
+/// The contributors of a source, the outer `b:Author` element
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "b:Author")]
+pub struct Contributors<'a> {
+    #[xml(child = "b:Author")]
+    pub author: Option<Contributor<'a>>,
+}
+
+/// The authors of a source, either people or an organization
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "b:Author")]
+pub struct Contributor<'a> {
+    #[xml(child = "b:NameList")]
+    pub names: Option<NameList<'a>>,
+    #[xml(flatten_text = "b:Corporate")]
+    pub corporate: Option<Cow<'a, str>>,
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "b:NameList")]
+pub struct NameList<'a> {
+    #[xml(child = "b:Person")]
+    pub people: Vec<Person<'a>>,
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "b:Person")]
+pub struct Person<'a> {
+    #[xml(flatten_text = "b:Last")]
+    pub last: Option<Cow<'a, str>>,
+    #[xml(flatten_text = "b:First")]
+    pub first: Option<Cow<'a, str>>,
+    #[xml(flatten_text = "b:Middle")]
+    pub middle: Option<Cow<'a, str>>,
+}
+
+/// Specifies the kind of a source
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceType {
+    Book,
+    BookSection,
+    JournalArticle,
+    ArticleInAPeriodical,
+    ConferenceProceedings,
+    Report,
+    InternetSite,
+    DocumentFromInternetSite,
+    ElectronicSource,
+    Art,
+    SoundRecording,
+    Performance,
+    Film,
+    Interview,
+    Patent,
+    Case,
+    Misc,
+}
+
+__string_enum! {
+    SourceType {
+        Book = "Book",
+        BookSection = "BookSection",
+        JournalArticle = "JournalArticle",
+        ArticleInAPeriodical = "ArticleInAPeriodical",
+        ConferenceProceedings = "ConferenceProceedings",
+        Report = "Report",
+        InternetSite = "InternetSite",
+        DocumentFromInternetSite = "DocumentFromInternetSite",
+        ElectronicSource = "ElectronicSource",
+        Art = "Art",
+        SoundRecording = "SoundRecording",
+        Performance = "Performance",
+        Film = "Film",
+        Interview = "Interview",
+        Patent = "Patent",
+        Case = "Case",
+        Misc = "Misc",
+    }
+}
+
+__xml_test_suites!(
+    Source,
+    Source::default(),
+    r#"<b:Source/>"#,
+    Source::new("Knu84", SourceType::Book, "The TeXbook")
+        .person("Knuth", "Donald")
+        .year("1984"),
+    r#"<b:Source><b:Tag>Knu84</b:Tag><b:SourceType>Book</b:SourceType><b:Author><b:Author><b:NameList><b:Person><b:Last>Knuth</b:Last><b:First>Donald</b:First></b:Person></b:NameList></b:Author></b:Author><b:Title>The TeXbook</b:Title><b:Year>1984</b:Year></b:Source>"#,
+);
+
+#[test]
+fn citation() {
+    let mut sources = Sources::default();
+    sources
+        .push(Source::new("a", SourceType::Book, "A").person("Doe", "Jane"))
+        .push(
+            Source::new("b", SourceType::Report, "B")
+                .person("Doe", "Jane")
+                .person("Roe", "Rick")
+                .year("2020"),
+        )
+        .push(
+            Source::new("c", SourceType::Misc, "C")
+                .person("Doe", "Jane")
+                .person("Roe", "Rick")
+                .person("Poe", "Edgar")
+                .year("2021"),
+        )
+        .push(Source::new("d", SourceType::InternetSite, "D").corporate("ACME"))
+        .push(Source::new("a", SourceType::Book, "Replaced").year("1999"));
+
+    assert_eq!(sources.sources.len(), 4);
+    assert_eq!(sources.citation("a").unwrap(), "(Replaced, 1999)");
+    assert_eq!(sources.citation("b").unwrap(), "(Doe and Roe, 2020)");
+    assert_eq!(sources.citation("c").unwrap(), "(Doe et al., 2021)");
+    assert_eq!(sources.citation("d").unwrap(), "(ACME, n.d.)");
+    assert_eq!(sources.citation("e"), None);
+}
+
+#[test]
+fn bibliography_part() {
+    use crate::custom_xml::DatastoreItem;
+    use crate::Docx;
+
+    assert!(is_bibliography(&format!(
+        r#"<?xml version="1.0"?><b:Sources xmlns:b="{}" SelectedStyle=""/>"#,
+        SCHEMA_BIBLIOGRAPHY
+    )));
+    assert!(!is_bibliography(&format!(
+        r#"<invoice><note xmlns:b="{}">b:Sources</note></invoice>"#,
+        SCHEMA_BIBLIOGRAPHY
+    )));
+
+    let mut docx = Docx::default();
+    docx.add_source(Source::new("a", SourceType::Book, "A"));
+    docx.bibliography_item = Some((
+        "item3.xml".to_string(),
+        Some(DatastoreItem::new(
+            "{1C2B3A4D-5E6F-4708-9A1B-2C3D4E5F6A7B}",
+            SCHEMA_BIBLIOGRAPHY,
+        )),
+    ));
+    docx.add_relationships();
+    let rels = docx.document_rels.as_ref().unwrap();
+    assert!(rels.get_id("../customXml/item3.xml").is_some());
+}
+
+#[test]
+fn unknown_children() -> XmlResult<()> {
+    let xml = r#"<b:Source><b:Tag>Knu84</b:Tag><b:LCID>1033</b:LCID><b:Title>The TeXbook</b:Title><b:ShortTitle><b:Sub>TeX</b:Sub></b:ShortTitle></b:Source>"#;
+
+    let source = Source::from_str(xml)?;
+    assert_eq!(source.title.as_deref(), Some("The TeXbook"));
+    assert_eq!(
+        source.unknown,
+        [
+            "<b:LCID>1033</b:LCID>",
+            "<b:ShortTitle><b:Sub>TeX</b:Sub></b:ShortTitle>"
+        ]
+    );
+    assert_eq!(
+        source.to_string()?,
+        r#"<b:Source><b:Tag>Knu84</b:Tag><b:Title>The TeXbook</b:Title><b:LCID>1033</b:LCID><b:ShortTitle><b:Sub>TeX</b:Sub></b:ShortTitle></b:Source>"#
+    );
+
+    Ok(())
+}
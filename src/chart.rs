@@ -0,0 +1,750 @@
+//! Charts
+//!
+//! The corresponding ZIP items are `/word/charts/chart{n}.xml`, holding a
+//! chart and the values it shows, and the workbook the data is edited in,
+//! usually `/word/embeddings/Microsoft_Excel_Worksheet{n}.xlsx`. The XML of
+//! a chart is kept as is, apart from the values cached in its series, which
+//! [`Docx::update_chart`] replaces along with the workbook.
+
+use std::{
+    borrow::Cow,
+    io::{Cursor, Error, ErrorKind, Write},
+    ops::Range,
+};
+
+use hard_xml::XmlError;
+use xmlparser::{ElementEnd, Token, Tokenizer};
+use zip::{write::SimpleFileOptions, ZipWriter};
+
+use crate::{
+    document::Drawing,
+    escape::{unescape, write_escaped},
+    rels::{Relationships, TargetMode},
+    repair::resolve,
+    schema::SCHEMA_PACKAGE,
+    Docx, DocxResult,
+};
+
+/// A chart part, or the style or colors part of a chart
+#[derive(Debug, Default, Clone)]
+pub struct ChartPart<'a> {
+    /// Specifies the XML of the part, written as is
+    pub xml: Cow<'a, str>,
+    /// Specifies the relationships of the part, e.g. to the workbook holding
+    /// the data of a chart
+    pub rels: Option<Relationships<'a>>,
+}
+
+/// A series of values of a chart
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ChartSeries {
+    /// Specifies the name, shown in the legend
+    pub name: String,
+    /// Specifies a value per category, `NaN` for a blank
+    pub values: Vec<f64>,
+}
+
+/// The data a chart shows
+///
+/// ```rust
+/// use docx_rust::chart::ChartData;
+///
+/// let data = ChartData::new(["Q1", "Q2"])
+///     .series("Sales", [4.3, 2.5])
+///     .series("Costs", [2.4, f64::NAN]);
+/// assert_eq!(data.series[0].values, [4.3, 2.5]);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ChartData {
+    /// Specifies the categories, the labels of the horizontal axis of most
+    /// charts
+    pub categories: Vec<String>,
+    pub series: Vec<ChartSeries>,
+}
+
+impl ChartData {
+    /// Creates data with the given categories and no series.
+    pub fn new<I, T>(categories: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        ChartData {
+            categories: categories.into_iter().map(Into::into).collect(),
+            series: Vec::new(),
+        }
+    }
+
+    /// Adds a series.
+    pub fn series<T, I>(mut self, name: T, values: I) -> Self
+    where
+        T: Into<String>,
+        I: IntoIterator<Item = f64>,
+    {
+        self.series.push(ChartSeries {
+            name: name.into(),
+            values: values.into_iter().collect(),
+        });
+        self
+    }
+
+    /// Returns the number of points of the series.
+    fn len(&self) -> usize {
+        self.series
+            .iter()
+            .map(|series| series.values.len())
+            .fold(self.categories.len(), usize::max)
+    }
+}
+
+impl<'a> ChartPart<'a> {
+    /// Returns the data cached in the chart: the categories of its first
+    /// series, and the name and values of every series, in order.
+    pub fn data(&self) -> DocxResult<ChartData> {
+        let series = read_series(&self.xml)?;
+        let mut data = ChartData::default();
+        if let Some(categories) = series.first().and_then(|s| s.reference(Role::Categories)) {
+            data.categories = categories
+                .values()
+                .into_iter()
+                .map(|value| value.unwrap_or_default())
+                .collect();
+        }
+        for s in &series {
+            let name = s
+                .reference(Role::Name)
+                .and_then(|name| name.values().into_iter().next().flatten());
+            let values = s
+                .reference(Role::Values)
+                .map(|values| values.values())
+                .unwrap_or_default();
+            data.series.push(ChartSeries {
+                name: name.unwrap_or_default(),
+                values: values
+                    .into_iter()
+                    .map(|value| value.and_then(|v| v.parse().ok()).unwrap_or(f64::NAN))
+                    .collect(),
+            });
+        }
+        Ok(data)
+    }
+
+    /// Replaces the data cached in the chart, keeping the formatting of its
+    /// series, see [`Docx::update_chart`].
+    pub fn set_data(&mut self, data: &ChartData) -> DocxResult<()> {
+        self.update(data)?;
+        Ok(())
+    }
+
+    /// Replaces the data cached in the chart, returning the name of the
+    /// sheet the series refer to.
+    fn update(&mut self, data: &ChartData) -> DocxResult<String> {
+        let xml = &*self.xml;
+        let series = read_series(xml)?;
+        let sheet = series
+            .iter()
+            .flat_map(|s| &s.references)
+            .find_map(|reference| reference.formula?.rsplit_once('!'))
+            .map_or("Sheet1", |(sheet, _)| sheet);
+
+        let mut out = Vec::with_capacity(xml.len());
+        let mut position = 0;
+        for (index, s) in series.iter().enumerate() {
+            out.write_all(&xml.as_bytes()[position..s.range.start])?;
+            if index < data.series.len() {
+                write_series(&mut out, xml, s, data, index, sheet, None)?;
+            }
+            position = s.range.end;
+        }
+        // the series added copy the formatting of the last one
+        if data.series.len() > series.len() {
+            let last = series.last().ok_or_else(|| {
+                Error::new(
+                    ErrorKind::InvalidInput,
+                    "the chart has no series to add others like",
+                )
+            })?;
+            let first = series
+                .iter()
+                .flat_map(|s| &s.numbers)
+                .filter_map(|range| xml[range.clone()].parse::<usize>().ok())
+                .max()
+                .map_or(0, |max| max + 1);
+            for (index, number) in (series.len()..data.series.len()).zip(first..) {
+                write_series(&mut out, xml, last, data, index, sheet, Some(number))?;
+            }
+        }
+        out.write_all(&xml.as_bytes()[position..])?;
+
+        let sheet = unquote(&unescape(sheet));
+        self.xml = Cow::Owned(String::from_utf8_lossy(&out).into_owned());
+        Ok(sheet)
+    }
+
+    /// Returns the name of the embedded workbook holding the data of the
+    /// chart, relative to `word/`, given the name of the chart part.
+    pub(crate) fn workbook_name(&self, name: &str) -> Option<String> {
+        let rel = self.rels.as_ref()?.relationships.iter().find(|rel| {
+            rel.ty == SCHEMA_PACKAGE && rel.target_mode != Some(TargetMode::External)
+        })?;
+        let folder = name.rsplit_once('/').map_or("", |(folder, _)| folder);
+        let target = resolve(&format!("/word/{}/", folder), &rel.target);
+        target.strip_prefix("/word/").map(str::to_string)
+    }
+}
+
+impl<'a> Docx<'a> {
+    /// Returns the name of the chart part a drawing shows, like
+    /// `charts/chart1.xml`, the key of [`Docx::charts`].
+    pub fn chart_name(&self, drawing: &Drawing) -> Option<&str> {
+        self.document_rels.as_ref()?.get_target(drawing.chart_id()?)
+    }
+
+    /// Replaces the data of a chart, returning `false` if there's no chart
+    /// part of that name.
+    ///
+    /// The values cached in the chart are replaced, and so is its embedded
+    /// workbook, by one holding the new data on a sheet of the same name:
+    /// the categories in the first column, and a column per series, its name
+    /// in the first row. Series are removed or added as needed, the added
+    /// ones formatted like the last one.
+    ///
+    /// ```rust
+    /// use docx_rust::chart::{ChartData, ChartPart};
+    /// use docx_rust::Docx;
+    ///
+    /// let mut docx = Docx::default();
+    /// docx.charts.insert(
+    ///     "charts/chart1.xml".to_string(),
+    ///     ChartPart {
+    ///         xml: r#"<c:chartSpace xmlns:c="http://schemas.openxmlformats.org/drawingml/2006/chart"><c:chart><c:plotArea><c:barChart><c:ser><c:idx val="0"/><c:order val="0"/><c:val><c:numRef><c:f>Sheet1!$B$2:$B$2</c:f><c:numCache><c:ptCount val="1"/><c:pt idx="0"><c:v>1</c:v></c:pt></c:numCache></c:numRef></c:val></c:ser></c:barChart></c:plotArea></c:chart></c:chartSpace>"#.into(),
+    ///         rels: None,
+    ///     },
+    /// );
+    ///
+    /// let data = ChartData::new(["January", "February"]).series("Revenue", [12.5, 14.0]);
+    /// assert!(docx.update_chart("charts/chart1.xml", &data)?);
+    /// let chart = &docx.charts["charts/chart1.xml"];
+    /// assert_eq!(chart.data()?.series[0].values, [12.5, 14.0]);
+    /// assert!(chart.xml.contains("<c:f>Sheet1!$B$2:$B$3</c:f>"));
+    /// # Ok::<(), docx_rust::DocxError>(())
+    /// ```
+    pub fn update_chart(&mut self, name: &str, data: &ChartData) -> DocxResult<bool> {
+        let chart = match self.charts.get_mut(name) {
+            Some(chart) => chart,
+            None => return Ok(false),
+        };
+        let sheet = chart.update(data)?;
+        if let Some(workbook_name) = chart.workbook_name(name) {
+            if let Some(embedding) = self.embeddings.get_mut(&workbook_name) {
+                *embedding = Cow::Owned(workbook(&sheet, data)?);
+            }
+        }
+        Ok(true)
+    }
+}
+
+/// The element of a series a reference is in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Role {
+    Name,
+    Categories,
+    Values,
+}
+
+impl Role {
+    fn of(local: &str) -> Option<Role> {
+        match local {
+            "tx" => Some(Role::Name),
+            "cat" | "xVal" => Some(Role::Categories),
+            "val" | "yVal" => Some(Role::Values),
+            _ => None,
+        }
+    }
+}
+
+/// A `strRef` or `numRef` element of a series, with its cached values
+struct Reference<'x> {
+    role: Role,
+    prefix: &'x str,
+    numeric: bool,
+    /// The content of the element, replaced when updating
+    content: Range<usize>,
+    formula: Option<&'x str>,
+    format_code: Option<&'x str>,
+    count: Option<usize>,
+    points: Vec<(usize, Cow<'x, str>)>,
+}
+
+impl<'x> Reference<'x> {
+    /// Returns the cached values by index.
+    fn values(&self) -> Vec<Option<String>> {
+        let len = self
+            .points
+            .iter()
+            .map(|(index, _)| index + 1)
+            .fold(self.count.unwrap_or(0), usize::max);
+        let mut values = vec![None; len];
+        for (index, value) in &self.points {
+            values[*index] = Some(value.to_string());
+        }
+        values
+    }
+}
+
+/// A `ser` element
+struct Series<'x> {
+    range: Range<usize>,
+    /// The values of its `idx` and `order` children
+    numbers: Vec<Range<usize>>,
+    references: Vec<Reference<'x>>,
+}
+
+impl<'x> Series<'x> {
+    fn reference(&self, role: Role) -> Option<&Reference<'x>> {
+        self.references
+            .iter()
+            .find(|reference| reference.role == role)
+    }
+}
+
+/// Reads the series of every chart of the plot area, in document order.
+fn read_series(xml: &str) -> Result<Vec<Series<'_>>, XmlError> {
+    let mut all = Vec::new();
+    let mut stack: Vec<&str> = Vec::new();
+    let mut series: Option<Series> = None;
+    let mut reference: Option<(Reference, Option<usize>)> = None;
+    let mut point = 0;
+
+    // the name of the element `n` levels above the current one
+    fn ancestor<'s>(stack: &[&'s str], n: usize) -> Option<&'s str> {
+        stack.len().checked_sub(n + 1).map(|index| stack[index])
+    }
+
+    for token in Tokenizer::from(xml) {
+        match token.map_err(XmlError::Parser)? {
+            Token::ElementStart {
+                prefix,
+                local,
+                span,
+            } => {
+                let local = local.as_str();
+                match local {
+                    "ser" => {
+                        series = Some(Series {
+                            range: span.start()..span.start(),
+                            numbers: Vec::new(),
+                            references: Vec::new(),
+                        })
+                    }
+                    "strRef" | "numRef"
+                        if series.is_some() && ancestor(&stack, 1) == Some("ser") =>
+                    {
+                        reference = ancestor(&stack, 0).and_then(Role::of).map(|role| {
+                            let reference = Reference {
+                                role,
+                                prefix: prefix.as_str(),
+                                numeric: local == "numRef",
+                                content: 0..0,
+                                formula: None,
+                                format_code: None,
+                                count: None,
+                                points: Vec::new(),
+                            };
+                            (reference, None)
+                        });
+                    }
+                    _ => (),
+                }
+                stack.push(local);
+            }
+            Token::Attribute { local, value, .. } => match (ancestor(&stack, 0), local.as_str()) {
+                (Some("idx" | "order"), "val") if ancestor(&stack, 1) == Some("ser") => {
+                    if let Some(series) = &mut series {
+                        series.numbers.push(value.start()..value.end());
+                    }
+                }
+                (Some("ptCount"), "val") => {
+                    if let Some((reference, _)) = &mut reference {
+                        reference.count = value.as_str().parse().ok();
+                    }
+                }
+                (Some("pt"), "idx") => point = value.as_str().parse().unwrap_or(0),
+                _ => (),
+            },
+            Token::ElementEnd { end, span } => match end {
+                ElementEnd::Open => {
+                    if let Some((_, start @ None)) = &mut reference {
+                        *start = Some(span.end());
+                    }
+                }
+                ElementEnd::Empty => {
+                    if matches!(stack.pop(), Some("strRef" | "numRef")) {
+                        reference = None;
+                    }
+                }
+                ElementEnd::Close(_, local) => {
+                    stack.pop();
+                    match local.as_str() {
+                        "strRef" | "numRef" => {
+                            if let Some((mut reference, Some(start))) = reference.take() {
+                                reference.content = start..span.start();
+                                if let Some(series) = &mut series {
+                                    series.references.push(reference);
+                                }
+                            }
+                        }
+                        "ser" => {
+                            if let Some(mut series) = series.take() {
+                                series.range.end = span.end();
+                                all.push(series);
+                            }
+                        }
+                        _ => (),
+                    }
+                }
+            },
+            Token::Text { text } => {
+                if let Some((reference, Some(_))) = &mut reference {
+                    match ancestor(&stack, 0) {
+                        Some("f") => reference.formula = Some(text.as_str()),
+                        Some("formatCode") => reference.format_code = Some(text.as_str()),
+                        Some("v") if ancestor(&stack, 1) == Some("pt") => {
+                            reference.points.push((point, unescape(text.as_str())))
+                        }
+                        _ => (),
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+
+    Ok(all)
+}
+
+/// Writes a series with the data of series `index`, renumbering it if
+/// `number` is given.
+fn write_series<W: Write>(
+    out: &mut W,
+    xml: &str,
+    series: &Series,
+    data: &ChartData,
+    index: usize,
+    sheet: &str,
+    number: Option<usize>,
+) -> DocxResult<()> {
+    let mut edits: Vec<(Range<usize>, Vec<u8>)> = Vec::new();
+    if let Some(number) = number {
+        for range in &series.numbers {
+            edits.push((range.clone(), number.to_string().into_bytes()));
+        }
+    }
+    for reference in &series.references {
+        let mut content = Vec::new();
+        write_reference(&mut content, reference, data, index, sheet)?;
+        edits.push((reference.content.clone(), content));
+    }
+    edits.sort_by_key(|(range, _)| range.start);
+
+    let mut position = series.range.start;
+    for (range, content) in edits {
+        out.write_all(&xml.as_bytes()[position..range.start])?;
+        out.write_all(&content)?;
+        position = range.end;
+    }
+    out.write_all(&xml.as_bytes()[position..series.range.end])?;
+    Ok(())
+}
+
+/// Writes the formula and cached values of a reference of series `index`.
+fn write_reference<W: Write>(
+    out: &mut W,
+    reference: &Reference,
+    data: &ChartData,
+    index: usize,
+    sheet: &str,
+) -> DocxResult<()> {
+    let tag = |local: &str| match reference.prefix {
+        "" => local.to_string(),
+        prefix => format!("{}:{}", prefix, local),
+    };
+    let series = &data.series[index];
+    let column = column_name(index + 1);
+    let last_row = data.len().max(1) + 1;
+
+    let (formula, count, points): (String, usize, Vec<(usize, Cow<str>)>) = match reference.role {
+        Role::Name => (
+            format!("{}!${}$1", sheet, column),
+            1,
+            vec![(0, Cow::Borrowed(series.name.as_str()))],
+        ),
+        Role::Categories => (
+            format!("{}!$A$2:$A${}", sheet, last_row),
+            data.len(),
+            data.categories
+                .iter()
+                .enumerate()
+                .filter(|(_, category)| !reference.numeric || category.parse::<f64>().is_ok())
+                .map(|(i, category)| (i, Cow::Borrowed(category.as_str())))
+                .collect(),
+        ),
+        Role::Values => (
+            format!("{}!${}$2:${}${}", sheet, column, column, last_row),
+            data.len(),
+            series
+                .values
+                .iter()
+                .enumerate()
+                .filter(|(_, value)| value.is_finite())
+                .map(|(i, value)| (i, Cow::Owned(value.to_string())))
+                .collect(),
+        ),
+    };
+
+    let cache = tag(if reference.numeric {
+        "numCache"
+    } else {
+        "strCache"
+    });
+    write!(out, "<{0}>{1}</{0}><{2}>", tag("f"), formula, cache)?;
+    if reference.numeric {
+        let format_code = reference.format_code.unwrap_or("General");
+        write!(out, "<{0}>{1}</{0}>", tag("formatCode"), format_code)?;
+    }
+    write!(out, r#"<{} val="{}"/>"#, tag("ptCount"), count)?;
+    for (i, value) in points {
+        write!(out, r#"<{} idx="{}"><{}>"#, tag("pt"), i, tag("v"))?;
+        write_escaped(out, &value)?;
+        write!(out, "</{}></{}>", tag("v"), tag("pt"))?;
+    }
+    write!(out, "</{}>", cache)?;
+    Ok(())
+}
+
+/// Returns the name of a spreadsheet column, counting from 0.
+fn column_name(mut index: usize) -> String {
+    let mut name = Vec::new();
+    loop {
+        name.push(b'A' + (index % 26) as u8);
+        if index < 26 {
+            break;
+        }
+        index = index / 26 - 1;
+    }
+    name.reverse();
+    String::from_utf8_lossy(&name).into_owned()
+}
+
+/// Removes the quotes around a sheet name of a formula, like `'My data'`.
+fn unquote(sheet: &str) -> String {
+    match sheet.strip_prefix('\'').and_then(|s| s.strip_suffix('\'')) {
+        Some(quoted) => quoted.replace("''", "'"),
+        None => sheet.to_string(),
+    }
+}
+
+/// Returns a workbook holding the data on a single sheet.
+fn workbook(sheet: &str, data: &ChartData) -> DocxResult<Vec<u8>> {
+    const SPREADSHEET: &str = "http://schemas.openxmlformats.org/spreadsheetml/2006/main";
+
+    let mut worksheet = Vec::new();
+    write!(
+        worksheet,
+        r#"{}<worksheet xmlns="{}"><sheetData><row r="1">"#,
+        crate::schema::SCHEMA_XML,
+        SPREADSHEET
+    )?;
+    let text_cell = |out: &mut Vec<u8>, cell: String, text: &str| -> DocxResult<()> {
+        write!(out, r#"<c r="{}" t="inlineStr"><is><t>"#, cell)?;
+        write_escaped(out, text)?;
+        out.write_all(b"</t></is></c>")?;
+        Ok(())
+    };
+    for (index, series) in data.series.iter().enumerate() {
+        text_cell(
+            &mut worksheet,
+            format!("{}1", column_name(index + 1)),
+            &series.name,
+        )?;
+    }
+    worksheet.write_all(b"</row>")?;
+    for point in 0..data.len() {
+        let row = point + 2;
+        write!(worksheet, r#"<row r="{}">"#, row)?;
+        if let Some(category) = data.categories.get(point) {
+            text_cell(&mut worksheet, format!("A{}", row), category)?;
+        }
+        for (index, series) in data.series.iter().enumerate() {
+            if let Some(value) = series.values.get(point).filter(|value| value.is_finite()) {
+                let cell = format!("{}{}", column_name(index + 1), row);
+                write!(worksheet, r#"<c r="{}"><v>{}</v></c>"#, cell, value)?;
+            }
+        }
+        worksheet.write_all(b"</row>")?;
+    }
+    worksheet.write_all(b"</sheetData></worksheet>")?;
+
+    let mut workbook = Vec::new();
+    write!(
+        workbook,
+        r#"{}<workbook xmlns="{}" xmlns:r="{}"><sheets><sheet name=""#,
+        crate::schema::SCHEMA_XML,
+        SPREADSHEET,
+        crate::schema::SCHEMA_RELATIONSHIPS_DOCUMENT
+    )?;
+    write_escaped(&mut workbook, sheet)?;
+    workbook.write_all(br#"" sheetId="1" r:id="rId1"/></sheets></workbook>"#)?;
+
+    let parts: [(&str, &[u8]); 5] = [
+        (
+            "[Content_Types].xml",
+            concat!(
+                r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+                r#"<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">"#,
+                r#"<Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>"#,
+                r#"<Default Extension="xml" ContentType="application/xml"/>"#,
+                r#"<Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/>"#,
+                r#"<Override PartName="/xl/worksheets/sheet1.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/>"#,
+                r#"</Types>"#,
+            )
+            .as_bytes(),
+        ),
+        (
+            "_rels/.rels",
+            concat!(
+                r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+                r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">"#,
+                r#"<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/>"#,
+                r#"</Relationships>"#,
+            )
+            .as_bytes(),
+        ),
+        ("xl/workbook.xml", &workbook),
+        (
+            "xl/_rels/workbook.xml.rels",
+            concat!(
+                r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+                r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">"#,
+                r#"<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet1.xml"/>"#,
+                r#"</Relationships>"#,
+            )
+            .as_bytes(),
+        ),
+        ("xl/worksheets/sheet1.xml", &worksheet),
+    ];
+
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    for (name, data) in parts {
+        zip.start_file(name, SimpleFileOptions::default())?;
+        zip.write_all(data)?;
+    }
+    Ok(zip.finish()?.into_inner())
+}
+
+#[test]
+fn update_chart() {
+    use crate::rels::Relationship;
+    use std::io::Read;
+
+    let series = |n: usize, name: &str, value: &str| {
+        format!(
+            concat!(
+                r#"<c:ser><c:idx val="{0}"/><c:order val="{0}"/>"#,
+                r#"<c:tx><c:strRef><c:f>'R&amp;D'!${1}$1</c:f><c:strCache><c:ptCount val="1"/><c:pt idx="0"><c:v>{2}</c:v></c:pt></c:strCache></c:strRef></c:tx>"#,
+                r#"<c:spPr><a:solidFill><a:schemeClr val="accent{3}"/></a:solidFill></c:spPr>"#,
+                r#"<c:cat><c:strRef><c:f>'R&amp;D'!$A$2:$A$2</c:f><c:strCache><c:ptCount val="1"/><c:pt idx="0"><c:v>A &amp; B</c:v></c:pt></c:strCache></c:strRef></c:cat>"#,
+                r#"<c:val><c:numRef><c:f>'R&amp;D'!${1}$2:${1}$2</c:f><c:numCache><c:formatCode>0.0</c:formatCode><c:ptCount val="1"/><c:pt idx="0"><c:v>{4}</c:v></c:pt></c:numCache></c:numRef></c:val>"#,
+                r#"</c:ser>"#,
+            ),
+            n,
+            column_name(n + 1),
+            name,
+            n + 1,
+            value
+        )
+    };
+    let xml = format!(
+        r#"<c:chartSpace><c:chart><c:plotArea><c:barChart>{}{}<c:axId val="1"/></c:barChart></c:plotArea></c:chart><c:externalData r:id="rId1"/></c:chartSpace>"#,
+        series(0, "North", "1.5"),
+        series(1, "South", "2")
+    );
+
+    let mut docx = Docx::default();
+    docx.charts.insert(
+        "charts/chart1.xml".to_string(),
+        ChartPart {
+            xml: xml.into(),
+            rels: Some(Relationships {
+                relationships: vec![Relationship {
+                    id: "rId1".into(),
+                    ty: SCHEMA_PACKAGE.into(),
+                    target: "../embeddings/Microsoft_Excel_Worksheet.xlsx".into(),
+                    target_mode: None,
+                }],
+            }),
+        },
+    );
+    docx.embeddings.insert(
+        "embeddings/Microsoft_Excel_Worksheet.xlsx".to_string(),
+        Cow::Borrowed(b"PK"),
+    );
+
+    let chart = &docx.charts["charts/chart1.xml"];
+    assert_eq!(
+        chart.data().unwrap(),
+        ChartData::new(["A & B"])
+            .series("North", [1.5])
+            .series("South", [2.0])
+    );
+
+    let data = ChartData::new(["Q1", "Q2 & Q3", "Q4"])
+        .series("North", [1.0, 2.0, 3.0])
+        .series("South", [4.0, f64::NAN, 6.0])
+        .series("East", [7.0, 8.0]);
+    assert!(docx.update_chart("charts/chart1.xml", &data).unwrap());
+    assert!(!docx.update_chart("charts/chart2.xml", &data).unwrap());
+
+    let chart = &docx.charts["charts/chart1.xml"];
+    let mut read = chart.data().unwrap();
+    assert!(read.series[1].values[1].is_nan());
+    read.series[1].values[1] = 5.0;
+    let mut expected = data.clone();
+    expected.series[1].values[1] = 5.0;
+    expected.series[2].values.push(f64::NAN);
+    assert!(read.series[2].values[2].is_nan());
+    read.series[2].values[2] = f64::NAN;
+    assert_eq!(read.categories, expected.categories);
+    assert_eq!(read.series[0], expected.series[0]);
+    assert_eq!(read.series[1], expected.series[1]);
+    assert!(chart.xml.contains(r#"<c:f>'R&amp;D'!$D$2:$D$4</c:f>"#));
+    assert!(chart.xml.contains(r#"<c:idx val="2"/><c:order val="2"/>"#));
+    assert!(chart.xml.contains("accent2"));
+    assert!(chart.xml.contains("<c:formatCode>0.0</c:formatCode>"));
+    assert!(chart.xml.ends_with(r#"<c:axId val="1"/></c:barChart></c:plotArea></c:chart><c:externalData r:id="rId1"/></c:chartSpace>"#));
+
+    let workbook = &docx.embeddings["embeddings/Microsoft_Excel_Worksheet.xlsx"];
+    let mut zip = zip::ZipArchive::new(Cursor::new(workbook.to_vec())).unwrap();
+    let mut sheet = String::new();
+    zip.by_name("xl/worksheets/sheet1.xml")
+        .unwrap()
+        .read_to_string(&mut sheet)
+        .unwrap();
+    assert!(sheet.contains(r#"<c r="D1" t="inlineStr"><is><t>East</t></is></c>"#));
+    assert!(sheet.contains(r#"<row r="3"><c r="A3" t="inlineStr"><is><t>Q2 &amp; Q3</t></is></c><c r="B3"><v>2</v></c><c r="D3"><v>8</v></c></row>"#));
+    let mut book = String::new();
+    zip.by_name("xl/workbook.xml")
+        .unwrap()
+        .read_to_string(&mut book)
+        .unwrap();
+    assert!(book.contains(r#"<sheet name="R&amp;D""#));
+
+    docx.update_chart(
+        "charts/chart1.xml",
+        &ChartData::new(["Q1"]).series("Total", [1.0]),
+    )
+    .unwrap();
+    let chart = &docx.charts["charts/chart1.xml"];
+    assert_eq!(chart.data().unwrap().series.len(), 1);
+    assert_eq!(column_name(27), "AB");
+}
@@ -0,0 +1,130 @@
+//! Clipboard formats for copying a part of the document
+//!
+//! Word and browsers read the "HTML Format" clipboard format (CF_HTML), an
+//! HTML fragment with a header giving its offsets. Word also reads a whole
+//! package as the "docx chunk", keeping the styles and numbering.
+
+use std::ops::{Bound, RangeBounds};
+
+use crate::{document::BodyContent, error::DocxResult, Docx};
+
+const CF_HTML_START: &str = "<html>\r\n<body>\r\n<!--StartFragment-->";
+const CF_HTML_END: &str = "<!--EndFragment-->\r\n</body>\r\n</html>";
+
+impl<'a> Docx<'a> {
+    /// Returns a copy of the document with only the given body blocks,
+    /// paragraphs and tables being indexes of `document.body.content`.
+    ///
+    /// The section properties at the end of the body are kept.
+    pub fn fragment<R: RangeBounds<usize>>(&self, blocks: R) -> Docx<'a> {
+        let content = &self.document.body.content;
+        let start = match blocks.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match blocks.end_bound() {
+            Bound::Included(&end) => end + 1,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => content.len(),
+        };
+        let end = end.min(content.len());
+        let start = start.min(end);
+
+        let mut body = content[start..end].to_vec();
+        if let Some(section @ BodyContent::SectionProperty(_)) = content.last() {
+            if !matches!(body.last(), Some(BodyContent::SectionProperty(_))) {
+                body.push(section.clone());
+            }
+        }
+
+        let mut fragment = self.clone();
+        fragment.document.body.content = body;
+        fragment
+    }
+
+    /// Converts body blocks to the CF_HTML clipboard format, see
+    /// [`Docx::fragment`] and [`Docx::to_html`].
+    ///
+    /// ```rust
+    /// use docx_rust::Docx;
+    ///
+    /// let mut docx = Docx::default();
+    /// docx.add_paragraph("Not copied");
+    /// docx.add_paragraph("Copied");
+    ///
+    /// let html = docx.to_cf_html(1..);
+    /// assert!(html.starts_with("Version:0.9\r\nStartHTML:0000000105\r\n"));
+    /// assert!(html.contains("<!--StartFragment--><p>Copied</p>\n<!--EndFragment-->"));
+    /// ```
+    pub fn to_cf_html<R: RangeBounds<usize>>(&self, blocks: R) -> String {
+        cf_html(&self.fragment(blocks).to_html())
+    }
+
+    /// Writes body blocks as a package, the "docx chunk" Word puts on the
+    /// clipboard, see [`Docx::fragment`].
+    pub fn to_docx_chunk<R: RangeBounds<usize>>(&self, blocks: R) -> DocxResult<Vec<u8>> {
+        self.fragment(blocks).write_to_vec()
+    }
+}
+
+/// Wraps an HTML fragment into the CF_HTML format, whose header gives the
+/// byte offsets of the document and the fragment.
+fn cf_html(fragment: &str) -> String {
+    // the header has a fixed length as the offsets have ten digits
+    let header_len = "Version:0.9\r\n".len()
+        + ["StartHTML", "EndHTML", "StartFragment", "EndFragment"]
+            .iter()
+            .map(|name| name.len() + ":0000000000\r\n".len())
+            .sum::<usize>();
+    let start_fragment = header_len + CF_HTML_START.len();
+    let end_fragment = start_fragment + fragment.len();
+    let end_html = end_fragment + CF_HTML_END.len();
+
+    format!(
+        "Version:0.9\r\n\
+         StartHTML:{:010}\r\n\
+         EndHTML:{:010}\r\n\
+         StartFragment:{:010}\r\n\
+         EndFragment:{:010}\r\n\
+         {}{}{}",
+        header_len, end_html, start_fragment, end_fragment, CF_HTML_START, fragment, CF_HTML_END
+    )
+}
+
+#[test]
+fn clipboard() {
+    use crate::formatting::SectionProperty;
+
+    let html = cf_html("<p>é</p>");
+    let offset = |name: &str| -> usize {
+        let start = html.find(name).unwrap() + name.len() + 1;
+        html[start..start + 10].parse().unwrap()
+    };
+    assert_eq!(
+        &html[offset("StartHTML")..offset("EndHTML")],
+        format!("{}<p>é</p>{}", CF_HTML_START, CF_HTML_END)
+    );
+    assert_eq!(
+        &html[offset("StartFragment")..offset("EndFragment")],
+        "<p>é</p>"
+    );
+    assert_eq!(offset("EndHTML"), html.len());
+
+    let mut docx = Docx::default();
+    docx.add_paragraph("one");
+    docx.add_paragraph("two");
+    docx.add_paragraph("three");
+    docx.document
+        .push(BodyContent::SectionProperty(SectionProperty::default()));
+
+    let fragment = docx.fragment(1..=1);
+    assert_eq!(fragment.document.body.content.len(), 2);
+    assert_eq!(fragment.document.body.text(), "two");
+    assert!(matches!(
+        fragment.document.body.content[1],
+        BodyContent::SectionProperty(_)
+    ));
+    assert_eq!(docx.fragment(..).document.body.content.len(), 4);
+    assert_eq!(docx.fragment(5..).document.body.content.len(), 1);
+}
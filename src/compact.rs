@@ -0,0 +1,141 @@
+//! Shrinking the XML of generated documents
+
+use crate::{
+    document::{Paragraph, ParagraphContent, Run, RunContent, TextSpace},
+    Docx,
+};
+
+impl<'a> Docx<'a> {
+    /// Merges adjacent runs with the same properties, and removes empty
+    /// paragraph and run properties.
+    ///
+    /// Documents built run by run, e.g. by [`Paragraph::push_text`], often
+    /// repeat the same properties for every run. Only runs of text, tabs and
+    /// breaks are merged, so fields, notes and pictures keep their own runs.
+    /// See [`WriteOptions::compact_runs`] to do it when writing.
+    ///
+    /// ```rust
+    /// use docx_rust::document::{BodyContent, Paragraph};
+    /// use docx_rust::Docx;
+    ///
+    /// let mut docx = Docx::default();
+    /// docx.document
+    ///     .push(Paragraph::default().push_text("Hello, ").push_text("world"));
+    /// docx.compact_runs();
+    ///
+    /// match &docx.document.body.content[0] {
+    ///     BodyContent::Paragraph(para) => assert_eq!(para.content.len(), 1),
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    ///
+    /// [`WriteOptions::compact_runs`]: crate::WriteOptions::compact_runs
+    pub fn compact_runs(&mut self) {
+        for para in self.paragraphs_mut() {
+            compact_paragraph(para);
+        }
+    }
+}
+
+fn compact_paragraph(para: &mut Paragraph) {
+    if para.property.as_ref().is_some_and(is_default) {
+        para.property = None;
+    }
+
+    let mut content: Vec<ParagraphContent> = Vec::with_capacity(para.content.len());
+    for mut next in para.content.drain(..) {
+        if let ParagraphContent::Run(run) = &mut next {
+            if run.property.as_ref().is_some_and(is_default) {
+                run.property = None;
+            }
+        }
+        match (content.last_mut(), next) {
+            (Some(ParagraphContent::Run(run)), ParagraphContent::Run(next))
+                if can_merge(run, &next) =>
+            {
+                merge_run(run, next)
+            }
+            (_, next) => content.push(next),
+        }
+    }
+    para.content = content;
+}
+
+/// Returns whether the properties are empty.
+fn is_default<T: Default + PartialEq>(property: &T) -> bool {
+    *property == T::default()
+}
+
+fn can_merge(run: &Run, next: &Run) -> bool {
+    let plain = |run: &Run| {
+        run.content.iter().all(|content| {
+            matches!(
+                content,
+                RunContent::Text(_)
+                    | RunContent::Tab(_)
+                    | RunContent::Break(_)
+                    | RunContent::NoBreakHyphen(_)
+                    | RunContent::SoftHyphen(_)
+            )
+        })
+    };
+
+    plain(run)
+        && plain(next)
+        && run.rsid_r == next.rsid_r
+        && run.rsid_r_pr == next.rsid_r_pr
+        && run.rsid_del == next.rsid_del
+        && run.property == next.property
+}
+
+fn merge_run<'a>(run: &mut Run<'a>, next: Run<'a>) {
+    for content in next.content {
+        match (run.content.last_mut(), content) {
+            (Some(RunContent::Text(text)), RunContent::Text(next)) => {
+                text.text.to_mut().push_str(&next.text);
+                if !matches!(
+                    (&text.space, &next.space),
+                    (Some(TextSpace::Preserve), Some(TextSpace::Preserve))
+                        | (Some(TextSpace::Default), Some(TextSpace::Default))
+                        | (None, None)
+                ) {
+                    text.space = None;
+                }
+            }
+            (_, content) => run.content.push(content),
+        }
+    }
+}
+
+#[test]
+fn compact_runs() {
+    use crate::document::{BodyContent, Break};
+    use crate::formatting::{CharacterProperty, ParagraphProperty};
+
+    let mut docx = Docx::default();
+    docx.document.push(
+        Paragraph::default()
+            .property(ParagraphProperty::default())
+            .push_text("a")
+            .push(Run::default().push_text("b").push_break(Break::default()))
+            .push(
+                Run::default()
+                    .property(CharacterProperty::default().bold(true))
+                    .push_text("c"),
+            )
+            .push(
+                Run::default()
+                    .property(CharacterProperty::default().bold(true))
+                    .push_text("d"),
+            ),
+    );
+    docx.compact_runs();
+
+    let para = match &docx.document.body.content[0] {
+        BodyContent::Paragraph(para) => para,
+        _ => unreachable!(),
+    };
+    assert!(para.property.is_none());
+    assert_eq!(para.content.len(), 2);
+    assert_eq!(para.text(), "abcd");
+}
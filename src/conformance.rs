@@ -0,0 +1,153 @@
+//! Strict and transitional conformance
+//!
+//! ISO/IEC 29500 Strict documents use their own namespaces and relationship
+//! types, under `http://purl.oclc.org/ooxml/`, for the same elements as
+//! transitional ones. Strict parts are read by mapping their namespaces to
+//! the transitional ones, and written by mapping them back, depending on
+//! [`Docx::conformance`].
+//!
+//! [`Docx::conformance`]: crate::Docx::conformance
+
+use std::borrow::Cow;
+
+use crate::extra_attributes::merge_root_attributes;
+use crate::DocxResult;
+
+/// The conformance class of a document
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Conformance {
+    /// Uses the transitional namespaces, as most consumers expect
+    #[default]
+    Transitional,
+    /// Uses the strict namespaces, as required by some archives and
+    /// government systems
+    Strict,
+}
+
+/// The transitional namespaces and relationship types, with their strict
+/// counterparts, longest first where one starts another
+const NAMESPACES: &[(&str, &str)] = &[
+    (
+        "http://schemas.openxmlformats.org/officeDocument/2006/relationships/extended-properties",
+        "http://purl.oclc.org/ooxml/officeDocument/relationships/extendedProperties",
+    ),
+    (
+        "http://schemas.openxmlformats.org/officeDocument/2006/relationships",
+        "http://purl.oclc.org/ooxml/officeDocument/relationships",
+    ),
+    (
+        "http://schemas.openxmlformats.org/officeDocument/2006/extended-properties",
+        "http://purl.oclc.org/ooxml/officeDocument/extendedProperties",
+    ),
+    (
+        "http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes",
+        "http://purl.oclc.org/ooxml/officeDocument/docPropsVTypes",
+    ),
+    (
+        "http://schemas.openxmlformats.org/officeDocument/2006/bibliography",
+        "http://purl.oclc.org/ooxml/officeDocument/bibliography",
+    ),
+    (
+        "http://schemas.openxmlformats.org/officeDocument/2006/customXml",
+        "http://purl.oclc.org/ooxml/officeDocument/customXml",
+    ),
+    (
+        "http://schemas.openxmlformats.org/officeDocument/2006/math",
+        "http://purl.oclc.org/ooxml/officeDocument/math",
+    ),
+    (
+        "http://schemas.openxmlformats.org/wordprocessingml/2006/main",
+        "http://purl.oclc.org/ooxml/wordprocessingml/main",
+    ),
+    (
+        "http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing",
+        "http://purl.oclc.org/ooxml/drawingml/wordprocessingDrawing",
+    ),
+    (
+        "http://schemas.openxmlformats.org/drawingml/2006/picture",
+        "http://purl.oclc.org/ooxml/drawingml/picture",
+    ),
+    (
+        "http://schemas.openxmlformats.org/drawingml/2006/main",
+        "http://purl.oclc.org/ooxml/drawingml/main",
+    ),
+];
+
+/// The attribute of the main document root declaring its conformance class
+pub(crate) const CONFORMANCE: &str = "w:conformance";
+
+const STRICT_PREFIX: &str = "http://purl.oclc.org/ooxml/";
+
+/// Replaces the namespaces starting attribute values, i.e. those followed
+/// by the closing quote or by a `/`, as relationship types are.
+fn replace(xml: &str, strict: bool) -> Cow<'_, str> {
+    let mut xml = Cow::Borrowed(xml);
+    for &(transitional, strict_namespace) in NAMESPACES {
+        let (from, to) = if strict {
+            (transitional, strict_namespace)
+        } else {
+            (strict_namespace, transitional)
+        };
+        for quote in ['"', '\''] {
+            for end in [quote, '/'] {
+                let pattern = format!("{}{}{}", quote, from, end);
+                if xml.contains(&pattern) {
+                    xml = Cow::Owned(xml.replace(&pattern, &format!("{}{}{}", quote, to, end)));
+                }
+            }
+        }
+    }
+    xml
+}
+
+/// Maps the strict namespaces of a part to the transitional ones, returning
+/// `None` if it has none.
+pub(crate) fn to_transitional(xml: &str) -> Option<String> {
+    if !xml.contains(STRICT_PREFIX) {
+        return None;
+    }
+    match replace(xml, false) {
+        Cow::Owned(xml) => Some(xml),
+        Cow::Borrowed(_) => None,
+    }
+}
+
+/// Maps the transitional namespaces of a serialized part to the strict ones,
+/// marking the main document part as strict.
+pub(crate) fn to_strict(name: &str, xml: &[u8]) -> DocxResult<Vec<u8>> {
+    let text = match std::str::from_utf8(xml) {
+        Ok(text) => text,
+        Err(_) => return Ok(xml.to_vec()),
+    };
+    let xml = replace(text, true).into_owned().into_bytes();
+    if name == "word/document.xml" {
+        merge_root_attributes(&xml, &[(CONFORMANCE.to_string(), "strict".to_string())])
+    } else {
+        Ok(xml)
+    }
+}
+
+#[test]
+fn strict_namespaces() {
+    let transitional = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><w:body><w:t>http://schemas.openxmlformats.org/wordprocessingml/2006/main</w:t></w:body></w:document>"#;
+    let strict =
+        String::from_utf8(to_strict("word/document.xml", transitional.as_bytes()).unwrap())
+            .unwrap();
+    assert_eq!(
+        strict,
+        r#"<w:document xmlns:w="http://purl.oclc.org/ooxml/wordprocessingml/main" xmlns:r="http://purl.oclc.org/ooxml/officeDocument/relationships" w:conformance="strict"><w:body><w:t>http://schemas.openxmlformats.org/wordprocessingml/2006/main</w:t></w:body></w:document>"#
+    );
+    assert!(to_transitional(transitional).is_none());
+    assert_eq!(
+        to_transitional(&strict).unwrap(),
+        transitional.replace("><w:body>", r#" w:conformance="strict"><w:body>"#)
+    );
+
+    let rel = r#"<Relationship Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/extended-properties"/>"#;
+    let strict = String::from_utf8(to_strict("_rels/.rels", rel.as_bytes()).unwrap()).unwrap();
+    assert_eq!(
+        strict,
+        r#"<Relationship Type="http://purl.oclc.org/ooxml/officeDocument/relationships/extendedProperties"/>"#
+    );
+    assert_eq!(to_transitional(&strict).unwrap(), rel);
+}
@@ -8,15 +8,99 @@ use std::io::Write;
 
 use crate::schema::SCHEMA_CONTENT_TYPES;
 
-const CONTENT_TYPE_XML: &str = "application/xml";
-const CONTENT_TYPE_CORE: &str = "application/vnd.openxmlformats-package.core-properties+xml";
+pub(crate) const CONTENT_TYPE_XML: &str = "application/xml";
+pub(crate) const CONTENT_TYPE_CORE: &str =
+    "application/vnd.openxmlformats-package.core-properties+xml";
 const CONTENT_TYPE_RELATIONSHIP: &str = "application/vnd.openxmlformats-package.relationships+xml";
-const CONTENT_TYPE_EXTENDED: &str =
+pub(crate) const CONTENT_TYPE_EXTENDED: &str =
     "application/vnd.openxmlformats-officedocument.extended-properties+xml";
-const CONTENT_TYPE_DOCUMENT: &str =
+pub(crate) const CONTENT_TYPE_DOCUMENT: &str =
     "application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml";
-const CONTENT_TYPE_STYLES: &str =
+pub(crate) const CONTENT_TYPE_STYLES: &str =
     "application/vnd.openxmlformats-officedocument.wordprocessingml.styles+xml";
+pub(crate) const CONTENT_TYPE_SETTINGS: &str =
+    "application/vnd.openxmlformats-officedocument.wordprocessingml.settings+xml";
+pub(crate) const CONTENT_TYPE_WEB_SETTINGS: &str =
+    "application/vnd.openxmlformats-officedocument.wordprocessingml.webSettings+xml";
+pub(crate) const CONTENT_TYPE_FOOTNOTES: &str =
+    "application/vnd.openxmlformats-officedocument.wordprocessingml.footnotes+xml";
+pub(crate) const CONTENT_TYPE_ENDNOTES: &str =
+    "application/vnd.openxmlformats-officedocument.wordprocessingml.endnotes+xml";
+pub(crate) const CONTENT_TYPE_COMMENTS: &str =
+    "application/vnd.openxmlformats-officedocument.wordprocessingml.comments+xml";
+pub(crate) const CONTENT_TYPE_HEADER: &str =
+    "application/vnd.openxmlformats-officedocument.wordprocessingml.header+xml";
+pub(crate) const CONTENT_TYPE_FOOTER: &str =
+    "application/vnd.openxmlformats-officedocument.wordprocessingml.footer+xml";
+pub(crate) const CONTENT_TYPE_COMMENTS_EXTENDED: &str =
+    "application/vnd.openxmlformats-officedocument.wordprocessingml.commentsExtended+xml";
+pub(crate) const CONTENT_TYPE_PEOPLE: &str =
+    "application/vnd.openxmlformats-officedocument.wordprocessingml.people+xml";
+pub(crate) const CONTENT_TYPE_FONT_TABLE: &str =
+    "application/vnd.openxmlformats-officedocument.wordprocessingml.fontTable+xml";
+pub(crate) const CONTENT_TYPE_NUMBERING: &str =
+    "application/vnd.openxmlformats-officedocument.wordprocessingml.numbering+xml";
+pub(crate) const CONTENT_TYPE_THEME: &str =
+    "application/vnd.openxmlformats-officedocument.theme+xml";
+pub(crate) const CONTENT_TYPE_CUSTOM_XML_PROPS: &str =
+    "application/vnd.openxmlformats-officedocument.customXmlProperties+xml";
+pub(crate) const CONTENT_TYPE_CHART: &str =
+    "application/vnd.openxmlformats-officedocument.drawingml.chart+xml";
+pub(crate) const CONTENT_TYPE_CHART_STYLE: &str = "application/vnd.ms-office.chartstyle+xml";
+pub(crate) const CONTENT_TYPE_CHART_COLORS: &str = "application/vnd.ms-office.chartcolorstyle+xml";
+pub(crate) const CONTENT_TYPE_XLSX: &str =
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet";
+pub(crate) const CONTENT_TYPE_OLE_OBJECT: &str =
+    "application/vnd.openxmlformats-officedocument.oleObject";
+
+/// Returns the content type of a chart part, or of the style or colors of a
+/// chart, from its name like `charts/style1.xml`.
+pub(crate) fn chart_content_type(name: &str) -> &'static str {
+    if name.contains("/style") {
+        CONTENT_TYPE_CHART_STYLE
+    } else if name.contains("/colors") {
+        CONTENT_TYPE_CHART_COLORS
+    } else {
+        CONTENT_TYPE_CHART
+    }
+}
+
+/// Returns the content type of an embedding, like a workbook or an OLE
+/// object, from its name.
+pub(crate) fn embedding_content_type(name: &str) -> &'static str {
+    match name
+        .rsplit_once('.')
+        .map(|(_, ext)| ext.to_ascii_lowercase())
+    {
+        Some(ext) if ext == "xlsx" => CONTENT_TYPE_XLSX,
+        Some(ext) if ext == "bin" => CONTENT_TYPE_OLE_OBJECT,
+        _ => "application/octet-stream",
+    }
+}
+
+/// Returns the content type of images with the given file extension, for
+/// the formats Word shows.
+///
+/// ```rust
+/// use docx_rust::content_type::image_content_type;
+///
+/// assert_eq!(image_content_type("JPG"), Some("image/jpeg"));
+/// assert_eq!(image_content_type("svg"), Some("image/svg+xml"));
+/// assert_eq!(image_content_type("xml"), None);
+/// ```
+pub fn image_content_type(ext: &str) -> Option<&'static str> {
+    match ext.to_ascii_lowercase().as_str() {
+        "png" => Some("image/png"),
+        "jpeg" | "jpg" => Some("image/jpeg"),
+        "gif" => Some("image/gif"),
+        "bmp" => Some("image/bmp"),
+        "tiff" | "tif" => Some("image/tiff"),
+        "svg" => Some("image/svg+xml"),
+        "emf" => Some("image/x-emf"),
+        "wmf" => Some("image/x-wmf"),
+        _ => None,
+    }
+}
 
 #[derive(Debug, XmlRead, Clone)]
 #[xml(tag = "Types")]
@@ -62,6 +146,118 @@ impl Default for ContentTypes<'static> {
     }
 }
 
+impl<'a> ContentTypes<'a> {
+    /// Returns the content type of a part, like `/word/document.xml`, from
+    /// its override or the default for its extension. Part names are
+    /// compared ignoring case, as packages do.
+    pub fn get(&self, part: &str) -> Option<&str> {
+        if let Some(o) = self
+            .overrides
+            .iter()
+            .find(|o| o.part.eq_ignore_ascii_case(part))
+        {
+            return Some(&o.ty);
+        }
+        let ext = part.rsplit_once('.')?.1;
+        self.defaults
+            .iter()
+            .find(|d| d.ext.eq_ignore_ascii_case(ext))
+            .map(|d| &*d.ty)
+    }
+
+    /// Returns whether a part, like `/word/media/image1.png`, has a content
+    /// type, either from an override or a default.
+    pub fn contains(&self, part: &str) -> bool {
+        self.get(part).is_some()
+    }
+
+    /// Returns the parts with several overrides of different content types,
+    /// and those types, in order. Part names are compared ignoring case, as
+    /// packages do.
+    ///
+    /// ```rust
+    /// use docx_rust::content_type::ContentTypes;
+    ///
+    /// let mut content_types = ContentTypes::default();
+    /// assert!(content_types.conflicting_overrides().is_empty());
+    ///
+    /// let mut duplicate = content_types.overrides[2].clone();
+    /// duplicate.part = "/word/Document.xml".into();
+    /// duplicate.ty = "application/xml".into();
+    /// content_types.overrides.push(duplicate);
+    /// let conflicts = content_types.conflicting_overrides();
+    /// assert_eq!(conflicts.len(), 1);
+    /// assert_eq!(conflicts[0].0, "/word/document.xml");
+    /// assert_eq!(conflicts[0].1.len(), 2);
+    /// ```
+    pub fn conflicting_overrides(&self) -> Vec<(&str, Vec<&str>)> {
+        let mut conflicts: Vec<(&str, Vec<&str>)> = Vec::new();
+        for (i, o) in self.overrides.iter().enumerate() {
+            let seen = self.overrides[..i]
+                .iter()
+                .any(|other| other.part.eq_ignore_ascii_case(&o.part));
+            if seen {
+                continue;
+            }
+            let mut types: Vec<&str> = Vec::new();
+            for other in &self.overrides[i..] {
+                if other.part.eq_ignore_ascii_case(&o.part) && !types.contains(&&*other.ty) {
+                    types.push(&other.ty);
+                }
+            }
+            if types.len() > 1 {
+                conflicts.push((&o.part, types));
+            }
+        }
+        conflicts
+    }
+
+    /// Adds the content type of images with the given extension, see
+    /// [`image_content_type`], unless it is already defined. Returns whether
+    /// the extension is one of an image.
+    pub fn add_image_default(&mut self, ext: &str) -> bool {
+        match image_content_type(ext) {
+            Some(ty) => {
+                self.add_default(ext.to_ascii_lowercase(), ty);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Adds the content type of files with the given extension, unless it is
+    /// already defined.
+    pub fn add_default<T: Into<Cow<'a, str>>>(&mut self, ext: T, ty: &'a str) -> &mut Self {
+        let ext = ext.into();
+        if !self
+            .defaults
+            .iter()
+            .any(|d| d.ext.eq_ignore_ascii_case(&ext))
+        {
+            self.defaults
+                .push(DefaultContentType { ext, ty: ty.into() });
+        }
+        self
+    }
+
+    /// Adds an override for the given part, unless the part already has one,
+    /// its name compared ignoring case.
+    pub fn add_override<T: Into<Cow<'a, str>>>(&mut self, part: T, ty: &'a str) -> &mut Self {
+        let part = part.into();
+        if !self
+            .overrides
+            .iter()
+            .any(|o| o.part.eq_ignore_ascii_case(&part))
+        {
+            self.overrides.push(OverrideContentType {
+                part,
+                ty: ty.into(),
+            });
+        }
+        self
+    }
+}
+
 impl<'a> XmlWrite for ContentTypes<'a> {
     fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
         let ContentTypes {
@@ -112,3 +308,41 @@ pub struct OverrideContentType<'a> {
     #[xml(attr = "ContentType")]
     pub ty: Cow<'a, str>,
 }
+
+#[test]
+fn media_defaults() {
+    use crate::{media::MediaType, Docx};
+
+    let data = Vec::new();
+    let mut docx = Docx::default();
+    for name in ["media/image1.gif", "media/image2.EMF", "media/data.bin"] {
+        docx.media.insert(name.into(), (MediaType::Image, &data));
+    }
+    docx.add_relationships();
+
+    let content_types = &docx.content_types;
+    assert_eq!(
+        content_types.get("/word/media/image1.gif"),
+        Some("image/gif")
+    );
+    assert_eq!(
+        content_types.get("/word/media/image2.EMF"),
+        Some("image/x-emf")
+    );
+    assert!(!content_types.contains("/word/media/data.bin"));
+    assert!(content_types.conflicting_overrides().is_empty());
+}
+
+#[test]
+fn part_name_case() {
+    let mut content_types = ContentTypes::default();
+    assert_eq!(
+        content_types.get("/WORD/Document.xml"),
+        Some(CONTENT_TYPE_DOCUMENT)
+    );
+
+    let overrides = content_types.overrides.len();
+    content_types.add_override("/Word/Styles.xml", "application/xml");
+    assert_eq!(content_types.overrides.len(), overrides);
+    assert!(content_types.conflicting_overrides().is_empty());
+}
@@ -21,6 +21,9 @@ pub struct Core<'a> {
     pub keywords: Option<Cow<'a, str>>,
     #[xml(flatten_text = "dc:description")]
     pub description: Option<Cow<'a, str>>,
+    /// Specifies the language of the document, e.g. `en-US`
+    #[xml(flatten_text = "dc:language")]
+    pub language: Option<Cow<'a, str>>,
     #[xml(flatten_text = "cp:lastModifiedBy")]
     pub last_modified_by: Option<Cow<'a, str>>,
     #[xml(flatten_text = "cp:revision")]
@@ -35,6 +38,7 @@ impl<'a> XmlWrite for Core<'a> {
             creator,
             keywords,
             description,
+            language,
             last_modified_by,
             revision,
         } = self;
@@ -53,6 +57,7 @@ impl<'a> XmlWrite for Core<'a> {
             && creator.is_none()
             && keywords.is_none()
             && description.is_none()
+            && language.is_none()
             && last_modified_by.is_none()
             && revision.is_none()
         {
@@ -74,6 +79,9 @@ impl<'a> XmlWrite for Core<'a> {
             if let Some(val) = description {
                 writer.write_flatten_text("dc:description", val, false)?;
             }
+            if let Some(val) = language {
+                writer.write_flatten_text("dc:language", val, false)?;
+            }
             if let Some(val) = last_modified_by {
                 writer.write_flatten_text("cp:lastModifiedBy", val, false)?;
             }
@@ -0,0 +1,160 @@
+//! Custom XML Data parts
+//!
+//! The corresponding ZIP items are `/customXml/item{n}.xml`, holding any XML
+//! data, and `/customXml/itemProps{n}.xml`, describing it. Content controls
+//! show and edit the data they are bound to, see [`DataBinding`].
+//!
+//! ```rust
+//! use docx_rust::custom_xml::DatastoreItem;
+//! use docx_rust::document::{DataBinding, InlineSDT, Paragraph};
+//! use docx_rust::Docx;
+//!
+//! const ITEM_ID: &str = "{6E1FD3B1-8B4C-4F4B-9C43-A3C2B1F0D5E7}";
+//!
+//! let mut docx = Docx::default();
+//! let name = docx.add_custom_xml(
+//!     r#"<invoice xmlns="urn:invoice"><customer>ACME</customer></invoice>"#,
+//!     DatastoreItem::new(ITEM_ID, "urn:invoice"),
+//! );
+//! assert_eq!(name, "item1.xml");
+//!
+//! let binding = DataBinding::new("/ns0:invoice[1]/ns0:customer[1]", ITEM_ID)
+//!     .prefix_mappings("xmlns:ns0='urn:invoice'");
+//! docx.document.push(
+//!     Paragraph::default()
+//!         .push_text("Customer: ")
+//!         .push(InlineSDT::bound_text(binding, "ACME")),
+//! );
+//!
+//! assert!(docx.custom_xml_by_id(ITEM_ID).is_some());
+//! ```
+//!
+//! [`DataBinding`]: crate::document::DataBinding
+
+use hard_xml::{XmlRead, XmlResult, XmlWrite, XmlWriter};
+use std::borrow::Cow;
+use std::io::Write;
+
+use crate::__xml_test_suites;
+use crate::schema::{SCHEMA_CUSTOM_XML_DATA, SCHEMA_XML};
+
+/// A custom XML data part and its properties
+#[derive(Debug, Default, Clone)]
+pub struct CustomXmlPart<'a> {
+    /// Specifies the XML data, written as is
+    pub data: Cow<'a, str>,
+    /// Specifies the properties part, which gives the id data bindings
+    /// refer to the data by
+    pub properties: Option<DatastoreItem<'a>>,
+}
+
+/// Returns the name of the properties part of a data part, e.g.
+/// `itemProps1.xml` for `item1.xml`.
+pub(crate) fn properties_name(name: &str) -> String {
+    name.replacen("item", "itemProps", 1)
+}
+
+/// Identifies a custom XML data part and the schemas its content follows.
+///
+/// ```rust
+/// use docx_rust::custom_xml::DatastoreItem;
+///
+/// let props = DatastoreItem::new(
+///     "{6E1FD3B1-8B4C-4F4B-9C43-A3C2B1F0D5E7}",
+///     "http://example.com/invoice",
+/// );
+/// assert_eq!(props.schemas().collect::<Vec<_>>(), ["http://example.com/invoice"]);
+/// ```
+#[derive(Debug, Default, XmlRead, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "ds:datastoreItem")]
+pub struct DatastoreItem<'a> {
+    /// Specifies the unique id of the data part, a GUID in braces
+    #[xml(attr = "ds:itemID")]
+    pub item_id: Cow<'a, str>,
+    /// Specifies the schemas the data part follows
+    #[xml(child = "ds:schemaRefs")]
+    pub schema_refs: Option<SchemaRefs<'a>>,
+}
+
+impl<'a> DatastoreItem<'a> {
+    /// Creates the properties of a data part following a single schema.
+    pub fn new<I, S>(item_id: I, schema: S) -> Self
+    where
+        I: Into<Cow<'a, str>>,
+        S: Into<Cow<'a, str>>,
+    {
+        DatastoreItem {
+            item_id: item_id.into(),
+            schema_refs: Some(SchemaRefs {
+                schema_refs: vec![SchemaRef { uri: schema.into() }],
+            }),
+        }
+    }
+
+    /// Returns the namespace uris of the schemas.
+    pub fn schemas(&self) -> impl Iterator<Item = &str> {
+        self.schema_refs
+            .iter()
+            .flat_map(|refs| refs.schema_refs.iter())
+            .map(|schema_ref| schema_ref.uri.as_ref())
+    }
+}
+
+impl<'a> XmlWrite for DatastoreItem<'a> {
+    fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
+        let DatastoreItem {
+            item_id,
+            schema_refs,
+        } = self;
+
+        log::debug!("[DatastoreItem] Started writing.");
+        let _ = write!(writer.inner, "{}", SCHEMA_XML);
+
+        writer.write_element_start("ds:datastoreItem")?;
+        writer.write_attribute("ds:itemID", item_id)?;
+        writer.write_attribute("xmlns:ds", SCHEMA_CUSTOM_XML_DATA)?;
+
+        match schema_refs {
+            Some(schema_refs) => {
+                writer.write_element_end_open()?;
+                schema_refs.to_writer(writer)?;
+                writer.write_element_end_close("ds:datastoreItem")?;
+            }
+            None => writer.write_element_end_empty()?,
+        }
+
+        log::debug!("[DatastoreItem] Finished writing.");
+
+        Ok(())
+    }
+}
+
+/// Schema References
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "ds:schemaRefs")]
+pub struct SchemaRefs<'a> {
+    #[xml(child = "ds:schemaRef")]
+    pub schema_refs: Vec<SchemaRef<'a>>,
+}
+
+/// Schema Reference
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "ds:schemaRef")]
+pub struct SchemaRef<'a> {
+    /// Specifies the namespace uri of the schema
+    #[xml(attr = "ds:uri")]
+    pub uri: Cow<'a, str>,
+}
+
+__xml_test_suites!(
+    DatastoreItem,
+    DatastoreItem::new("{1}", "urn:a"),
+    format!(
+        r#"{}<ds:datastoreItem ds:itemID="{{1}}" xmlns:ds="{}"><ds:schemaRefs><ds:schemaRef ds:uri="urn:a"/></ds:schemaRefs></ds:datastoreItem>"#,
+        SCHEMA_XML, SCHEMA_CUSTOM_XML_DATA
+    )
+    .as_str(),
+);
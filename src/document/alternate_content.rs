@@ -0,0 +1,308 @@
+use hard_xml::{XmlRead, XmlReader, XmlResult, XmlWrite, XmlWriter};
+use std::{borrow::Cow, io::Write};
+use xmlparser::{Token, Tokenizer};
+
+use crate::{
+    __setter, __xml_test_suites,
+    document::{
+        raw::{map_relationship_ids, read_element, relationship_ids},
+        RunContent,
+    },
+    escape::write_escaped,
+    schema::write_raw,
+};
+
+/// Alternate Content
+///
+/// Markup compatibility block that Word emits around content requiring newer
+/// features, e.g. shapes and text boxes, together with an older fallback.
+/// The choices and the fallback are kept as they were read, so the block
+/// round-trips even when their content isn't modelled.
+///
+/// ```rust
+/// use docx_rust::document::*;
+///
+/// let ac = AlternateContent::default()
+///     .push_choice(Choice::new("wps"))
+///     .fallback(Fallback::default().push(Text::from("fallback")));
+///
+/// assert_eq!(ac.selected().len(), 1);
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "mc:AlternateContent")]
+pub struct AlternateContent<'a> {
+    /// Specifies the preferred representations, in order
+    #[xml(child = "mc:Choice")]
+    pub choices: Vec<Choice<'a>>,
+    /// Specifies the representation used when no choice is understood
+    #[xml(child = "mc:Fallback")]
+    pub fallback: Option<Fallback<'a>>,
+}
+
+impl<'a> AlternateContent<'a> {
+    __setter!(fallback: Option<Fallback<'a>>);
+
+    pub fn push_choice(mut self, choice: Choice<'a>) -> Self {
+        self.choices.push(choice);
+        self
+    }
+
+    /// Returns the content of the first choice that has any content this crate
+    /// understands, or the content of the fallback otherwise.
+    pub fn selected(&self) -> Vec<RunContent<'_>> {
+        self.choices
+            .iter()
+            .map(Choice::content)
+            .find(|c| !c.is_empty())
+            .or_else(|| self.fallback.as_ref().map(Fallback::content))
+            .unwrap_or_default()
+    }
+
+    /// Returns the relationship ids the choices and the fallback refer to.
+    pub fn relationship_ids(&self) -> Vec<&str> {
+        self.choices
+            .iter()
+            .map(|choice| &choice.xml)
+            .chain(self.fallback.as_ref().map(|fallback| &fallback.xml))
+            .flat_map(|xml| relationship_ids(xml))
+            .collect()
+    }
+
+    /// Replaces the relationship ids the choices and the fallback refer to,
+    /// see [`map_relationship_ids`].
+    pub fn map_relationship_ids<F: FnMut(&str) -> Option<String>>(&mut self, mut f: F) {
+        for choice in self.choices.iter_mut() {
+            map_relationship_ids(&mut choice.xml, &mut f);
+        }
+        if let Some(fallback) = &mut self.fallback {
+            map_relationship_ids(&mut fallback.xml, &mut f);
+        }
+    }
+}
+
+/// A representation guarded by the namespaces it requires
+///
+/// Kept as it was read, see [`Choice::content`] for the runs it holds.
+#[derive(Debug, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+pub struct Choice<'a> {
+    /// The markup of the choice, written as is
+    pub xml: Cow<'a, str>,
+}
+
+impl<'a> Choice<'a> {
+    pub fn new<T: AsRef<str>>(requires: T) -> Self {
+        let mut xml = br#"<mc:Choice Requires=""#.to_vec();
+        let _ = write_escaped(&mut xml, requires.as_ref());
+        xml.extend_from_slice(br#""/>"#);
+        Choice {
+            xml: String::from_utf8(xml).unwrap_or_default().into(),
+        }
+    }
+
+    pub fn push<T: Into<RunContent<'a>>>(mut self, content: T) -> Self {
+        push_content(&mut self.xml, "mc:Choice", content.into());
+        self
+    }
+
+    /// Returns the space separated namespace prefixes a consumer must
+    /// understand to use this choice.
+    pub fn requires(&self) -> Option<&str> {
+        Tokenizer::from(&*self.xml).find_map(|token| match token {
+            Ok(Token::Attribute { local, value, .. }) if local.as_str() == "Requires" => {
+                Some(value.as_str())
+            }
+            _ => None,
+        })
+    }
+
+    /// Returns the run content of the choice this crate understands.
+    pub fn content(&self) -> Vec<RunContent<'_>> {
+        ChoiceContent::from_str(&self.xml)
+            .map(|choice| choice.content)
+            .unwrap_or_default()
+    }
+}
+
+impl<'a> XmlRead<'a> for Choice<'a> {
+    fn from_reader(reader: &mut XmlReader<'a>) -> XmlResult<Self> {
+        Ok(Choice {
+            xml: read_element(reader)?.into(),
+        })
+    }
+}
+
+impl<'a> XmlWrite for Choice<'a> {
+    fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
+        write_raw(writer, &self.xml)
+    }
+}
+
+/// The representation used when none of the choices is understood
+///
+/// Kept as it was read, see [`Fallback::content`] for the runs it holds.
+#[derive(Debug, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+pub struct Fallback<'a> {
+    /// The markup of the fallback, written as is
+    pub xml: Cow<'a, str>,
+}
+
+impl Default for Fallback<'_> {
+    fn default() -> Self {
+        Fallback {
+            xml: Cow::Borrowed("<mc:Fallback/>"),
+        }
+    }
+}
+
+impl<'a> Fallback<'a> {
+    pub fn push<T: Into<RunContent<'a>>>(mut self, content: T) -> Self {
+        push_content(&mut self.xml, "mc:Fallback", content.into());
+        self
+    }
+
+    /// Returns the run content of the fallback this crate understands.
+    pub fn content(&self) -> Vec<RunContent<'_>> {
+        FallbackContent::from_str(&self.xml)
+            .map(|fallback| fallback.content)
+            .unwrap_or_default()
+    }
+}
+
+impl<'a> XmlRead<'a> for Fallback<'a> {
+    fn from_reader(reader: &mut XmlReader<'a>) -> XmlResult<Self> {
+        Ok(Fallback {
+            xml: read_element(reader)?.into(),
+        })
+    }
+}
+
+impl<'a> XmlWrite for Fallback<'a> {
+    fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
+        write_raw(writer, &self.xml)
+    }
+}
+
+/// Appends the markup of some content to an element with the given tag.
+fn push_content(xml: &mut Cow<'_, str>, tag: &str, content: RunContent<'_>) {
+    let content = match content.to_string() {
+        Ok(content) => content,
+        Err(_) => return,
+    };
+    let xml = xml.to_mut();
+    if xml.ends_with("/>") {
+        xml.truncate(xml.len() - 2);
+        xml.push('>');
+        xml.push_str(&content);
+        xml.push_str("</");
+        xml.push_str(tag);
+        xml.push('>');
+    } else if let Some(end) = xml.rfind("</") {
+        xml.insert_str(end, &content);
+    }
+}
+
+/// The run content of a choice
+#[derive(XmlRead)]
+#[xml(tag = "mc:Choice")]
+struct ChoiceContent<'a> {
+    #[xml(
+        child = "w:br",
+        child = "w:t",
+        child = "w:delText",
+        child = "w:instrText",
+        child = "w:delInstrText",
+        child = "w:noBreakHyphen",
+        child = "w:softHyphen",
+        child = "w:dayShort",
+        child = "w:monthShort",
+        child = "w:yearShort",
+        child = "w:dayLong",
+        child = "w:monthLong",
+        child = "w:yearLong",
+        child = "w:annotationRef",
+        child = "w:footnoteRef",
+        child = "w:endnoteRef",
+        child = "w:separator",
+        child = "w:continuationSeparator",
+        child = "w:sym",
+        child = "w:pgNum",
+        child = "w:cr",
+        child = "w:tab",
+        child = "w:object",
+        child = "w:pict",
+        child = "w:fldChar",
+        child = "w:footnoteReference",
+        child = "w:endnoteReference",
+        child = "w:commentReference",
+        child = "w:drawing",
+        child = "w:ptab",
+        child = "w:lastRenderedPageBreak",
+        child = "mc:AlternateContent"
+    )]
+    content: Vec<RunContent<'a>>,
+}
+
+/// The run content of a fallback
+#[derive(XmlRead)]
+#[xml(tag = "mc:Fallback")]
+struct FallbackContent<'a> {
+    #[xml(
+        child = "w:br",
+        child = "w:t",
+        child = "w:delText",
+        child = "w:instrText",
+        child = "w:delInstrText",
+        child = "w:noBreakHyphen",
+        child = "w:softHyphen",
+        child = "w:dayShort",
+        child = "w:monthShort",
+        child = "w:yearShort",
+        child = "w:dayLong",
+        child = "w:monthLong",
+        child = "w:yearLong",
+        child = "w:annotationRef",
+        child = "w:footnoteRef",
+        child = "w:endnoteRef",
+        child = "w:separator",
+        child = "w:continuationSeparator",
+        child = "w:sym",
+        child = "w:pgNum",
+        child = "w:cr",
+        child = "w:tab",
+        child = "w:object",
+        child = "w:pict",
+        child = "w:fldChar",
+        child = "w:footnoteReference",
+        child = "w:endnoteReference",
+        child = "w:commentReference",
+        child = "w:drawing",
+        child = "w:ptab",
+        child = "w:lastRenderedPageBreak",
+        child = "mc:AlternateContent"
+    )]
+    content: Vec<RunContent<'a>>,
+}
+
+__xml_test_suites!(
+    AlternateContent,
+    AlternateContent::default(),
+    "<mc:AlternateContent/>",
+    AlternateContent::default()
+        .push_choice(Choice::new("wps").push(crate::document::Text::from("choice")))
+        .fallback(Fallback::default().push(crate::document::Text::from("fallback"))),
+    r#"<mc:AlternateContent><mc:Choice Requires="wps"><w:t>choice</w:t></mc:Choice><mc:Fallback><w:t>fallback</w:t></mc:Fallback></mc:AlternateContent>"#,
+);
+
+#[test]
+fn verbatim() -> XmlResult<()> {
+    let xml = r#"<mc:AlternateContent><mc:Choice xmlns:a14="http://schemas.microsoft.com/office/drawing/2010/main" Requires="a14"><w:drawing><wp:anchor distT="0"><wps:wsp><wps:bodyPr rot="0"/></wps:wsp></wp:anchor></w:drawing></mc:Choice><mc:Fallback><w:pict><v:rect style="width:10pt"/></w:pict></mc:Fallback></mc:AlternateContent>"#;
+
+    let ac = AlternateContent::from_str(xml)?;
+    assert_eq!(ac.choices[0].requires(), Some("a14"));
+    assert_eq!(ac.to_string()?, xml);
+
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use hard_xml::{XmlRead, XmlWrite};
+
+use crate::{__define_struct, __xml_test_suites, formatting::ThemeColor};
+
+__define_struct! {
+    ("w:background", Background) {
+        "w:color", color, String // Background Color, as RRGGBB
+        "w:themeColor", theme_color, ThemeColor
+        "w:themeTint", theme_tint, String
+        "w:themeShade", theme_shade, String
+    }
+}
+
+__xml_test_suites!(
+    Background,
+    Background::default(),
+    r#"<w:background/>"#,
+    Background::default().color("FFF8E7"),
+    r#"<w:background w:color="FFF8E7"/>"#,
+);
@@ -2,7 +2,7 @@ use derive_more::From;
 use hard_xml::{XmlRead, XmlWrite};
 
 use crate::__xml_test_suites;
-use crate::document::{Paragraph, Table, TableCell};
+use crate::document::{Paragraph, RawXml, Table, TableCell, TableCellContent, TableRowContent};
 use crate::formatting::SectionProperty;
 
 use super::SDT;
@@ -25,6 +25,74 @@ impl<'a> Body<'a> {
         self
     }
 
+    /// Keeps only the content for which `f` returns `true`, like
+    /// [`Vec::retain`].
+    ///
+    /// The properties of the last section are content of the body too, and
+    /// are to be kept.
+    pub fn retain<F: FnMut(&BodyContent<'a>) -> bool>(&mut self, f: F) -> &mut Self {
+        self.content.retain(f);
+        self
+    }
+
+    /// Removes the paragraphs of the body and its tables for which `f`
+    /// returns `true`, returning how many were removed, e.g. instructions
+    /// left in a template.
+    ///
+    /// A paragraph ending a section is emptied instead, to keep the section
+    /// break, as is the last paragraph of a table cell, which must have one.
+    ///
+    /// ```rust
+    /// use docx_rust::document::*;
+    /// use docx_rust::formatting::ParagraphProperty;
+    /// use docx_rust::Selector;
+    ///
+    /// let note = ParagraphProperty::default().style_id("RemoveMe");
+    /// let mut body = Body::default();
+    /// body.push(Paragraph::default().property(note).push_text("Fill in the name"))
+    ///     .push(Paragraph::default().push_text("Name: "));
+    ///
+    /// let selector = Selector::Style("RemoveMe");
+    /// assert_eq!(body.remove_paragraphs(|para| selector.matches(para)), 1);
+    /// assert_eq!(body.text(), "Name: ");
+    /// ```
+    pub fn remove_paragraphs<F: FnMut(&Paragraph<'a>) -> bool>(&mut self, mut f: F) -> usize {
+        let mut removed = 0;
+        let mut content = Vec::with_capacity(self.content.len());
+        for c in self.content.drain(..) {
+            match c {
+                BodyContent::Paragraph(mut para) if f(&para) => {
+                    removed += 1;
+                    let ends_section = para
+                        .property
+                        .as_ref()
+                        .is_some_and(|property| property.section_property.is_some());
+                    if ends_section {
+                        para.content.clear();
+                        content.push(BodyContent::Paragraph(para));
+                    }
+                }
+                BodyContent::Table(mut table) => {
+                    for row in table.rows.iter_mut() {
+                        for cell in row.cells.iter_mut() {
+                            if let TableRowContent::TableCell(cell) = cell {
+                                removed += remove_from_cell(cell, &mut f);
+                            }
+                        }
+                    }
+                    content.push(BodyContent::Table(table));
+                }
+                BodyContent::TableCell(mut cell) => {
+                    removed += remove_from_cell(&mut cell, &mut f);
+                    content.push(BodyContent::TableCell(cell));
+                }
+                c => content.push(c),
+            }
+        }
+        self.content = content;
+        removed
+    }
+
     pub fn text(&self) -> String {
         let v: Vec<_> = self
             .content
@@ -35,6 +103,7 @@ impl<'a> Body<'a> {
                 BodyContent::SectionProperty(_) => None,
                 BodyContent::Sdt(_) => None,
                 BodyContent::TableCell(_) => None,
+                BodyContent::Raw(_) => None,
             })
             .collect();
         v.join("\r\n")
@@ -65,6 +134,7 @@ impl<'a> Body<'a> {
                 BodyContent::SectionProperty(_) => {}
                 BodyContent::Sdt(_) => {}
                 BodyContent::TableCell(_) => {}
+                BodyContent::Raw(_) => {}
             }
         }
         Ok(())
@@ -89,6 +159,33 @@ impl<'a> Body<'a> {
     // }
 }
 
+/// Removes the paragraphs of a cell for which `f` returns `true`, emptying
+/// the last one instead.
+fn remove_from_cell<'a, F: FnMut(&Paragraph<'a>) -> bool>(
+    cell: &mut TableCell<'a>,
+    f: &mut F,
+) -> usize {
+    let before = cell.content.len();
+    let mut last = None;
+    cell.content.retain(|content| match content {
+        TableCellContent::Paragraph(para) if f(para) => {
+            last = Some(para.property.clone());
+            false
+        }
+        _ => true,
+    });
+    let removed = before - cell.content.len();
+    if cell.content.is_empty() {
+        if let Some(property) = last {
+            cell.content.push(TableCellContent::Paragraph(Paragraph {
+                property,
+                ..Default::default()
+            }));
+        }
+    }
+    removed
+}
+
 /// A set of elements that can be contained in the body
 #[derive(Debug, From, XmlRead, XmlWrite, Clone)]
 #[cfg_attr(test, derive(PartialEq))]
@@ -103,6 +200,9 @@ pub enum BodyContent<'a> {
     SectionProperty(SectionProperty<'a>),
     #[xml(tag = "w:tc")]
     TableCell(TableCell<'a>),
+    /// Pre-serialized XML, see [`RawXml::unsafe_raw`]
+    #[xml(tag = "docx-rust:raw")]
+    Raw(RawXml<'a>),
 }
 
 __xml_test_suites!(
@@ -118,3 +218,45 @@ __xml_test_suites!(
     },
     r#"<w:body><w:tbl><w:tblPr/><w:tblGrid/></w:tbl></w:body>"#,
 );
+
+#[test]
+fn remove_paragraphs() {
+    use crate::formatting::ParagraphProperty;
+
+    let section = ParagraphProperty {
+        section_property: Some(SectionProperty::default()),
+        ..Default::default()
+    };
+    let mut body = Body::default();
+    body.push(Paragraph::default().push_text("TODO: intro"))
+        .push(
+            Paragraph::default()
+                .property(section)
+                .push_text("TODO: end"),
+        )
+        .push(Table::from_rows([["TODO: cell", "kept"]]))
+        .push(Paragraph::default().push_text("Done"));
+
+    assert_eq!(
+        body.remove_paragraphs(|para| para.text().starts_with("TODO")),
+        3
+    );
+    assert_eq!(body.content.len(), 3);
+    match &body.content[0] {
+        BodyContent::Paragraph(para) => {
+            assert!(para.content.is_empty());
+            assert!(para.property.as_ref().unwrap().section_property.is_some());
+        }
+        _ => panic!("expected the section break"),
+    }
+    match &body.content[1] {
+        BodyContent::Table(table) => {
+            let text: Vec<_> = table.iter_text().collect();
+            assert_eq!(text, ["kept"]);
+        }
+        _ => panic!("expected the table"),
+    }
+
+    body.retain(|content| !matches!(content, BodyContent::Table(_)));
+    assert_eq!(body.text(), "\r\nDone");
+}
@@ -18,6 +18,18 @@ pub struct Break {
     pub ty: Option<BreakType>,
 }
 
+impl Break {
+    /// Creates a break that moves the following text to the next column.
+    pub fn column() -> Self {
+        Break::from(BreakType::Column)
+    }
+
+    /// Creates a break that moves the following text to the next page.
+    pub fn page() -> Self {
+        Break::from(BreakType::Page)
+    }
+}
+
 impl<T: Into<Option<BreakType>>> From<T> for Break {
     fn from(val: T) -> Self {
         Break { ty: val.into() }
@@ -57,4 +69,6 @@ __xml_test_suites!(
     r#"<w:br/>"#,
     Break::from(BreakType::Page),
     r#"<w:br w:type="page"/>"#,
+    Break::column(),
+    r#"<w:br w:type="column"/>"#,
 );
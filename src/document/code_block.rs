@@ -0,0 +1,178 @@
+use std::borrow::Cow;
+
+use crate::{
+    document::{Break, Paragraph, Run, RunContent, Tab, Text, TextSpace},
+    formatting::{
+        CharacterProperty, Fonts, LineRule, NoProof, ParagraphProperty, Shading, ShadingStyle,
+        Spacing,
+    },
+};
+
+const CODE_FONT: &str = "Consolas";
+const CODE_FILL: &str = "F2F2F2";
+
+impl<'a> Paragraph<'a> {
+    /// Creates a code listing: a shaded paragraph in a monospace font that is
+    /// excluded from spelling and grammar checks.
+    ///
+    /// Whitespace is kept as is, every line of `code` goes on its own line
+    /// and tabs become tab characters.
+    ///
+    /// ```rust
+    /// use docx_rust::document::Paragraph;
+    ///
+    /// let para = Paragraph::code_block("fn main() {\n    println!(\"hi\");\n}");
+    /// assert_eq!(para.text(), "fn main() {    println!(\"hi\");}");
+    /// ```
+    pub fn code_block<T: Into<Cow<'a, str>>>(code: T) -> Self {
+        let lines: Vec<Cow<'a, str>> = match code.into() {
+            Cow::Borrowed(code) => code.lines().map(Cow::Borrowed).collect(),
+            Cow::Owned(code) => code.lines().map(|line| line.to_owned().into()).collect(),
+        };
+        code_paragraph(
+            lines
+                .into_iter()
+                .map(|line| vec![(code_character_property(), line)]),
+        )
+    }
+
+    /// Creates a code listing like [`Paragraph::code_block`], colored by
+    /// syntect's default syntaxes and `InspiredGitHub` theme.
+    ///
+    /// `language` is a file extension or a language name, like `rs` or
+    /// `Rust`. Unknown languages are rendered as plain text.
+    ///
+    /// ```rust
+    /// use docx_rust::document::Paragraph;
+    ///
+    /// let para = Paragraph::highlighted_code_block("let x = 1;", "rs");
+    /// assert_eq!(para.text(), "let x = 1;");
+    /// ```
+    #[cfg(feature = "syntect")]
+    pub fn highlighted_code_block(code: &'a str, language: &str) -> Self {
+        use std::sync::OnceLock;
+        use syntect::{
+            easy::HighlightLines,
+            highlighting::{FontStyle, ThemeSet},
+            parsing::SyntaxSet,
+        };
+
+        static SYNTAXES: OnceLock<SyntaxSet> = OnceLock::new();
+        static THEMES: OnceLock<ThemeSet> = OnceLock::new();
+
+        let syntaxes = SYNTAXES.get_or_init(SyntaxSet::load_defaults_nonewlines);
+        let themes = THEMES.get_or_init(ThemeSet::load_defaults);
+        let syntax = syntaxes
+            .find_syntax_by_token(language)
+            .unwrap_or_else(|| syntaxes.find_syntax_plain_text());
+        let mut highlighter = HighlightLines::new(syntax, &themes.themes["InspiredGitHub"]);
+
+        let lines: Vec<_> = code
+            .lines()
+            .map(|line| match highlighter.highlight_line(line, syntaxes) {
+                Ok(ranges) => ranges
+                    .into_iter()
+                    .map(|(style, text)| {
+                        let mut prop = code_character_property();
+                        let color = style.foreground;
+                        prop.color = Some((color.r, color.g, color.b).into());
+                        if style.font_style.contains(FontStyle::BOLD) {
+                            prop.bold = Some(true.into());
+                        }
+                        if style.font_style.contains(FontStyle::ITALIC) {
+                            prop.italics = Some(true.into());
+                        }
+                        (prop, Cow::Borrowed(text))
+                    })
+                    .collect(),
+                Err(_) => vec![(code_character_property(), Cow::Borrowed(line))],
+            })
+            .collect();
+
+        code_paragraph(lines)
+    }
+}
+
+fn code_character_property<'a>() -> CharacterProperty<'a> {
+    CharacterProperty {
+        no_proof: Some(NoProof { value: Some(true) }),
+        ..Default::default()
+    }
+    .fonts(
+        Fonts::default()
+            .ascii(CODE_FONT)
+            .h_ansi(CODE_FONT)
+            .custom(CODE_FONT),
+    )
+    .size(20isize)
+}
+
+/// Builds the paragraph from lines of differently formatted pieces of text.
+fn code_paragraph<'a, L>(lines: L) -> Paragraph<'a>
+where
+    L: IntoIterator<Item = Vec<(CharacterProperty<'a>, Cow<'a, str>)>>,
+{
+    let mut para = Paragraph::default().property(
+        ParagraphProperty {
+            shading: Some(Shading {
+                style: Some(ShadingStyle::Clear),
+                color: Some("auto".into()),
+                fill: Some(CODE_FILL.into()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+        .spacing(Spacing {
+            line: Some(240),
+            line_rule: Some(LineRule::Auto),
+            ..Default::default()
+        }),
+    );
+
+    for (i, pieces) in lines.into_iter().enumerate() {
+        if i > 0 {
+            para = para.push(
+                Run::default()
+                    .property(code_character_property())
+                    .push_break(Break::default()),
+            );
+        }
+        for (prop, text) in pieces {
+            let mut run = Run::default().property(prop);
+            for (j, part) in split_tabs(text).into_iter().enumerate() {
+                if j > 0 {
+                    run.content.push(RunContent::Tab(Tab));
+                }
+                if !part.is_empty() {
+                    run.content.push(RunContent::Text(Text {
+                        space: Some(TextSpace::Preserve),
+                        text: part,
+                    }));
+                }
+            }
+            para = para.push(run);
+        }
+    }
+
+    para
+}
+
+fn split_tabs(text: Cow<'_, str>) -> Vec<Cow<'_, str>> {
+    match text {
+        Cow::Borrowed(text) => text.split('\t').map(Cow::Borrowed).collect(),
+        Cow::Owned(text) => text.split('\t').map(|s| s.to_owned().into()).collect(),
+    }
+}
+
+#[test]
+fn code_block() {
+    let para = Paragraph::code_block("if x {\n\treturn;\n}");
+    assert_eq!(para.text(), "if x {return;}");
+    assert_eq!(para.content.len(), 5);
+
+    let property = para.property.as_ref().unwrap();
+    assert_eq!(
+        property.shading.as_ref().unwrap().fill.as_deref(),
+        Some(CODE_FILL)
+    );
+}
@@ -8,7 +8,7 @@ use std::{borrow::Cow, io::Write};
 
 use crate::{
     document::Paragraph,
-    schema::{SCHEMA_MAIN, SCHEMA_WORDML_14},
+    schema::{write_root, NamespaceSet},
 };
 
 /// The root element of the comments document part.
@@ -50,6 +50,15 @@ pub struct CommentReference<'a> {
     pub id: Option<Cow<'a, str>>,
 }
 
+impl<'a> Comment<'a> {
+    /// Returns the `w14:paraId` of the comment paragraph.
+    ///
+    /// This is the key used by the comments extended part to thread replies.
+    pub fn para_id(&self) -> Option<&str> {
+        self.content.id.as_deref()
+    }
+}
+
 impl<'a> XmlWrite for Comments<'a> {
     fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
         let Comments { comments } = self;
@@ -57,19 +66,18 @@ impl<'a> XmlWrite for Comments<'a> {
         log::debug!("[Comments] Started writing.");
         let _ = write!(writer.inner, "{}", crate::schema::SCHEMA_XML);
 
-        writer.write_element_start("w:comments")?;
-
-        writer.write_attribute("xmlns:w", SCHEMA_MAIN)?;
-
-        writer.write_attribute("xmlns:w14", SCHEMA_WORDML_14)?;
-
-        writer.write_element_end_open()?;
-
-        for c in comments {
-            c.to_writer(writer)?;
-        }
-
-        writer.write_element_end_close("w:comments")?;
+        write_root(
+            writer,
+            "w:comments",
+            NamespaceSet::wordprocessing(),
+            |writer| {
+                for c in comments {
+                    c.to_writer(writer)?;
+                }
+
+                Ok(())
+            },
+        )?;
 
         log::debug!("[Comments] Finished writing.");
 
@@ -0,0 +1,135 @@
+//! Comments Extended part
+//!
+//! The corresponding ZIP item is `/word/commentsExtended.xml`.
+//!
+//! Each entry is keyed by the `w14:paraId` of the last paragraph of a comment,
+//! and records the comment it replies to and whether it has been resolved.
+#![allow(unused_must_use)]
+
+use hard_xml::{XmlRead, XmlResult, XmlWrite, XmlWriter};
+use std::{borrow::Cow, io::Write};
+
+use crate::{
+    __setter, __xml_test_suites,
+    schema::{write_root, NamespaceSet, SCHEMA_WORDML_15},
+};
+
+/// The root element of the comments extended part.
+///
+/// ```rust
+/// use docx_rust::document::*;
+///
+/// let mut ext = CommentsExtended::default();
+/// ext.push(CommentExtended::new("00000001"));
+/// ext.push(CommentExtended::new("00000002").para_id_parent("00000001"));
+///
+/// assert_eq!(ext.replies_to("00000001").count(), 1);
+/// ```
+#[derive(Debug, Default, XmlRead, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w15:commentsEx")]
+pub struct CommentsExtended<'a> {
+    /// Specifies the extended comment information
+    #[xml(child = "w15:commentEx")]
+    pub comments: Vec<CommentExtended<'a>>,
+}
+
+impl<'a> CommentsExtended<'a> {
+    pub fn push(&mut self, comment: CommentExtended<'a>) -> &mut Self {
+        self.comments.push(comment);
+        self
+    }
+
+    /// Finds the entry of the comment whose last paragraph has the given id.
+    pub fn get(&self, para_id: &str) -> Option<&CommentExtended<'a>> {
+        self.comments.iter().find(|c| c.para_id == para_id)
+    }
+
+    pub fn get_mut(&mut self, para_id: &str) -> Option<&mut CommentExtended<'a>> {
+        self.comments.iter_mut().find(|c| c.para_id == para_id)
+    }
+
+    /// Iterates the replies of the comment whose last paragraph has the given id.
+    pub fn replies_to<'b>(
+        &'b self,
+        para_id: &'b str,
+    ) -> impl Iterator<Item = &'b CommentExtended<'a>> + 'b {
+        self.comments
+            .iter()
+            .filter(move |c| c.para_id_parent.as_deref() == Some(para_id))
+    }
+
+    /// Marks a comment as resolved or reopens it, inserting an entry if needed.
+    pub fn set_done<T: Into<Cow<'a, str>>>(&mut self, para_id: T, done: bool) -> &mut Self {
+        let para_id = para_id.into();
+        match self.get_mut(&para_id) {
+            Some(c) => c.done = Some(done),
+            None => self.comments.push(CommentExtended::new(para_id).done(done)),
+        }
+        self
+    }
+}
+
+/// Extended information of a single comment
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w15:commentEx")]
+pub struct CommentExtended<'a> {
+    /// Specifies the `w14:paraId` of the last paragraph of the comment.
+    #[xml(attr = "w15:paraId")]
+    pub para_id: Cow<'a, str>,
+    /// Specifies the `w14:paraId` of the last paragraph of the parent comment.
+    #[xml(attr = "w15:paraIdParent")]
+    pub para_id_parent: Option<Cow<'a, str>>,
+    /// Specifies whether the comment has been resolved.
+    #[xml(attr = "w15:done")]
+    pub done: Option<bool>,
+}
+
+impl<'a> CommentExtended<'a> {
+    pub fn new<T: Into<Cow<'a, str>>>(para_id: T) -> Self {
+        CommentExtended {
+            para_id: para_id.into(),
+            ..Default::default()
+        }
+    }
+
+    __setter!(para_id_parent: Option<Cow<'a, str>>);
+    __setter!(done: Option<bool>);
+}
+
+impl<'a> XmlWrite for CommentsExtended<'a> {
+    fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
+        let CommentsExtended { comments } = self;
+
+        log::debug!("[CommentsExtended] Started writing.");
+        let _ = write!(writer.inner, "{}", crate::schema::SCHEMA_XML);
+
+        write_root(
+            writer,
+            "w15:commentsEx",
+            NamespaceSet::wordprocessing().with("w15", SCHEMA_WORDML_15),
+            |writer| {
+                for c in comments {
+                    c.to_writer(writer)?;
+                }
+
+                Ok(())
+            },
+        )?;
+
+        log::debug!("[CommentsExtended] Finished writing.");
+
+        Ok(())
+    }
+}
+
+__xml_test_suites!(
+    CommentExtended,
+    CommentExtended::new("00000001"),
+    r#"<w15:commentEx w15:paraId="00000001"/>"#,
+    CommentExtended::new("00000002")
+        .para_id_parent("00000001")
+        .done(true),
+    r#"<w15:commentEx w15:paraId="00000002" w15:paraIdParent="00000001" w15:done="true"/>"#,
+);
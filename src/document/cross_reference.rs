@@ -0,0 +1,239 @@
+use std::collections::HashMap;
+
+use crate::{
+    document::{Body, CharType, ListLabels, ParagraphContent, RunContent},
+    walk::{content_paragraphs, content_paragraphs_mut, paragraph_content, runs_mut},
+};
+
+/// Text and list label of a bookmarked range
+struct Bookmark {
+    text: String,
+    label: Option<String>,
+}
+
+/// What happens to the result of a field
+enum Update {
+    /// The result is replaced by the text
+    Text(String),
+    /// The field is marked dirty, to be recalculated by Word
+    Dirty,
+}
+
+impl<'a> Body<'a> {
+    /// Recomputes the cached results of cross-reference fields, so that they
+    /// read correctly before the fields are updated in Word.
+    ///
+    /// `REF` fields get the text of the bookmark they refer to, or with the
+    /// `\n`, `\r` or `\w` switch, the list label of the bookmarked paragraph.
+    /// Page numbers are only known once the document is laid out, so
+    /// `PAGEREF` fields are marked dirty instead, which makes Word update them
+    /// when the document is opened. Fields referring to a missing bookmark
+    /// keep their result.
+    ///
+    /// Returns the number of updated fields.
+    pub fn update_cross_references(&mut self, labels: &mut ListLabels) -> usize {
+        let bookmarks = self.bookmarks(labels);
+        let updates: Vec<Option<Update>> = self
+            .field_instructions()
+            .iter()
+            .map(|instruction| update_for(instruction, &bookmarks))
+            .collect();
+
+        let mut depth = 0usize;
+        let mut field = None;
+        let mut in_result = false;
+        let mut result_written = false;
+
+        for para in content_paragraphs_mut(&mut self.content) {
+            for run in runs_mut(para) {
+                for content in run.content.iter_mut() {
+                    match content {
+                        RunContent::FieldChar(field_char) => match field_char.ty {
+                            Some(CharType::Begin) => {
+                                depth += 1;
+                                if depth == 1 {
+                                    let index = field.map_or(0, |i| i + 1);
+                                    field = Some(index);
+                                    result_written = false;
+                                    if let Some(Some(Update::Dirty)) = updates.get(index) {
+                                        field_char.dirty = Some(true);
+                                    }
+                                }
+                            }
+                            Some(CharType::Separate) if depth == 1 => in_result = true,
+                            Some(CharType::End) => {
+                                depth = depth.saturating_sub(1);
+                                if depth == 0 {
+                                    in_result = false;
+                                }
+                            }
+                            _ => (),
+                        },
+                        RunContent::Text(text) if in_result && depth == 1 => {
+                            if let Some(Some(Update::Text(new))) =
+                                field.and_then(|i| updates.get(i))
+                            {
+                                text.text = if result_written {
+                                    "".into()
+                                } else {
+                                    new.clone().into()
+                                };
+                                result_written = true;
+                            }
+                        }
+                        _ => (),
+                    }
+                }
+            }
+        }
+
+        updates.iter().filter(|update| update.is_some()).count()
+    }
+
+    /// Collects the text and list label of every bookmark.
+    fn bookmarks(&self, labels: &mut ListLabels) -> HashMap<String, Bookmark> {
+        let mut bookmarks: HashMap<String, Bookmark> = HashMap::new();
+        // names of the open bookmarks by id
+        let mut open: HashMap<String, String> = HashMap::new();
+
+        for para in content_paragraphs(&self.content) {
+            let label = labels.label(para);
+            for content in paragraph_content(para) {
+                let run = match content {
+                    ParagraphContent::BookmarkStart(start) => {
+                        if let (Some(id), Some(name)) = (&start.id, &start.name) {
+                            open.insert(id.to_string(), name.to_string());
+                            bookmarks.insert(
+                                name.to_string(),
+                                Bookmark {
+                                    text: String::new(),
+                                    label: label.clone(),
+                                },
+                            );
+                        }
+                        continue;
+                    }
+                    ParagraphContent::BookmarkEnd(end) => {
+                        if let Some(id) = &end.id {
+                            open.remove(id.as_ref());
+                        }
+                        continue;
+                    }
+                    ParagraphContent::Run(run) => run,
+                    ParagraphContent::Link(link) => &link.content,
+                    _ => continue,
+                };
+                for content in &run.content {
+                    let text = match content {
+                        RunContent::Text(text) => text.text.as_ref(),
+                        RunContent::Tab(_) => "\t",
+                        _ => continue,
+                    };
+                    for name in open.values() {
+                        if let Some(bookmark) = bookmarks.get_mut(name) {
+                            bookmark.text.push_str(text);
+                        }
+                    }
+                }
+            }
+        }
+
+        bookmarks
+    }
+
+    /// Collects the instruction of every top-level complex field, in order.
+    pub(crate) fn field_instructions(&self) -> Vec<String> {
+        self.fields()
+            .into_iter()
+            .map(|field| field.instruction)
+            .collect()
+    }
+}
+
+fn update_for(instruction: &str, bookmarks: &HashMap<String, Bookmark>) -> Option<Update> {
+    let mut words = instruction.split_whitespace();
+    match words.next()?.to_ascii_uppercase().as_str() {
+        "REF" => {
+            let bookmark = bookmarks.get(words.next()?.trim_matches('"'))?;
+            if words.any(|switch| matches!(switch, "\\n" | "\\r" | "\\w")) {
+                bookmark.label.clone().map(Update::Text)
+            } else {
+                Some(Update::Text(bookmark.text.clone()))
+            }
+        }
+        "PAGEREF" => Some(Update::Dirty),
+        _ => None,
+    }
+}
+
+#[test]
+fn cross_references() {
+    use super::instrtext::InstrText;
+    use crate::document::{
+        BodyContent, BookmarkEnd, BookmarkStart, FieldChar, Paragraph, Run, SDTContent, SDT,
+    };
+
+    let field = |instruction: &str, result: &str| {
+        Run::default()
+            .push(FieldChar::from(CharType::Begin))
+            .push(InstrText::from(instruction.to_string()))
+            .push(FieldChar::from(CharType::Separate))
+            .push_text(result.to_string())
+            .push(FieldChar::from(CharType::End))
+    };
+
+    let mut body = Body::default();
+    body.push(BodyContent::Sdt(SDT::default().content(SDTContent {
+        content: vec![BodyContent::Paragraph(
+            Paragraph::default().push(field(" REF _Ref1 ", "stale")),
+        )],
+    })))
+    .push(
+        Paragraph::default()
+            .push(BookmarkStart::default().id("0").name("_Ref1"))
+            .push_text("Figure ")
+            .push_text("1")
+            .push(BookmarkEnd::default().id("0"))
+            .push_text(" shows it."),
+    )
+    .push(
+        Paragraph::default()
+            .push_text("See ")
+            .push(field(
+                " REF _Ref1 \\h ",
+                "Error! Reference source not found.",
+            ))
+            .push(field(" PAGEREF _Ref1 \\h ", "1"))
+            .push(field(" REF missing ", "stale")),
+    );
+
+    let mut labels = ListLabels::new(None, None);
+    assert_eq!(body.update_cross_references(&mut labels), 3);
+
+    let text = match &body.content[2] {
+        BodyContent::Paragraph(para) => para.text(),
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        text,
+        "See  REF _Ref1 \\h Figure 1 PAGEREF _Ref1 \\h 1 REF missing stale"
+    );
+
+    let dirty: Vec<_> = match &body.content[2] {
+        BodyContent::Paragraph(para) => para
+            .content
+            .iter()
+            .filter_map(|content| match content {
+                ParagraphContent::Run(run) => match &run.content[0] {
+                    RunContent::FieldChar(field_char) => Some(field_char.dirty),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect(),
+        _ => unreachable!(),
+    };
+    assert_eq!(dirty, [None, Some(true), None]);
+
+    assert_eq!(body.fields()[0].result, "Figure 1");
+}
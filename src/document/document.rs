@@ -7,15 +7,18 @@ use hard_xml::{XmlRead, XmlResult, XmlWrite, XmlWriter};
 use std::io::Write;
 
 use crate::__xml_test_suites;
-use crate::schema::{SCHEMA_MAIN, SCHEMA_RELATIONSHIPS_DOCUMENT, SCHEMA_WORDML_14, SCHEMA_WP};
+use crate::schema::{write_root, NamespaceSet, SCHEMA_RELATIONSHIPS_DOCUMENT, SCHEMA_WP};
 
-use crate::document::{Body, BodyContent};
+use crate::document::{Background, Body, BodyContent, Paragraph};
 
 /// The root element of the main document part.
 #[derive(Debug, Default, XmlRead, Clone)]
 #[cfg_attr(test, derive(PartialEq))]
 #[xml(tag = "w:document")]
 pub struct Document<'a> {
+    /// Specifies the page background, see [`crate::Docx::set_background`].
+    #[xml(child = "w:background")]
+    pub background: Option<Background>,
     /// Specifies the body of the docment.
     #[xml(child = "w:body")]
     pub body: Body<'a>,
@@ -26,30 +29,43 @@ impl<'a> Document<'a> {
         self.body.push(content);
         self
     }
+
+    /// Keeps only the content of the body for which `f` returns `true`, see
+    /// [`Body::retain`].
+    pub fn retain<F: FnMut(&BodyContent<'a>) -> bool>(&mut self, f: F) -> &mut Self {
+        self.body.retain(f);
+        self
+    }
+
+    /// Removes the paragraphs for which `f` returns `true`, see
+    /// [`Body::remove_paragraphs`].
+    pub fn remove_paragraphs<F: FnMut(&Paragraph<'a>) -> bool>(&mut self, f: F) -> usize {
+        self.body.remove_paragraphs(f)
+    }
 }
 
 impl<'a> XmlWrite for Document<'a> {
     fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
-        let Document { body } = self;
+        let Document { background, body } = self;
 
         log::debug!("[Document] Started writing.");
         let _ = write!(writer.inner, "{}", crate::schema::SCHEMA_XML);
 
-        writer.write_element_start("w:document")?;
-
-        writer.write_attribute("xmlns:w", SCHEMA_MAIN)?;
-
-        writer.write_attribute("xmlns:w14", SCHEMA_WORDML_14)?;
-
-        writer.write_attribute("xmlns:wp", SCHEMA_WP)?;
-
-        writer.write_attribute("xmlns:r", SCHEMA_RELATIONSHIPS_DOCUMENT)?;
-
-        writer.write_element_end_open()?;
-
-        body.to_writer(writer)?;
-
-        writer.write_element_end_close("w:document")?;
+        write_root(
+            writer,
+            "w:document",
+            NamespaceSet::wordprocessing()
+                .with("wp", SCHEMA_WP)
+                .with("r", SCHEMA_RELATIONSHIPS_DOCUMENT),
+            |writer| {
+                if let Some(background) = background {
+                    background.to_writer(writer)?;
+                }
+                body.to_writer(writer)?;
+
+                Ok(())
+            },
+        )?;
 
         log::debug!("[Document] Finished writing.");
 
@@ -61,10 +77,25 @@ __xml_test_suites!(
     Document,
     Document::default(),
     format!(
-        r#"{}<w:document xmlns:w="{}" xmlns:w14="{}" xmlns:wp="{}" xmlns:r="{}"><w:body/></w:document>"#,
+        r#"{}<w:document xmlns:w="{}" xmlns:w14="{}" xmlns:mc="{}" xmlns:wp="{}" xmlns:r="{}" mc:Ignorable="w14"><w:body/></w:document>"#,
+        crate::schema::SCHEMA_XML,
+        crate::schema::SCHEMA_MAIN,
+        crate::schema::SCHEMA_WORDML_14,
+        crate::schema::SCHEMA_MC,
+        SCHEMA_WP,
+        SCHEMA_RELATIONSHIPS_DOCUMENT
+    )
+    .as_str(),
+    Document {
+        background: Some(Background::default().color("FFF8E7")),
+        ..Default::default()
+    },
+    format!(
+        r#"{}<w:document xmlns:w="{}" xmlns:w14="{}" xmlns:mc="{}" xmlns:wp="{}" xmlns:r="{}" mc:Ignorable="w14"><w:background w:color="FFF8E7"/><w:body/></w:document>"#,
         crate::schema::SCHEMA_XML,
-        SCHEMA_MAIN,
-        SCHEMA_WORDML_14,
+        crate::schema::SCHEMA_MAIN,
+        crate::schema::SCHEMA_WORDML_14,
+        crate::schema::SCHEMA_MC,
         SCHEMA_WP,
         SCHEMA_RELATIONSHIPS_DOCUMENT
     )
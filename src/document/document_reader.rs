@@ -0,0 +1,269 @@
+use hard_xml::{
+    xmlparser::{ElementEnd, Token, Tokenizer},
+    XmlError, XmlRead, XmlResult,
+};
+use std::io::{self, ErrorKind, Read, Seek};
+use std::ops::Range;
+use zip::{read::ZipFile, ZipArchive};
+
+use crate::document::BodyContent;
+use crate::DocxResult;
+
+/// The number of bytes read from the part at once
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Streaming reader over the body of a main document part
+///
+/// Yields the items of `w:body` one at a time instead of building the whole
+/// [`Document`](crate::document::Document). The part is read in chunks as it
+/// is decompressed, so that only a single paragraph or table is held in
+/// memory at once. Unknown elements are skipped.
+///
+/// ```no_run
+/// use docx_rust::document::{BodyContent, DocumentReader};
+/// use std::fs::File;
+/// use zip::ZipArchive;
+///
+/// let mut package = ZipArchive::new(File::open("huge.docx")?)?;
+/// let mut reader = DocumentReader::from_package(&mut package)?;
+/// while let Some(content) = reader.read_content()? {
+///     if let BodyContent::Paragraph(para) = content {
+///         println!("{}", para.text());
+///     }
+/// }
+/// # Ok::<(), docx_rust::DocxError>(())
+/// ```
+pub struct DocumentReader<R> {
+    source: R,
+    /// The text read but not parsed yet, after the text already parsed
+    text: String,
+    /// The length of the text already parsed, dropped before reading more
+    parsed: usize,
+    /// The bytes read after `text`, the start of an incomplete character
+    bytes: Vec<u8>,
+    state: State,
+}
+
+enum State {
+    Start,
+    Body,
+    Done,
+}
+
+impl<'a> DocumentReader<ZipFile<'a>> {
+    /// Reads the `word/document.xml` part of a package as it is
+    /// decompressed.
+    pub fn from_package<T: Read + Seek>(package: &'a mut ZipArchive<T>) -> DocxResult<Self> {
+        Ok(DocumentReader::new(package.by_name("word/document.xml")?))
+    }
+}
+
+impl<R: Read> DocumentReader<R> {
+    /// Reads the XML of a `word/document.xml` part.
+    pub fn new(source: R) -> Self {
+        DocumentReader {
+            source,
+            text: String::new(),
+            parsed: 0,
+            bytes: Vec::new(),
+            state: State::Start,
+        }
+    }
+
+    /// Returns the next item of `w:body`, or `None` after the last one.
+    ///
+    /// The item borrows from the reader, so it has to be dropped before
+    /// reading the next one.
+    pub fn read_content(&mut self) -> XmlResult<Option<BodyContent<'_>>> {
+        let range = loop {
+            match self.state {
+                State::Done => return Ok(None),
+                State::Start => self.enter_body()?,
+                State::Body => {
+                    if let Some(range) = self.next_element()? {
+                        break range;
+                    }
+                }
+            }
+        };
+
+        match BodyContent::from_str(&self.text[range]) {
+            Ok(content) => Ok(Some(content)),
+            Err(err) => {
+                self.state = State::Done;
+                Err(err)
+            }
+        }
+    }
+
+    /// Moves past the start of `w:body`, or reads more of the part if it
+    /// isn't complete yet.
+    fn enter_body(&mut self) -> XmlResult<()> {
+        let mut depth = 0usize;
+        let mut body = false;
+
+        let mut error = XmlError::UnexpectedEof;
+        for token in Tokenizer::from(self.text.as_str()) {
+            match token {
+                Ok(Token::ElementStart { prefix, local, .. }) => {
+                    body = depth == 1 && prefix.as_str() == "w" && local.as_str() == "body";
+                    depth += 1;
+                }
+                Ok(Token::ElementEnd {
+                    end: ElementEnd::Open,
+                    span,
+                }) if body => {
+                    self.parsed = span.end();
+                    self.state = State::Body;
+                    return Ok(());
+                }
+                Ok(Token::ElementEnd {
+                    end: ElementEnd::Empty | ElementEnd::Close(..),
+                    ..
+                }) => {
+                    depth = depth.saturating_sub(1);
+                    if body || depth == 0 {
+                        self.state = State::Done;
+                        return Ok(());
+                    }
+                }
+                Ok(_) => {}
+                Err(err) => {
+                    error = err.into();
+                    break;
+                }
+            }
+        }
+
+        self.fill(error)
+    }
+
+    /// Returns the range of the next item of `w:body` in the text, skipping
+    /// unknown elements, or `None` at the end of `w:body` or when more of the
+    /// part has been read.
+    fn next_element(&mut self) -> XmlResult<Option<Range<usize>>> {
+        let mut depth = 0usize;
+        let mut start = 0;
+        let mut content = false;
+
+        let tokens = Tokenizer::from_fragment(&self.text, self.parsed..self.text.len());
+        let mut error = XmlError::UnexpectedEof;
+        for token in tokens {
+            match token {
+                Ok(Token::ElementStart {
+                    prefix,
+                    local,
+                    span,
+                }) => {
+                    if depth == 0 {
+                        start = span.start();
+                        content = prefix.as_str() == "w"
+                            && matches!(local.as_str(), "p" | "tbl" | "sdt" | "sectPr" | "tc");
+                    }
+                    depth += 1;
+                }
+                Ok(Token::ElementEnd {
+                    end: ElementEnd::Open,
+                    ..
+                }) => {}
+                Ok(Token::ElementEnd { span, .. }) => {
+                    if depth == 0 {
+                        // the end of `w:body`
+                        self.state = State::Done;
+                        return Ok(None);
+                    }
+                    depth -= 1;
+                    if depth == 0 {
+                        self.parsed = span.end();
+                        if content {
+                            return Ok(Some(start..span.end()));
+                        }
+                    }
+                }
+                Ok(_) => {}
+                Err(err) => {
+                    error = err.into();
+                    break;
+                }
+            }
+        }
+
+        self.fill(error)?;
+        Ok(None)
+    }
+
+    /// Drops the text already parsed and reads more of the part, returning
+    /// `error` if it ended.
+    fn fill(&mut self, error: XmlError) -> XmlResult<()> {
+        self.text.drain(..self.parsed);
+        self.parsed = 0;
+
+        let len = self.bytes.len();
+        self.bytes.resize(len + CHUNK_SIZE, 0);
+        let read = loop {
+            match self.source.read(&mut self.bytes[len..]) {
+                Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+                read => break read,
+            }
+        };
+        self.bytes.truncate(len + *read.as_ref().unwrap_or(&0));
+        if read? == 0 {
+            self.state = State::Done;
+            return Err(error);
+        }
+
+        let valid = match std::str::from_utf8(&self.bytes) {
+            Ok(text) => text.len(),
+            // a character split between two reads
+            Err(err) if err.error_len().is_none() => err.valid_up_to(),
+            Err(err) => {
+                self.state = State::Done;
+                return Err(io::Error::new(ErrorKind::InvalidData, err).into());
+            }
+        };
+        self.text
+            .push_str(std::str::from_utf8(&self.bytes[..valid]).unwrap_or_default());
+        self.bytes.drain(..valid);
+
+        Ok(())
+    }
+}
+
+#[test]
+fn streaming() -> XmlResult<()> {
+    /// Returns a few bytes per read, splitting elements and characters
+    struct Trickle<'a>(&'a [u8]);
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let len = self.0.len().min(buf.len()).min(3);
+            buf[..len].copy_from_slice(&self.0[..len]);
+            self.0 = &self.0[len..];
+            Ok(len)
+        }
+    }
+
+    let xml = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:background w:color="FFFFFF"/><w:body><w:p><w:r><w:t>é</w:t></w:r></w:p><w:customXml><w:p/></w:customXml><w:p><w:r><w:t>b</w:t></w:r></w:p><w:sectPr/></w:body></w:document>"#;
+
+    let mut texts = Vec::new();
+    let mut reader = DocumentReader::new(Trickle(xml.as_bytes()));
+    while let Some(content) = reader.read_content()? {
+        texts.push(match content {
+            BodyContent::Paragraph(p) => p.text(),
+            BodyContent::SectionProperty(_) => "sectPr".to_string(),
+            _ => unreachable!(),
+        });
+    }
+    assert_eq!(texts, ["é", "b", "sectPr"]);
+
+    let mut empty = DocumentReader::new(&b"<w:document><w:body/></w:document>"[..]);
+    assert!(empty.read_content()?.is_none());
+
+    let end = xml.find("<w:customXml>").unwrap_or_default();
+    let mut truncated = DocumentReader::new(&xml.as_bytes()[..end]);
+    assert!(truncated.read_content()?.is_some());
+    assert!(truncated.read_content().is_err());
+
+    Ok(())
+}
@@ -1,11 +1,18 @@
 #![allow(unused_must_use)]
 
 use std::borrow::Cow;
+use std::io::Write;
 
 use derive_more::From;
-use hard_xml::{XmlRead, XmlWrite};
+use hard_xml::{XmlRead, XmlReader, XmlResult, XmlWrite, XmlWriter};
+use xmlparser::{ElementEnd, Token};
 
-use crate::{__define_enum, __string_enum};
+use crate::{
+    __define_enum, __string_enum, __xml_test_suites,
+    document::read_element,
+    rels::Relationships,
+    schema::{write_raw, SCHEMA_DECORATIVE, SCHEMA_DRAWINGML, SCHEMA_PICTURE},
+};
 
 #[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
 #[cfg_attr(test, derive(PartialEq))]
@@ -18,6 +25,272 @@ pub struct Drawing<'a> {
     pub inline: Option<Inline<'a>>,
 }
 
+impl<'a> Drawing<'a> {
+    /// Returns the relationship id of the embedded picture.
+    pub fn image_id(&self) -> Option<&str> {
+        let graphic = match (&self.inline, &self.anchor) {
+            (Some(inline), _) => inline.graphic.as_ref(),
+            (None, Some(anchor)) => anchor.graphic.as_ref(),
+            (None, None) => None,
+        }?;
+        let embed = &graphic.data.pic.fill.blip.embed;
+        (!embed.is_empty()).then_some(embed)
+    }
+
+    /// Returns the relationship id of the chart, if the drawing shows one.
+    pub fn chart_id(&self) -> Option<&str> {
+        let graphic = match (&self.inline, &self.anchor) {
+            (Some(inline), _) => inline.graphic.as_ref(),
+            (None, Some(anchor)) => anchor.graphic.as_ref(),
+            (None, None) => None,
+        }?;
+        Some(&graphic.data.chart.as_ref()?.id)
+    }
+
+    /// Mutable version of [`Drawing::chart_id`].
+    pub(crate) fn chart_id_mut(&mut self) -> Option<&mut Cow<'a, str>> {
+        let graphic = match (&mut self.inline, &mut self.anchor) {
+            (Some(inline), _) => inline.graphic.as_mut(),
+            (None, Some(anchor)) => anchor.graphic.as_mut(),
+            (None, None) => None,
+        }?;
+        Some(&mut graphic.data.chart.as_mut()?.id)
+    }
+
+    /// Mutable version of [`Drawing::image_id`].
+    pub(crate) fn image_id_mut(&mut self) -> Option<&mut Cow<'a, str>> {
+        let graphic = match (&mut self.inline, &mut self.anchor) {
+            (Some(inline), _) => inline.graphic.as_mut(),
+            (None, Some(anchor)) => anchor.graphic.as_mut(),
+            (None, None) => None,
+        }?;
+        let embed = &mut graphic.data.pic.fill.blip.embed;
+        (!embed.is_empty()).then_some(embed)
+    }
+
+    /// Returns the path of the embedded picture relative to the part it was
+    /// read from, like `media/image1.png`, resolving its relationship id
+    /// against the relationships of that part.
+    pub fn image_target<'r>(&self, rels: &'r Relationships) -> Option<&'r str> {
+        rels.get_target(self.image_id()?)
+    }
+
+    /// Creates an inline picture of the given size in EMUs, 914400 per inch,
+    /// showing the image of relationship `embed`.
+    ///
+    /// The relationship belongs to the part the drawing is written to. The
+    /// `id` must be unique among the drawings of the document, see
+    /// [`Docx::next_drawing_id`](crate::Docx::next_drawing_id).
+    ///
+    /// ```rust
+    /// use docx_rust::document::Drawing;
+    ///
+    /// let logo = Drawing::inline_picture(1, "rId1", 914400, 457200);
+    /// assert_eq!(logo.image_id(), Some("rId1"));
+    /// assert_eq!(logo.doc_property().unwrap().name.as_deref(), Some("Picture 1"));
+    /// ```
+    pub fn inline_picture<T: Into<Cow<'a, str>>>(id: isize, embed: T, cx: u64, cy: u64) -> Self {
+        let name: Cow<'a, str> = format!("Picture {}", id).into();
+        let picture = Picture {
+            a: SCHEMA_PICTURE.into(),
+            nv_pic_pr: NvPicPr {
+                c_nv_pr: Some(CNvPr {
+                    id: Some(0),
+                    name: Some(name.clone()),
+                    ..Default::default()
+                }),
+                c_nv_pic_pr: Some(CNvPicPr {}),
+            },
+            fill: BlipFill {
+                blip: Blip {
+                    embed: embed.into(),
+                    cstate: None,
+                },
+                stretch: Some(Stretch {
+                    fill_rect: Some(FillRect {}),
+                }),
+            },
+            sp_pr: SpPr {
+                xfrm: Some(Xfrm {
+                    offset: Some(Offset {
+                        x: Some(0),
+                        y: Some(0),
+                    }),
+                    ext: Some(Ext {
+                        cx: Some(cx as isize),
+                        cy: Some(cy as isize),
+                    }),
+                }),
+                prst_geom: Some(PrstGeom {
+                    prst: Some("rect".into()),
+                    av_lst: Some(AvList {}),
+                }),
+            },
+        };
+        Drawing {
+            anchor: None,
+            inline: Some(Inline {
+                extent: Some(Extent { cx, cy }),
+                doc_property: DocPr {
+                    id: Some(id),
+                    name: Some(name),
+                    ..Default::default()
+                },
+                graphic: Some(Graphic {
+                    a: SCHEMA_DRAWINGML.into(),
+                    data: GraphicData {
+                        uri: SCHEMA_PICTURE.into(),
+                        pic: picture,
+                        ..Default::default()
+                    },
+                }),
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// Sets the alternative text of the drawing, and of its picture.
+    ///
+    /// ```rust
+    /// use docx_rust::document::Drawing;
+    ///
+    /// let logo = Drawing::inline_picture(1, "rId1", 914400, 457200)
+    ///     .title("Logo")
+    ///     .description("The ACME logo, a red anvil");
+    /// assert_eq!(logo.description_text(), Some("The ACME logo, a red anvil"));
+    /// ```
+    pub fn description<T: Into<Cow<'a, str>>>(mut self, descr: T) -> Self {
+        let descr = descr.into();
+        if let Some(c_nv_pr) = self.picture_properties_mut() {
+            c_nv_pr.descr = Some(descr.clone());
+        }
+        if let Some(doc_property) = self.doc_property_mut() {
+            doc_property.descr = Some(descr);
+        }
+        self
+    }
+
+    /// Sets the title of the drawing, and of its picture.
+    pub fn title<T: Into<Cow<'a, str>>>(mut self, title: T) -> Self {
+        let title = title.into();
+        if let Some(c_nv_pr) = self.picture_properties_mut() {
+            c_nv_pr.title = Some(title.clone());
+        }
+        if let Some(doc_property) = self.doc_property_mut() {
+            doc_property.title = Some(title);
+        }
+        self
+    }
+
+    /// Marks the drawing as decorative, so that screen readers skip it.
+    ///
+    /// ```rust
+    /// use docx_rust::document::Drawing;
+    ///
+    /// let rule = Drawing::inline_picture(1, "rId1", 914400, 9144).decorative();
+    /// assert!(rule.is_decorative());
+    /// assert_eq!(rule.doc_property().unwrap().hidden, None);
+    /// ```
+    pub fn decorative(mut self) -> Self {
+        if let Some(doc_property) = self.doc_property_mut() {
+            let extensions = doc_property
+                .extensions
+                .get_or_insert_with(ExtensionList::default);
+            extensions.a = Some(SCHEMA_DRAWINGML.into());
+            extensions
+                .extensions
+                .retain(|ext| ext.uri != DECORATIVE_EXTENSION);
+            extensions.extensions.push(Extension {
+                uri: DECORATIVE_EXTENSION.into(),
+                decorative: Some(Decorative {
+                    adec: SCHEMA_DECORATIVE.into(),
+                    value: Some(true),
+                }),
+            });
+        }
+        self
+    }
+
+    /// Returns whether the drawing is marked as decorative.
+    pub fn is_decorative(&self) -> bool {
+        self.doc_property()
+            .and_then(|doc_property| doc_property.extensions.as_ref())
+            .is_some_and(|extensions| {
+                extensions.extensions.iter().any(|ext| {
+                    ext.decorative
+                        .as_ref()
+                        .is_some_and(|decorative| decorative.value != Some(false))
+                })
+            })
+    }
+
+    /// Returns the alternative text of the drawing.
+    pub fn description_text(&self) -> Option<&str> {
+        self.doc_property()?.descr.as_deref()
+    }
+
+    /// Returns the non-visual properties of the drawing.
+    pub fn doc_property(&self) -> Option<&DocPr<'a>> {
+        match (&self.inline, &self.anchor) {
+            (Some(inline), _) => Some(&inline.doc_property),
+            (None, Some(anchor)) => Some(&anchor.doc_property),
+            (None, None) => None,
+        }
+    }
+
+    /// Mutable version of [`Drawing::doc_property`].
+    pub fn doc_property_mut(&mut self) -> Option<&mut DocPr<'a>> {
+        match (&mut self.inline, &mut self.anchor) {
+            (Some(inline), _) => Some(&mut inline.doc_property),
+            (None, Some(anchor)) => Some(&mut anchor.doc_property),
+            (None, None) => None,
+        }
+    }
+
+    /// Returns the size of the drawing in EMUs, 914400 per inch.
+    pub fn size(&self) -> Option<(u64, u64)> {
+        let extent = match (&self.inline, &self.anchor) {
+            (Some(inline), _) => inline.extent.as_ref(),
+            (None, Some(anchor)) => anchor.extent.as_ref(),
+            (None, None) => None,
+        }?;
+        Some((extent.cx, extent.cy))
+    }
+
+    /// Resizes the drawing and its picture, in EMUs.
+    ///
+    /// ```rust
+    /// use docx_rust::document::Drawing;
+    ///
+    /// let mut logo = Drawing::inline_picture(1, "rId1", 914400, 457200);
+    /// logo.set_size(457200, 228600);
+    /// assert_eq!(logo.size(), Some((457200, 228600)));
+    /// ```
+    pub fn set_size(&mut self, cx: u64, cy: u64) {
+        let (extent, graphic) = match (&mut self.inline, &mut self.anchor) {
+            (Some(inline), _) => (&mut inline.extent, inline.graphic.as_mut()),
+            (None, Some(anchor)) => (&mut anchor.extent, anchor.graphic.as_mut()),
+            (None, None) => return,
+        };
+        *extent = Some(Extent { cx, cy });
+        if let Some(xfrm) = graphic.and_then(|graphic| graphic.data.pic.sp_pr.xfrm.as_mut()) {
+            xfrm.ext = Some(Ext {
+                cx: Some(cx as isize),
+                cy: Some(cy as isize),
+            });
+        }
+    }
+
+    fn picture_properties_mut(&mut self) -> Option<&mut CNvPr<'a>> {
+        let graphic = match (&mut self.inline, &mut self.anchor) {
+            (Some(inline), _) => inline.graphic.as_mut(),
+            (None, Some(anchor)) => anchor.graphic.as_mut(),
+            (None, None) => None,
+        }?;
+        graphic.data.pic.nv_pic_pr.c_nv_pr.as_mut()
+    }
+}
+
 #[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
 #[cfg_attr(test, derive(PartialEq))]
 #[xml(tag = "wp:anchor")]
@@ -247,8 +520,53 @@ pub struct DocPr<'a> {
     pub id: Option<isize>,
     #[xml(attr = "name")]
     pub name: Option<Cow<'a, str>>,
+    /// Specifies the alternative text, read by screen readers
     #[xml(attr = "descr")]
     pub descr: Option<Cow<'a, str>>,
+    /// Specifies that the object isn't shown
+    #[xml(attr = "hidden")]
+    pub hidden: Option<bool>,
+    /// Specifies the title of the object
+    #[xml(attr = "title")]
+    pub title: Option<Cow<'a, str>>,
+    /// Specifies the extensions, like the decorative flag, see
+    /// [`Drawing::decorative`]
+    #[xml(child = "a:extLst")]
+    pub extensions: Option<ExtensionList<'a>>,
+}
+
+/// The uri of the extension marking a drawing as decorative
+const DECORATIVE_EXTENSION: &str = "{C183D7F6-B498-43B3-948B-1728B52AA6E4}";
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "a:extLst")]
+pub struct ExtensionList<'a> {
+    #[xml(attr = "xmlns:a")]
+    pub a: Option<Cow<'a, str>>,
+    #[xml(child = "a:ext")]
+    pub extensions: Vec<Extension<'a>>,
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "a:ext")]
+pub struct Extension<'a> {
+    #[xml(attr = "uri")]
+    pub uri: Cow<'a, str>,
+    #[xml(child = "adec:decorative")]
+    pub decorative: Option<Decorative<'a>>,
+}
+
+/// Marks an object as decorative, so that screen readers skip it
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "adec:decorative")]
+pub struct Decorative<'a> {
+    #[xml(attr = "xmlns:adec")]
+    pub adec: Cow<'a, str>,
+    #[xml(attr = "val")]
+    pub value: Option<bool>,
 }
 
 #[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
@@ -261,14 +579,86 @@ pub struct Graphic<'a> {
     pub data: GraphicData<'a>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+/// The content of a drawing, a picture or a chart
+///
+/// Other content, e.g. the `dgm:relIds` of a SmartArt diagram or the `wps:wsp`
+/// of a shape, is kept as it was read, see [`GraphicData::unknown`].
+#[derive(Debug, Default, Clone)]
 #[cfg_attr(test, derive(PartialEq))]
-#[xml(tag = "a:graphicData")]
 pub struct GraphicData<'a> {
-    #[xml(default, attr = "uri")]
     pub uri: Cow<'a, str>,
-    #[xml(child = "pic:pic")]
+    /// Specifies the picture, unless the drawing shows a chart or other content
     pub pic: Picture<'a>,
+    /// Specifies the chart, written instead of the picture
+    pub chart: Option<ChartReference<'a>>,
+    /// The children that aren't modelled, written verbatim instead of the
+    /// picture
+    pub unknown: Vec<Cow<'a, str>>,
+}
+
+impl<'a> XmlRead<'a> for GraphicData<'a> {
+    fn from_reader(reader: &mut XmlReader<'a>) -> XmlResult<Self> {
+        let mut data = GraphicData::default();
+
+        reader.read_till_element_start("a:graphicData")?;
+        while let Some((key, value)) = reader.find_attribute()? {
+            if key == "uri" {
+                data.uri = value;
+            }
+        }
+        if let Some(Token::ElementEnd {
+            end: ElementEnd::Empty,
+            ..
+        }) = reader.next().transpose()?
+        {
+            return Ok(data);
+        }
+
+        while let Some(tag) = reader.find_element_start(Some("a:graphicData"))? {
+            match tag {
+                "pic:pic" => data.pic = Picture::from_reader(reader)?,
+                "c:chart" => data.chart = Some(ChartReference::from_reader(reader)?),
+                _ => data.unknown.push(read_element(reader)?.into()),
+            }
+        }
+
+        Ok(data)
+    }
+}
+
+impl<'a> XmlWrite for GraphicData<'a> {
+    fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
+        let GraphicData {
+            uri,
+            pic,
+            chart,
+            unknown,
+        } = self;
+
+        writer.write_element_start("a:graphicData")?;
+        writer.write_attribute("uri", uri)?;
+        writer.write_element_end_open()?;
+        match chart {
+            Some(chart) => chart.to_writer(writer)?,
+            None if unknown.is_empty() => pic.to_writer(writer)?,
+            None => {}
+        }
+        for xml in unknown {
+            write_raw(writer, xml)?;
+        }
+        writer.write_element_end_close("a:graphicData")?;
+        Ok(())
+    }
+}
+
+/// A chart shown by a drawing, see [`crate::chart`]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "c:chart")]
+pub struct ChartReference<'a> {
+    /// Specifies the relationship id of the chart part
+    #[xml(attr = "r:id")]
+    pub id: Cow<'a, str>,
 }
 
 #[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
@@ -368,8 +758,15 @@ pub struct CNvPr<'a> {
     pub id: Option<isize>,
     #[xml(attr = "name")]
     pub name: Option<Cow<'a, str>>,
+    /// Specifies the alternative text, read by screen readers
     #[xml(attr = "descr")]
     pub descr: Option<Cow<'a, str>>,
+    /// Specifies that the object isn't shown
+    #[xml(attr = "hidden")]
+    pub hidden: Option<bool>,
+    /// Specifies the title of the object
+    #[xml(attr = "title")]
+    pub title: Option<Cow<'a, str>>,
 }
 
 #[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
@@ -420,3 +817,29 @@ pub struct Extent {
     #[xml(default, attr = "cy")]
     pub cy: u64,
 }
+
+__xml_test_suites!(
+    DocPr,
+    DocPr {
+        id: Some(1),
+        ..Default::default()
+    },
+    r#"<wp:docPr id="1"/>"#,
+    Drawing::inline_picture(1, "rId1", 1, 1)
+        .decorative()
+        .doc_property()
+        .cloned()
+        .unwrap(),
+    r#"<wp:docPr id="1" name="Picture 1"><a:extLst xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><a:ext uri="{C183D7F6-B498-43B3-948B-1728B52AA6E4}"><adec:decorative xmlns:adec="http://schemas.microsoft.com/office/drawing/2017/decorative" val="true"/></a:ext></a:extLst></wp:docPr>"#,
+);
+
+#[test]
+fn smart_art_round_trip() -> XmlResult<()> {
+    let xml = r#"<a:graphicData uri="http://schemas.openxmlformats.org/drawingml/2006/diagram"><dgm:relIds xmlns:dgm="http://schemas.openxmlformats.org/drawingml/2006/diagram" r:dm="rId4" r:lo="rId5" r:qs="rId6" r:cs="rId7"/></a:graphicData>"#;
+
+    let data = GraphicData::from_str(xml)?;
+    assert_eq!(data.unknown.len(), 1);
+    assert_eq!(data.to_string()?, xml);
+
+    Ok(())
+}
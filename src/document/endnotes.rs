@@ -8,7 +8,7 @@ use std::borrow::Cow;
 use std::io::Write;
 
 use crate::__xml_test_suites;
-use crate::schema::{SCHEMA_MAIN, SCHEMA_WORDML_14};
+use crate::schema::{write_root, NamespaceSet};
 
 use crate::document::BodyContent;
 
@@ -64,19 +64,18 @@ impl<'a> XmlWrite for EndNotes<'a> {
         log::debug!("[EndNotes] Started writing.");
         let _ = write!(writer.inner, "{}", crate::schema::SCHEMA_XML);
 
-        writer.write_element_start("w:endnotes")?;
+        write_root(
+            writer,
+            "w:endnotes",
+            NamespaceSet::wordprocessing(),
+            |writer| {
+                for c in content {
+                    c.to_writer(writer)?;
+                }
 
-        writer.write_attribute("xmlns:w", SCHEMA_MAIN)?;
-
-        writer.write_attribute("xmlns:w14", SCHEMA_WORDML_14)?;
-
-        writer.write_element_end_open()?;
-
-        for c in content {
-            c.to_writer(writer)?;
-        }
-
-        writer.write_element_end_close("w:endnotes")?;
+                Ok(())
+            },
+        )?;
 
         log::debug!("[Document] Finished writing.");
 
@@ -88,10 +87,11 @@ __xml_test_suites!(
     EndNotes,
     EndNotes::default(),
     format!(
-        r#"{}<w:endnotes xmlns:w="{}" xmlns:w14="{}"></w:endnotes>"#,
+        r#"{}<w:endnotes xmlns:w="{}" xmlns:w14="{}" xmlns:mc="{}" mc:Ignorable="w14"></w:endnotes>"#,
         crate::schema::SCHEMA_XML,
-        SCHEMA_MAIN,
-        SCHEMA_WORDML_14
+        crate::schema::SCHEMA_MAIN,
+        crate::schema::SCHEMA_WORDML_14,
+        crate::schema::SCHEMA_MC
     )
     .as_str(),
 );
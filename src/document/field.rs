@@ -0,0 +1,244 @@
+use crate::{
+    document::{Body, CharType, Paragraph, Run, RunContent},
+    walk::{content_paragraphs, runs},
+};
+
+/// A complex field, read from its `begin`, `separate` and `end` characters
+///
+/// Complex fields are stored as runs: the field characters delimit the
+/// instruction, like ` PAGE \* MERGEFORMAT `, and the result cached by the
+/// application which last updated the field. [`Paragraph::fields`] and
+/// [`Body::fields`] gather them back into fields, leaving the runs as they
+/// are.
+///
+/// ```rust
+/// use docx_rust::document::*;
+///
+/// let para = Paragraph::default()
+///     .push_text(("Page ", TextSpace::Preserve))
+///     .push_field("PAGE \\* ARABIC", "3");
+/// let fields = para.fields();
+/// assert_eq!(fields[0].code().as_deref(), Some("PAGE"));
+/// assert_eq!(fields[0].arguments(), ["\\*", "ARABIC"]);
+/// assert_eq!(fields[0].result, "3");
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Field {
+    /// Specifies the instruction, including the results of nested fields
+    pub instruction: String,
+    /// Specifies the cached result, empty if the field has none
+    pub result: String,
+    /// Specifies that the result is stale and is to be recalculated
+    pub dirty: bool,
+    /// Specifies the fields nested in the instruction or the result
+    pub fields: Vec<Field>,
+}
+
+impl Field {
+    /// Returns the field code, the first word of the instruction in upper
+    /// case, like `PAGE` or `REF`.
+    pub fn code(&self) -> Option<String> {
+        self.instruction
+            .split_whitespace()
+            .next()
+            .map(str::to_ascii_uppercase)
+    }
+
+    /// Returns the words of the instruction after the field code, arguments
+    /// and switches, without the quotes around quoted ones.
+    pub fn arguments(&self) -> Vec<&str> {
+        let mut arguments = Vec::new();
+        let mut rest = self.instruction.trim_start();
+        while !rest.is_empty() {
+            let (word, next) = match rest.strip_prefix('"') {
+                Some(quoted) => match quoted.find('"') {
+                    Some(end) => (&quoted[..end], &quoted[end + 1..]),
+                    None => (quoted, ""),
+                },
+                None => match rest.find(char::is_whitespace) {
+                    Some(end) => (&rest[..end], &rest[end..]),
+                    None => (rest, ""),
+                },
+            };
+            arguments.push(word);
+            rest = next.trim_start();
+        }
+        arguments.into_iter().skip(1).collect()
+    }
+}
+
+/// A field whose `end` character hasn't been read yet
+struct OpenField {
+    field: Field,
+    in_result: bool,
+}
+
+/// Gathers the fields of runs read in document order.
+#[derive(Default)]
+struct FieldReader {
+    open: Vec<OpenField>,
+    fields: Vec<Field>,
+}
+
+impl FieldReader {
+    fn paragraph(&mut self, para: &Paragraph) {
+        for run in runs(para) {
+            self.run(run);
+        }
+    }
+
+    fn run(&mut self, run: &Run) {
+        for content in &run.content {
+            match content {
+                RunContent::FieldChar(field_char) => match field_char.ty {
+                    Some(CharType::Begin) => self.open.push(OpenField {
+                        field: Field {
+                            dirty: field_char.dirty == Some(true),
+                            ..Default::default()
+                        },
+                        in_result: false,
+                    }),
+                    Some(CharType::Separate) => {
+                        if let Some(open) = self.open.last_mut() {
+                            open.in_result = true;
+                        }
+                    }
+                    Some(CharType::End) => self.end(),
+                    None => (),
+                },
+                RunContent::InstrText(text) => {
+                    if let Some(open) = self.open.last_mut().filter(|open| !open.in_result) {
+                        open.field.instruction.push_str(&text.text);
+                    }
+                }
+                RunContent::Text(text) => self.text(&text.text),
+                RunContent::Tab(_) => self.text("\t"),
+                _ => (),
+            }
+        }
+    }
+
+    /// Adds text shown in the result of the innermost field to the open
+    /// fields, in their instruction or their result.
+    fn text(&mut self, text: &str) {
+        if !self.open.last().is_some_and(|open| open.in_result) {
+            return;
+        }
+        for open in self.open.iter_mut() {
+            if open.in_result {
+                open.field.result.push_str(text);
+            } else {
+                open.field.instruction.push_str(text);
+            }
+        }
+    }
+
+    fn end(&mut self) {
+        if let Some(open) = self.open.pop() {
+            match self.open.last_mut() {
+                Some(parent) => parent.field.fields.push(open.field),
+                None => self.fields.push(open.field),
+            }
+        }
+    }
+
+    /// Returns the fields read, closing those left open.
+    fn finish(mut self) -> Vec<Field> {
+        while !self.open.is_empty() {
+            self.end();
+        }
+        self.fields
+    }
+}
+
+impl<'a> Paragraph<'a> {
+    /// Returns the complex fields of the paragraph, see [`Field`].
+    pub fn fields(&self) -> Vec<Field> {
+        let mut reader = FieldReader::default();
+        reader.paragraph(self);
+        reader.finish()
+    }
+}
+
+impl<'a> Body<'a> {
+    /// Returns the complex fields of the body, its tables and its content
+    /// controls, in document order, see [`Field`]. The result of a field
+    /// spanning paragraphs, like a table of contents, has a line feed between
+    /// them.
+    pub fn fields(&self) -> Vec<Field> {
+        let mut reader = FieldReader::default();
+        for para in content_paragraphs(&self.content) {
+            if !reader.open.is_empty() {
+                reader.text("\n");
+            }
+            reader.paragraph(para);
+        }
+        reader.finish()
+    }
+}
+
+#[test]
+fn nested_fields() {
+    use super::instrtext::InstrText;
+    use crate::document::FieldChar;
+
+    let mut body = Body::default();
+    body.push(
+        Paragraph::default()
+            .push(
+                Run::default()
+                    .push(FieldChar::from(CharType::Begin))
+                    .push(InstrText::from(" IF "))
+                    .push(FieldChar::from(CharType::Begin))
+                    .push(InstrText::from(" PAGE "))
+                    .push(FieldChar::from(CharType::Separate))
+                    .push_text("2")
+                    .push(FieldChar::from(CharType::End))
+                    .push(InstrText::from(" = 1 \"first\" \"other page\" "))
+                    .push(FieldChar::from(CharType::Separate))
+                    .push_text("other"),
+            )
+            .push_text(" ignored"),
+    )
+    .push(
+        Paragraph::default()
+            .push(
+                Run::default()
+                    .push_text("page")
+                    .push(FieldChar::from(CharType::End)),
+            )
+            .push_field("NUMPAGES", "5"),
+    );
+
+    let fields = body.fields();
+    assert_eq!(fields.len(), 2);
+    assert_eq!(fields[0].instruction, " IF 2 = 1 \"first\" \"other page\" ");
+    assert_eq!(fields[0].code().as_deref(), Some("IF"));
+    assert_eq!(
+        fields[0].arguments(),
+        ["2", "=", "1", "first", "other page"]
+    );
+    assert_eq!(fields[0].result, "other ignored\npage");
+    assert_eq!(fields[0].fields[0].instruction, " PAGE ");
+    assert_eq!(fields[0].fields[0].result, "2");
+    assert_eq!(fields[1].result, "5");
+}
+
+#[test]
+fn content_control_fields() {
+    use crate::document::{BodyContent, SDTContent, SDT};
+
+    let mut body = Body::default();
+    body.push(BodyContent::Sdt(SDT::default().content(SDTContent {
+        content: vec![BodyContent::Paragraph(
+            Paragraph::default().push_field("TOC \\o \"1-3\"", "Introduction"),
+        )],
+    })))
+    .push(Paragraph::default().push_field("PAGE", "2"));
+
+    let fields = body.fields();
+    assert_eq!(fields.len(), 2);
+    assert_eq!(fields[0].code().as_deref(), Some("TOC"));
+    assert_eq!(fields[0].result, "Introduction");
+    assert_eq!(fields[1].result, "2");
+}
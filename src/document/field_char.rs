@@ -16,11 +16,17 @@ pub struct FieldChar {
     /// Specifies the break type of this break.
     #[xml(attr = "w:fldCharType")]
     pub ty: Option<CharType>,
+    /// Specifies that the field result is stale and is to be recalculated
+    #[xml(attr = "w:dirty")]
+    pub dirty: Option<bool>,
 }
 
 impl<T: Into<Option<CharType>>> From<T> for FieldChar {
     fn from(val: T) -> Self {
-        FieldChar { ty: val.into() }
+        FieldChar {
+            ty: val.into(),
+            dirty: None,
+        }
     }
 }
 
@@ -50,4 +56,9 @@ __xml_test_suites!(
     FieldChar,
     FieldChar::from(CharType::Begin),
     r#"<w:fldChar w:fldCharType="begin"/>"#,
+    FieldChar {
+        ty: Some(CharType::Begin),
+        dirty: Some(true),
+    },
+    r#"<w:fldChar w:fldCharType="begin" w:dirty="true"/>"#,
 );
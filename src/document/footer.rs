@@ -7,9 +7,10 @@ use hard_xml::{XmlRead, XmlResult, XmlWrite, XmlWriter};
 use std::io::Write;
 
 use crate::__xml_test_suites;
-use crate::schema::{SCHEMA_MAIN, SCHEMA_WORDML_14};
+use crate::schema::{write_root, NamespaceSet};
 
-use crate::document::BodyContent;
+use crate::document::{BodyContent, Paragraph, TextSpace};
+use crate::formatting::{JustificationVal, ParagraphProperty};
 
 /// The root element of the main document part.
 #[derive(Debug, Default, XmlRead, Clone)]
@@ -25,6 +26,32 @@ impl<'a> Footer<'a> {
         self.content.push(content.into());
         self
     }
+
+    /// Creates a footer reading "Page N of M", with the current page number
+    /// and the number of pages as fields.
+    ///
+    /// ```rust
+    /// use docx_rust::document::{Footer, HeaderFooterReferenceType};
+    /// use docx_rust::formatting::JustificationVal;
+    /// use docx_rust::Docx;
+    ///
+    /// let mut docx = Docx::default();
+    /// docx.set_footer(
+    ///     HeaderFooterReferenceType::Default,
+    ///     Footer::page_of_total(JustificationVal::Center),
+    /// );
+    /// ```
+    pub fn page_of_total(alignment: JustificationVal) -> Self {
+        let para = Paragraph::default()
+            .property(ParagraphProperty::default().justification(alignment))
+            .push_text(("Page ", TextSpace::Preserve))
+            .push_field("PAGE", "1")
+            .push_text((" of ", TextSpace::Preserve))
+            .push_field("NUMPAGES", "1");
+        Footer {
+            content: vec![para.into()],
+        }
+    }
 }
 
 impl<'a> XmlWrite for Footer<'a> {
@@ -34,19 +61,13 @@ impl<'a> XmlWrite for Footer<'a> {
         log::debug!("[Footer] Started writing.");
         let _ = write!(writer.inner, "{}", crate::schema::SCHEMA_XML);
 
-        writer.write_element_start("w:ftr")?;
-
-        writer.write_attribute("xmlns:w", SCHEMA_MAIN)?;
-
-        writer.write_attribute("xmlns:w14", SCHEMA_WORDML_14)?;
+        write_root(writer, "w:ftr", NamespaceSet::wordprocessing(), |writer| {
+            for c in content {
+                c.to_writer(writer)?;
+            }
 
-        writer.write_element_end_open()?;
-
-        for c in content {
-            c.to_writer(writer)?;
-        }
-
-        writer.write_element_end_close("w:ftr")?;
+            Ok(())
+        })?;
 
         log::debug!("[Document] Finished writing.");
 
@@ -58,10 +79,35 @@ __xml_test_suites!(
     Footer,
     Footer::default(),
     format!(
-        r#"{}<w:ftr xmlns:w="{}" xmlns:w14="{}"></w:ftr>"#,
+        r#"{}<w:ftr xmlns:w="{}" xmlns:w14="{}" xmlns:mc="{}" mc:Ignorable="w14"></w:ftr>"#,
         crate::schema::SCHEMA_XML,
-        SCHEMA_MAIN,
-        SCHEMA_WORDML_14
+        crate::schema::SCHEMA_MAIN,
+        crate::schema::SCHEMA_WORDML_14,
+        crate::schema::SCHEMA_MC
     )
     .as_str(),
 );
+
+#[test]
+fn page_of_total() {
+    use crate::document::{ParagraphContent, RunContent};
+
+    let footer = Footer::page_of_total(JustificationVal::Right);
+    let para = match &footer.content[..] {
+        [BodyContent::Paragraph(para)] => para,
+        _ => panic!("expected a single paragraph"),
+    };
+    let instructions: Vec<_> = para
+        .content
+        .iter()
+        .filter_map(|content| match content {
+            ParagraphContent::Run(run) => run.content.first(),
+            _ => None,
+        })
+        .filter_map(|content| match content {
+            RunContent::InstrText(instr) => Some(instr.text.trim()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(instructions, ["PAGE", "NUMPAGES"]);
+}
@@ -7,7 +7,7 @@ use hard_xml::{XmlRead, XmlResult, XmlWrite, XmlWriter};
 use std::borrow::Cow;
 use std::io::Write;
 
-use crate::schema::{SCHEMA_MAIN, SCHEMA_WORDML_14};
+use crate::schema::{write_root, NamespaceSet};
 use crate::{__string_enum, __xml_test_suites};
 
 use crate::document::BodyContent;
@@ -62,19 +62,18 @@ impl<'a> XmlWrite for FootNotes<'a> {
         log::debug!("[FootNotes] Started writing.");
         let _ = write!(writer.inner, "{}", crate::schema::SCHEMA_XML);
 
-        writer.write_element_start("w:footnotes")?;
+        write_root(
+            writer,
+            "w:footnotes",
+            NamespaceSet::wordprocessing(),
+            |writer| {
+                for c in content {
+                    c.to_writer(writer)?;
+                }
 
-        writer.write_attribute("xmlns:w", SCHEMA_MAIN)?;
-
-        writer.write_attribute("xmlns:w14", SCHEMA_WORDML_14)?;
-
-        writer.write_element_end_open()?;
-
-        for c in content {
-            c.to_writer(writer)?;
-        }
-
-        writer.write_element_end_close("w:footnotes")?;
+                Ok(())
+            },
+        )?;
 
         log::debug!("[Document] Finished writing.");
 
@@ -100,10 +99,11 @@ __xml_test_suites!(
     FootNotes,
     FootNotes::default(),
     format!(
-        r#"{}<w:footnotes xmlns:w="{}" xmlns:w14="{}"></w:footnotes>"#,
+        r#"{}<w:footnotes xmlns:w="{}" xmlns:w14="{}" xmlns:mc="{}" mc:Ignorable="w14"></w:footnotes>"#,
         crate::schema::SCHEMA_XML,
-        SCHEMA_MAIN,
-        SCHEMA_WORDML_14
+        crate::schema::SCHEMA_MAIN,
+        crate::schema::SCHEMA_WORDML_14,
+        crate::schema::SCHEMA_MC
     )
     .as_str(),
 );
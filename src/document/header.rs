@@ -7,9 +7,11 @@ use hard_xml::{XmlRead, XmlResult, XmlWrite, XmlWriter};
 use std::io::Write;
 
 use crate::__xml_test_suites;
-use crate::schema::{SCHEMA_MAIN, SCHEMA_WORDML_14};
+use crate::schema::{write_root, NamespaceSet};
 
-use crate::document::BodyContent;
+use crate::document::{
+    BodyContent, Drawing, PTab, PTabAlignment, PTabRelativeTo, Paragraph, Run, Text,
+};
 
 /// The root element of the main document part.
 #[derive(Debug, Default, XmlRead, Clone)]
@@ -25,6 +27,23 @@ impl<'a> Header<'a> {
         self.content.push(content.into());
         self
     }
+
+    /// Creates a header with an image on the left and a title on the right
+    /// margin, see [`Drawing::inline_picture`].
+    ///
+    /// The image refers to a relationship of the header part, see
+    /// [`Docx::add_header_image`](crate::Docx::add_header_image).
+    pub fn with_logo_and_title<T: Into<Text<'a>>>(image: Drawing<'a>, title: T) -> Self {
+        let tab = PTab::default()
+            .alignment(PTabAlignment::Right)
+            .relative_to(PTabRelativeTo::Margin);
+        let para = Paragraph::default()
+            .push(Run::default().push(image).push(tab))
+            .push_text(title);
+        Header {
+            content: vec![para.into()],
+        }
+    }
     pub fn replace_text_simple<S>(&mut self, old: S, new: S)
     where
         S: AsRef<str>,
@@ -48,6 +67,7 @@ impl<'a> Header<'a> {
                 BodyContent::SectionProperty(_) => {}
                 BodyContent::Sdt(_) => {}
                 BodyContent::TableCell(_) => {}
+                BodyContent::Raw(_) => {}
             }
         }
         Ok(())
@@ -61,19 +81,13 @@ impl<'a> XmlWrite for Header<'a> {
         log::debug!("[Header] Started writing.");
         let _ = write!(writer.inner, "{}", crate::schema::SCHEMA_XML);
 
-        writer.write_element_start("w:hdr")?;
-
-        writer.write_attribute("xmlns:w", SCHEMA_MAIN)?;
-
-        writer.write_attribute("xmlns:w14", SCHEMA_WORDML_14)?;
-
-        writer.write_element_end_open()?;
-
-        for c in content {
-            c.to_writer(writer)?;
-        }
+        write_root(writer, "w:hdr", NamespaceSet::wordprocessing(), |writer| {
+            for c in content {
+                c.to_writer(writer)?;
+            }
 
-        writer.write_element_end_close("w:hdr")?;
+            Ok(())
+        })?;
 
         log::debug!("[Document] Finished writing.");
 
@@ -85,10 +99,11 @@ __xml_test_suites!(
     Header,
     Header::default(),
     format!(
-        r#"{}<w:hdr xmlns:w="{}" xmlns:w14="{}"></w:hdr>"#,
+        r#"{}<w:hdr xmlns:w="{}" xmlns:w14="{}" xmlns:mc="{}" mc:Ignorable="w14"></w:hdr>"#,
         crate::schema::SCHEMA_XML,
-        SCHEMA_MAIN,
-        SCHEMA_WORDML_14
+        crate::schema::SCHEMA_MAIN,
+        crate::schema::SCHEMA_WORDML_14,
+        crate::schema::SCHEMA_MC
     )
     .as_str(),
 );
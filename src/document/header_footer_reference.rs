@@ -6,8 +6,7 @@ use std::borrow::Cow;
 use crate::{__setter, __string_enum, __xml_test_suites};
 
 /// A set of elements that can be contained as the content of a run.
-#[derive(Debug, From, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, From, XmlRead, XmlWrite, Clone, PartialEq)]
 pub enum HeaderFooterReference<'a> {
     #[xml(tag = "w:headerReference")]
     Header(HeaderReference<'a>),
@@ -17,8 +16,7 @@ pub enum HeaderFooterReference<'a> {
 
 /// HeaderReference
 ///
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:headerReference")]
 pub struct HeaderReference<'a> {
     /// Specifies the HeaderReference type of this HeaderReference.
@@ -35,8 +33,7 @@ impl<'a> HeaderReference<'a> {
 
 /// FooterReference
 ///
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:footerReference")]
 pub struct FooterReference<'a> {
     /// Specifies the FooterReference type of this FooterReference.
@@ -51,8 +48,7 @@ impl<'a> FooterReference<'a> {
     __setter!(id: Option<Cow<'a, str>>);
 }
 
-#[derive(Debug, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Clone, PartialEq)]
 pub enum HeaderFooterReferenceType {
     Default,
     Even,
@@ -3,7 +3,7 @@
 use hard_xml::{XmlRead, XmlWrite};
 use std::borrow::Cow;
 
-use crate::{__setter, __xml_test_suites, document::Run};
+use crate::{__setter, __xml_test_suites, document::Run, rels::Relationships};
 
 /// The root element of a hyperlink within the paragraph
 #[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
@@ -16,6 +16,18 @@ pub struct Hyperlink<'a> {
     /// Specifies the name of a bookmark within the document.
     #[xml(attr = "w:anchor")]
     pub anchor: Option<Cow<'a, str>>,
+    /// Specifies a location in the target document, e.g. a named range.
+    #[xml(attr = "w:docLocation")]
+    pub doc_location: Option<Cow<'a, str>>,
+    /// Specifies the text shown in a tip when hovering the link.
+    #[xml(attr = "w:tooltip")]
+    pub tooltip: Option<Cow<'a, str>>,
+    /// Specifies the frame to open the link in, like `_blank` for a new window.
+    #[xml(attr = "w:tgtFrame")]
+    pub target_frame: Option<Cow<'a, str>>,
+    /// Specifies that the target is to be added to the list of viewed links.
+    #[xml(attr = "w:history")]
+    pub history: Option<bool>,
     #[xml(child = "w:r")]
     /// Link content
     pub content: Run<'a>,
@@ -24,7 +36,17 @@ pub struct Hyperlink<'a> {
 impl<'a> Hyperlink<'a> {
     __setter!(id: Option<Cow<'a, str>>);
     __setter!(anchor: Option<Cow<'a, str>>);
+    __setter!(doc_location: Option<Cow<'a, str>>);
+    __setter!(tooltip: Option<Cow<'a, str>>);
+    __setter!(target_frame: Option<Cow<'a, str>>);
+    __setter!(history: Option<bool>);
     __setter!(content: Run<'a>);
+
+    /// Returns the address of an external link, resolving its relationship id
+    /// against the relationships of the part it was read from.
+    pub fn target<'r>(&self, rels: &'r Relationships) -> Option<&'r str> {
+        rels.get_target(self.id.as_deref()?)
+    }
 }
 
 __xml_test_suites!(
@@ -35,4 +57,12 @@ __xml_test_suites!(
     r#"<w:hyperlink r:id="id"><w:r/></w:hyperlink>"#,
     Hyperlink::default().anchor("anchor"),
     r#"<w:hyperlink w:anchor="anchor"><w:r/></w:hyperlink>"#,
+    Hyperlink::default()
+        .id("id")
+        .tooltip("Opens the site")
+        .target_frame("_blank")
+        .history(true),
+    r#"<w:hyperlink r:id="id" w:tooltip="Opens the site" w:tgtFrame="_blank" w:history="true"><w:r/></w:hyperlink>"#,
+    Hyperlink::default().anchor("anchor").doc_location("Sheet1"),
+    r#"<w:hyperlink w:anchor="anchor" w:docLocation="Sheet1"><w:r/></w:hyperlink>"#,
 );
@@ -1,7 +1,7 @@
 use hard_xml::{XmlRead, XmlWrite};
 use std::borrow::Cow;
 
-use crate::{__string_enum, __xml_test_suites};
+use crate::{__text_element, __xml_test_suites, document::TextSpace};
 
 /// Literal Text
 ///
@@ -14,15 +14,14 @@ use crate::{__string_enum, __xml_test_suites};
 /// let text = Text::from(String::from("text"));
 /// let text = Text::from(("text", TextSpace::Preserve));
 /// ```
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[derive(Debug, Default, Clone)]
 #[cfg_attr(test, derive(PartialEq))]
-#[xml(tag = "w:instrText")]
 pub struct InstrText<'a> {
     /// Specifies how to handle whitespace
-    #[xml(attr = "xml:space")]
+    ///
+    /// If not set, `xml:space="preserve"` is written when needed.
     pub space: Option<TextSpace>,
     /// Specifies a literal text
-    #[xml(text)]
     pub text: Cow<'a, str>,
 }
 
@@ -62,15 +61,14 @@ impl<'a> From<(&'a str, TextSpace)> for InstrText<'a> {
     }
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[derive(Debug, Default, Clone)]
 #[cfg_attr(test, derive(PartialEq))]
-#[xml(tag = "w:delInstrText")]
 pub struct DelInstrText<'a> {
     /// Specifies how to handle whitespace
-    #[xml(attr = "xml:space")]
+    ///
+    /// If not set, `xml:space="preserve"` is written when needed.
     pub space: Option<TextSpace>,
     /// Specifies a literal text
-    #[xml(text)]
     pub text: Cow<'a, str>,
 }
 
@@ -110,24 +108,8 @@ impl<'a> From<(&'a str, TextSpace)> for DelInstrText<'a> {
     }
 }
 
-/// Text Space Rules
-///
-/// Specifies how whitespace should be handled in a literal text.
-#[derive(Debug, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
-pub enum TextSpace {
-    /// Default rules
-    Default,
-    /// Using the W3C space preservation rules
-    Preserve,
-}
-
-__string_enum! {
-    TextSpace {
-        Default = "default",
-        Preserve = "preserve",
-    }
-}
+__text_element!(InstrText, "w:instrText");
+__text_element!(DelInstrText, "w:delInstrText");
 
 __xml_test_suites!(
     InstrText,
@@ -0,0 +1,327 @@
+use std::collections::HashMap;
+
+use crate::{
+    document::{AbstractNum, Level, Num, Numbering, Paragraph},
+    styles::Styles,
+};
+
+/// Computes the labels of numbered paragraphs, like `1.`, `a)` or `1.2.3`
+///
+/// Labels are numbered the way Word does: every numbered paragraph advances
+/// the counter of its level and restarts the deeper levels, unless they
+/// restart at another level, and lists sharing an abstract numbering continue
+/// each other unless they override the start.
+/// Paragraphs therefore have to be passed in document order.
+///
+/// ```rust
+/// use docx_rust::document::BodyContent;
+/// use docx_rust::Docx;
+///
+/// let mut docx = Docx::default();
+/// docx.numbered_list(["first", "  nested", "second"]);
+///
+/// let mut labels = docx.list_labels();
+/// let text: Vec<String> = docx
+///     .document
+///     .body
+///     .content
+///     .iter()
+///     .filter_map(|content| match content {
+///         BodyContent::Paragraph(para) => Some(para),
+///         _ => None,
+///     })
+///     .map(|para| match labels.label(para) {
+///         Some(label) => format!("{} {}", label, para.text()),
+///         None => para.text(),
+///     })
+///     .collect();
+///
+/// assert_eq!(text, ["1. first", "a. nested", "2. second"]);
+/// ```
+pub struct ListLabels<'n, 'a> {
+    numbering: Option<&'n Numbering<'a>>,
+    styles: Option<&'n Styles<'a>>,
+    counters: HashMap<CounterKey, [Option<isize>; 9]>,
+}
+
+#[derive(PartialEq, Eq, Hash)]
+enum CounterKey {
+    Abstract(isize),
+    Num(isize),
+}
+
+impl<'n, 'a> ListLabels<'n, 'a> {
+    /// Uses the given numbering definitions, and paragraph styles to find the
+    /// numbering of paragraphs that don't specify it themselves.
+    pub fn new(numbering: Option<&'n Numbering<'a>>, styles: Option<&'n Styles<'a>>) -> Self {
+        ListLabels {
+            numbering,
+            styles,
+            counters: HashMap::new(),
+        }
+    }
+
+    /// Returns the label of the paragraph, if it is numbered, and advances
+    /// the counters of its list.
+    ///
+    /// The label of a bulleted paragraph is its bullet.
+    pub fn label(&mut self, para: &Paragraph) -> Option<String> {
+        let (num, abstract_num, level) = self.definitions(para)?;
+        let num_id = num.num_id?;
+        let abstract_id = abstract_num.abstract_num_id?;
+        let find_level = |i: usize| {
+            abstract_num
+                .levels
+                .iter()
+                .find(|level| level.i_level == Some(i as isize))
+        };
+        let start = |i: usize| {
+            num.level_overrides
+                .iter()
+                .find(|o| o.i_level == Some(i as isize))
+                .and_then(|o| o.start_override.as_ref()?.value)
+                .or_else(|| find_level(i)?.start.as_ref()?.value)
+                .unwrap_or(0)
+        };
+
+        let key = if num.level_overrides.is_empty() {
+            CounterKey::Abstract(abstract_id)
+        } else {
+            CounterKey::Num(num_id)
+        };
+        let counters = self.counters.entry(key).or_insert([None; 9]);
+        counters[level] = Some(counters[level].map_or_else(|| start(level), |n| n + 1));
+        for (i, counter) in counters.iter_mut().enumerate().skip(level + 1) {
+            // the level, counting from 1, after which the deeper level restarts
+            let restart = find_level(i)
+                .and_then(|deeper| deeper.restart.as_ref())
+                .map_or(i as isize, |restart| restart.value);
+            if (level as isize) < restart {
+                *counter = None;
+            }
+        }
+
+        let current = find_level(level)?;
+        let is_legal = current
+            .is_legal
+            .as_ref()
+            .is_some_and(|is_legal| is_legal.value != Some(false));
+        if let Some(bullet) = current.bullet() {
+            return Some(bullet);
+        }
+        let text = &current.level_text.as_ref()?.value;
+
+        let mut label = String::with_capacity(text.len());
+        let mut chars = text.chars().peekable();
+        while let Some(c) = chars.next() {
+            let placeholder = chars
+                .peek()
+                .and_then(|d| d.to_digit(10))
+                .filter(|d| c == '%' && (1..=9).contains(d));
+            match placeholder {
+                Some(d) => {
+                    chars.next();
+                    let i = d as usize - 1;
+                    let n = counters[i].unwrap_or_else(|| start(i));
+                    let format = match find_level(i) {
+                        Some(level) if !is_legal => format_of(level),
+                        _ => "decimal",
+                    };
+                    label.push_str(&format_number(format, n));
+                }
+                None => label.push(c),
+            }
+        }
+
+        Some(label)
+    }
+
+    /// Returns the list level of the paragraph, from 0 to 8, if it is
+    /// numbered.
+    pub fn level(&self, para: &Paragraph) -> Option<usize> {
+        self.numbering_of(para).map(|(_, level)| level)
+    }
+
+    /// Returns the number format of the list level of the paragraph, like
+    /// `decimal`, `lowerLetter` or `bullet`, if it is numbered.
+    pub fn number_format(&self, para: &Paragraph) -> Option<&'n str> {
+        let (_, abstract_num, level) = self.definitions(para)?;
+        let level = abstract_num
+            .levels
+            .iter()
+            .find(|l| l.i_level == Some(level as isize))?;
+        Some(format_of(level))
+    }
+
+    /// Returns the numbering and abstract numbering of a paragraph, and its
+    /// level.
+    fn definitions(&self, para: &Paragraph) -> Option<(&'n Num, &'n AbstractNum<'a>, usize)> {
+        let numbering = self.numbering?;
+        let (num_id, level) = self.numbering_of(para)?;
+
+        let num = numbering
+            .numberings
+            .iter()
+            .find(|num| num.num_id == Some(num_id))?;
+        let abstract_id = num.abstract_num_id.as_ref()?.value?;
+        let abstract_num = numbering
+            .abstract_numberings
+            .iter()
+            .find(|an| an.abstract_num_id == Some(abstract_id))?;
+        Some((num, abstract_num, level))
+    }
+
+    /// Returns the numbering id and level of a paragraph, taken from its
+    /// style if needed.
+    fn numbering_of(&self, para: &Paragraph) -> Option<(isize, usize)> {
+        let property = para.property.as_ref()?;
+        let numbering = match &property.numbering {
+            Some(numbering) if numbering.id.is_some() => numbering,
+            _ => {
+                let style = self.styles?.get(&property.style_id.as_ref()?.value)?;
+                style.paragraph.as_ref()?.numbering.as_ref()?
+            }
+        };
+
+        let id = numbering.id.as_ref()?.value;
+        let level = numbering.level.as_ref().map_or(0, |level| level.value);
+        // a numbering id of zero removes the numbering
+        (id != 0).then_some((id, level.clamp(0, 8) as usize))
+    }
+}
+
+fn format_of<'l>(level: &'l Level) -> &'l str {
+    level
+        .number_format
+        .as_ref()
+        .map_or("decimal", |format| &format.value)
+}
+
+/// Formats a number as given by a `w:numFmt` value, falling back to decimal.
+fn format_number(format: &str, n: isize) -> String {
+    match format {
+        "none" => String::new(),
+        "decimalZero" => format!("{:02}", n),
+        "upperRoman" if n > 0 => roman(n),
+        "lowerRoman" if n > 0 => roman(n).to_lowercase(),
+        "upperLetter" if n > 0 => letter(n),
+        "lowerLetter" if n > 0 => letter(n).to_lowercase(),
+        "ordinal" => {
+            let suffix = match (n % 10, n % 100) {
+                (_, 11..=13) => "th",
+                (1, _) => "st",
+                (2, _) => "nd",
+                (3, _) => "rd",
+                _ => "th",
+            };
+            format!("{}{}", n, suffix)
+        }
+        _ => n.to_string(),
+    }
+}
+
+fn roman(mut n: isize) -> String {
+    const NUMERALS: [(isize, &str); 13] = [
+        (1000, "M"),
+        (900, "CM"),
+        (500, "D"),
+        (400, "CD"),
+        (100, "C"),
+        (90, "XC"),
+        (50, "L"),
+        (40, "XL"),
+        (10, "X"),
+        (9, "IX"),
+        (5, "V"),
+        (4, "IV"),
+        (1, "I"),
+    ];
+
+    let mut out = String::new();
+    for (value, numeral) in NUMERALS {
+        while n >= value {
+            out.push_str(numeral);
+            n -= value;
+        }
+    }
+    out
+}
+
+/// Letters repeat after Z, as in Word: `AA`, `BB`, ...
+fn letter(n: isize) -> String {
+    let letter = (b'A' + ((n - 1) % 26) as u8) as char;
+    letter.to_string().repeat(((n - 1) / 26 + 1) as usize)
+}
+
+#[test]
+fn list_labels() {
+    use crate::{
+        document::{AbstractNum, LevelOverride, LevelText, StartOverride},
+        formatting::ParagraphProperty,
+    };
+
+    let item = |num_id: isize, level: isize| {
+        Paragraph::default().property(ParagraphProperty::default().numbering((num_id, level)))
+    };
+
+    let mut numbering = Numbering::default();
+    let mut outline = AbstractNum::decimal();
+    outline.levels[1].level_text = Some(LevelText::from("%1.%2)"));
+    let outline = numbering.push_list(outline);
+    let bullets = numbering.push_list(AbstractNum::bullet());
+    let restarted = numbering.push_list(AbstractNum::decimal());
+    numbering.numberings[2].level_overrides.push(LevelOverride {
+        i_level: Some(0),
+        start_override: Some(StartOverride { value: Some(4) }),
+    });
+
+    let mut labels = ListLabels::new(Some(&numbering), None);
+    let mut label = |para: Paragraph| labels.label(&para);
+
+    assert_eq!(label(item(outline, 0)).as_deref(), Some("1."));
+    assert_eq!(label(item(outline, 1)).as_deref(), Some("1.a)"));
+    assert_eq!(label(item(outline, 1)).as_deref(), Some("1.b)"));
+    assert_eq!(label(item(bullets, 0)).as_deref(), Some("\u{2022}"));
+    assert_eq!(label(item(outline, 0)).as_deref(), Some("2."));
+    assert_eq!(label(item(outline, 1)).as_deref(), Some("2.a)"));
+    assert_eq!(label(item(outline, 2)).as_deref(), Some("i."));
+    assert_eq!(label(item(restarted, 0)).as_deref(), Some("4."));
+    assert_eq!(label(item(0, 0)), None);
+    assert_eq!(label(Paragraph::default()), None);
+
+    assert_eq!(format_number("upperRoman", 1994), "MCMXCIV");
+    assert_eq!(format_number("lowerLetter", 28), "bb");
+    assert_eq!(format_number("ordinal", 12), "12th");
+    assert_eq!(format_number("ordinal", 22), "22nd");
+    assert_eq!(format_number("decimalZero", 7), "07");
+}
+
+#[test]
+fn restart_and_legal_levels() {
+    use crate::{
+        document::{AbstractNum, IsLegal, LevelRestart},
+        formatting::ParagraphProperty,
+    };
+
+    let item = |num_id: isize, level: isize| {
+        Paragraph::default().property(ParagraphProperty::default().numbering((num_id, level)))
+    };
+
+    let mut numbering = Numbering::default();
+    let mut figures = AbstractNum::outline();
+    // level 2 restarts after level 1 only, and shows level 0 as a number
+    figures.levels[2].restart = Some(LevelRestart { value: 1 });
+    figures.levels[2].is_legal = Some(IsLegal::default());
+    figures.levels[2].level_text = Some("%1-%3".into());
+    let figures = numbering.push_list(figures);
+
+    let mut labels = ListLabels::new(Some(&numbering), None);
+    let mut label = |para: Paragraph| labels.label(&para);
+
+    assert_eq!(label(item(figures, 0)).as_deref(), Some("I."));
+    assert_eq!(label(item(figures, 2)).as_deref(), Some("1-1"));
+    assert_eq!(label(item(figures, 1)).as_deref(), Some("A."));
+    assert_eq!(label(item(figures, 2)).as_deref(), Some("1-2"));
+    assert_eq!(label(item(figures, 0)).as_deref(), Some("II."));
+    assert_eq!(label(item(figures, 2)).as_deref(), Some("2-1"));
+}
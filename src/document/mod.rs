@@ -1,13 +1,20 @@
+mod alternate_content;
+mod background;
 mod body;
 mod bookmark_end;
 mod bookmark_start;
 mod r#break;
+mod code_block;
 mod comment_range;
 mod comments;
+mod comments_extended;
+mod cross_reference;
 mod date;
 mod document;
+mod document_reader;
 mod drawing;
 mod endnotes;
+mod field;
 mod field_char;
 mod footer;
 mod footnotes;
@@ -16,22 +23,31 @@ mod header;
 mod header_footer_reference;
 mod hyperlink;
 mod instrtext;
+mod list_label;
 mod numbering;
+mod object;
 mod paragraph;
+mod people;
+mod raw;
 mod run;
 mod sdt;
+mod sub_document;
 mod sym;
 mod tab;
 mod table;
 mod table_cell;
+#[cfg(feature = "csv")]
+mod table_csv;
 mod table_grid;
 mod table_row;
 mod text;
 mod theme;
 
 pub use self::{
-    body::*, bookmark_end::*, bookmark_start::*, comment_range::*, comments::*, document::*,
-    drawing::*, endnotes::*, field_char::*, footer::*, footnotes::*, grid_column::*, header::*,
-    header_footer_reference::*, hyperlink::*, numbering::*, paragraph::*, r#break::*, run::*,
-    sdt::*, tab::*, table::*, table_cell::*, table_grid::*, table_row::*, text::*, theme::*,
+    alternate_content::*, background::*, body::*, bookmark_end::*, bookmark_start::*,
+    comment_range::*, comments::*, comments_extended::*, document::*, document_reader::*,
+    drawing::*, endnotes::*, field::*, field_char::*, footer::*, footnotes::*, grid_column::*,
+    header::*, header_footer_reference::*, hyperlink::*, list_label::*, numbering::*, object::*,
+    paragraph::*, people::*, r#break::*, raw::*, run::*, sdt::*, sub_document::*, sym::*, tab::*,
+    table::*, table_cell::*, table_grid::*, table_row::*, text::*, theme::*,
 };
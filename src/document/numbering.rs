@@ -7,8 +7,10 @@ use hard_xml::{XmlRead, XmlResult, XmlWrite, XmlWriter};
 use std::{borrow::Cow, io::Write};
 
 use crate::{
-    formatting::{CharacterProperty, Indent, JustificationVal},
-    schema::{SCHEMA_MAIN, SCHEMA_WORDML_14},
+    __string_enum,
+    document::sym::symbol_to_unicode,
+    formatting::{CharacterProperty, FontHint, Fonts, Indent, JustificationVal, NumberFormat},
+    schema::{write_root, NamespaceSet},
 };
 
 #[derive(Debug, Default, XmlRead, Clone)]
@@ -61,20 +63,99 @@ pub struct MultiLevelType<'a> {
 pub struct Level<'a> {
     #[xml(attr = "w:ilvl")]
     pub i_level: Option<isize>,
+    /// Specifies the template code Word uses to recognize its own lists
+    #[xml(attr = "w:tplc")]
+    pub template_code: Option<Cow<'a, str>>,
+    /// Specifies that the level isn't shown in the user interface until used
+    #[xml(attr = "w:tentative")]
+    pub tentative: Option<bool>,
     #[xml(child = "w:start")]
     pub start: Option<LevelStart>,
     #[xml(child = "w:numFmt")]
     pub number_format: Option<NumFmt<'a>>,
+    /// Specifies the level whose numbers restart this one
+    #[xml(child = "w:lvlRestart")]
+    pub restart: Option<LevelRestart>,
+    /// Specifies the paragraph style numbered by this level
+    #[xml(child = "w:pStyle")]
+    pub style_id: Option<LevelStyleId<'a>>,
+    /// Specifies that the numbers of all levels are shown as decimal
+    #[xml(child = "w:isLgl")]
+    pub is_legal: Option<IsLegal>,
+    /// Specifies what follows the number
+    #[xml(child = "w:suff")]
+    pub suffix: Option<LevelSuffix>,
     #[xml(child = "w:lvlText")]
     pub level_text: Option<LevelText<'a>>,
+    /// Specifies the picture bullet of the level
+    #[xml(child = "w:lvlPicBulletId")]
+    pub picture_bullet_id: Option<LevelPictureBulletId>,
+    /// Specifies the numbering of Word 6 and Word 95 documents
+    #[xml(child = "w:legacy")]
+    pub legacy: Option<Legacy>,
     #[xml(child = "w:lvlJc")]
     pub justification: Option<LevelJustification>,
     #[xml(child = "w:pPr")]
     pub p_pr: Option<PPr>,
+    /// Specifies the formatting of the number or bullet, like the symbol
+    /// font of a bullet, see [`Level::bullet`]
     #[xml(child = "w:rPr")]
     pub r_pr: Vec<CharacterProperty<'a>>,
 }
 
+impl<'a> Level<'a> {
+    /// Returns the formatting of the number or bullet.
+    pub fn character_property(&self) -> Option<&CharacterProperty<'a>> {
+        self.r_pr.first()
+    }
+
+    /// Returns the font of the number or bullet, if the level sets one.
+    pub fn font(&self) -> Option<&str> {
+        let fonts = self.character_property()?.fonts.as_ref()?;
+        fonts.ascii.as_deref().or(fonts.h_ansi.as_deref())
+    }
+
+    /// Sets the font of the number or bullet, as Word does for bullets of
+    /// symbol fonts.
+    pub fn with_font(mut self, font: &str) -> Self {
+        if self.r_pr.is_empty() {
+            self.r_pr.push(CharacterProperty::default());
+        }
+        self.r_pr[0].fonts = Some(
+            Fonts::default()
+                .ascii(font)
+                .h_ansi(font)
+                .hint(FontHint::Default),
+        );
+        self
+    }
+
+    /// Returns the bullet of a bulleted level, with the characters of the
+    /// Symbol and Wingdings fonts replaced by Unicode ones looking alike.
+    ///
+    /// ```rust
+    /// use docx_rust::document::AbstractNum;
+    ///
+    /// let num = AbstractNum::symbol_bullets();
+    /// assert_eq!(num.levels[0].level_text.as_ref().unwrap().value, "\u{F0B7}");
+    /// assert_eq!(num.levels[0].bullet().as_deref(), Some("\u{2022}"));
+    /// assert_eq!(num.levels[1].bullet().as_deref(), Some("o"));
+    /// ```
+    pub fn bullet(&self) -> Option<String> {
+        if self.number_format.as_ref()?.value != "bullet" {
+            return None;
+        }
+        let text = &self.level_text.as_ref()?.value;
+        Some(match self.font() {
+            Some(font) => text
+                .chars()
+                .map(|c| symbol_to_unicode(font, c).unwrap_or(c))
+                .collect(),
+            None => text.to_string(),
+        })
+    }
+}
+
 #[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
 #[cfg_attr(test, derive(PartialEq))]
 #[xml(tag = "w:pPr")]
@@ -86,27 +167,167 @@ pub struct PPr {
 #[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
 #[cfg_attr(test, derive(PartialEq))]
 #[xml(tag = "w:numFmt")]
-/// TODO Replace by enum NumberFormat
+/// Number format of a list level
+///
+/// The value is kept as is, so formats added by later versions of Word are
+/// written back unchanged; see [`NumFmt::format`] for the typed value.
 pub struct NumFmt<'a> {
     #[xml(attr = "w:val")]
     pub value: Cow<'a, str>,
 }
 
+impl<'a> NumFmt<'a> {
+    /// Returns the format, if it is a known one.
+    pub fn format(&self) -> Option<NumberFormat> {
+        self.value.parse().ok()
+    }
+}
+
+impl<'a> From<NumberFormat> for NumFmt<'a> {
+    fn from(format: NumberFormat) -> Self {
+        NumFmt {
+            value: format.to_string().into(),
+        }
+    }
+}
+
 #[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
 #[cfg_attr(test, derive(PartialEq))]
 #[xml(tag = "w:start")]
-/// TODO Replace by enum NumberFormat
+/// Starting value of a list level
 pub struct LevelStart {
     #[xml(attr = "w:val")]
     pub value: Option<isize>,
 }
 
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:lvlRestart")]
+/// Restart Numbering Level
+///
+/// The level restarts after a paragraph of the given level, counting from 1,
+/// or a higher one; it never restarts if the value is 0. Without it, a level
+/// restarts after any higher level.
+pub struct LevelRestart {
+    #[xml(attr = "w:val")]
+    pub value: isize,
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:pStyle")]
+pub struct LevelStyleId<'a> {
+    #[xml(attr = "w:val")]
+    pub value: Cow<'a, str>,
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:isLgl")]
+pub struct IsLegal {
+    #[xml(attr = "w:val")]
+    pub value: Option<bool>,
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:suff")]
+pub struct LevelSuffix {
+    #[xml(attr = "w:val")]
+    pub value: LevelSuffixType,
+}
+
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+pub enum LevelSuffixType {
+    #[default]
+    Tab,
+    Space,
+    Nothing,
+}
+
+__string_enum! {
+    LevelSuffixType {
+        Tab = "tab",
+        Space = "space",
+        Nothing = "nothing",
+    }
+}
+
 #[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
 #[cfg_attr(test, derive(PartialEq))]
 #[xml(tag = "w:lvlText")]
+/// Level Text
+///
+/// `%1` to `%9` stand for the numbers of the levels 0 to 8, e.g. `%1.%2.` for
+/// `1.3.`.
 pub struct LevelText<'a> {
     #[xml(attr = "w:val")]
     pub value: Cow<'a, str>,
+    /// Specifies that the level has no text, not even a bullet
+    #[xml(attr = "w:null")]
+    pub null: Option<bool>,
+}
+
+impl<'a> LevelText<'a> {
+    /// Returns the levels whose numbers the text shows, counting from 0, in
+    /// order of appearance.
+    ///
+    /// ```rust
+    /// use docx_rust::document::LevelText;
+    ///
+    /// let text = LevelText::from("Section %1.%2");
+    /// assert_eq!(text.placeholders(), [0, 1]);
+    /// ```
+    pub fn placeholders(&self) -> Vec<usize> {
+        let mut levels = Vec::new();
+        let mut chars = self.value.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                continue;
+            }
+            if let Some(d) = chars.peek().and_then(|d| d.to_digit(10)) {
+                if (1..=9).contains(&d) {
+                    chars.next();
+                    levels.push(d as usize - 1);
+                }
+            }
+        }
+        levels
+    }
+}
+
+impl<'a, T: Into<Cow<'a, str>>> From<T> for LevelText<'a> {
+    fn from(value: T) -> Self {
+        LevelText {
+            value: value.into(),
+            null: None,
+        }
+    }
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:lvlPicBulletId")]
+pub struct LevelPictureBulletId {
+    #[xml(attr = "w:val")]
+    pub value: isize,
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:legacy")]
+/// Legacy Numbering Level Properties
+pub struct Legacy {
+    /// Specifies that the level uses the legacy numbering
+    #[xml(attr = "w:legacy")]
+    pub legacy: Option<bool>,
+    /// Specifies the space after the number, in twentieths of a point
+    #[xml(attr = "w:legacySpace")]
+    pub space: Option<isize>,
+    /// Specifies the indentation of the number, in twentieths of a point
+    #[xml(attr = "w:legacyIndent")]
+    pub indent: Option<isize>,
 }
 
 #[derive(Debug, XmlRead, XmlWrite, Clone)]
@@ -155,7 +376,251 @@ pub struct AbstractNumId {
     pub value: Option<isize>,
 }
 
+impl<'a> AbstractNum<'a> {
+    /// Round bullets: `•`, `◦`, `▪`
+    pub const ROUND_BULLETS: [&'static str; 3] = ["\u{2022}", "\u{25e6}", "\u{25aa}"];
+    /// Square bullets: `▪`, `▫`, `■`
+    pub const SQUARE_BULLETS: [&'static str; 3] = ["\u{25aa}", "\u{25ab}", "\u{25a0}"];
+    /// Arrow bullets: `➢`, `►`, `–`
+    pub const ARROW_BULLETS: [&'static str; 3] = ["\u{27a2}", "\u{25ba}", "\u{2013}"];
+    /// Check mark bullets: `✓`, `•`, `◦`
+    pub const CHECK_BULLETS: [&'static str; 3] = ["\u{2713}", "\u{2022}", "\u{25e6}"];
+    /// Dash bullets: `–` for every level
+    pub const DASH_BULLETS: [&'static str; 1] = ["\u{2013}"];
+
+    /// Creates a nine level bulleted list definition, with
+    /// [`AbstractNum::ROUND_BULLETS`].
+    pub fn bullet() -> Self {
+        AbstractNum::bullets(&Self::ROUND_BULLETS)
+    }
+
+    /// Creates a nine level bulleted list definition like Word's default
+    /// one, with the bullets of the Symbol, Courier New and Wingdings fonts,
+    /// which render the same in every version of Word.
+    pub fn symbol_bullets() -> Self {
+        const BULLETS: [(&str, &str); 3] = [
+            ("Symbol", "\u{F0B7}"),
+            ("Courier New", "o"),
+            ("Wingdings", "\u{F0A7}"),
+        ];
+
+        let mut num = AbstractNum::with_levels(|level| {
+            (NumberFormat::Bullet, Cow::Borrowed(BULLETS[level % 3].1))
+        });
+        num.levels = num
+            .levels
+            .into_iter()
+            .enumerate()
+            .map(|(i, level)| level.with_font(BULLETS[i % 3].0))
+            .collect();
+        num
+    }
+
+    /// Creates a nine level bulleted list definition, going through the
+    /// glyphs level by level and starting over when they run out.
+    ///
+    /// ```rust
+    /// use docx_rust::document::AbstractNum;
+    ///
+    /// let arrows = AbstractNum::bullets(&AbstractNum::ARROW_BULLETS);
+    /// let stars = AbstractNum::bullets(&["\u{2605}", "\u{2606}"]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `glyphs` is empty.
+    pub fn bullets(glyphs: &[&str]) -> Self {
+        assert!(!glyphs.is_empty(), "no bullet glyphs");
+        AbstractNum::with_levels(|level| {
+            (
+                NumberFormat::Bullet,
+                glyphs[level % glyphs.len()].to_string().into(),
+            )
+        })
+    }
+
+    /// Creates a nine level numbered list definition, with levels numbered
+    /// `1.`, `a.`, `i.` and repeating.
+    pub fn decimal() -> Self {
+        const FORMATS: [NumberFormat; 3] = [
+            NumberFormat::Decimal,
+            NumberFormat::LowerLetter,
+            NumberFormat::LowerRoman,
+        ];
+
+        AbstractNum::with_levels(|level| {
+            (
+                FORMATS[level % 3].clone(),
+                format!("%{}.", level + 1).into(),
+            )
+        })
+    }
+
+    /// Creates a nine level numbered list definition, with levels numbered
+    /// `1.`, `1.1.`, `1.1.1.` and so on, indented like Word's.
+    ///
+    /// ```rust
+    /// use docx_rust::document::AbstractNum;
+    ///
+    /// let num = AbstractNum::nested_decimal();
+    /// assert_eq!(num.levels[2].level_text.as_ref().unwrap().value, "%1.%2.%3.");
+    /// ```
+    pub fn nested_decimal() -> Self {
+        const INDENTS: [(isize, isize); 9] = [
+            (360, 360),
+            (792, 432),
+            (1224, 504),
+            (1728, 648),
+            (2232, 792),
+            (2736, 936),
+            (3240, 1080),
+            (3744, 1224),
+            (4320, 1440),
+        ];
+
+        let mut num = AbstractNum::with_levels(|level| {
+            let text: String = (1..=level + 1).map(|i| format!("%{}.", i)).collect();
+            (NumberFormat::Decimal, text.into())
+        })
+        .indent(|level| Indent {
+            left: Some(INDENTS[level].0),
+            hanging: Some(INDENTS[level].1),
+            ..Default::default()
+        });
+        num.multi_level_type.value = "multilevel".into();
+        num
+    }
+
+    /// Creates a nine level numbered list definition for legal documents
+    /// and numbered headings, with levels numbered `1`, `1.1`, `1.1.1` and
+    /// so on, each number hanging in front of the text.
+    pub fn legal() -> Self {
+        let mut num = AbstractNum::with_levels(|level| {
+            let text: Vec<String> = (1..=level + 1).map(|i| format!("%{}", i)).collect();
+            (NumberFormat::Decimal, text.join(".").into())
+        })
+        .indent(|level| {
+            let indent = 432 + 144 * level as isize;
+            Indent {
+                left: Some(indent),
+                hanging: Some(indent),
+                ..Default::default()
+            }
+        });
+        num.multi_level_type.value = "multilevel".into();
+        num
+    }
+
+    /// Creates a nine level outline definition, with levels numbered `I.`,
+    /// `A.`, `1.`, `a)`, `(1)`, `(a)`, `(i)`, `(a)` and `(i)`.
+    pub fn outline() -> Self {
+        const LEVELS: [(NumberFormat, &str); 9] = [
+            (NumberFormat::UpperRoman, "%1."),
+            (NumberFormat::UpperLetter, "%2."),
+            (NumberFormat::Decimal, "%3."),
+            (NumberFormat::LowerLetter, "%4)"),
+            (NumberFormat::Decimal, "(%5)"),
+            (NumberFormat::LowerLetter, "(%6)"),
+            (NumberFormat::LowerRoman, "(%7)"),
+            (NumberFormat::LowerLetter, "(%8)"),
+            (NumberFormat::LowerRoman, "(%9)"),
+        ];
+
+        let mut num = AbstractNum::with_levels(|level| {
+            let (format, text) = &LEVELS[level];
+            (format.clone(), Cow::Borrowed(*text))
+        });
+        num.multi_level_type.value = "multilevel".into();
+        num
+    }
+
+    /// Replaces the indentation of the levels by the one `indent` returns
+    /// for each level, counting from 0.
+    ///
+    /// The definitions above indent like Word, see [`Indent::list`].
+    ///
+    /// ```rust
+    /// use docx_rust::document::AbstractNum;
+    /// use docx_rust::formatting::Indent;
+    ///
+    /// let compact = AbstractNum::bullet().indent(|level| Indent {
+    ///     left: Some(360 * (level as isize + 1)),
+    ///     hanging: Some(360),
+    ///     ..Default::default()
+    /// });
+    /// ```
+    pub fn indent<F: Fn(usize) -> Indent>(mut self, indent: F) -> Self {
+        for (index, level) in self.levels.iter_mut().enumerate() {
+            let i_level = level.i_level.map_or(index, |i_level| i_level as usize);
+            level.p_pr.get_or_insert_with(PPr::default).indent = Some(indent(i_level));
+        }
+        self
+    }
+
+    fn with_levels<F>(level: F) -> Self
+    where
+        F: Fn(usize) -> (NumberFormat, Cow<'a, str>),
+    {
+        AbstractNum {
+            multi_level_type: MultiLevelType {
+                value: "hybridMultilevel".into(),
+            },
+            levels: (0..9)
+                .map(|i| {
+                    let (format, text) = level(i);
+                    Level {
+                        i_level: Some(i as isize),
+                        start: Some(LevelStart { value: Some(1) }),
+                        number_format: Some(NumFmt::from(format)),
+                        level_text: Some(LevelText::from(text)),
+                        justification: Some(LevelJustification {
+                            value: JustificationVal::Left,
+                        }),
+                        p_pr: Some(PPr {
+                            indent: Some(Indent::list(i)),
+                        }),
+                        ..Default::default()
+                    }
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+}
+
 impl<'a> Numbering<'a> {
+    /// Adds an abstract numbering and a numbering referring to it, assigning
+    /// unused ids to both. Returns the id to reference from paragraphs.
+    pub fn push_list(&mut self, mut abstract_num: AbstractNum<'a>) -> isize {
+        let abstract_num_id = self
+            .abstract_numberings
+            .iter()
+            .filter_map(|an| an.abstract_num_id)
+            .max()
+            .map_or(0, |id| id + 1);
+        let num_id = self
+            .numberings
+            .iter()
+            .filter_map(|n| n.num_id)
+            .max()
+            .map_or(1, |id| id + 1);
+
+        abstract_num.abstract_num_id = Some(abstract_num_id);
+        if abstract_num.nsid.value.is_empty() {
+            abstract_num.nsid.value = format!("{:08X}", 0x2A00_0000 + abstract_num_id).into();
+        }
+        self.abstract_numberings.push(abstract_num);
+        self.numberings.push(Num {
+            num_id: Some(num_id),
+            abstract_num_id: Some(AbstractNumId {
+                value: Some(abstract_num_id),
+            }),
+            level_overrides: Vec::new(),
+        });
+
+        num_id
+    }
+
     /// Actual numberings refer to abstract numberings, and may overrule some settings.
     /// This helper function takes an numbering id that is provided in a paragraph, looks up
     /// the details in the numbering section and merges it with the abstract numbering to get
@@ -208,23 +673,22 @@ impl<'a> XmlWrite for Numbering<'a> {
 
         let _ = write!(writer.inner, "{}", crate::schema::SCHEMA_XML);
 
-        writer.write_element_start("w:numbering")?;
-
-        writer.write_attribute("xmlns:w", SCHEMA_MAIN)?;
-
-        writer.write_attribute("xmlns:w14", SCHEMA_WORDML_14)?;
-
-        writer.write_element_end_open()?;
-
-        for an in abstract_nums {
-            an.to_writer(writer)?;
-        }
+        write_root(
+            writer,
+            "w:numbering",
+            NamespaceSet::wordprocessing(),
+            |writer| {
+                for an in abstract_nums {
+                    an.to_writer(writer)?;
+                }
 
-        for num in nums {
-            num.to_writer(writer)?;
-        }
+                for num in nums {
+                    num.to_writer(writer)?;
+                }
 
-        writer.write_element_end_close("w:numbering")?;
+                Ok(())
+            },
+        )?;
 
         log::debug!("[Numbering] Finished writing.");
 
@@ -351,12 +815,7 @@ fn find_numbering_details() {
                 value: Cow::Borrowed("decimal")
             })
         );
-        assert_eq!(
-            num.levels[1].level_text,
-            Some(LevelText {
-                value: Cow::Borrowed("%2.")
-            })
-        );
+        assert_eq!(num.levels[1].level_text, Some(LevelText::from("%2.")));
     }
 }
 
@@ -386,8 +845,91 @@ fn xml_writing() {
     assert_eq!(
         replace_whitespace(NUMBERING_XML, " "),
         replace_whitespace(
-            &result.replace(&format!(" xmlns:w14=\"{SCHEMA_WORDML_14}\""), ""),
+            &result.replace(
+                &format!(
+                    " xmlns:w14=\"{}\" xmlns:mc=\"{}\" mc:Ignorable=\"w14\"",
+                    crate::schema::SCHEMA_WORDML_14,
+                    crate::schema::SCHEMA_MC
+                ),
+                "",
+            ),
             " "
         )
     );
 }
+
+#[test]
+fn list_presets() {
+    let mut numbering = Numbering::default();
+    assert_eq!(numbering.push_list(AbstractNum::bullet()), 1);
+    assert_eq!(numbering.push_list(AbstractNum::decimal()), 2);
+
+    let details = numbering.numbering_details(2).unwrap();
+    assert_eq!(details.abstract_num_id, Some(1));
+    assert_eq!(details.levels.len(), 9);
+    assert_eq!(
+        details.levels[1].number_format.as_ref().unwrap().value,
+        "lowerLetter"
+    );
+    assert_eq!(details.levels[1].level_text.as_ref().unwrap().value, "%2.");
+}
+
+#[test]
+fn list_indent() {
+    use crate::Docx;
+
+    let indent = |num: &AbstractNum, level: usize| {
+        let indent = num.levels[level].p_pr.as_ref()?.indent.as_ref()?;
+        Some((indent.left?, indent.hanging?))
+    };
+
+    let mut docx = Docx::default();
+    docx.bulleted_list(["a"]);
+    docx.list_indent = Some(|level| Indent {
+        left: Some(360 * (level as isize + 1)),
+        hanging: Some(180),
+        ..Default::default()
+    });
+    docx.numbered_list(["b"]);
+
+    let numbering = docx.numbering.unwrap();
+    assert_eq!(
+        indent(&numbering.abstract_numberings[0], 2),
+        Some((2160, 360))
+    );
+    assert_eq!(
+        indent(&numbering.abstract_numberings[1], 2),
+        Some((1080, 180))
+    );
+}
+
+#[test]
+fn number_format_presets() {
+    let level_text = |num: &AbstractNum, level: usize| {
+        let level = &num.levels[level];
+        (
+            level.number_format.as_ref().unwrap().format(),
+            level.level_text.as_ref().unwrap().value.to_string(),
+        )
+    };
+
+    assert_eq!(
+        level_text(&AbstractNum::legal(), 2),
+        (Some(NumberFormat::Decimal), "%1.%2.%3".to_string())
+    );
+    assert_eq!(
+        level_text(&AbstractNum::outline(), 0),
+        (Some(NumberFormat::UpperRoman), "%1.".to_string())
+    );
+    assert_eq!(
+        level_text(&AbstractNum::bullets(&AbstractNum::DASH_BULLETS), 4),
+        (Some(NumberFormat::Bullet), "\u{2013}".to_string())
+    );
+    assert_eq!(
+        NumFmt {
+            value: "futureFormat".into()
+        }
+        .format(),
+        None
+    );
+}
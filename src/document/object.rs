@@ -0,0 +1,96 @@
+use hard_xml::{XmlRead, XmlReader, XmlResult, XmlWrite, XmlWriter};
+use std::{borrow::Cow, io::Write};
+
+use crate::{
+    document::raw::{map_relationship_ids, read_element, relationship_ids},
+    schema::write_raw,
+};
+
+macro_rules! raw_element {
+    ($(#[$attr:meta])* $name:ident) => {
+        $(#[$attr])*
+        #[derive(Debug, Default, Clone)]
+        #[cfg_attr(test, derive(PartialEq))]
+        pub struct $name<'a> {
+            /// The markup of the element, as it was read
+            pub xml: Cow<'a, str>,
+        }
+
+        impl<'a> $name<'a> {
+            /// Returns the relationship ids the element refers to, e.g. the
+            /// embedded package and the image of its preview.
+            pub fn relationship_ids(&self) -> Vec<&str> {
+                relationship_ids(&self.xml)
+            }
+
+            /// Replaces the relationship ids the element refers to, see
+            /// [`map_relationship_ids`].
+            pub fn map_relationship_ids<F: FnMut(&str) -> Option<String>>(&mut self, f: F) {
+                map_relationship_ids(&mut self.xml, f)
+            }
+        }
+
+        impl<'a> XmlRead<'a> for $name<'a> {
+            fn from_reader(reader: &mut XmlReader<'a>) -> XmlResult<Self> {
+                Ok($name {
+                    xml: read_element(reader)?.into(),
+                })
+            }
+        }
+
+        impl<'a> XmlWrite for $name<'a> {
+            fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
+                write_raw(writer, &self.xml)
+            }
+        }
+    };
+}
+
+raw_element! {
+    /// Inline Embedded Object
+    ///
+    /// An OLE object, e.g. an embedded workbook or equation, with the VML
+    /// shape of its preview. It isn't modelled, so it is kept as it was read,
+    /// and the relationships it refers to are kept with it.
+    ///
+    /// ```rust
+    /// use docx_rust::document::*;
+    /// use hard_xml::XmlRead;
+    ///
+    /// let run = Run::from_str(
+    ///     r#"<w:r><w:object><o:OLEObject Type="Embed" ProgID="Excel.Sheet.12" r:id="rId6"/></w:object></w:r>"#,
+    /// )?;
+    /// match &run.content[0] {
+    ///     RunContent::Object(object) => assert_eq!(object.relationship_ids(), ["rId6"]),
+    ///     _ => unreachable!(),
+    /// }
+    /// # Ok::<(), hard_xml::XmlError>(())
+    /// ```
+    Object
+}
+
+raw_element! {
+    /// VML Object
+    ///
+    /// A legacy shape, e.g. a text box or a watermark. It isn't modelled, so
+    /// it is kept as it was read, and the relationships it refers to are kept
+    /// with it.
+    Pict
+}
+
+#[test]
+fn xml_round_trip() -> XmlResult<()> {
+    use crate::document::{Run, RunContent};
+
+    let xml = r##"<w:r><w:object w:dxaOrig="1440" w:dyaOrig="960"><v:shape id="_x0000_i1025" type="#_x0000_t75" style="width:72pt;height:48pt" o:ole=""><v:imagedata r:id="rId5" o:title=""/></v:shape><o:OLEObject Type="Embed" ProgID="Package" ShapeID="_x0000_i1025" DrawAspect="Icon" ObjectID="_1234" r:id="rId6"/></w:object><w:pict><v:rect style="width:10pt"><v:textbox><w:txbxContent><w:p><w:r><w:t xml:space="preserve"> a &lt; b </w:t></w:r></w:p></w:txbxContent></v:textbox></v:rect></w:pict></w:r>"##;
+
+    let run = Run::from_str(xml)?;
+    assert_eq!(run.content.len(), 2);
+    assert!(
+        matches!(&run.content[0], RunContent::Object(o) if o.relationship_ids() == ["rId5", "rId6"])
+    );
+    assert!(matches!(&run.content[1], RunContent::Pict(p) if p.relationship_ids().is_empty()));
+    assert_eq!(run.to_string()?, xml);
+
+    Ok(())
+}
@@ -2,14 +2,16 @@
 use derive_more::From;
 use hard_xml::{XmlRead, XmlWrite};
 use std::borrow::Cow;
+use std::ops::{Deref, DerefMut};
 
 use crate::{
     __setter, __xml_test_suites,
     document::{
-        BookmarkEnd, BookmarkStart, CommentRangeEnd, CommentRangeStart, Hyperlink, Run, RunContent,
-        Text,
+        instrtext::InstrText, BookmarkEnd, BookmarkStart, CharType, CommentRangeEnd,
+        CommentRangeStart, FieldChar, Hyperlink, InlineSDT, Run, RunContent, SubDocument, Sym,
+        Text, TextSpace,
     },
-    formatting::ParagraphProperty,
+    formatting::{CharacterProperty, Color, ParagraphProperty},
 };
 
 /// Paragraph
@@ -32,10 +34,11 @@ use crate::{
 #[cfg_attr(test, derive(PartialEq))]
 #[xml(tag = "w:p")]
 pub struct Paragraph<'a> {
-    //#[xml(attr = "w14:paraId")]
-    //pub id: Option<Cow<'a, str>>,
-    //#[xml(attr = "w14:textId")]
-    //pub text_id: Option<Cow<'a, str>>,
+    /// Specifies a unique identifier for the paragraph.
+    #[xml(attr = "w14:paraId")]
+    pub id: Option<Cow<'a, str>>,
+    #[xml(attr = "w14:textId")]
+    pub text_id: Option<Cow<'a, str>>,
     #[xml(attr = "w:rsidR")]
     pub rsid_r: Option<Cow<'a, str>>,
     #[xml(attr = "w:rsidRDefault")]
@@ -54,12 +57,21 @@ pub struct Paragraph<'a> {
         child = "w:r",
         child = "w:hyperlink",
         child = "w:bookmarkStart",
-        child = "w:bookmarkEnd"
+        child = "w:bookmarkEnd",
+        child = "w:sdt",
+        child = "w:subDoc"
     )]
     pub content: Vec<ParagraphContent<'a>>,
 }
 
+impl<'a, T: Into<Text<'a>>> From<T> for Paragraph<'a> {
+    fn from(val: T) -> Self {
+        Paragraph::default().push_text(val)
+    }
+}
+
 impl<'a> Paragraph<'a> {
+    __setter!(id: Option<Cow<'a, str>>);
     __setter!(property: Option<ParagraphProperty<'a>>);
 
     #[inline(always)]
@@ -77,6 +89,89 @@ impl<'a> Paragraph<'a> {
         self
     }
 
+    /// Creates a paragraph starting with a checked or unchecked box.
+    ///
+    /// ```rust
+    /// use docx_rust::document::*;
+    ///
+    /// let done = Paragraph::checklist_item(true, "write docs", CheckboxStyle::Glyph);
+    /// let todo = Paragraph::checklist_item(false, "release", CheckboxStyle::ContentControl);
+    /// ```
+    pub fn checklist_item<T: Into<Text<'a>>>(checked: bool, text: T, style: CheckboxStyle) -> Self {
+        let para = match style {
+            CheckboxStyle::Glyph => Paragraph::default().push(
+                Run::default().push(Sym::new("Wingdings", if checked { 0xF0FE } else { 0xF0A8 })),
+            ),
+            CheckboxStyle::ContentControl => {
+                Paragraph::default().push(InlineSDT::checkbox(checked))
+            }
+        };
+        para.push_text((" ", TextSpace::Preserve)).push_text(text)
+    }
+
+    /// Appends a complex field, like `PAGE` or `NUMPAGES`, showing `result`
+    /// until the field is updated.
+    ///
+    /// ```rust
+    /// use docx_rust::document::*;
+    ///
+    /// let para = Paragraph::default()
+    ///     .push_text(("Page ", TextSpace::Preserve))
+    ///     .push_field("PAGE", "1");
+    /// assert_eq!(para.content.len(), 6);
+    /// ```
+    pub fn push_field<I: AsRef<str>, T: Into<Text<'a>>>(self, instruction: I, result: T) -> Self {
+        self.push_field_with(FieldChar::from(CharType::Begin), instruction, result)
+    }
+
+    /// Appends a complex field like [`Paragraph::push_field`], marked dirty so
+    /// Word updates it when the document is opened, e.g. a table of contents.
+    ///
+    /// ```rust
+    /// use docx_rust::document::*;
+    ///
+    /// let para = Paragraph::default().push_dirty_field("TOC \\o", "No entries");
+    /// assert!(matches!(
+    ///     &para.content[0],
+    ///     ParagraphContent::Run(run) if matches!(
+    ///         &run.content[0],
+    ///         RunContent::FieldChar(begin) if begin.dirty == Some(true)
+    ///     )
+    /// ));
+    /// ```
+    pub fn push_dirty_field<I: AsRef<str>, T: Into<Text<'a>>>(
+        self,
+        instruction: I,
+        result: T,
+    ) -> Self {
+        let begin = FieldChar {
+            dirty: Some(true),
+            ..FieldChar::from(CharType::Begin)
+        };
+        self.push_field_with(begin, instruction, result)
+    }
+
+    fn push_field_with<I: AsRef<str>, T: Into<Text<'a>>>(
+        self,
+        begin: FieldChar,
+        instruction: I,
+        result: T,
+    ) -> Self {
+        let instruction = InstrText::from(format!(" {} ", instruction.as_ref()));
+        self.push(Run::default().push(begin))
+            .push(Run::default().push(instruction))
+            .push(Run::default().push(FieldChar::from(CharType::Separate)))
+            .push(Run::default().push_text(result))
+            .push(Run::default().push(FieldChar::from(CharType::End)))
+    }
+
+    /// Appends a citation of the bibliography source with the given tag,
+    /// showing `result` until Word updates the field, see
+    /// [`crate::bibliography`].
+    pub fn push_citation<T: Into<Text<'a>>>(self, tag: &str, result: T) -> Self {
+        self.push_field(format!("CITATION {}", tag), result)
+    }
+
     pub fn text(&self) -> String {
         self.iter_text()
             .map(|c| c.to_string())
@@ -106,6 +201,176 @@ impl<'a> Paragraph<'a> {
             .flatten()
     }
 
+    /// Makes every run of this paragraph bold.
+    pub fn bold(mut self) -> Self {
+        self.set_bold();
+        self
+    }
+
+    /// Makes every run of this paragraph italic.
+    pub fn italics(mut self) -> Self {
+        self.set_italics();
+        self
+    }
+
+    /// Sets the font size of every run of this paragraph, in points.
+    pub fn size_pt<T: Into<f64>>(mut self, size: T) -> Self {
+        self.set_size_pt(size);
+        self
+    }
+
+    /// Sets the text color of every run of this paragraph.
+    pub fn color<T: Into<Color<'a>>>(mut self, color: T) -> Self {
+        self.set_color(color);
+        self
+    }
+
+    /// Makes every run of this paragraph bold, see [`Paragraph::bold`].
+    pub fn set_bold(&mut self) -> &mut Self {
+        for prop in self.character_properties_mut() {
+            prop.bold = Some(true.into());
+        }
+        self
+    }
+
+    /// Makes every run of this paragraph italic, see [`Paragraph::italics`].
+    pub fn set_italics(&mut self) -> &mut Self {
+        for prop in self.character_properties_mut() {
+            prop.italics = Some(true.into());
+        }
+        self
+    }
+
+    /// Sets the font size of every run of this paragraph, in points, see
+    /// [`Paragraph::size_pt`].
+    pub fn set_size_pt<T: Into<f64>>(&mut self, size: T) -> &mut Self {
+        let half_points = (size.into() * 2.0).round() as isize;
+        for prop in self.character_properties_mut() {
+            prop.size = Some(half_points.into());
+        }
+        self
+    }
+
+    /// Sets the text color of every run of this paragraph, see
+    /// [`Paragraph::color`].
+    pub fn set_color<T: Into<Color<'a>>>(&mut self, color: T) -> &mut Self {
+        let color = color.into();
+        for prop in self.character_properties_mut() {
+            prop.color = Some(color.clone());
+        }
+        self
+    }
+
+    fn character_properties_mut(&mut self) -> impl Iterator<Item = &mut CharacterProperty<'a>> {
+        self.content
+            .iter_mut()
+            .filter_map(|content| match content {
+                ParagraphContent::Run(run) => Some(run),
+                ParagraphContent::Link(link) => Some(&mut link.content),
+                _ => None,
+            })
+            .map(|run| run.property.get_or_insert_with(CharacterProperty::default))
+    }
+
+    /// Returns a copy of the paragraph with every `mc:AlternateContent` block
+    /// in its runs replaced by the content it selects, see
+    /// [`Run::resolve_alternate_content`].
+    pub fn resolve_alternate_content(&self) -> Paragraph<'_> {
+        let mut para: Paragraph<'_> = self.clone();
+        for (content, resolved) in self.content.iter().zip(para.content.iter_mut()) {
+            match (content, resolved) {
+                (ParagraphContent::Run(run), ParagraphContent::Run(resolved)) => {
+                    *resolved = run.resolve_alternate_content()
+                }
+                (ParagraphContent::Link(link), ParagraphContent::Link(resolved)) => {
+                    resolved.content = link.content.resolve_alternate_content()
+                }
+                _ => {}
+            }
+        }
+        para
+    }
+
+    /// Splits the paragraph at a character index of [`Paragraph::text`],
+    /// returning a paragraph with the same properties and the content from
+    /// the index on, as pressing Enter does in Word.
+    ///
+    /// The run or link at the index is split in two with the same
+    /// properties, see [`Run::split_at`]. A section break ending the
+    /// paragraph moves to the returned one, and the paragraph ids are left
+    /// out as they have to be unique.
+    ///
+    /// ```rust
+    /// use docx_rust::document::*;
+    ///
+    /// let mut para = Paragraph::default().push_text("Dear Sir, {{body}} Regards");
+    /// let mut rest = para.split_at(10);
+    /// let after = rest.split_at(8);
+    /// assert_eq!(para.text(), "Dear Sir, ");
+    /// assert_eq!(rest.text(), "{{body}}");
+    ///
+    /// para.merge_with(after);
+    /// assert_eq!(para.text(), "Dear Sir,  Regards");
+    /// ```
+    pub fn split_at(&mut self, index: usize) -> Paragraph<'a> {
+        let mut content = std::mem::take(&mut self.content);
+        let mut rest = Paragraph {
+            id: None,
+            text_id: None,
+            ..self.clone()
+        };
+        if let Some(property) = &mut self.property {
+            property.section_property = None;
+        }
+
+        let mut pos = 0;
+        let mut split = content.len();
+        let mut tail = None;
+        for (i, c) in content.iter_mut().enumerate() {
+            if pos >= index {
+                split = i;
+                break;
+            }
+            let run = match c {
+                ParagraphContent::Run(run) => run,
+                ParagraphContent::Link(link) => &mut link.content,
+                _ => continue,
+            };
+            let len = run.text_len();
+            if pos + len > index {
+                let run = run.split_at(index - pos);
+                tail = Some(match c {
+                    ParagraphContent::Link(link) => ParagraphContent::Link(Hyperlink {
+                        content: run,
+                        ..link.clone()
+                    }),
+                    _ => ParagraphContent::Run(run),
+                });
+                split = i + 1;
+                break;
+            }
+            pos += len;
+        }
+
+        rest.content.extend(tail);
+        rest.content.extend(content.drain(split..));
+        self.content = content;
+        rest
+    }
+
+    /// Appends the content of the next paragraph, as deleting the end of
+    /// this paragraph does in Word. The paragraph keeps its properties, but
+    /// takes the section break ending the next one.
+    pub fn merge_with(&mut self, next: Paragraph<'a>) {
+        let section = next.property.and_then(|property| property.section_property);
+        if let Some(section) = section {
+            self.property
+                .get_or_insert_with(ParagraphProperty::default)
+                .section_property = Some(section);
+        }
+        self.content.extend(next.content);
+    }
+
     pub fn replace_text<'b, T, S>(&mut self, dic: T) -> crate::DocxResult<()>
     where
         S: AsRef<str> + 'b,
@@ -124,6 +389,61 @@ impl<'a> Paragraph<'a> {
     }
 }
 
+/// A paragraph appended to a document, see [`crate::Docx::add_paragraph`]
+///
+/// Formats its runs in a chain like a new [`Paragraph`], and derefs to the
+/// paragraph otherwise.
+///
+/// ```rust
+/// use docx_rust::Docx;
+///
+/// let mut docx = Docx::default();
+/// let para = docx.add_paragraph("text").bold().size_pt(12);
+/// assert_eq!(para.text(), "text");
+/// ```
+#[derive(Debug)]
+pub struct ParagraphMut<'p, 'a>(pub &'p mut Paragraph<'a>);
+
+impl<'p, 'a> ParagraphMut<'p, 'a> {
+    /// Makes every run of the paragraph bold.
+    pub fn bold(self) -> Self {
+        self.0.set_bold();
+        self
+    }
+
+    /// Makes every run of the paragraph italic.
+    pub fn italics(self) -> Self {
+        self.0.set_italics();
+        self
+    }
+
+    /// Sets the font size of every run of the paragraph, in points.
+    pub fn size_pt<T: Into<f64>>(self, size: T) -> Self {
+        self.0.set_size_pt(size);
+        self
+    }
+
+    /// Sets the text color of every run of the paragraph.
+    pub fn color<T: Into<Color<'a>>>(self, color: T) -> Self {
+        self.0.set_color(color);
+        self
+    }
+}
+
+impl<'a> Deref for ParagraphMut<'_, 'a> {
+    type Target = Paragraph<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        self.0
+    }
+}
+
+impl DerefMut for ParagraphMut<'_, '_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.0
+    }
+}
+
 /// A set of elements that can be contained as the content of a paragraph.
 #[derive(Debug, From, XmlRead, XmlWrite, Clone)]
 #[cfg_attr(test, derive(PartialEq))]
@@ -140,6 +460,20 @@ pub enum ParagraphContent<'a> {
     BookmarkStart(BookmarkStart<'a>),
     #[xml(tag = "w:bookmarkEnd")]
     BookmarkEnd(BookmarkEnd<'a>),
+    #[xml(tag = "w:sdt")]
+    Sdt(InlineSDT<'a>),
+    #[xml(tag = "w:subDoc")]
+    SubDocument(SubDocument<'a>),
+}
+
+/// How [`Paragraph::checklist_item`] renders the box of a checklist item
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CheckboxStyle {
+    /// A Wingdings box glyph, which is static text
+    #[default]
+    Glyph,
+    /// A check box content control, which can be toggled in Word
+    ContentControl,
 }
 
 __xml_test_suites!(
@@ -154,4 +488,76 @@ __xml_test_suites!(
     r#"<w:p><w:bookmarkStart/></w:p>"#,
     Paragraph::default().push(BookmarkEnd::default()),
     r#"<w:p><w:bookmarkEnd/></w:p>"#,
+    Paragraph::default().push(SubDocument::default().id("rId5")),
+    r#"<w:p><w:subDoc r:id="rId5"/></w:p>"#,
 );
+
+#[test]
+fn run_formatting() {
+    let para = Paragraph::default()
+        .push_text("a")
+        .push(Hyperlink::default())
+        .bold()
+        .size_pt(10.5)
+        .color(0xff0000);
+
+    for content in &para.content {
+        let run = match content {
+            ParagraphContent::Run(run) => run,
+            ParagraphContent::Link(link) => &link.content,
+            _ => unreachable!(),
+        };
+        let prop = run.property.as_ref().unwrap();
+        assert_eq!(prop.bold.as_ref().and_then(|b| b.value), Some(true));
+        assert_eq!(prop.size.as_ref().map(|s| s.value), Some(21));
+        assert!(prop.color.is_some());
+    }
+}
+
+#[test]
+fn split_and_merge() {
+    use crate::formatting::SectionProperty;
+
+    let mut para = Paragraph::default()
+        .id("1A2B3C4D")
+        .property(ParagraphProperty {
+            section_property: Some(SectionProperty::default()),
+            ..Default::default()
+        })
+        .push(
+            Run::default()
+                .push_text("one ")
+                .push(FieldChar::from(CharType::Begin)),
+        )
+        .push(
+            Hyperlink::default()
+                .anchor("top")
+                .content(Run::default().push_text("two")),
+        )
+        .push(BookmarkStart::default().name("end"))
+        .push_text(String::from("three"));
+
+    let mut rest = para.split_at(6);
+    assert_eq!(para.text(), "one tw");
+    assert_eq!(rest.text(), "othree");
+    assert_eq!(para.content.len(), 2);
+    assert!(matches!(&rest.content[0], ParagraphContent::Link(link) if link.anchor.is_some()));
+    assert!(rest.id.is_none());
+    assert!(para.property.as_ref().unwrap().section_property.is_none());
+    assert!(rest.property.as_ref().unwrap().section_property.is_some());
+
+    let end = rest.split_at(1);
+    assert_eq!(rest.content.len(), 1);
+    assert!(matches!(
+        &end.content[0],
+        ParagraphContent::BookmarkStart(_)
+    ));
+
+    let empty = rest.split_at(1);
+    assert!(empty.content.is_empty());
+
+    para.merge_with(rest);
+    para.merge_with(end);
+    assert_eq!(para.text(), "one twothree");
+    assert!(para.property.as_ref().unwrap().section_property.is_some());
+}
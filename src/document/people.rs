@@ -0,0 +1,143 @@
+//! People part
+//!
+//! The corresponding ZIP item is `/word/people.xml`.
+//!
+//! Lists the authors of comments and revisions together with their identity
+//! provider information, so that Word can match them to user accounts.
+#![allow(unused_must_use)]
+
+use hard_xml::{XmlRead, XmlResult, XmlWrite, XmlWriter};
+use std::{borrow::Cow, io::Write};
+
+use crate::{
+    __setter, __xml_test_suites,
+    document::Comments,
+    schema::{write_root, NamespaceSet, SCHEMA_MAIN, SCHEMA_WORDML_15},
+};
+
+/// The root element of the people part.
+///
+/// ```rust
+/// use docx_rust::document::*;
+///
+/// let mut people = People::default();
+/// people.push(Person::new("John Doe"));
+/// people.push(Person::new("John Doe"));
+///
+/// assert_eq!(people.persons.len(), 1);
+/// assert!(people.get("John Doe").is_some());
+/// ```
+#[derive(Debug, Default, XmlRead, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w15:people")]
+pub struct People<'a> {
+    /// Specifies the persons
+    #[xml(child = "w15:person")]
+    pub persons: Vec<Person<'a>>,
+}
+
+impl<'a> People<'a> {
+    /// Adds a person, unless a person with the same author name already exists.
+    pub fn push(&mut self, person: Person<'a>) -> &mut Self {
+        if self.get(&person.author).is_none() {
+            self.persons.push(person);
+        }
+        self
+    }
+
+    /// Collects a person for every distinct comment author.
+    pub fn from_comments(comments: &Comments<'a>) -> Self {
+        let mut people = People::default();
+        for comment in &comments.comments {
+            people.push(Person::new(comment.author.clone()));
+        }
+        people
+    }
+
+    /// Finds a person by the author name used in comments and revisions.
+    pub fn get(&self, author: &str) -> Option<&Person<'a>> {
+        self.persons.iter().find(|p| p.author == author)
+    }
+}
+
+/// A single author
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w15:person")]
+pub struct Person<'a> {
+    /// Specifies the author name, as used in `w:author` attributes.
+    #[xml(attr = "w15:author")]
+    pub author: Cow<'a, str>,
+    /// Specifies the identity of the author.
+    #[xml(child = "w15:presenceInfo")]
+    pub presence_info: Option<PresenceInfo<'a>>,
+}
+
+impl<'a> Person<'a> {
+    pub fn new<T: Into<Cow<'a, str>>>(author: T) -> Self {
+        Person {
+            author: author.into(),
+            ..Default::default()
+        }
+    }
+
+    __setter!(presence_info: Option<PresenceInfo<'a>>);
+}
+
+/// Identity provider information of an author
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w15:presenceInfo")]
+pub struct PresenceInfo<'a> {
+    /// Specifies the identity provider, e.g. `None`, `Windows Live` or `AD`.
+    #[xml(attr = "w15:providerId")]
+    pub provider_id: Cow<'a, str>,
+    /// Specifies the user id within the provider.
+    #[xml(attr = "w15:userId")]
+    pub user_id: Cow<'a, str>,
+}
+
+impl<'a> PresenceInfo<'a> {
+    pub fn new<T: Into<Cow<'a, str>>, U: Into<Cow<'a, str>>>(provider_id: T, user_id: U) -> Self {
+        PresenceInfo {
+            provider_id: provider_id.into(),
+            user_id: user_id.into(),
+        }
+    }
+}
+
+impl<'a> XmlWrite for People<'a> {
+    fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
+        let People { persons } = self;
+
+        log::debug!("[People] Started writing.");
+        let _ = write!(writer.inner, "{}", crate::schema::SCHEMA_XML);
+
+        write_root(
+            writer,
+            "w15:people",
+            NamespaceSet::default()
+                .with("w", SCHEMA_MAIN)
+                .with("w15", SCHEMA_WORDML_15),
+            |writer| {
+                for p in persons {
+                    p.to_writer(writer)?;
+                }
+
+                Ok(())
+            },
+        )?;
+
+        log::debug!("[People] Finished writing.");
+
+        Ok(())
+    }
+}
+
+__xml_test_suites!(
+    Person,
+    Person::new("John Doe"),
+    r#"<w15:person w15:author="John Doe"/>"#,
+    Person::new("John Doe").presence_info(PresenceInfo::new("None", "John Doe")),
+    r#"<w15:person w15:author="John Doe"><w15:presenceInfo w15:providerId="None" w15:userId="John Doe"/></w15:person>"#,
+);
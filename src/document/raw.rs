@@ -0,0 +1,192 @@
+use hard_xml::{XmlRead, XmlReader, XmlResult, XmlWrite, XmlWriter};
+use std::{borrow::Cow, io::Write};
+use xmlparser::{ElementEnd, Token, Tokenizer};
+
+use crate::schema::write_raw;
+
+/// The tag `RawXml` pretends to have. It never appears in a document, so raw
+/// content is never produced while reading.
+const RAW_TAG: &str = "docx-rust:raw";
+
+/// Pre-serialized XML, written verbatim
+///
+/// An escape hatch for elements the typed API doesn't cover yet. The content
+/// is neither validated nor escaped, so it is the caller's responsibility to
+/// supply well-formed markup whose namespace prefixes are declared on the
+/// root element of the part.
+///
+/// ```rust
+/// use docx_rust::document::*;
+///
+/// let mut body = Body::default();
+/// body.push(RawXml::unsafe_raw(r#"<w:p><w:r><w:t>raw</w:t></w:r></w:p>"#));
+///
+/// let run = Run::default().push(RawXml::unsafe_raw("<w:noBreakHyphen/>"));
+/// ```
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+pub struct RawXml<'a> {
+    pub xml: Cow<'a, str>,
+}
+
+impl<'a> RawXml<'a> {
+    /// Wraps the given markup, which will be written out as is.
+    pub fn unsafe_raw<T: Into<Cow<'a, str>>>(xml: T) -> Self {
+        RawXml { xml: xml.into() }
+    }
+
+    /// Returns the relationship ids the markup refers to, see
+    /// [`relationship_ids`].
+    pub fn relationship_ids(&self) -> Vec<&str> {
+        relationship_ids(&self.xml)
+    }
+
+    /// Replaces the relationship ids the markup refers to, see
+    /// [`map_relationship_ids`].
+    pub fn map_relationship_ids<F: FnMut(&str) -> Option<String>>(&mut self, f: F) {
+        map_relationship_ids(&mut self.xml, f)
+    }
+}
+
+/// Reads the element the reader is at, returning its markup as it appears in
+/// the source.
+pub(crate) fn read_element(reader: &mut XmlReader<'_>) -> XmlResult<String> {
+    let mut xml = String::new();
+    let mut depth = 0usize;
+
+    while let Some(token) = reader.next() {
+        match token? {
+            Token::ElementStart { span, .. } => {
+                xml.push_str(span.as_str());
+                depth += 1;
+            }
+            Token::Attribute { span, .. } => {
+                xml.push(' ');
+                xml.push_str(span.as_str());
+            }
+            Token::ElementEnd { end, span } => {
+                xml.push_str(span.as_str());
+                if !matches!(end, ElementEnd::Open) {
+                    depth = depth.saturating_sub(1);
+                    if depth == 0 {
+                        break;
+                    }
+                }
+            }
+            Token::Text { text } if depth > 0 => xml.push_str(text.as_str()),
+            Token::Cdata { span, .. } | Token::Comment { span, .. } if depth > 0 => {
+                xml.push_str(span.as_str())
+            }
+            _ => {}
+        }
+    }
+
+    Ok(xml)
+}
+
+/// Whether an attribute holds a relationship id, like `r:id`, `r:embed`,
+/// `r:link` or the `o:relid` of VML.
+fn is_relationship_attribute(prefix: &str, local: &str) -> bool {
+    prefix == "r" || (prefix == "o" && local == "relid")
+}
+
+/// Returns the relationship ids the markup refers to, in document order and
+/// with duplicates, from attributes like `r:id`, `r:embed`, `r:link` and
+/// `o:relid`, at any depth.
+///
+/// ```rust
+/// use docx_rust::document::relationship_ids;
+///
+/// let xml = r#"<w:object><v:shape><v:imagedata r:id="rId5"/></v:shape><o:OLEObject r:id="rId6"/></w:object>"#;
+/// assert_eq!(relationship_ids(xml), ["rId5", "rId6"]);
+/// ```
+pub fn relationship_ids(xml: &str) -> Vec<&str> {
+    Tokenizer::from(xml)
+        .filter_map(|token| match token {
+            Ok(Token::Attribute {
+                prefix,
+                local,
+                value,
+                ..
+            }) if is_relationship_attribute(prefix.as_str(), local.as_str()) => {
+                Some(value.as_str())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Replaces the relationship ids the markup refers to, see
+/// [`relationship_ids`], with the ones `f` returns. Ids for which `f` returns
+/// `None` are left as they are.
+///
+/// ```rust
+/// use docx_rust::document::map_relationship_ids;
+/// use std::borrow::Cow;
+///
+/// let mut xml = Cow::Borrowed(r#"<w:pict><v:imagedata r:id="rId5" o:title=""/></w:pict>"#);
+/// map_relationship_ids(&mut xml, |id| Some(format!("{}0", id)));
+/// assert_eq!(xml, r#"<w:pict><v:imagedata r:id="rId50" o:title=""/></w:pict>"#);
+/// ```
+pub fn map_relationship_ids<F: FnMut(&str) -> Option<String>>(xml: &mut Cow<'_, str>, mut f: F) {
+    let mut replaced = String::new();
+    let mut last = 0;
+
+    for token in Tokenizer::from(&**xml) {
+        if let Ok(Token::Attribute {
+            prefix,
+            local,
+            value,
+            ..
+        }) = token
+        {
+            if !is_relationship_attribute(prefix.as_str(), local.as_str()) {
+                continue;
+            }
+            if let Some(id) = f(value.as_str()) {
+                replaced.push_str(&xml[last..value.start()]);
+                replaced.push_str(&id);
+                last = value.end();
+            }
+        }
+    }
+
+    if last > 0 {
+        replaced.push_str(&xml[last..]);
+        *xml = Cow::Owned(replaced);
+    }
+}
+
+impl<'a> XmlRead<'a> for RawXml<'a> {
+    fn from_reader(reader: &mut XmlReader<'a>) -> XmlResult<Self> {
+        reader.read_till_element_start(RAW_TAG)?;
+        reader.read_to_end(RAW_TAG)?;
+        Ok(RawXml::default())
+    }
+}
+
+impl<'a> XmlWrite for RawXml<'a> {
+    fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
+        write_raw(writer, &self.xml)
+    }
+}
+
+#[test]
+fn xml_writing() -> XmlResult<()> {
+    use crate::document::{Body, Paragraph};
+
+    let raw = RawXml::unsafe_raw("<w:p><w:r><w:t>a &amp; b</w:t></w:r></w:p>");
+    assert_eq!(
+        raw.to_string()?,
+        "<w:p><w:r><w:t>a &amp; b</w:t></w:r></w:p>"
+    );
+
+    let mut body = Body::default();
+    body.push(Paragraph::default()).push(raw);
+    assert_eq!(
+        body.to_string()?,
+        "<w:body><w:p/><w:p><w:r><w:t>a &amp; b</w:t></w:r></w:p></w:body>"
+    );
+
+    Ok(())
+}
@@ -4,21 +4,21 @@ use hard_xml::{XmlRead, XmlWrite};
 use std::borrow::Cow;
 
 use crate::{
-    __setter, __xml_test_suites,
+    __define_enum, __define_struct, __setter, __xml_test_suites,
     document::{
         drawing::Drawing, field_char::FieldChar, instrtext::InstrText, r#break::Break,
         r#break::LastRenderedPageBreak, tab::Tab, text::Text,
     },
     formatting::CharacterProperty,
-    DocxResult, __define_enum, __define_struct,
+    DocxResult,
 };
 
 use super::{
     date::{DayLong, DayShort, MonthLong, MonthShort, YearLong, YearShort},
     instrtext::DelInstrText,
     sym::Sym,
-    AnnotationRef, CarriageReturn, CommentReference, DelText, EndnoteRef, EndnoteReference,
-    FootnoteRef, FootnoteReference,
+    AlternateContent, AnnotationRef, CarriageReturn, CommentReference, DelText, EndnoteRef,
+    EndnoteReference, FootnoteRef, FootnoteReference, Object, Pict, RawXml,
 };
 
 /// Run
@@ -46,6 +46,10 @@ pub struct Run<'a> {
     pub rsid_r: Option<Cow<'a, str>>,
     #[xml(attr = "w:rsidRDefault")]
     pub rsid_r_default: Option<Cow<'a, str>>,
+    #[xml(attr = "w:rsidRPr")]
+    pub rsid_r_pr: Option<Cow<'a, str>>,
+    #[xml(attr = "w:rsidDel")]
+    pub rsid_del: Option<Cow<'a, str>>,
     /// Just as paragraph, a run's properties is applied to all the contents of the run.
     #[xml(child = "w:rPr")]
     pub property: Option<CharacterProperty<'a>>,
@@ -72,8 +76,8 @@ pub struct Run<'a> {
         child = "w:pgNum", //Page Number Block
         child = "w:cr", //Carriage Return
         child = "w:tab", //Tab Character
-        //child = "w:object", //Inline Embedded Object
-        //child = "w:pict", //VML Object
+        child = "w:object", //Inline Embedded Object
+        child = "w:pict", //VML Object
         child = "w:fldChar", //Complex Field Character
         //child = "w:ruby", //Phonetic Guide
         child = "w:footnoteReference", //Footnote Reference
@@ -82,11 +86,15 @@ pub struct Run<'a> {
         child = "w:drawing", //DrawingML Object
         child = "w:ptab", //Absolute Position Tab Character
         child = "w:lastRenderedPageBreak", //Position of Last Calculated Page Break
+        child = "mc:AlternateContent", //Markup Compatibility Alternate Content
     )]
     /// Specifies the content of a run
     pub content: Vec<RunContent<'a>>,
 }
 
+static NON_BREAKING_HYPHEN: Cow<str> = Cow::Borrowed("\u{2011}");
+static SOFT_HYPHEN: Cow<str> = Cow::Borrowed("\u{ad}");
+
 impl<'a> Run<'a> {
     __setter!(property: Option<CharacterProperty<'a>>);
 
@@ -108,20 +116,10 @@ impl<'a> Run<'a> {
         self
     }
 
+    /// Iterates over the text of the run, non-breaking and soft hyphens
+    /// included as U+2011 and U+00AD.
     pub fn iter_text(&self) -> impl Iterator<Item = &Cow<'a, str>> {
-        self.content.iter().filter_map(|content| match content {
-            RunContent::Text(Text { text, .. }) => Some(text),
-            RunContent::InstrText(InstrText { text, .. }) => Some(text),
-            RunContent::Break(_) => None,
-            RunContent::LastRenderedPageBreak(_) => None,
-            RunContent::FieldChar(_) => None,
-            RunContent::Separator(_) => None,
-            RunContent::ContinuationSeparator(_) => None,
-            RunContent::Tab(_) => None,
-            RunContent::CarriageReturn(_) => None,
-            RunContent::Drawing(_) => None,
-            _ => None,
-        })
+        self.content.iter().filter_map(text_of)
     }
 
     pub fn iter_text_mut(&mut self) -> impl Iterator<Item = &mut Cow<'a, str>> {
@@ -140,6 +138,27 @@ impl<'a> Run<'a> {
         })
     }
 
+    /// Returns a copy of the run with every `mc:AlternateContent` block
+    /// replaced by the content it selects, see [`AlternateContent::selected`].
+    /// Blocks nested in the selected content are kept as they are.
+    pub fn resolve_alternate_content(&self) -> Run<'_> {
+        let mut content = Vec::with_capacity(self.content.len());
+        for c in &self.content {
+            match c {
+                RunContent::AlternateContent(ac) => content.extend(ac.selected()),
+                c => content.push(c.clone()),
+            }
+        }
+        Run {
+            rsid_r: self.rsid_r.clone(),
+            rsid_r_default: self.rsid_r_default.clone(),
+            rsid_r_pr: self.rsid_r_pr.clone(),
+            rsid_del: self.rsid_del.clone(),
+            property: self.property.clone(),
+            content,
+        }
+    }
+
     pub fn replace_text_simple<S>(&mut self, old: S, new: S)
     where
         S: AsRef<str>,
@@ -169,6 +188,95 @@ impl<'a> Run<'a> {
 
         Ok(())
     }
+
+    /// Returns the number of characters of [`Run::iter_text`].
+    pub fn text_len(&self) -> usize {
+        self.iter_text().map(|text| text.chars().count()).sum()
+    }
+
+    /// Splits the run at a character index of [`Run::iter_text`], returning
+    /// a run with the same properties and the content from the index on.
+    ///
+    /// Only text is split: an index inside a field instruction splits the
+    /// run after it. Content without text at the index goes to the returned
+    /// run.
+    ///
+    /// ```rust
+    /// use docx_rust::document::Run;
+    ///
+    /// let mut run = Run::default().push_text("Hello world");
+    /// let rest = run.split_at(5);
+    /// assert_eq!(run.iter_text().next().unwrap(), "Hello");
+    /// assert_eq!(rest.iter_text().next().unwrap(), " world");
+    /// ```
+    pub fn split_at(&mut self, index: usize) -> Run<'a> {
+        let mut content = std::mem::take(&mut self.content);
+        let mut rest = self.clone();
+
+        let mut pos = 0;
+        let mut split = content.len();
+        let mut tail = None;
+        for (i, c) in content.iter_mut().enumerate() {
+            if pos >= index {
+                split = i;
+                break;
+            }
+            let len = text_of(c).map_or(0, |text| text.chars().count());
+            if let RunContent::Text(text) = c {
+                if pos + len > index {
+                    let at = text
+                        .text
+                        .char_indices()
+                        .nth(index - pos)
+                        .map_or(text.text.len(), |(at, _)| at);
+                    text.space = None;
+                    tail = Some(Text {
+                        space: None,
+                        text: split_cow(&mut text.text, at),
+                    });
+                    split = i + 1;
+                    break;
+                }
+            }
+            pos += len;
+        }
+
+        rest.content.extend(tail.map(RunContent::Text));
+        rest.content.extend(content.drain(split..));
+        self.content = content;
+        rest
+    }
+}
+
+/// Returns the text of a run content, see [`Run::iter_text`].
+fn text_of<'r, 'a>(content: &'r RunContent<'a>) -> Option<&'r Cow<'a, str>> {
+    match content {
+        RunContent::Text(Text { text, .. }) => Some(text),
+        RunContent::InstrText(InstrText { text, .. }) => Some(text),
+        RunContent::NoBreakHyphen(_) => Some(&NON_BREAKING_HYPHEN),
+        RunContent::SoftHyphen(_) => Some(&SOFT_HYPHEN),
+        RunContent::Break(_) => None,
+        RunContent::LastRenderedPageBreak(_) => None,
+        RunContent::FieldChar(_) => None,
+        RunContent::Separator(_) => None,
+        RunContent::ContinuationSeparator(_) => None,
+        RunContent::Tab(_) => None,
+        RunContent::CarriageReturn(_) => None,
+        RunContent::Drawing(_) => None,
+        _ => None,
+    }
+}
+
+/// Splits a string at a byte index, keeping the borrowed parts borrowed.
+fn split_cow<'a>(cow: &mut Cow<'a, str>, at: usize) -> Cow<'a, str> {
+    match cow {
+        Cow::Borrowed(s) => {
+            let (head, tail) = s.split_at(at);
+            *cow = Cow::Borrowed(head);
+            Cow::Borrowed(tail)
+        }
+        Cow::Owned(s) => Cow::Owned(s.split_off(at)),
+    }
 }
 
 /// A set of elements that can be contained as the content of a run.
@@ -219,10 +327,10 @@ pub enum RunContent<'a> {
     CarriageReturn(CarriageReturn),
     #[xml(tag = "w:tab")]
     Tab(Tab),
-    //#[xml(tag = "w:object")]
-    //Object(Object<'a>),
-    //#[xml(tag = "w:pict")]
-    //Pict(Pict<'a>),
+    #[xml(tag = "w:object")]
+    Object(Object<'a>),
+    #[xml(tag = "w:pict")]
+    Pict(Pict<'a>),
     #[xml(tag = "w:fldChar")]
     FieldChar(FieldChar),
     //#[xml(tag = "w:ruby")]
@@ -239,6 +347,56 @@ pub enum RunContent<'a> {
     PTab(PTab),
     #[xml(tag = "w:lastRenderedPageBreak")]
     LastRenderedPageBreak(LastRenderedPageBreak),
+    #[xml(tag = "mc:AlternateContent")]
+    AlternateContent(AlternateContent<'a>),
+    /// Pre-serialized XML, see [`RawXml::unsafe_raw`]
+    #[xml(tag = "docx-rust:raw")]
+    Raw(RawXml<'a>),
+}
+
+impl<'a> RunContent<'a> {
+    /// Returns the relationship ids the content refers to: the picture or
+    /// chart of a drawing, and those in objects, VML shapes, alternate
+    /// content and raw XML.
+    pub fn relationship_ids(&self) -> Vec<&str> {
+        match self {
+            RunContent::Drawing(drawing) => drawing
+                .image_id()
+                .into_iter()
+                .chain(drawing.chart_id())
+                .collect(),
+            RunContent::Object(object) => object.relationship_ids(),
+            RunContent::Pict(pict) => pict.relationship_ids(),
+            RunContent::AlternateContent(ac) => ac.relationship_ids(),
+            RunContent::Raw(raw) => raw.relationship_ids(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Replaces the relationship ids the content refers to, see
+    /// [`RunContent::relationship_ids`], with the ones `f` returns. Ids for
+    /// which `f` returns `None` are left as they are.
+    pub fn map_relationship_ids<F: FnMut(&str) -> Option<String>>(&mut self, mut f: F) {
+        match self {
+            RunContent::Drawing(drawing) => {
+                if let Some(id) = drawing.image_id_mut() {
+                    if let Some(new_id) = f(id) {
+                        *id = new_id.into();
+                    }
+                }
+                if let Some(id) = drawing.chart_id_mut() {
+                    if let Some(new_id) = f(id) {
+                        *id = new_id.into();
+                    }
+                }
+            }
+            RunContent::Object(object) => object.map_relationship_ids(f),
+            RunContent::Pict(pict) => pict.map_relationship_ids(f),
+            RunContent::AlternateContent(ac) => ac.map_relationship_ids(f),
+            RunContent::Raw(raw) => raw.map_relationship_ids(f),
+            _ => {}
+        }
+    }
 }
 
 __define_struct! {
@@ -307,4 +465,20 @@ __xml_test_suites!(
     r#"<w:r><w:br/></w:r>"#,
     Run::default().push_text("text"),
     r#"<w:r><w:t>text</w:t></w:r>"#,
+    Run {
+        rsid_r_pr: Some("00A1".into()),
+        ..Default::default()
+    }
+    .push(FootnoteRef)
+    .push(EndnoteRef)
+    .push(CarriageReturn)
+    .push(LastRenderedPageBreak {}),
+    r#"<w:r w:rsidRPr="00A1"><w:footnoteRef/><w:endnoteRef/><w:cr/><w:lastRenderedPageBreak/></w:r>"#,
+    Run::default().push(
+        PTab::default()
+            .alignment(PTabAlignment::Right)
+            .relative_to(PTabRelativeTo::Margin)
+            .leader(PTabLeader::Dot)
+    ),
+    r#"<w:r><w:ptab w:alignment="right" w:relativeTo="margin" w:leader="dot"/></w:r>"#,
 );
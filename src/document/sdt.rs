@@ -3,9 +3,9 @@ use std::borrow::Cow;
 
 use hard_xml::{XmlRead, XmlWrite};
 
-use crate::{__setter, __xml_test_suites};
+use crate::{__setter, __xml_test_suites, formatting::CharacterProperty, formatting::Fonts};
 
-use super::BodyContent;
+use super::{BodyContent, ParagraphContent, Run, Text};
 
 /// SDT
 ///
@@ -45,8 +45,138 @@ impl<'a> SDT<'a> {
 pub struct SDTProperty<'a> {
     #[xml(child = "w:id")]
     pub id: Option<STDId>,
+    /// Binds the content of the control to a custom XML data part
+    #[xml(child = "w:dataBinding")]
+    pub data_binding: Option<DataBinding<'a>>,
     #[xml(child = "w:docPartObj")]
     pub doc_part_obj: Option<DocPartObj<'a>>,
+    /// Makes the content control a check box
+    #[xml(child = "w14:checkbox")]
+    pub checkbox: Option<CheckBox<'a>>,
+    /// Makes the content control a plain text one
+    #[xml(child = "w:text")]
+    pub text: Option<SDTText>,
+}
+
+/// Data Binding
+///
+/// Maps the content of a control to the node an XPath selects in the custom
+/// XML data part with the given id, see [`crate::custom_xml`].
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:dataBinding")]
+pub struct DataBinding<'a> {
+    /// Specifies the namespace prefixes used in the XPath, like
+    /// `xmlns:ns0='urn:invoice'`
+    #[xml(attr = "w:prefixMappings")]
+    pub prefix_mappings: Option<Cow<'a, str>>,
+    #[xml(attr = "w:xpath")]
+    pub xpath: Cow<'a, str>,
+    /// Specifies the item id of the custom XML data part
+    #[xml(attr = "w:storeItemID")]
+    pub store_item_id: Option<Cow<'a, str>>,
+}
+
+impl<'a> DataBinding<'a> {
+    __setter!(prefix_mappings: Option<Cow<'a, str>>);
+
+    pub fn new<X, S>(xpath: X, store_item_id: S) -> Self
+    where
+        X: Into<Cow<'a, str>>,
+        S: Into<Cow<'a, str>>,
+    {
+        DataBinding {
+            prefix_mappings: None,
+            xpath: xpath.into(),
+            store_item_id: Some(store_item_id.into()),
+        }
+    }
+}
+
+/// Plain Text Content Control
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:text")]
+pub struct SDTText {
+    /// Specifies whether the text may contain line breaks
+    #[xml(attr = "w:multiLine")]
+    pub multi_line: Option<bool>,
+}
+
+/// Check Box Content Control
+///
+/// The content of the control shows the glyph of the current state.
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w14:checkbox")]
+pub struct CheckBox<'a> {
+    #[xml(child = "w14:checked")]
+    pub checked: Option<CheckBoxChecked>,
+    #[xml(child = "w14:checkedState")]
+    pub checked_state: Option<CheckedState<'a>>,
+    #[xml(child = "w14:uncheckedState")]
+    pub unchecked_state: Option<UncheckedState<'a>>,
+}
+
+impl<'a> CheckBox<'a> {
+    /// Creates a check box toggling between ☒ and ☐, the glyphs Word uses.
+    pub fn new(checked: bool) -> Self {
+        CheckBox {
+            checked: Some(CheckBoxChecked {
+                value: Some(checked),
+            }),
+            checked_state: Some(CheckedState {
+                value: Some(CHECKED_GLYPH.into()),
+                font: Some(CHECKBOX_FONT.into()),
+            }),
+            unchecked_state: Some(UncheckedState {
+                value: Some(UNCHECKED_GLYPH.into()),
+                font: Some(CHECKBOX_FONT.into()),
+            }),
+        }
+    }
+
+    /// Returns whether the check box is checked, a `w14:checked` element
+    /// without a value being on.
+    pub fn is_checked(&self) -> bool {
+        self.checked
+            .as_ref()
+            .is_some_and(|checked| checked.value != Some(false))
+    }
+}
+
+const CHECKED_GLYPH: &str = "2612";
+const UNCHECKED_GLYPH: &str = "2610";
+const CHECKBOX_FONT: &str = "MS Gothic";
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w14:checked")]
+pub struct CheckBoxChecked {
+    #[xml(attr = "w14:val")]
+    pub value: Option<bool>,
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w14:checkedState")]
+pub struct CheckedState<'a> {
+    /// Specifies the hexadecimal code point of the glyph
+    #[xml(attr = "w14:val")]
+    pub value: Option<Cow<'a, str>>,
+    #[xml(attr = "w14:font")]
+    pub font: Option<Cow<'a, str>>,
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w14:uncheckedState")]
+pub struct UncheckedState<'a> {
+    /// Specifies the hexadecimal code point of the glyph
+    #[xml(attr = "w14:val")]
+    pub value: Option<Cow<'a, str>>,
+    #[xml(attr = "w14:font")]
+    pub font: Option<Cow<'a, str>>,
 }
 
 #[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
@@ -99,4 +229,122 @@ pub struct SDTContent<'a> {
     pub content: Vec<BodyContent<'a>>,
 }
 
-__xml_test_suites!(SDT, SDT::default(), "<w:sdt/>",);
+/// Run-level structured document tag, i.e. an inline content control
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:sdt")]
+pub struct InlineSDT<'a> {
+    #[xml(child = "w:sdtPr")]
+    pub property: Option<SDTProperty<'a>>,
+    #[xml(child = "w:sdtEndPr")]
+    pub end_property: Option<SDTEndProperty>,
+    #[xml(child = "w:sdtContent")]
+    pub content: Option<InlineSDTContent<'a>>,
+}
+
+impl<'a> InlineSDT<'a> {
+    __setter!(property: Option<SDTProperty<'a>>);
+    __setter!(content: Option<InlineSDTContent<'a>>);
+
+    /// Creates a plain text content control bound to custom XML data,
+    /// showing `text` until Word reads the bound value.
+    pub fn bound_text<T: Into<Text<'a>>>(binding: DataBinding<'a>, text: T) -> Self {
+        InlineSDT::default()
+            .property(SDTProperty {
+                data_binding: Some(binding),
+                text: Some(SDTText::default()),
+                ..Default::default()
+            })
+            .content(InlineSDTContent {
+                content: vec![ParagraphContent::Run(Run::default().push_text(text))],
+            })
+    }
+
+    /// Creates a check box content control showing the glyph of its state.
+    pub fn checkbox(checked: bool) -> Self {
+        let glyph = if checked { "\u{2612}" } else { "\u{2610}" };
+        InlineSDT::default()
+            .property(SDTProperty {
+                checkbox: Some(CheckBox::new(checked)),
+                ..Default::default()
+            })
+            .content(InlineSDTContent {
+                content: vec![ParagraphContent::Run(
+                    Run::default()
+                        .property(
+                            CharacterProperty::default().fonts(
+                                Fonts::default()
+                                    .ascii(CHECKBOX_FONT)
+                                    .east_asia(CHECKBOX_FONT)
+                                    .h_ansi(CHECKBOX_FONT),
+                            ),
+                        )
+                        .push_text(glyph),
+                )],
+            })
+    }
+}
+
+/// The content of an inline content control, the same as a paragraph's
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:sdtContent")]
+pub struct InlineSDTContent<'a> {
+    #[xml(
+        child = "w:commentRangeStart",
+        child = "w:commentRangeEnd",
+        child = "w:r",
+        child = "w:hyperlink",
+        child = "w:bookmarkStart",
+        child = "w:bookmarkEnd",
+        child = "w:sdt",
+        child = "w:subDoc"
+    )]
+    pub content: Vec<ParagraphContent<'a>>,
+}
+
+__xml_test_suites!(
+    SDT,
+    SDT::default(),
+    "<w:sdt/>",
+    SDT::default().property(SDTProperty {
+        checkbox: Some(CheckBox::new(true)),
+        ..Default::default()
+    }),
+    r#"<w:sdt><w:sdtPr><w14:checkbox><w14:checked w14:val="true"/><w14:checkedState w14:val="2612" w14:font="MS Gothic"/><w14:uncheckedState w14:val="2610" w14:font="MS Gothic"/></w14:checkbox></w:sdtPr></w:sdt>"#,
+    SDT::default().property(SDTProperty {
+        data_binding: Some(DataBinding::new("/a[1]", "{1}").prefix_mappings("xmlns:ns0='urn:a'")),
+        text: Some(SDTText::default()),
+        ..Default::default()
+    }),
+    r#"<w:sdt><w:sdtPr><w:dataBinding w:prefixMappings="xmlns:ns0='urn:a'" w:xpath="/a[1]" w:storeItemID="{1}"/><w:text/></w:sdtPr></w:sdt>"#,
+);
+
+#[test]
+fn inline_content() {
+    use crate::document::{Hyperlink, Paragraph};
+    use crate::walk::{relationship_ids, runs};
+
+    let nested = InlineSDT::default().content(InlineSDTContent {
+        content: vec![ParagraphContent::Run(Run::default().push_text("nested"))],
+    });
+    let sdt = InlineSDT::default().content(InlineSDTContent {
+        content: vec![
+            ParagraphContent::Link(
+                Hyperlink::default()
+                    .id("rId4")
+                    .content(Run::default().push_text("link")),
+            ),
+            ParagraphContent::Sdt(nested),
+        ],
+    });
+    let para = Paragraph::default().push(sdt);
+
+    let text: Vec<_> = runs(&para).iter().flat_map(|run| run.iter_text()).collect();
+    assert_eq!(text, ["link", "nested"]);
+    assert_eq!(relationship_ids(&para), ["rId4"]);
+    assert!(InlineSDT::checkbox(false)
+        .property
+        .and_then(|property| property.checkbox)
+        .is_some_and(|checkbox| !checkbox.is_checked()));
+}
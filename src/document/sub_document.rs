@@ -0,0 +1,49 @@
+use hard_xml::{XmlRead, XmlWrite};
+use std::borrow::Cow;
+
+use crate::{__setter, __xml_test_suites, rels::Relationships};
+
+/// Anchor for Subdocument Location
+///
+/// Refers to another document through an external relationship, which a
+/// master document shows in place of this paragraph content.
+///
+/// ```rust
+/// use docx_rust::document::Paragraph;
+/// use docx_rust::Docx;
+///
+/// let mut docx = Docx::default();
+/// docx.add_heading(1, "Manual");
+/// for chapter in ["install.docx", "usage.docx"] {
+///     let sub_doc = docx.subdocument(chapter);
+///     docx.document.push(Paragraph::default().push(sub_doc));
+/// }
+///
+/// assert_eq!(docx.subdocuments(), ["install.docx", "usage.docx"]);
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:subDoc")]
+pub struct SubDocument<'a> {
+    /// Specifies the id of the relationship to the subdocument
+    #[xml(attr = "r:id")]
+    pub id: Option<Cow<'a, str>>,
+}
+
+impl<'a> SubDocument<'a> {
+    __setter!(id: Option<Cow<'a, str>>);
+
+    /// Returns the location of the subdocument, resolving its relationship id
+    /// against the relationships of the part it was read from.
+    pub fn target<'r>(&self, rels: &'r Relationships) -> Option<&'r str> {
+        rels.get_target(self.id.as_deref()?)
+    }
+}
+
+__xml_test_suites!(
+    SubDocument,
+    SubDocument::default(),
+    r#"<w:subDoc/>"#,
+    SubDocument::default().id("rId5"),
+    r#"<w:subDoc r:id="rId5"/>"#,
+);
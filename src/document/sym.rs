@@ -2,13 +2,110 @@ use std::borrow::Cow;
 
 use hard_xml::{XmlRead, XmlWrite};
 
+use crate::__xml_test_suites;
+
 /// Symbol Character
+///
+/// A character of a symbol font, like Wingdings or Symbol, which is inserted
+/// without being converted to a Unicode code point.
+///
+/// ```rust
+/// use docx_rust::document::*;
+///
+/// // a right arrow
+/// let run = Run::default().push(Sym::new("Wingdings", 0xF0E0));
+/// ```
 #[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
 #[cfg_attr(test, derive(PartialEq))]
 #[xml(tag = "w:sym")]
 pub struct Sym<'a> {
+    /// Specifies the name of the symbol font
     #[xml(attr = "w:font")]
     pub font: Option<Cow<'a, str>>,
+    /// Specifies the character code as four hexadecimal digits
     #[xml(attr = "w:char")]
     pub char: Option<Cow<'a, str>>,
 }
+
+impl<'a> Sym<'a> {
+    /// Creates a symbol from a font name and a character code.
+    ///
+    /// Codes below `0x100` are moved to the `F0xx` range Word uses for
+    /// symbol fonts, so `0xFE` and `0xF0FE` are the same character.
+    pub fn new<T: Into<Cow<'a, str>>>(font: T, code: u16) -> Self {
+        let code = if code < 0x100 { code + 0xF000 } else { code };
+        Sym {
+            font: Some(font.into()),
+            char: Some(format!("{:04X}", code).into()),
+        }
+    }
+
+    /// Returns the character code, if it is valid hexadecimal.
+    pub fn code(&self) -> Option<u16> {
+        self.char
+            .as_deref()
+            .and_then(|char| u16::from_str_radix(char, 16).ok())
+    }
+
+    /// Returns the Unicode character looking like the symbol, for the common
+    /// bullets and check marks of the Symbol and Wingdings fonts.
+    ///
+    /// ```rust
+    /// use docx_rust::document::Sym;
+    ///
+    /// assert_eq!(Sym::new("Symbol", 0xB7).unicode(), Some('\u{2022}'));
+    /// assert_eq!(Sym::new("Wingdings", 0xFC).unicode(), Some('\u{2713}'));
+    /// ```
+    pub fn unicode(&self) -> Option<char> {
+        symbol_to_unicode(self.font.as_deref()?, char::from_u32(self.code()?.into())?)
+    }
+}
+
+/// Returns the Unicode character looking like a character of a symbol font,
+/// given in the `F0xx` range or below `0x100`.
+pub(crate) fn symbol_to_unicode(font: &str, c: char) -> Option<char> {
+    let code = match c as u32 {
+        code @ 0xF000..=0xF0FF => code - 0xF000,
+        code @ 0..=0xFF => code,
+        _ => return None,
+    };
+    let table: &[(u32, char)] = if font.eq_ignore_ascii_case("Symbol") {
+        &[
+            (0xA7, '\u{2663}'),
+            (0xA8, '\u{2666}'),
+            (0xA9, '\u{2665}'),
+            (0xAA, '\u{2660}'),
+            (0xB7, '\u{2022}'),
+        ]
+    } else if font.eq_ignore_ascii_case("Wingdings") {
+        &[
+            (0x6E, '\u{25A0}'),
+            (0x71, '\u{2751}'),
+            (0x76, '\u{2756}'),
+            (0x9F, '\u{2022}'),
+            (0xA7, '\u{25AA}'),
+            (0xA8, '\u{25FB}'),
+            (0xD8, '\u{27A2}'),
+            (0xE0, '\u{2192}'),
+            (0xFB, '\u{2717}'),
+            (0xFC, '\u{2713}'),
+            (0xFE, '\u{2611}'),
+        ]
+    } else {
+        return None;
+    };
+    table
+        .iter()
+        .find(|(symbol, _)| *symbol == code)
+        .map(|(_, c)| *c)
+}
+
+__xml_test_suites!(
+    Sym,
+    Sym::default(),
+    "<w:sym/>",
+    Sym::new("Wingdings", 0xFE),
+    r#"<w:sym w:font="Wingdings" w:char="F0FE"/>"#,
+    Sym::new("Symbol", 0xF0B7),
+    r#"<w:sym w:font="Symbol" w:char="F0B7"/>"#,
+);
@@ -4,10 +4,19 @@ use std::borrow::Cow;
 
 use crate::{
     __setter, __xml_test_suites,
-    document::{TableGrid, TableRow},
-    formatting::TableProperty,
+    document::{
+        Paragraph, TableCell, TableCellContent, TableGrid, TableRow, TableRowContent, Text,
+    },
+    formatting::{
+        BorderStyle, OnOffOnlyType, TableBorders, TableCellProperty, TableCellWidth, TableProperty,
+        TableWidthUnit,
+    },
 };
 
+/// The width available for text on a Letter page with one inch margins, in
+/// twentieths of a point.
+const TEXT_WIDTH: isize = 9360;
+
 /// Table
 ///
 /// ```rust
@@ -33,6 +42,85 @@ pub struct Table<'a> {
 impl<'a> Table<'a> {
     __setter!(property: TableProperty<'a>);
 
+    /// Builds a table with one cell per item, splitting the page width evenly
+    /// between the columns and drawing single borders.
+    ///
+    /// Rows shorter than the longest one are padded with empty cells.
+    ///
+    /// ```rust
+    /// use docx_rust::document::*;
+    ///
+    /// let tbl = Table::from_rows(vec![vec!["a", "b"], vec!["c"]]);
+    ///
+    /// assert_eq!(tbl.grids.columns.len(), 2);
+    /// assert_eq!(tbl.rows[1].cells.len(), 2);
+    /// ```
+    pub fn from_rows<R, C, T>(rows: R) -> Self
+    where
+        R: IntoIterator<Item = C>,
+        C: IntoIterator<Item = T>,
+        T: Into<Text<'a>>,
+    {
+        let rows: Vec<Vec<Text<'a>>> = rows
+            .into_iter()
+            .map(|row| row.into_iter().map(Into::into).collect())
+            .collect();
+        let columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let width = TEXT_WIDTH / columns.max(1) as isize;
+
+        let cell = |text: Option<Text<'a>>| {
+            let para = match text {
+                Some(text) => Paragraph::default().push_text(text),
+                None => Paragraph::default(),
+            };
+            TableCell::paragraph(para).property(TableCellProperty::default().wide(TableCellWidth {
+                value: Some(width),
+                unit: Some(TableWidthUnit::Dxa),
+            }))
+        };
+
+        Table {
+            property: TableProperty::default()
+                .width((5000, TableWidthUnit::Pct))
+                .borders(TableBorders::all(BorderStyle::Single, 4)),
+            grids: TableGrid::from(vec![width; columns]),
+            rows: rows
+                .into_iter()
+                .map(|row| {
+                    let padding = columns - row.len();
+                    TableRow {
+                        cells: row
+                            .into_iter()
+                            .map(Some)
+                            .chain(std::iter::repeat_with(|| None).take(padding))
+                            .map(|text| TableRowContent::TableCell(cell(text)))
+                            .collect(),
+                        ..Default::default()
+                    }
+                })
+                .collect(),
+        }
+    }
+
+    /// Marks the first row as a header row, repeated on every page and bold.
+    pub fn header_row(mut self) -> Self {
+        if let Some(row) = self.rows.first_mut() {
+            row.property.table_header = Some(OnOffOnlyType::On.into());
+            for cell in row.cells.iter_mut() {
+                if let TableRowContent::TableCell(cell) = cell {
+                    for content in cell.content.iter_mut() {
+                        match content {
+                            TableCellContent::Paragraph(para) => {
+                                para.set_bold();
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        self
+    }
+
     pub fn push_row<T: Into<TableRow<'a>>>(mut self, row: T) -> Self {
         self.rows.push(row.into());
         self
@@ -4,7 +4,11 @@ use std::borrow::Cow;
 use derive_more::From;
 use hard_xml::{XmlRead, XmlWrite};
 
-use crate::{__setter, __xml_test_suites, document::Paragraph, formatting::TableCellProperty};
+use crate::{
+    __setter, __xml_test_suites,
+    document::{Paragraph, ParagraphContent, Run, Text},
+    formatting::TableCellProperty,
+};
 
 /// Table Cell
 ///
@@ -16,6 +20,13 @@ use crate::{__setter, __xml_test_suites, document::Paragraph, formatting::TableC
 ///
 /// let cell = TableCell::paragraph(Paragraph::default())
 ///     .property(TableCellProperty::default());
+///
+/// let mut cell = TableCell::from("Total");
+/// cell.set_text("Sum");
+/// assert_eq!(cell.text(), "Sum");
+///
+/// let cell: TableCell = ["First line", "Second line"].into_iter().collect();
+/// assert_eq!(cell.text(), "First line\r\nSecond line");
 /// ```
 #[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
 #[cfg_attr(test, derive(PartialEq))]
@@ -55,6 +66,49 @@ impl<'a> TableCell<'a> {
             .flatten()
     }
 
+    /// Returns the text of the cell, with a line break between paragraphs, like
+    /// [`Body::text`](crate::document::Body::text).
+    pub fn text(&self) -> String {
+        self.content
+            .iter()
+            .map(|content| match content {
+                TableCellContent::Paragraph(p) => p.text(),
+            })
+            .collect::<Vec<_>>()
+            .join("\r\n")
+    }
+
+    /// Replaces the content of the cell with a single paragraph of text.
+    ///
+    /// The properties of the first paragraph and of its first run are kept,
+    /// so the text keeps its formatting.
+    pub fn set_text<T: Into<Text<'a>>>(&mut self, text: T) -> &mut Self {
+        let para = self.content.first().map(|content| match content {
+            TableCellContent::Paragraph(p) => p,
+        });
+        let para_property = para.and_then(|p| p.property.clone());
+        let run_property = para
+            .and_then(|p| {
+                p.content.iter().find_map(|content| match content {
+                    ParagraphContent::Run(run) => Some(run),
+                    _ => None,
+                })
+            })
+            .and_then(|run| run.property.clone());
+
+        let run = Run {
+            property: run_property,
+            ..Default::default()
+        }
+        .push_text(text);
+        self.content = vec![TableCellContent::Paragraph(Paragraph {
+            property: para_property,
+            content: vec![ParagraphContent::Run(run)],
+            ..Default::default()
+        })];
+        self
+    }
+
     pub fn replace_text<'b, T, S>(&mut self, dic: T) -> crate::DocxResult<()>
     where
         S: AsRef<str> + 'b,
@@ -78,6 +132,15 @@ impl<'a, T: Into<TableCellContent<'a>>> From<T> for TableCell<'a> {
     }
 }
 
+impl<'a, T: Into<TableCellContent<'a>>> FromIterator<T> for TableCell<'a> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        TableCell {
+            property: TableCellProperty::default(),
+            content: iter.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
 #[derive(Debug, From, XmlRead, XmlWrite, Clone)]
 #[cfg_attr(test, derive(PartialEq))]
 pub enum TableCellContent<'a> {
@@ -87,8 +150,45 @@ pub enum TableCellContent<'a> {
     // Table(Table<'a>),
 }
 
+impl<'a> From<&'a str> for TableCellContent<'a> {
+    fn from(text: &'a str) -> Self {
+        TableCellContent::Paragraph(Paragraph::default().push_text(text))
+    }
+}
+
+impl From<String> for TableCellContent<'_> {
+    fn from(text: String) -> Self {
+        TableCellContent::Paragraph(Paragraph::default().push_text(text))
+    }
+}
+
 __xml_test_suites!(
     TableCell,
     TableCell::paragraph(Paragraph::default()),
     r#"<w:tc><w:tcPr><w:vAlign w:val="top"/></w:tcPr><w:p/></w:tc>"#,
 );
+
+#[test]
+fn set_text_keeps_formatting() {
+    use crate::formatting::{CharacterProperty, JustificationVal, ParagraphProperty};
+
+    let mut cell = TableCell::paragraph(
+        Paragraph::default()
+            .property(ParagraphProperty::default().justification(JustificationVal::Right))
+            .push(
+                Run::default()
+                    .property(CharacterProperty::default().bold(true))
+                    .push_text("1"),
+            )
+            .push_text("0"),
+    );
+    cell.set_text("42");
+
+    assert_eq!(cell.text(), "42");
+    let TableCellContent::Paragraph(para) = &cell.content[0];
+    assert!(para.property.is_some());
+    match &para.content[..] {
+        [ParagraphContent::Run(run)] => assert!(run.property.is_some()),
+        _ => panic!("expected a single run"),
+    }
+}
@@ -0,0 +1,80 @@
+use std::io::{Error as IOError, Read};
+
+use crate::{
+    document::{Table, TableCellContent, TableRowContent},
+    formatting::{JustificationVal, ParagraphProperty},
+    DocxResult,
+};
+
+impl Table<'static> {
+    /// Builds a table from CSV data, see [`Table::from_rows`].
+    ///
+    /// If the reader has headers, they become a bold header row repeated on
+    /// every page. Columns whose values all parse as numbers are right-aligned.
+    ///
+    /// ```rust
+    /// use docx_rust::document::Table;
+    ///
+    /// let data = "item,price\napple,1.25\npear,0.8\n";
+    /// let mut reader = csv::Reader::from_reader(data.as_bytes());
+    /// let tbl = Table::from_csv(&mut reader).unwrap();
+    ///
+    /// assert_eq!(tbl.rows.len(), 3);
+    /// ```
+    pub fn from_csv<R: Read>(reader: &mut csv::Reader<R>) -> DocxResult<Self> {
+        let headers: Option<Vec<String>> = if reader.has_headers() {
+            let headers = reader.headers().map_err(IOError::from)?;
+            Some(headers.iter().map(String::from).collect())
+        } else {
+            None
+        };
+        let records = reader
+            .records()
+            .map(|record| Ok(record?.iter().map(String::from).collect()))
+            .collect::<Result<Vec<Vec<String>>, csv::Error>>()
+            .map_err(IOError::from)?;
+
+        let columns = headers
+            .iter()
+            .chain(records.iter())
+            .map(Vec::len)
+            .max()
+            .unwrap_or(0);
+        let numeric: Vec<bool> = (0..columns)
+            .map(|i| {
+                let mut values = records
+                    .iter()
+                    .filter_map(|record| record.get(i))
+                    .map(|value| value.trim())
+                    .filter(|value| !value.is_empty())
+                    .peekable();
+                values.peek().is_some() && values.all(|value| value.parse::<f64>().is_ok())
+            })
+            .collect();
+
+        let has_headers = headers.is_some();
+        let mut table = Table::from_rows(headers.into_iter().chain(records));
+        if has_headers {
+            table = table.header_row();
+        }
+
+        for row in table.rows.iter_mut() {
+            for (cell, _) in row.cells.iter_mut().zip(&numeric).filter(|(_, n)| **n) {
+                if let TableRowContent::TableCell(cell) = cell {
+                    for content in cell.content.iter_mut() {
+                        match content {
+                            TableCellContent::Paragraph(para) => {
+                                para.property = Some(
+                                    ParagraphProperty::default()
+                                        .justification(JustificationVal::End),
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(table)
+    }
+}
@@ -1,7 +1,7 @@
 use hard_xml::{XmlRead, XmlWrite};
 use std::borrow::Cow;
 
-use crate::{__string_enum, __xml_test_suites};
+use crate::{__string_enum, __text_element, __xml_test_suites};
 
 /// Literal Text
 ///
@@ -14,15 +14,16 @@ use crate::{__string_enum, __xml_test_suites};
 /// let text = Text::from(String::from("text"));
 /// let text = Text::from(("text", TextSpace::Preserve));
 /// ```
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[derive(Debug, Default, Clone)]
 #[cfg_attr(test, derive(PartialEq))]
-#[xml(tag = "w:t")]
 pub struct Text<'a> {
     /// Specifies how to handle whitespace
-    #[xml(attr = "xml:space")]
+    ///
+    /// If not set, `xml:space="preserve"` is written when needed.
     pub space: Option<TextSpace>,
     /// Specifies a literal text
-    #[xml(text)]
+    ///
+    /// Borrowed from the part it was parsed from, unless it had to be unescaped.
     pub text: Cow<'a, str>,
 }
 
@@ -62,15 +63,14 @@ impl<'a> From<(&'a str, TextSpace)> for Text<'a> {
     }
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[derive(Debug, Default, Clone)]
 #[cfg_attr(test, derive(PartialEq))]
-#[xml(tag = "w:delText")]
 pub struct DelText<'a> {
     /// Specifies how to handle whitespace
-    #[xml(attr = "xml:space")]
+    ///
+    /// If not set, `xml:space="preserve"` is written when needed.
     pub space: Option<TextSpace>,
     /// Specifies a literal text
-    #[xml(text)]
     pub text: Cow<'a, str>,
 }
 
@@ -110,6 +110,19 @@ impl<'a> From<(&'a str, TextSpace)> for DelText<'a> {
     }
 }
 
+__text_element!(Text, "w:t");
+__text_element!(DelText, "w:delText");
+
+/// Returns whether whitespace of a text would be lost without
+/// `xml:space="preserve"`: leading or trailing whitespace, runs of spaces, tabs
+/// or line breaks.
+pub(crate) fn needs_preserve(text: &str) -> bool {
+    text.starts_with(char::is_whitespace)
+        || text.ends_with(char::is_whitespace)
+        || text.contains("  ")
+        || text.contains(['\t', '\n', '\r'])
+}
+
 /// Text Space Rules
 ///
 /// Specifies how whitespace should be handled in a literal text.
@@ -139,4 +152,24 @@ __xml_test_suites!(
     r#"<w:t xml:space="preserve">text</w:t>"#,
     Text::from((String::from("text"), TextSpace::Default)),
     r#"<w:t xml:space="default">text</w:t>"#,
+    Text::from(("  ", TextSpace::Preserve)),
+    r#"<w:t xml:space="preserve">  </w:t>"#,
+    Text::from(("\t\u{a0}\n", TextSpace::Preserve)),
+    "<w:t xml:space=\"preserve\">\t\u{a0}\n</w:t>",
 );
+
+#[test]
+fn preserve_space() -> hard_xml::XmlResult<()> {
+    assert_eq!(Text::from("a b").to_string()?, "<w:t>a b</w:t>");
+    for text in [" a", "a ", "a  b", "a\tb"] {
+        assert_eq!(
+            Text::from(text).to_string()?,
+            format!(r#"<w:t xml:space="preserve">{}</w:t>"#, text)
+        );
+    }
+    assert_eq!(
+        Text::from((" a", TextSpace::Default)).to_string()?,
+        r#"<w:t xml:space="default"> a</w:t>"#
+    );
+    Ok(())
+}
@@ -295,8 +295,7 @@ pub enum CustClrChoice {
     PrstClr(PrstClr),
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "a:scrgbClr")]
 pub struct ScrgbClr {
     #[xml(attr = "r")]
@@ -307,16 +306,14 @@ pub struct ScrgbClr {
     pub b: u8,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "a:srgbClr")]
 pub struct SrgbClr {
     #[xml(attr = "val")]
     pub value: Option<String>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "a:hslClr")]
 pub struct HslClr {
     #[xml(attr = "hue")]
@@ -327,8 +324,7 @@ pub struct HslClr {
     pub lum: Option<isize>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "a:sysClr")]
 pub struct SysClr {
     #[xml(attr = "val")]
@@ -337,8 +333,7 @@ pub struct SysClr {
     pub last_color: Option<String>,
 }
 
-#[derive(Debug, Default, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub enum SysClrType {
     #[default]
     ScrollBar, //Scroll Bar System Color.
@@ -408,16 +403,14 @@ __string_enum! {
     }
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "a:schemeClr")]
 pub struct SchemeClr {
     #[xml(attr = "val")]
     pub val: SchemeClrType,
 }
 
-#[derive(Debug, Default, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub enum SchemeClrType {
     #[default]
     Bg1, //Background Color 1.
@@ -461,16 +454,14 @@ __string_enum! {
     }
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "a:prstClr")]
 pub struct PrstClr {
     #[xml(attr = "val")]
     pub val: Option<PrstClrType>,
 }
 
-#[derive(Debug, Default, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub enum PrstClrType {
     #[default]
     AliceBlue, //Alice Blue Preset Color.
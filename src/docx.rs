@@ -1,33 +1,70 @@
-use hard_xml::{XmlRead, XmlWrite, XmlWriter};
+use hard_xml::{XmlError, XmlRead, XmlResult, XmlWrite, XmlWriter};
+use std::borrow::Cow;
+use std::cell::OnceCell;
 use std::collections::HashMap;
+#[cfg(feature = "fs")]
 use std::fs::File;
-use std::io::{Read, Seek, Write};
+use std::io::{Cursor, Read, Seek, Write};
+use std::ops::{Deref, Range};
+#[cfg(feature = "fs")]
 use std::path::Path;
+use std::sync::Arc;
+use xmlparser::{ElementEnd, Token, Tokenizer};
 use zip::write::SimpleFileOptions;
-use zip::{result::ZipError, CompressionMethod, ZipArchive, ZipWriter};
+use zip::{read::ZipFile, result::ZipError, CompressionMethod, DateTime, ZipArchive, ZipWriter};
 
-use crate::document::{Comments, EndNotes, FootNotes, Footer, Header, Numbering, Theme};
-use crate::media::MediaType;
+use crate::bibliography::{is_bibliography, Source, Sources};
+use crate::chart::ChartPart;
+use crate::conformance::{to_strict, to_transitional, Conformance, CONFORMANCE};
+use crate::content_type::{
+    CONTENT_TYPE_COMMENTS_EXTENDED, CONTENT_TYPE_CUSTOM_XML_PROPS, CONTENT_TYPE_FONT_TABLE,
+    CONTENT_TYPE_FOOTER, CONTENT_TYPE_HEADER, CONTENT_TYPE_NUMBERING, CONTENT_TYPE_PEOPLE,
+    CONTENT_TYPE_THEME,
+};
+use crate::custom_xml::{properties_name, CustomXmlPart, DatastoreItem};
+use crate::document::{
+    AbstractNum, Background, BodyContent, BookmarkEnd, BookmarkStart, CheckboxStyle, Comments,
+    CommentsExtended, DocumentReader, Drawing, EndNotes, FootNotes, Footer, Header,
+    HeaderFooterReferenceType, Hyperlink, ListLabels, Numbering, Paragraph, ParagraphContent,
+    ParagraphMut, People, Run, SubDocument, Table, Text, TextSpace, Theme,
+};
+use crate::extra_attributes::{merge_root_attributes, root_attributes, ExtraAttributes};
+use crate::formatting::{
+    CharacterProperty, Indent, Lang, ParagraphProperty, SectionProperty, SectionType, SectionTypeP,
+};
+use crate::media::{image_extension, MediaType};
+use crate::prolog::{decode, skip_prolog, strip_prolog};
 use crate::schema::{
-    SCHEMA_COMMENTS, SCHEMA_ENDNOTES, SCHEMA_FOOTNOTES, SCHEMA_HEADER, SCHEMA_NUMBERING,
-    SCHEMA_SETTINGS, SCHEMA_THEME, SCHEMA_WEB_SETTINGS,
+    SCHEMA_BIBLIOGRAPHY, SCHEMA_COMMENTS, SCHEMA_COMMENTS_EXTENDED, SCHEMA_CUSTOM_XML,
+    SCHEMA_CUSTOM_XML_PROPS, SCHEMA_ENDNOTES, SCHEMA_FOOTER, SCHEMA_FOOTNOTES, SCHEMA_HEADER,
+    SCHEMA_HYPERLINK, SCHEMA_IMAGE, SCHEMA_MAIL_MERGE_SOURCE, SCHEMA_NUMBERING, SCHEMA_PEOPLE,
+    SCHEMA_SETTINGS, SCHEMA_SUBDOCUMENT, SCHEMA_THEME, SCHEMA_THUMBNAIL, SCHEMA_WEB_SETTINGS,
+};
+use crate::settings::{
+    DisplayBackgroundShape, EvenAndOddHeaders, MailMerge, Settings, ThemeFontLang,
 };
-use crate::settings::Settings;
+use crate::thumbnail::{Thumbnail, ThumbnailFormat};
 use crate::web_settings::WebSettings;
 use crate::{
+    __setter,
     app::App,
     content_type::ContentTypes,
     core::Core,
     document::Document,
     error::DocxResult,
     font_table::FontTable,
-    rels::Relationships,
+    rels::{Relationship, Relationships, TargetMode},
+    repair::resolve,
     schema::{
         SCHEMA_CORE, SCHEMA_FONT_TABLE, SCHEMA_OFFICE_DOCUMENT, SCHEMA_REL_EXTENDED, SCHEMA_STYLES,
     },
-    styles::Styles,
+    styles::{DefaultStyle, Style, Styles},
+    typography::Typography,
+    uri,
 };
 
+const BIBLIOGRAPHY_ITEM_ID: &str = "{6A3E2C4B-1F0D-4B8E-9C7A-5D2E8F1B3A60}";
+
 /// A WordprocessingML package
 #[derive(Debug, Default, Clone)]
 pub struct Docx<'a> {
@@ -35,6 +72,8 @@ pub struct Docx<'a> {
     pub app: Option<App<'a>>,
     /// Specifies core properties part
     pub core: Option<Core<'a>>,
+    /// Specifies the preview image shown by file explorers
+    pub thumbnail: Option<Thumbnail<'a>>,
     /// Specifies the content type of relationship parts and the main document part.
     pub content_types: ContentTypes<'a>,
     /// Specifies the main document part.
@@ -51,24 +90,286 @@ pub struct Docx<'a> {
     pub footers: HashMap<String, Footer<'a>>,
     pub themes: HashMap<String, Theme<'a>>,
     pub media: HashMap<String, (MediaType, &'a Vec<u8>)>,
+    /// Specifies the chart parts and their style and colors parts, keyed by
+    /// their name relative to `word/`, e.g. `charts/chart1.xml`, see
+    /// [`crate::chart`]
+    pub charts: HashMap<String, ChartPart<'a>>,
+    /// Specifies the embedded packages and objects, like the workbooks
+    /// holding the data of charts, keyed by their name relative to `word/`,
+    /// e.g. `embeddings/oleObject1.bin`, see [`Docx::embedding_data`]
+    ///
+    /// They are written as is.
+    pub embeddings: HashMap<String, Cow<'a, [u8]>>,
+    /// Specifies the parts that aren't modelled, like printer settings or
+    /// glossary documents, keyed by their name in the package, e.g.
+    /// `word/printerSettings/printerSettings1.bin`
+    ///
+    /// They are written as is, and so are the relationships to them.
+    pub other_parts: HashMap<String, Cow<'a, [u8]>>,
     pub footnotes: Option<FootNotes<'a>>,
     pub endnotes: Option<EndNotes<'a>>,
     pub settings: Option<Settings<'a>>,
+    /// Specifies the relationships of the settings part, e.g. mail merge data sources
+    pub settings_rels: Option<Relationships<'a>>,
     pub web_settings: Option<WebSettings>,
     pub comments: Option<Comments<'a>>,
+    /// Specifies the threading and resolved state of comments
+    pub comments_extended: Option<CommentsExtended<'a>>,
+    /// Specifies the identities of comment and revision authors
+    pub people: Option<People<'a>>,
     pub numbering: Option<Numbering<'a>>,
+    /// Specifies the bibliography sources part, see [`Docx::add_source`]
+    pub bibliography: Option<Sources<'a>>,
+    /// Specifies the name of the bibliography part relative to `customXml/`
+    /// and its properties, as read; new ones are given when missing
+    pub bibliography_item: Option<(String, Option<DatastoreItem<'a>>)>,
+    /// Specifies the custom XML data parts other than the bibliography,
+    /// keyed by their name relative to `customXml/`, see [`crate::custom_xml`]
+    pub custom_xml: HashMap<String, CustomXmlPart<'a>>,
+    /// Specifies the typographic replacements applied to the text added by
+    /// helpers like [`Docx::add_paragraph`], none by default
+    ///
+    /// Code blocks are left untouched.
+    pub typography: Option<Typography>,
+    /// Specifies that hyperlinks created by [`Docx::hyperlink`] aren't given
+    /// the `Hyperlink` character style
+    pub plain_hyperlinks: bool,
+    /// Specifies the indentation of the list levels created by helpers like
+    /// [`Docx::bulleted_list`], given the level counting from 0;
+    /// [`Indent::list`] by default
+    pub list_indent: Option<fn(usize) -> Indent>,
+    /// Specifies the attributes of the root element of parts that aren't
+    /// modelled, keyed by part name, e.g. `word/document.xml`
+    ///
+    /// Filled when parsing and added back when writing, unless the written
+    /// root already has them; the prefixes of `mc:Ignorable` are merged.
+    pub extra_attributes: HashMap<String, ExtraAttributes>,
+    /// Specifies whether the package is written with the strict or the
+    /// transitional namespaces, the conformance of the parsed document by
+    /// default
+    pub conformance: Conformance,
 }
 
 impl<'a> Docx<'a> {
-    pub fn write<W: Write + Seek>(&'a mut self, writer: W) -> DocxResult<W> {
-        let mut writer = XmlWriter::new(ZipWriter::new(writer));
+    pub fn write<W: Write + Seek>(&mut self, writer: W) -> DocxResult<W> {
+        self.write_with_options(writer, &WriteOptions::default())
+    }
+
+    /// Writes the package, formatting the XML of each part as requested.
+    ///
+    /// ```rust
+    /// use docx_rust::{Docx, WriteOptions};
+    ///
+    /// let options = WriteOptions::default().pretty(true).canonical(true);
+    /// ```
+    pub fn write_with_options<W: Write + Seek>(
+        &mut self,
+        writer: W,
+        options: &WriteOptions,
+    ) -> DocxResult<W> {
+        let mut zip = ZipWriter::new(writer);
+        self.prepare_write(options);
+
+        let mut write_entry = |name: &str, data: &[u8], is_media: bool| -> DocxResult<()> {
+            zip.start_file(name, options.file_options(name, is_media, data.len()))?;
+            zip.write_all(data)?;
+            Ok(())
+        };
+
+        if options.part_order.is_empty() {
+            self.write_parts(options, |name, xml| write_entry(name, xml, false))?;
+            for (name, data) in self.binary_parts() {
+                write_entry(&name, data, true)?;
+            }
+        } else {
+            let mut entries: Vec<(String, Cow<[u8]>, bool)> = Vec::new();
+            self.write_parts(options, |name, xml| {
+                entries.push((name.to_string(), Cow::Owned(xml.to_vec()), false));
+                Ok(())
+            })?;
+            for (name, data) in self.binary_parts() {
+                entries.push((name, Cow::Borrowed(data), true));
+            }
+            entries.sort_by_key(|(name, ..)| options.position(name));
+            for (name, data, is_media) in &entries {
+                write_entry(name, data, *is_media)?;
+            }
+        }
+
+        Ok(zip.finish()?)
+    }
 
-        let opt = SimpleFileOptions::default()
-            .compression_method(CompressionMethod::Deflated)
-            .unix_permissions(0o755);
+    /// Updates the parts derived from the others before writing, as
+    /// requested.
+    fn prepare_write(&mut self, options: &WriteOptions) {
+        if options.strip_rsids {
+            self.strip_rsids();
+        }
+        if options.compact_runs {
+            self.compact_runs();
+        }
+        if options.para_ids {
+            if options.deterministic {
+                let mut counter = 0;
+                self.assign_para_ids_with(|| {
+                    counter += 1;
+                    counter
+                });
+            } else {
+                self.assign_para_ids();
+            }
+        }
+        self.add_relationships();
+        if options.deterministic {
+            self.rels.sort_by_id();
+            for rels in [&mut self.document_rels, &mut self.settings_rels]
+                .into_iter()
+                .flatten()
+            {
+                rels.sort_by_id();
+            }
+        }
+    }
+
+    /// Writes the package like [`Docx::write`], copying the entries of
+    /// `source` that didn't change as they are, without recompressing them.
+    ///
+    /// `source` is the package the document was parsed from. An entry is
+    /// copied if the part or media file that would be written has the same
+    /// size and CRC-32 as the entry. These are read from the central
+    /// directory, so entries are never inflated, which makes saving small
+    /// edits to large documents with many pictures much faster. Parts
+    /// written by Word are rewritten the first time, as their XML is
+    /// formatted differently.
+    ///
+    /// ```no_run
+    /// use docx_rust::DocxFile;
+    /// use std::fs::{self, File};
+    ///
+    /// let file = DocxFile::from_file("manual.docx")?;
+    /// let mut docx = file.parse()?;
+    /// docx.add_paragraph("Reviewed");
+    ///
+    /// let source = File::open("manual.docx")?;
+    /// docx.save_into(source, File::create("manual.tmp.docx")?)?;
+    /// fs::rename("manual.tmp.docx", "manual.docx")?;
+    /// # Ok::<(), docx_rust::DocxError>(())
+    /// ```
+    pub fn save_into<R, W>(&mut self, source: R, writer: W) -> DocxResult<W>
+    where
+        R: Read + Seek,
+        W: Write + Seek,
+    {
+        self.save_into_with_options(source, writer, &WriteOptions::default())
+    }
 
-        // ==== Add Relationships ====
+    pub fn save_into_with_options<R, W>(
+        &mut self,
+        source: R,
+        writer: W,
+        options: &WriteOptions,
+    ) -> DocxResult<W>
+    where
+        R: Read + Seek,
+        W: Write + Seek,
+    {
+        let mut source = ZipArchive::new(source)?;
+        let mut zip = ZipWriter::new(writer);
+        self.prepare_write(options);
 
+        let mut entries: Vec<(String, Cow<[u8]>, bool)> = Vec::new();
+        self.write_parts(options, |name, xml| {
+            entries.push((name.to_string(), Cow::Owned(xml.to_vec()), false));
+            Ok(())
+        })?;
+        for (name, data) in self.binary_parts() {
+            entries.push((name, Cow::Borrowed(data), true));
+        }
+        entries.sort_by_key(|(name, ..)| options.position(name));
+
+        for (name, data, is_media) in &entries {
+            let unchanged = match source.index_for_name(name) {
+                Some(index) => {
+                    let file = source.by_index_raw(index)?;
+                    (file.size() == data.len() as u64 && file.crc32() == crc32fast::hash(data))
+                        .then_some(index)
+                }
+                None => None,
+            };
+            match unchanged {
+                Some(index) => zip.raw_copy_file(source.by_index_raw(index)?)?,
+                None => {
+                    zip.start_file(
+                        name.as_str(),
+                        options.file_options(name, *is_media, data.len()),
+                    )?;
+                    zip.write_all(data)?;
+                }
+            }
+        }
+
+        Ok(zip.finish()?)
+    }
+
+    /// Writes the package into a new buffer.
+    ///
+    /// ```rust
+    /// use docx_rust::{Docx, DocxFile};
+    ///
+    /// let mut docx = Docx::default();
+    /// let bytes = docx.write_to_vec()?;
+    ///
+    /// let file = DocxFile::from_bytes(&bytes)?;
+    /// let docx = file.parse()?;
+    /// # Ok::<(), docx_rust::DocxError>(())
+    /// ```
+    pub fn write_to_vec(&mut self) -> DocxResult<Vec<u8>> {
+        self.write_to_vec_with_options(&WriteOptions::default())
+    }
+
+    pub fn write_to_vec_with_options(&mut self, options: &WriteOptions) -> DocxResult<Vec<u8>> {
+        let cursor = self.write_with_options(Cursor::new(Vec::new()), options)?;
+        Ok(cursor.into_inner())
+    }
+
+    /// Returns the name and XML of every part as `write` would store them,
+    /// without producing a package. Media files are not included.
+    ///
+    /// ```rust
+    /// use docx_rust::Docx;
+    ///
+    /// let docx = Docx::default();
+    ///
+    /// for (name, xml) in docx.parts()? {
+    ///     println!("{}: {} bytes", name, xml.len());
+    /// }
+    /// assert!(docx.part_xml("word/document.xml")?.is_some());
+    /// # Ok::<(), docx_rust::DocxError>(())
+    /// ```
+    pub fn parts(&self) -> DocxResult<Vec<(String, String)>> {
+        let mut docx = self.clone();
+        docx.add_relationships();
+
+        let mut parts = Vec::new();
+        docx.write_parts(&WriteOptions::default(), |name, xml| {
+            parts.push((name.to_string(), String::from_utf8_lossy(xml).into_owned()));
+            Ok(())
+        })?;
+        Ok(parts)
+    }
+
+    /// Returns the XML of a single part, e.g. `word/document.xml`.
+    pub fn part_xml(&self, name: &str) -> DocxResult<Option<String>> {
+        let name = name.trim_start_matches('/');
+        Ok(self
+            .parts()?
+            .into_iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, xml)| xml))
+    }
+
+    /// Adds the relationships and content types of the parts in the package.
+    pub(crate) fn add_relationships(&mut self) {
         if self.app.is_some() {
             self.rels.add_rel(SCHEMA_REL_EXTENDED, "docProps/app.xml");
         }
@@ -77,6 +378,14 @@ impl<'a> Docx<'a> {
             self.rels.add_rel(SCHEMA_CORE, "docProps/core.xml");
         }
 
+        if let Some(thumbnail) = &self.thumbnail {
+            self.rels.add_rel(SCHEMA_THUMBNAIL, thumbnail.name());
+            self.content_types.add_default(
+                thumbnail.format.extension(),
+                thumbnail.format.content_type(),
+            );
+        }
+
         self.rels
             .add_rel(SCHEMA_OFFICE_DOCUMENT, "word/document.xml");
 
@@ -88,6 +397,8 @@ impl<'a> Docx<'a> {
             self.document_rels
                 .get_or_insert(Relationships::default())
                 .add_rel(SCHEMA_FONT_TABLE, "fontTable.xml");
+            self.content_types
+                .add_override("/word/fontTable.xml", CONTENT_TYPE_FONT_TABLE);
         }
 
         if self.footnotes.is_some() {
@@ -120,43 +431,104 @@ impl<'a> Docx<'a> {
                 .add_rel(SCHEMA_COMMENTS, "comments.xml");
         }
 
+        if self.comments_extended.is_some() {
+            self.document_rels
+                .get_or_insert(Relationships::default())
+                .add_rel(SCHEMA_COMMENTS_EXTENDED, "commentsExtended.xml");
+            self.content_types
+                .add_override("/word/commentsExtended.xml", CONTENT_TYPE_COMMENTS_EXTENDED);
+        }
+
+        if self.people.is_some() {
+            self.document_rels
+                .get_or_insert(Relationships::default())
+                .add_rel(SCHEMA_PEOPLE, "people.xml");
+            self.content_types
+                .add_override("/word/people.xml", CONTENT_TYPE_PEOPLE);
+        }
+
         if self.numbering.is_some() {
             self.document_rels
                 .get_or_insert(Relationships::default())
                 .add_rel(SCHEMA_NUMBERING, "numbering.xml");
+            self.content_types
+                .add_override("/word/numbering.xml", CONTENT_TYPE_NUMBERING);
         }
 
-        for hd in &self.headers {
+        for hd in sorted(&self.headers) {
             self.document_rels
                 .get_or_insert(Relationships::default())
-                .add_rel(SCHEMA_HEADER, hd.0);
+                .add_rel(SCHEMA_HEADER, hd.0.clone());
+            self.content_types
+                .add_override(format!("/word/{}", hd.0), CONTENT_TYPE_HEADER);
         }
 
-        for ft in &self.footers {
+        for ft in sorted(&self.footers) {
             self.document_rels
                 .get_or_insert(Relationships::default())
-                .add_rel(SCHEMA_HEADER, ft.0);
+                .add_rel(SCHEMA_FOOTER, ft.0.clone());
+            self.content_types
+                .add_override(format!("/word/{}", ft.0), CONTENT_TYPE_FOOTER);
         }
 
-        for theme in &self.themes {
+        for theme in sorted(&self.themes) {
             self.document_rels
                 .get_or_insert(Relationships::default())
-                .add_rel(SCHEMA_THEME, theme.0);
+                .add_rel(SCHEMA_THEME, theme.0.clone());
+            self.content_types
+                .add_override(format!("/word/{}", theme.0), CONTENT_TYPE_THEME);
         }
 
-        for media in &self.media {
+        let mut custom_xml: Vec<_> = sorted(&self.custom_xml)
+            .into_iter()
+            .map(|(name, part)| (name.clone(), part.properties.is_some()))
+            .collect();
+        if self.bibliography.is_some() {
+            custom_xml.push((self.bibliography_part().0, true));
+        }
+        for (name, has_properties) in custom_xml {
+            self.document_rels
+                .get_or_insert(Relationships::default())
+                .add_rel(SCHEMA_CUSTOM_XML, format!("../customXml/{}", name));
+            if has_properties {
+                self.content_types.add_override(
+                    format!("/customXml/{}", properties_name(&name)),
+                    CONTENT_TYPE_CUSTOM_XML_PROPS,
+                );
+            }
+        }
+
+        for media in sorted(&self.media) {
             let rel = crate::media::get_media_type_relation_type(&media.1 .0);
             self.document_rels
                 .get_or_insert(Relationships::default())
-                .add_rel(rel, media.0);
+                .add_rel(rel, media.0.clone());
+            if let Some((_, ext)) = media.0.rsplit_once('.') {
+                self.content_types.add_image_default(ext);
+            }
         }
+    }
 
-        // ==== Write Zip Item ====
+    /// Serializes the XML parts in package order, handing each to `f`.
+    pub(crate) fn write_parts<F>(&self, options: &WriteOptions, mut f: F) -> DocxResult<()>
+    where
+        F: FnMut(&str, &[u8]) -> DocxResult<()>,
+    {
+        let mut f = |name: &str, xml: &[u8]| {
+            let mut xml = Cow::Borrowed(xml);
+            if let Some(extras) = self.extra_attributes.get(name) {
+                xml = Cow::Owned(merge_root_attributes(&xml, extras)?);
+            }
+            if self.conformance == Conformance::Strict {
+                xml = Cow::Owned(to_strict(name, &xml)?);
+            }
+            f(name, &xml)
+        };
 
         macro_rules! write_xml {
             (Some($xml:expr) => $name:tt) => {
                 if let Some(ref xml) = $xml {
-                    write_xml!(xml => $name);
+                    f(&$name, &options.serialize(xml)?)?;
                 }
             };
             (Some($xml:expr) => $name:tt $($rest:tt)*) => {
@@ -164,8 +536,7 @@ impl<'a> Docx<'a> {
                 write_xml!($($rest)*);
             };
             ($xml:expr => $name:tt) => {
-                writer.inner.start_file($name, opt)?;
-                $xml.to_writer(&mut writer)?;
+                f(&$name, &options.serialize(&$xml)?)?;
             };
             ($xml:expr => $name:tt $($rest:tt)*) => {
                 write_xml!($xml => $name);
@@ -186,85 +557,1579 @@ impl<'a> Docx<'a> {
             Some(self.settings)       => "word/settings.xml"
             Some(self.web_settings)   => "word/webSettings.xml"
             Some(self.comments)       => "word/comments.xml"
+            Some(self.comments_extended) => "word/commentsExtended.xml"
+            Some(self.people)         => "word/people.xml"
             Some(self.numbering)      => "word/numbering.xml"
             Some(self.document_rels)  => "word/_rels/document.xml.rels"
+            Some(self.settings_rels)  => "word/_rels/settings.xml.rels"
         );
 
-        for hd in self.headers.iter() {
-            let file_path = format!("word/{}", hd.0);
-            let content = hd.1;
-            write_xml!(
-                content => file_path
-            );
+        write_part_map(&self.headers, options, &mut f)?;
+        write_part_map(&self.footers, options, &mut f)?;
+        write_part_map(&self.themes, options, &mut f)?;
+
+        for (name, part) in sorted(&self.charts) {
+            let name = format!("word/{}", name);
+            f(&name, part.xml.as_bytes())?;
+            if let Some(rels) = &part.rels {
+                f(&rels_name(&name), &options.serialize(rels)?)?;
+            }
         }
 
-        for hd in self.footers.iter() {
-            let file_path = format!("word/{}", hd.0);
-            let content = hd.1;
-            write_xml!(
-                content => file_path
-            );
+        for (name, part) in sorted(&self.custom_xml) {
+            f(&format!("customXml/{}", name), part.data.as_bytes())?;
+            if let Some(properties) = &part.properties {
+                write_custom_xml_properties(name, properties, options, &mut f)?;
+            }
+        }
+
+        if let Some(bibliography) = &self.bibliography {
+            let (name, properties) = self.bibliography_part();
+            f(
+                &format!("customXml/{}", name),
+                &options.serialize(bibliography)?,
+            )?;
+            write_custom_xml_properties(&name, &properties, options, &mut f)?;
         }
 
-        for theme in self.themes.iter() {
-            let file_path = format!("word/{}", theme.0);
-            let content = theme.1;
-            write_xml!(
-                content => file_path
+        Ok(())
+    }
+
+    /// Returns the name and data of the entries written as is, the
+    /// thumbnail, the media files, the embeddings and the parts that aren't
+    /// modelled.
+    pub(crate) fn binary_parts(&self) -> Vec<(String, &[u8])> {
+        let mut parts: Vec<_> = self
+            .thumbnail
+            .iter()
+            .map(|thumbnail| (thumbnail.name(), &thumbnail.data[..]))
+            .collect();
+        for (name, (_, data)) in sorted(&self.media) {
+            parts.push((format!("word/{}", name), &data[..]));
+        }
+        for (name, data) in sorted(&self.embeddings) {
+            parts.push((format!("word/{}", name), &data[..]));
+        }
+        for (name, data) in sorted(&self.other_parts) {
+            parts.push((name.clone(), &data[..]));
+        }
+        parts
+    }
+
+    /// Appends a heading paragraph, adding the `Heading{level}` style if the
+    /// document doesn't define it yet.
+    ///
+    /// `level` is clamped to `1..=9`.
+    ///
+    /// ```rust
+    /// use docx_rust::Docx;
+    ///
+    /// let mut docx = Docx::default();
+    /// docx.add_heading(1, "Title");
+    /// docx.add_paragraph("Lorem ipsum").bold().size_pt(12);
+    ///
+    /// assert!(docx.styles.get("Heading1").is_some());
+    /// ```
+    pub fn add_heading<T: Into<Text<'a>>>(&mut self, level: usize, text: T) -> &mut Paragraph<'a> {
+        let level = level.clamp(1, 9);
+        self.styles.ensure_heading(level);
+        self.push_paragraph(
+            Paragraph::default()
+                .property(ParagraphProperty::default().style_id(format!("Heading{}", level)))
+                .push_text(text),
+        )
+    }
+
+    /// Adds a custom paragraph style and its linked character style, see
+    /// [`Style::linked`], replacing the styles with the same ids. Returns the
+    /// paragraph style.
+    ///
+    /// ```rust
+    /// use docx_rust::formatting::CharacterProperty;
+    /// use docx_rust::Docx;
+    ///
+    /// let mut docx = Docx::default();
+    /// let style = docx.create_linked_style("Code Sample", CharacterProperty::default().bold(true));
+    /// assert_eq!(style.style_id, "CodeSample");
+    ///
+    /// let run_style = docx.styles.get("CodeSampleChar").unwrap();
+    /// assert_eq!(run_style.link.as_ref().unwrap().value, "CodeSample");
+    /// ```
+    pub fn create_linked_style(
+        &mut self,
+        name: &str,
+        character: CharacterProperty<'a>,
+    ) -> &mut Style<'a> {
+        let (paragraph, run) = Style::linked(name, character);
+        let styles = &mut self.styles.styles;
+        styles
+            .retain(|style| style.style_id != paragraph.style_id && style.style_id != run.style_id);
+        styles.push(paragraph);
+        styles.push(run);
+        let index = styles.len() - 2;
+        &mut styles[index]
+    }
+
+    /// Appends a caption numbered by a `SEQ` field, like "Figure 2: text",
+    /// adding the `Caption` style if the document doesn't define it yet.
+    ///
+    /// `label` names the sequence, e.g. `Figure` or `Table`, and can't
+    /// contain spaces. The number shown until the fields are updated counts
+    /// the captions of that label already in the body.
+    ///
+    /// ```rust
+    /// use docx_rust::Docx;
+    ///
+    /// let mut docx = Docx::default();
+    /// docx.add_caption("Figure", "Architecture");
+    /// docx.add_caption("Table", "Results");
+    /// let caption = docx.add_caption("Figure", "Deployment");
+    /// assert!(caption.text().ends_with("2: Deployment"));
+    ///
+    /// docx.add_table_of_figures("Figure");
+    /// assert!(docx.styles.get("Caption").is_some());
+    /// ```
+    pub fn add_caption<T: Into<Text<'a>>>(&mut self, label: &str, text: T) -> &mut Paragraph<'a> {
+        self.styles.ensure_caption();
+        let number = self
+            .document
+            .body
+            .field_instructions()
+            .iter()
+            .filter(|instruction| {
+                let mut words = instruction.split_whitespace();
+                matches!(words.next(), Some(word) if word.eq_ignore_ascii_case("SEQ"))
+                    && words.next() == Some(label)
+            })
+            .count()
+            + 1;
+
+        let mut text = text.into();
+        self.typeset(std::iter::once(&mut text.text));
+        let mut para = Paragraph::default()
+            .property(ParagraphProperty::default().style_id("Caption"))
+            .push_text((format!("{} ", label), TextSpace::Preserve))
+            .push_field(format!("SEQ {} \\* ARABIC", label), number.to_string());
+        if !text.text.is_empty() {
+            para = para.push_text((": ", TextSpace::Preserve)).push_text(text);
+        }
+        self.push_verbatim(para)
+    }
+
+    /// Appends a table of contents listing the paragraphs of outline levels
+    /// `1..=levels`, e.g. those added by [`Docx::add_heading`].
+    ///
+    /// Besides the heading styles, the field picks up any paragraph with an
+    /// outline level, see [`ParagraphProperty::outline_lvl`]. It is marked
+    /// dirty, so Word builds the table when the document is opened.
+    ///
+    /// ```rust
+    /// use docx_rust::formatting::ParagraphProperty;
+    /// use docx_rust::document::Paragraph;
+    /// use docx_rust::Docx;
+    ///
+    /// let mut docx = Docx::default();
+    /// docx.add_table_of_contents(3);
+    /// docx.add_heading(1, "Introduction");
+    /// docx.document.push(
+    ///     Paragraph::default()
+    ///         .property(ParagraphProperty::default().outline_lvl(1))
+    ///         .push_text("Background"),
+    /// );
+    /// ```
+    pub fn add_table_of_contents(&mut self, levels: usize) -> &mut Paragraph<'a> {
+        let para = Paragraph::default().push_dirty_field(
+            format!("TOC \\o \"1-{}\" \\h \\z \\u", levels.clamp(1, 9)),
+            "Update the field to build the table of contents.",
+        );
+        self.push_verbatim(para)
+    }
+
+    /// Appends a table of figures listing the captions of the given label,
+    /// see [`Docx::add_caption`].
+    ///
+    /// The field is marked dirty, so Word builds the table when the
+    /// document is opened.
+    pub fn add_table_of_figures(&mut self, label: &str) -> &mut Paragraph<'a> {
+        let para = Paragraph::default().push_dirty_field(
+            format!("TOC \\h \\z \\c \"{}\"", label),
+            "Update the field to build the table of figures.",
+        );
+        self.push_verbatim(para)
+    }
+
+    /// Adds a custom XML data part, returning its name relative to
+    /// `customXml/`, see [`crate::custom_xml`].
+    pub fn add_custom_xml<D: Into<Cow<'a, str>>>(
+        &mut self,
+        data: D,
+        properties: DatastoreItem<'a>,
+    ) -> String {
+        let name = next_part_name(&self.custom_xml, "item");
+        self.custom_xml.insert(
+            name.clone(),
+            CustomXmlPart {
+                data: data.into(),
+                properties: Some(properties),
+            },
+        );
+        name
+    }
+
+    /// Returns the custom XML data part with the given item id, the store
+    /// item id of the data bindings.
+    pub fn custom_xml_by_id(&self, item_id: &str) -> Option<&CustomXmlPart<'a>> {
+        self.custom_xml.values().find(|part| {
+            part.properties
+                .as_ref()
+                .is_some_and(|properties| properties.item_id.eq_ignore_ascii_case(item_id))
+        })
+    }
+
+    /// Returns the name of the bibliography part relative to `customXml/`
+    /// and its properties, those read unless the name was taken by another
+    /// custom XML data part since.
+    pub(crate) fn bibliography_part(&self) -> (String, DatastoreItem<'a>) {
+        let (name, properties) = match &self.bibliography_item {
+            Some((name, properties)) if !self.custom_xml.contains_key(name) => {
+                (name.clone(), properties.clone())
+            }
+            _ => (next_part_name(&self.custom_xml, "item"), None),
+        };
+        let properties = properties
+            .unwrap_or_else(|| DatastoreItem::new(BIBLIOGRAPHY_ITEM_ID, SCHEMA_BIBLIOGRAPHY));
+        (name, properties)
+    }
+
+    /// Adds a source to the bibliography, replacing the one with the same
+    /// tag, see [`crate::bibliography`].
+    pub fn add_source(&mut self, source: Source<'a>) -> &mut Self {
+        self.bibliography
+            .get_or_insert_with(Default::default)
+            .push(source);
+        self
+    }
+
+    /// Appends a bibliography listing the sources, see [`crate::bibliography`].
+    ///
+    /// The field is marked dirty, so Word builds the list when the document
+    /// is opened.
+    pub fn add_bibliography(&mut self) -> &mut Paragraph<'a> {
+        let para = Paragraph::default().push_dirty_field(
+            "BIBLIOGRAPHY",
+            "Update the field to build the bibliography.",
+        );
+        self.push_verbatim(para)
+    }
+
+    /// Appends a paragraph, or a paragraph with the given text, whose runs
+    /// can then be formatted in a chain, see [`ParagraphMut`].
+    ///
+    /// ```rust
+    /// use docx_rust::document::Paragraph;
+    /// use docx_rust::typography::Typography;
+    /// use docx_rust::Docx;
+    ///
+    /// let mut docx = Docx::default();
+    /// docx.typography = Some(Typography::english());
+    ///
+    /// let para = docx.add_paragraph("\"Smart\" quotes...");
+    /// assert_eq!(para.text(), "\u{201c}Smart\u{201d} quotes\u{2026}");
+    ///
+    /// docx.add_paragraph("text").bold().size_pt(12);
+    /// docx.add_paragraph(Paragraph::from("Lorem ipsum").italics());
+    /// ```
+    pub fn add_paragraph<T: Into<Paragraph<'a>>>(&mut self, para: T) -> ParagraphMut<'_, 'a> {
+        ParagraphMut(self.push_paragraph(para.into()))
+    }
+
+    /// Creates a link to an external address, to be pushed into a paragraph,
+    /// added to the history of visited links.
+    ///
+    /// The relationship to `url` is added to the document, and unless
+    /// [`Docx::plain_hyperlinks`] is set, the link text gets the `Hyperlink`
+    /// character style, which is added if missing. Characters that aren't
+    /// allowed in a URI are percent-encoded, see [`uri::encode`].
+    ///
+    /// ```rust
+    /// use docx_rust::document::Paragraph;
+    /// use docx_rust::Docx;
+    ///
+    /// let mut docx = Docx::default();
+    /// let link = docx
+    ///     .hyperlink("https://www.rust-lang.org", "Rust")
+    ///     .tooltip("The Rust website")
+    ///     .target_frame("_blank");
+    /// docx.document.push(Paragraph::default().push_text("Visit ").push(link));
+    ///
+    /// assert!(docx.styles.get("Hyperlink").is_some());
+    /// ```
+    pub fn hyperlink<U, T>(&mut self, url: U, text: T) -> Hyperlink<'a>
+    where
+        U: Into<Cow<'a, str>>,
+        T: Into<Text<'a>>,
+    {
+        let url = url.into();
+        let url = match uri::encode(&url) {
+            encoded if encoded == url => url,
+            encoded => encoded.into(),
+        };
+        let rels = self.document_rels.get_or_insert_with(Default::default);
+        rels.add_rel_with_target_mode(SCHEMA_HYPERLINK, url.clone(), Some("External"));
+        let id = rels.get_id(&url).map(|id| id.to_string());
+
+        Hyperlink {
+            id: id.map(Into::into),
+            history: Some(true),
+            content: self.link_run(text),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a link that writes an email to `address`, see [`uri::mailto`].
+    ///
+    /// Returns `None` if `address` doesn't look like an email address.
+    pub fn mailto_link<T: Into<Text<'a>>>(
+        &mut self,
+        address: &str,
+        subject: Option<&str>,
+        text: T,
+    ) -> Option<Hyperlink<'a>> {
+        let url = uri::mailto(address, subject)?;
+        Some(self.hyperlink(url, text))
+    }
+
+    /// Creates a link that calls a phone number, see [`uri::tel`].
+    ///
+    /// Returns `None` if `number` isn't a phone number.
+    pub fn tel_link<T: Into<Text<'a>>>(&mut self, number: &str, text: T) -> Option<Hyperlink<'a>> {
+        let url = uri::tel(number)?;
+        Some(self.hyperlink(url, text))
+    }
+
+    /// Creates a link to a local or network file, see [`uri::file`].
+    pub fn file_link<T: Into<Text<'a>>>(&mut self, path: &str, text: T) -> Hyperlink<'a> {
+        let url = uri::file(path);
+        self.hyperlink(url, text)
+    }
+
+    /// Creates a reference to a subdocument, making this a master document.
+    ///
+    /// `target` is the location of the subdocument, usually a path relative
+    /// to this document. Push it as the only content of a paragraph.
+    pub fn subdocument<T: Into<Cow<'a, str>>>(&mut self, target: T) -> SubDocument<'a> {
+        let target = target.into();
+        let rels = self.document_rels.get_or_insert_with(Default::default);
+        rels.add_rel_with_target_mode(SCHEMA_SUBDOCUMENT, target.clone(), Some("External"));
+        let id = rels.get_id(&target).map(|id| id.to_string());
+
+        SubDocument {
+            id: id.map(Into::into),
+        }
+    }
+
+    /// Returns the locations of the subdocuments of a master document, in
+    /// the order they appear in the body.
+    pub fn subdocuments(&self) -> Vec<&str> {
+        let rels = match &self.document_rels {
+            Some(rels) => rels,
+            None => return Vec::new(),
+        };
+        self.document
+            .body
+            .content
+            .iter()
+            .filter_map(|content| match content {
+                BodyContent::Paragraph(para) => Some(&para.content),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|content| match content {
+                ParagraphContent::SubDocument(sub_doc) => sub_doc.target(rels),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Creates a link to the first heading whose text is `heading`.
+    ///
+    /// Paragraphs count as headings if they, or their style, have an outline
+    /// level, or if their style is `Title` or one of the built-in `Heading1`
+    /// to `Heading9` styles. A bookmark is added around the heading if it
+    /// doesn't start with one yet. Returns `None` if there is no such heading.
+    ///
+    /// ```rust
+    /// use docx_rust::document::Paragraph;
+    /// use docx_rust::Docx;
+    ///
+    /// let mut docx = Docx::default();
+    /// docx.add_heading(1, "Results");
+    /// let link = docx.heading_link("Results", "see the results").unwrap();
+    /// docx.document.push(Paragraph::default().push(link));
+    ///
+    /// assert!(docx.heading_link("Conclusion", "conclusion").is_none());
+    /// ```
+    pub fn heading_link<T: Into<Text<'a>>>(
+        &mut self,
+        heading: &str,
+        text: T,
+    ) -> Option<Hyperlink<'a>> {
+        let mut next_id = 0;
+        let mut index = None;
+        for (i, content) in self.document.body.content.iter().enumerate() {
+            if let BodyContent::Paragraph(para) = content {
+                for content in &para.content {
+                    if let ParagraphContent::BookmarkStart(start) = content {
+                        if let Some(id) = start.id.as_ref().and_then(|id| id.parse::<usize>().ok())
+                        {
+                            next_id = next_id.max(id + 1);
+                        }
+                    }
+                }
+                if index.is_none() && self.is_heading(para) && para.text().trim() == heading.trim()
+                {
+                    index = Some(i);
+                }
+            }
+        }
+
+        let para = match self.document.body.content.get_mut(index?) {
+            Some(BodyContent::Paragraph(para)) => para,
+            _ => return None,
+        };
+        let name = match para.content.first() {
+            Some(ParagraphContent::BookmarkStart(BookmarkStart {
+                name: Some(name), ..
+            })) => name.to_string(),
+            _ => {
+                let name = format!("_Heading{}", next_id);
+                para.content.insert(
+                    0,
+                    BookmarkStart::default()
+                        .id(next_id.to_string())
+                        .name(name.clone())
+                        .into(),
+                );
+                para.content
+                    .push(BookmarkEnd::default().id(next_id.to_string()).into());
+                name
+            }
+        };
+
+        Some(Hyperlink {
+            anchor: Some(name.into()),
+            history: Some(true),
+            content: self.link_run(text),
+            ..Default::default()
+        })
+    }
+
+    fn is_heading(&self, para: &Paragraph) -> bool {
+        let property = match &para.property {
+            Some(property) => property,
+            None => return false,
+        };
+        if property.outline_lvl.is_some() {
+            return true;
+        }
+        let style_id = match &property.style_id {
+            Some(style_id) => style_id.value.as_ref(),
+            None => return false,
+        };
+        let level = style_id.strip_prefix("Heading").unwrap_or_default();
+        style_id == "Title"
+            || (level.len() == 1 && ("1"..="9").contains(&level))
+            || self
+                .styles
+                .get(style_id)
+                .and_then(|style| style.paragraph.as_ref())
+                .is_some_and(|property| property.outline_lvl.is_some())
+    }
+
+    /// Returns the heading level of a paragraph, from 1 to 9, taken from its
+    /// style or outline level.
+    pub(crate) fn heading_level(&self, para: &Paragraph) -> Option<usize> {
+        let property = para.property.as_ref()?;
+        let outline_level = |level: isize| (0..9).contains(&level).then_some(level as usize + 1);
+        if let Some(outline_lvl) = &property.outline_lvl {
+            return outline_level(outline_lvl.value);
+        }
+
+        let style_id = property.style_id.as_ref()?.value.as_ref();
+        if style_id == "Title" {
+            return Some(1);
+        }
+        match style_id.strip_prefix("Heading").map(str::parse::<usize>) {
+            Some(Ok(level)) if (1..=9).contains(&level) => Some(level),
+            _ => {
+                let style = self.styles.get(style_id)?;
+                outline_level(style.paragraph.as_ref()?.outline_lvl.as_ref()?.value)
+            }
+        }
+    }
+
+    /// Creates the text of a link, styled unless [`Docx::plain_hyperlinks`]
+    /// is set.
+    fn link_run<T: Into<Text<'a>>>(&mut self, text: T) -> Run<'a> {
+        let run = Run::default().push_text(text);
+        if self.plain_hyperlinks {
+            run
+        } else {
+            self.styles.ensure_hyperlink();
+            run.property(CharacterProperty::default().style_id("Hyperlink"))
+        }
+    }
+
+    /// Returns the address of a link in the main document, see
+    /// [`Hyperlink::target`].
+    pub fn hyperlink_target(&self, link: &Hyperlink) -> Option<&str> {
+        link.target(self.document_rels.as_ref()?)
+    }
+
+    /// Returns the type and data of a picture in the main document, looked
+    /// up by the target of its relationship, see [`Drawing::image_target`].
+    pub fn image_data(&self, drawing: &Drawing) -> Option<(&MediaType, &[u8])> {
+        let target = drawing.image_target(self.document_rels.as_ref()?)?;
+        let (ty, data) = self.media.get(target)?;
+        Some((ty, data.as_slice()))
+    }
+
+    /// Returns the data of an embedded package or object of the main
+    /// document, looked up by the id of its relationship, like the `r:id` of
+    /// an `o:OLEObject`.
+    ///
+    /// ```rust
+    /// use docx_rust::rels::Relationships;
+    /// use docx_rust::Docx;
+    /// use std::borrow::Cow;
+    ///
+    /// let mut docx = Docx::default();
+    /// docx.embeddings.insert(
+    ///     "embeddings/oleObject1.bin".to_string(),
+    ///     Cow::Borrowed(&[0xD0, 0xCF, 0x11, 0xE0][..]),
+    /// );
+    /// let mut rels = Relationships::default();
+    /// rels.add_rel(
+    ///     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/oleObject",
+    ///     "embeddings/oleObject1.bin",
+    /// );
+    /// let id = rels.get_id("embeddings/oleObject1.bin").unwrap().to_string();
+    /// docx.document_rels = Some(rels);
+    ///
+    /// assert_eq!(docx.embedding_data(&id), Some(&[0xD0, 0xCF, 0x11, 0xE0][..]));
+    /// ```
+    pub fn embedding_data(&self, id: &str) -> Option<&[u8]> {
+        let target = self.document_rels.as_ref()?.get_target(id)?;
+        self.embeddings.get(target).map(|data| &data[..])
+    }
+
+    /// Appends a code listing, see [`Paragraph::code_block`].
+    pub fn add_code_block<T: Into<Cow<'a, str>>>(&mut self, code: T) -> &mut Paragraph<'a> {
+        self.push_verbatim(Paragraph::code_block(code))
+    }
+
+    /// Appends a syntax highlighted code listing, see
+    /// [`Paragraph::highlighted_code_block`].
+    #[cfg(feature = "syntect")]
+    pub fn add_highlighted_code_block(
+        &mut self,
+        code: &'a str,
+        language: &str,
+    ) -> &mut Paragraph<'a> {
+        self.push_verbatim(Paragraph::highlighted_code_block(code, language))
+    }
+
+    /// Appends a bulleted list, one paragraph per item, and returns the id of
+    /// the numbering it uses.
+    ///
+    /// Leading indentation of an item nests it: every tab or two spaces
+    /// go one level deeper.
+    ///
+    /// ```rust
+    /// use docx_rust::Docx;
+    ///
+    /// let mut docx = Docx::default();
+    /// docx.bulleted_list(["fruits", "  apples", "  pears", "vegetables"]);
+    /// docx.numbered_list(["first", "second"]);
+    ///
+    /// assert_eq!(docx.numbering.unwrap().numberings.len(), 2);
+    /// ```
+    pub fn bulleted_list<I, T>(&mut self, items: I) -> isize
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Cow<'a, str>>,
+    {
+        self.push_list(AbstractNum::bullet(), items)
+    }
+
+    /// Appends a numbered list, see [`Self::bulleted_list`].
+    pub fn numbered_list<I, T>(&mut self, items: I) -> isize
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Cow<'a, str>>,
+    {
+        self.push_list(AbstractNum::decimal(), items)
+    }
+
+    /// Appends a table with a header row, see [`Table::from_rows`].
+    ///
+    /// ```rust
+    /// use docx_rust::Docx;
+    ///
+    /// let mut docx = Docx::default();
+    /// docx.add_table(["Name", "Qty"], [["apples", "3"], ["pears", "5"]]);
+    /// ```
+    pub fn add_table<H, R, C, T>(&mut self, headers: H, rows: R) -> &mut Table<'a>
+    where
+        H: IntoIterator<Item = T>,
+        R: IntoIterator<Item = C>,
+        C: IntoIterator<Item = T>,
+        T: Into<Text<'a>>,
+    {
+        let headers: Vec<T> = headers.into_iter().collect();
+        let rows =
+            std::iter::once(headers).chain(rows.into_iter().map(|row| row.into_iter().collect()));
+        let mut table = Table::from_rows(rows).header_row();
+        self.typeset(table.iter_text_mut());
+        match self.push_content(BodyContent::Table(table)) {
+            BodyContent::Table(table) => table,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Appends a checklist, one paragraph per `(checked, text)` item.
+    ///
+    /// ```rust
+    /// use docx_rust::document::CheckboxStyle;
+    /// use docx_rust::Docx;
+    ///
+    /// let mut docx = Docx::default();
+    /// docx.checklist([(true, "triage"), (false, "fix")], CheckboxStyle::ContentControl);
+    /// ```
+    pub fn checklist<I, T>(&mut self, items: I, style: CheckboxStyle)
+    where
+        I: IntoIterator<Item = (bool, T)>,
+        T: Into<Text<'a>>,
+    {
+        for (checked, text) in items {
+            self.push_paragraph(Paragraph::checklist_item(checked, text, style));
+        }
+    }
+
+    /// Returns a [`ListLabels`] to compute the labels of numbered paragraphs.
+    pub fn list_labels(&self) -> ListLabels<'_, 'a> {
+        ListLabels::new(self.numbering.as_ref(), Some(&self.styles))
+    }
+
+    /// Recomputes the cached results of cross-reference fields in the body,
+    /// see [`Body::update_cross_references`](crate::document::Body::update_cross_references).
+    pub fn update_cross_references(&mut self) -> usize {
+        let mut labels = ListLabels::new(self.numbering.as_ref(), Some(&self.styles));
+        self.document.body.update_cross_references(&mut labels)
+    }
+
+    fn push_list<I, T>(&mut self, mut abstract_num: AbstractNum<'a>, items: I) -> isize
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Cow<'a, str>>,
+    {
+        if let Some(indent) = self.list_indent {
+            abstract_num = abstract_num.indent(indent);
+        }
+        let num_id = self
+            .numbering
+            .get_or_insert_with(Numbering::default)
+            .push_list(abstract_num);
+
+        for item in items {
+            let item = item.into();
+            let text = item.trim_start();
+            let level = item[..item.len() - text.len()]
+                .chars()
+                .map(|c| if c == '\t' { 2 } else { 1 })
+                .sum::<isize>()
+                / 2;
+            let text = match item {
+                Cow::Borrowed(item) => Cow::Borrowed(item.trim_start()),
+                Cow::Owned(item) => Cow::Owned(item.trim_start().to_string()),
+            };
+
+            self.push_paragraph(
+                Paragraph::default()
+                    .property(ParagraphProperty::default().numbering((num_id, level.min(8))))
+                    .push_text(Text { text, space: None }),
             );
         }
 
-        for media in self.media.iter() {
-            let file_path = format!("word/{}", media.0);
-            writer.inner.start_file(file_path, opt)?;
-            writer.inner.write_all(media.1 .1)?;
+        num_id
+    }
+
+    fn push_paragraph(&mut self, mut para: Paragraph<'a>) -> &mut Paragraph<'a> {
+        self.typeset(para.iter_text_mut());
+        self.push_verbatim(para)
+    }
+
+    fn push_verbatim(&mut self, para: Paragraph<'a>) -> &mut Paragraph<'a> {
+        match self.push_content(BodyContent::Paragraph(para)) {
+            BodyContent::Paragraph(para) => para,
+            _ => unreachable!(),
         }
+    }
+
+    /// Appends to the body, before the properties of the last section.
+    fn push_content(&mut self, content: BodyContent<'a>) -> &mut BodyContent<'a> {
+        let body = &mut self.document.body.content;
+        let index = match body.last() {
+            Some(BodyContent::SectionProperty(_)) => body.len() - 1,
+            _ => body.len(),
+        };
+        body.insert(index, content);
+        &mut body[index]
+    }
 
-        Ok(writer.inner.finish()?)
+    pub(crate) fn typeset<'b, I>(&self, texts: I)
+    where
+        'a: 'b,
+        I: IntoIterator<Item = &'b mut Cow<'a, str>>,
+    {
+        if let Some(typography) = &self.typography {
+            for text in texts {
+                *text = typography.apply(std::mem::take(text));
+            }
+        }
     }
 
-    pub fn write_file<P: AsRef<Path>>(&'a mut self, path: P) -> DocxResult<File> {
+    /// Takes over the look of a reference document, like pandoc's
+    /// `--reference-doc`.
+    ///
+    /// Styles of the reference replace the styles with the same id, other
+    /// styles are kept. The document defaults, the themes and the font table
+    /// are replaced by those of the reference, if it has any.
+    ///
+    /// ```no_run
+    /// use docx_rust::{Docx, DocxFile};
+    ///
+    /// let reference = DocxFile::from_file("reference.docx")?;
+    /// let reference = reference.parse()?;
+    ///
+    /// let mut docx = Docx::default();
+    /// docx.import_styles(&reference);
+    /// docx.add_heading(1, "Styled like the reference");
+    /// # Ok::<(), docx_rust::DocxError>(())
+    /// ```
+    pub fn import_styles(&mut self, reference: &Docx<'a>) -> &mut Self {
+        let styles = &reference.styles;
+        if styles.default.is_some() {
+            self.styles.default = styles.default.clone();
+        }
+        if styles.latent_styles.is_some() {
+            self.styles.latent_styles = styles.latent_styles.clone();
+        }
+        for style in &styles.styles {
+            match self
+                .styles
+                .styles
+                .iter_mut()
+                .find(|s| s.style_id == style.style_id)
+            {
+                Some(existing) => *existing = style.clone(),
+                None => self.styles.styles.push(style.clone()),
+            }
+        }
+
+        if !reference.themes.is_empty() {
+            self.themes = reference.themes.clone();
+        }
+        if reference.font_table.is_some() {
+            self.font_table = reference.font_table.clone();
+        }
+
+        self
+    }
+
+    /// Makes the document a mail merge main document reading from the given
+    /// external data source.
+    ///
+    /// ```rust
+    /// use docx_rust::{settings::*, Docx};
+    ///
+    /// let mut docx = Docx::default();
+    /// docx.set_mail_merge(
+    ///     MailMerge::new(MailMergeDocType::FormLetters, MailMergeDataType::TextFile)
+    ///         .query("SELECT * FROM `recipients.csv`"),
+    ///     "file:///C:/data/recipients.csv",
+    /// );
+    ///
+    /// let merge = docx.settings.unwrap().mail_merge.unwrap();
+    /// assert_eq!(merge.data_source.unwrap().id, "rId1");
+    /// ```
+    pub fn set_mail_merge(&mut self, mail_merge: MailMerge<'a>, data_source: &'a str) -> &mut Self {
+        let rels = self.settings_rels.get_or_insert(Relationships::default());
+        rels.add_rel_with_target_mode(SCHEMA_MAIL_MERGE_SOURCE, data_source, Some("External"));
+        let id = rels
+            .relationships
+            .iter()
+            .find(|r| r.target == data_source)
+            .map(|r| r.id.clone())
+            .unwrap_or_default();
+
+        self.settings.get_or_insert(Settings::default()).mail_merge =
+            Some(mail_merge.data_source(id));
+        self
+    }
+
+    /// Sets the page background color, `color` as `RRGGBB`.
+    ///
+    /// The `displayBackgroundShape` setting is turned on, without it Word
+    /// doesn't show the background in print layout.
+    ///
+    /// ```rust
+    /// use docx_rust::Docx;
+    ///
+    /// let mut docx = Docx::default();
+    /// docx.set_background("FFF8E7");
+    ///
+    /// assert!(docx.settings.unwrap().display_background_shape.is_some());
+    /// ```
+    pub fn set_background<T: Into<String>>(&mut self, color: T) -> &mut Self {
+        self.document.background = Some(Background::default().color(color));
+        self.settings
+            .get_or_insert(Settings::default())
+            .display_background_shape = Some(DisplayBackgroundShape::default());
+        self
+    }
+
+    /// Sets the language of the document, a tag like `en-US`, so that screen
+    /// readers and the spelling checker pick the right one.
+    ///
+    /// The language goes to the default character properties, the theme font
+    /// language of the settings and the core properties.
+    ///
+    /// ```rust
+    /// use docx_rust::Docx;
+    ///
+    /// let mut docx = Docx::default();
+    /// docx.set_language("en-US");
+    ///
+    /// assert_eq!(docx.core.unwrap().language.as_deref(), Some("en-US"));
+    /// let lang = docx.settings.unwrap().theme_font_lang.unwrap();
+    /// assert_eq!(lang.val.as_deref(), Some("en-US"));
+    /// ```
+    pub fn set_language<T: Into<Cow<'a, str>>>(&mut self, language: T) -> &mut Self {
+        let language = language.into();
+
+        let character = self
+            .styles
+            .default
+            .get_or_insert_with(DefaultStyle::default)
+            .character
+            .inner
+            .get_or_insert_with(CharacterProperty::default);
+        character.lang.get_or_insert_with(Lang::default).val = Some(language.clone());
+
+        self.settings
+            .get_or_insert(Settings::default())
+            .theme_font_lang
+            .get_or_insert_with(ThemeFontLang::default)
+            .val = Some(language.clone());
+
+        self.core.get_or_insert_with(Core::default).language = Some(language);
+        self
+    }
+
+    /// Ends the current section after the last paragraph, and starts a new
+    /// one on the next page, the same page, or the next even or odd page.
+    ///
+    /// The section properties so far move to the last paragraph, adding an
+    /// empty paragraph if the body doesn't end with one, and the new section
+    /// starts with a copy of them. Returns the properties of the new section,
+    /// which helpers like [`Docx::set_header`] apply to from now on.
+    ///
+    /// ```rust
+    /// use docx_rust::document::BodyContent;
+    /// use docx_rust::formatting::{PageCols, SectionType};
+    /// use docx_rust::Docx;
+    ///
+    /// let mut docx = Docx::default();
+    /// docx.add_paragraph("One column");
+    /// docx.insert_section_break(SectionType::Continuous).cols = Some(PageCols::equal(2, 720));
+    /// docx.add_paragraph("Two columns");
+    ///
+    /// match &docx.document.body.content[0] {
+    ///     BodyContent::Paragraph(para) => {
+    ///         assert!(para.property.as_ref().unwrap().section_property.is_some())
+    ///     }
+    ///     _ => unreachable!(),
+    /// }
+    /// assert!(matches!(
+    ///     docx.document.body.content.last(),
+    ///     Some(BodyContent::SectionProperty(_))
+    /// ));
+    /// ```
+    pub fn insert_section_break(&mut self, ty: SectionType) -> &mut SectionProperty<'a> {
+        let mut section = self.last_section_mut().clone();
+        let content = &mut self.document.body.content;
+        let end = content.len() - 1;
+        let ends_section = |content: &BodyContent| match content {
+            BodyContent::Paragraph(para) => para
+                .property
+                .as_ref()
+                .is_some_and(|property| property.section_property.is_some()),
+            _ => true,
+        };
+        if end == 0 || ends_section(&content[end - 1]) {
+            content.insert(end, BodyContent::Paragraph(Paragraph::default()));
+        }
+
+        let end = content.len() - 1;
+        if let BodyContent::Paragraph(para) = &mut content[end - 1] {
+            let property = para.property.get_or_insert_with(Default::default);
+            property.section_property = Some(section.clone());
+        }
+
+        section.ty = Some(SectionTypeP { ty: Some(ty) });
+        section.revision = None;
+        content[end] = BodyContent::SectionProperty(section);
+        self.last_section_mut()
+    }
+
+    /// Sets the header of the last section shown on the given pages,
+    /// replacing the previous one.
+    ///
+    /// A `First` header turns on the different first page of the section,
+    /// an `Even` one the different odd and even pages of the document, the
+    /// `Default` header being shown on odd pages.
+    ///
+    /// ```rust
+    /// use docx_rust::document::{Footer, Header, HeaderFooterReferenceType, Paragraph};
+    /// use docx_rust::Docx;
+    ///
+    /// let mut docx = Docx::default();
+    /// let mut header = Header::default();
+    /// header.push(Paragraph::default().push_text("Annual report"));
+    /// docx.set_header(HeaderFooterReferenceType::Default, header);
+    /// docx.set_header(HeaderFooterReferenceType::First, Header::default());
+    ///
+    /// let mut footer = Footer::default();
+    /// footer.push(Paragraph::default().push_text("Left page"));
+    /// docx.set_footer(HeaderFooterReferenceType::Even, footer);
+    ///
+    /// assert_eq!(docx.headers.len(), 2);
+    /// assert!(docx.settings.unwrap().even_and_odd_headers.is_some());
+    /// ```
+    pub fn set_header(&mut self, ty: HeaderFooterReferenceType, header: Header<'a>) -> &mut Self {
+        let existing = self.header_footer_part(&ty, true);
+        let name = match existing.filter(|name| self.headers.contains_key(name)) {
+            Some(name) => name,
+            None => next_part_name(&self.headers, "header"),
+        };
+        self.headers.insert(name.clone(), header);
+        let rels = self.document_rels.get_or_insert_with(Default::default);
+        rels.add_rel(SCHEMA_HEADER, name.clone());
+        let id = rels.get_id(&name).unwrap_or_default().to_string();
+        self.set_header_footer_pages(&ty);
+        self.last_section_mut().set_header_reference(ty, id);
+        self
+    }
+
+    /// Sets the footer of the last section shown on the given pages, see
+    /// [`Docx::set_header`].
+    pub fn set_footer(&mut self, ty: HeaderFooterReferenceType, footer: Footer<'a>) -> &mut Self {
+        let existing = self.header_footer_part(&ty, false);
+        let name = match existing.filter(|name| self.footers.contains_key(name)) {
+            Some(name) => name,
+            None => next_part_name(&self.footers, "footer"),
+        };
+        self.footers.insert(name.clone(), footer);
+        let rels = self.document_rels.get_or_insert_with(Default::default);
+        rels.add_rel(SCHEMA_FOOTER, name.clone());
+        let id = rels.get_id(&name).unwrap_or_default().to_string();
+        self.set_header_footer_pages(&ty);
+        self.last_section_mut().set_footer_reference(ty, id);
+        self
+    }
+
+    /// Adds a PNG, JPEG or BMP image to the header of the last section shown
+    /// on the given pages, adding an empty header if there is none. Returns
+    /// the id of the relationship of the header part to the image, for its
+    /// pictures.
+    ///
+    /// ```rust
+    /// use docx_rust::document::{Drawing, Header, HeaderFooterReferenceType};
+    /// use docx_rust::Docx;
+    ///
+    /// let png = b"\x89PNG\r\n\x1a\n".to_vec();
+    /// let mut docx = Docx::default();
+    /// let id = docx.add_header_image(HeaderFooterReferenceType::Default, &png)?;
+    /// let header = Header::with_logo_and_title(
+    ///     Drawing::inline_picture(docx.next_drawing_id(), id, 914400, 457200),
+    ///     "Annual report",
+    /// );
+    /// docx.set_header(HeaderFooterReferenceType::Default, header);
+    ///
+    /// assert!(docx.media.contains_key("media/image1.png"));
+    /// assert!(docx.other_parts.contains_key("word/_rels/header1.xml.rels"));
+    /// # Ok::<(), docx_rust::DocxError>(())
+    /// ```
+    pub fn add_header_image(
+        &mut self,
+        ty: HeaderFooterReferenceType,
+        data: &'a Vec<u8>,
+    ) -> DocxResult<String> {
+        let extension = image_extension(data).ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "the image isn't a PNG, JPEG or BMP image",
+            )
+        })?;
+        let header = match self.header_footer_part(&ty, true) {
+            Some(name) if self.headers.contains_key(&name) => name,
+            _ => {
+                self.set_header(ty.clone(), Header::default());
+                self.header_footer_part(&ty, true).unwrap_or_default()
+            }
+        };
+        let name = format!("media/image{}.{}", self.media.len() + 1, extension);
+        let name = self.insert_media(&name, (MediaType::Image, data));
+        self.content_types.add_image_default(extension);
+
+        // the relationships of headers are kept as read
+        let rels_name = rels_name(&format!("word/{}", header));
+        let (xml, id) = {
+            let mut rels = match self.other_parts.get(&rels_name) {
+                Some(data) => {
+                    Relationships::from_str(std::str::from_utf8(data).map_err(|err| {
+                        std::io::Error::new(std::io::ErrorKind::InvalidData, err)
+                    })?)?
+                }
+                None => Relationships::default(),
+            };
+            rels.add_rel(SCHEMA_IMAGE, name.as_str());
+            let id = rels.get_id(&name).unwrap_or_default().to_string();
+            (rels.to_string()?, id)
+        };
+        self.other_parts
+            .insert(rels_name, Cow::Owned(xml.into_bytes()));
+        Ok(id)
+    }
+
+    /// Sets whether odd and even pages have their own headers and footers,
+    /// in all sections.
+    pub fn set_even_and_odd_headers(&mut self, on: bool) -> &mut Self {
+        self.settings
+            .get_or_insert(Settings::default())
+            .even_and_odd_headers = on.then(EvenAndOddHeaders::default);
+        self
+    }
+
+    /// Returns the name of the header or footer part of the last section for
+    /// the given pages.
+    fn header_footer_part(&self, ty: &HeaderFooterReferenceType, header: bool) -> Option<String> {
+        let section =
+            self.document
+                .body
+                .content
+                .iter()
+                .rev()
+                .find_map(|content| match content {
+                    BodyContent::SectionProperty(section) => Some(section),
+                    _ => None,
+                })?;
+        let id = if header {
+            section.header_reference(ty)
+        } else {
+            section.footer_reference(ty)
+        }?;
+        let target = self.document_rels.as_ref()?.get_target(id)?;
+        Some(target.to_string())
+    }
+
+    fn set_header_footer_pages(&mut self, ty: &HeaderFooterReferenceType) {
+        match ty {
+            HeaderFooterReferenceType::First => {
+                self.last_section_mut().set_title_page(true);
+            }
+            HeaderFooterReferenceType::Even => {
+                self.set_even_and_odd_headers(true);
+            }
+            HeaderFooterReferenceType::Default => (),
+        }
+    }
+
+    /// Returns the section properties at the end of the body, adding them if
+    /// missing.
+    fn last_section_mut(&mut self) -> &mut SectionProperty<'a> {
+        let content = &mut self.document.body.content;
+        if !matches!(content.last(), Some(BodyContent::SectionProperty(_))) {
+            content.push(BodyContent::SectionProperty(SectionProperty::default()));
+        }
+        match content.last_mut() {
+            Some(BodyContent::SectionProperty(section)) => section,
+            _ => unreachable!(),
+        }
+    }
+
+    #[cfg(feature = "fs")]
+    pub fn write_file<P: AsRef<Path>>(&mut self, path: P) -> DocxResult<File> {
+        self.write_file_with_options(path, &WriteOptions::default())
+    }
+
+    #[cfg(feature = "fs")]
+    pub fn write_file_with_options<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+        options: &WriteOptions,
+    ) -> DocxResult<File> {
         if let Some(p) = path.as_ref().parent() {
             std::fs::create_dir_all(p)?;
         }
         let file = File::create(path)?;
-        self.write(file)
+        self.write_with_options(file, options)
+    }
+}
+
+/// Options controlling how a `Docx` is written
+///
+/// ```rust
+/// use docx_rust::{Compression, WriteOptions};
+///
+/// let options = WriteOptions::default()
+///     .media_compression(Compression::Stored)
+///     .compression_level(9)
+///     .alignment(4096u16)
+///     .part_compression("word/document.xml", Compression::Stored);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct WriteOptions {
+    /// Indents the XML of every part, for debugging and diffing.
+    pub pretty: bool,
+    /// Writes attributes in a stable order, for golden-file comparisons.
+    pub canonical: bool,
+    /// Specifies how the XML parts are stored.
+    pub compression: Compression,
+    /// Specifies how media files are stored.
+    pub media_compression: Compression,
+    /// Specifies the compression level of deflated entries, 0 to 9.
+    pub compression_level: Option<i64>,
+    /// Aligns the data of every entry to the given number of bytes.
+    pub alignment: u16,
+    /// Writes every entry with zip64 extensions. Entries over 4GB always are.
+    pub large_file: bool,
+    /// Overrides the compression of single entries, by name.
+    pub part_compression: HashMap<String, Compression>,
+    /// Removes every rsid before writing, see [`Docx::strip_rsids`].
+    pub strip_rsids: bool,
+    /// Gives every paragraph without one a paragraph and text id before
+    /// writing, see [`Docx::assign_para_ids`]. They are numbered in order
+    /// with `deterministic`.
+    pub para_ids: bool,
+    /// Merges adjacent runs with the same properties before writing, see
+    /// [`Docx::compact_runs`].
+    pub compact_runs: bool,
+    /// Writes the entries matching these names or name prefixes first, in
+    /// this order. The other entries follow in the usual order.
+    pub part_order: Vec<String>,
+    /// Makes identical documents produce byte-identical packages: entries get
+    /// a fixed timestamp, relationships are sorted by id and attributes are
+    /// written in a stable order, as with `canonical`.
+    pub deterministic: bool,
+}
+
+impl WriteOptions {
+    __setter!(pretty: bool);
+    __setter!(canonical: bool);
+    __setter!(compression: Compression);
+    __setter!(media_compression: Compression);
+    __setter!(compression_level: Option<i64>);
+    __setter!(alignment: u16);
+    __setter!(large_file: bool);
+    __setter!(strip_rsids: bool);
+    __setter!(para_ids: bool);
+    __setter!(compact_runs: bool);
+    __setter!(deterministic: bool);
+
+    pub fn part_compression<T: Into<String>>(mut self, name: T, compression: Compression) -> Self {
+        self.part_compression.insert(name.into(), compression);
+        self
+    }
+
+    pub fn part_order<I, T>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.part_order = names.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Creates options writing the entries in the order Word does, with
+    /// `[Content_Types].xml` first and stored, for consumers that expect it.
+    ///
+    /// ```rust
+    /// use docx_rust::{Docx, WriteOptions};
+    ///
+    /// let mut docx = Docx::default();
+    /// docx.add_paragraph("Hello");
+    /// let bytes = docx.write_to_vec_with_options(&WriteOptions::word_compatible())?;
+    ///
+    /// assert_eq!(&bytes[30..49], b"[Content_Types].xml");
+    /// # Ok::<(), docx_rust::DocxError>(())
+    /// ```
+    pub fn word_compatible() -> Self {
+        WriteOptions::default()
+            .part_compression("[Content_Types].xml", Compression::Stored)
+            .part_order(WORD_PART_ORDER.iter().copied())
+    }
+
+    /// Returns where an entry goes in `part_order`, after every listed one if
+    /// it isn't listed.
+    fn position(&self, name: &str) -> usize {
+        self.part_order
+            .iter()
+            .position(|prefix| name.starts_with(prefix.as_str()))
+            .unwrap_or(self.part_order.len())
+    }
+
+    /// Serializes a part, reformatting it as requested.
+    fn serialize<T: XmlWrite>(&self, xml: &T) -> DocxResult<Vec<u8>> {
+        let mut buffer = XmlWriter::new(Vec::new());
+        xml.to_writer(&mut buffer)?;
+        let canonical = self.canonical || self.deterministic;
+        if self.pretty || canonical {
+            let xml = crate::xml_output::reformat(
+                &String::from_utf8_lossy(&buffer.inner),
+                self.pretty,
+                canonical,
+            )?;
+            Ok(xml.into_bytes())
+        } else {
+            Ok(buffer.inner)
+        }
+    }
+
+    fn file_options(&self, name: &str, is_media: bool, len: usize) -> SimpleFileOptions {
+        let compression = match self.part_compression.get(name) {
+            Some(compression) => *compression,
+            None if is_media => self.media_compression,
+            None => self.compression,
+        };
+        let level = match compression {
+            Compression::Stored => None,
+            Compression::Deflated => self.compression_level,
+        };
+        let mut options = SimpleFileOptions::default();
+        if self.deterministic {
+            options = options.last_modified_time(DateTime::default());
+        }
+        options
+            .compression_method(compression.into())
+            .compression_level(level)
+            .unix_permissions(0o755)
+            .with_alignment(self.alignment)
+            .large_file(self.large_file || len as u64 > u32::MAX as u64)
+    }
+}
+
+/// The order of the entries in the packages Word writes
+const WORD_PART_ORDER: &[&str] = &[
+    "[Content_Types].xml",
+    "_rels/.rels",
+    "word/document.xml",
+    "word/_rels/",
+    "word/footnotes.xml",
+    "word/endnotes.xml",
+    "word/header",
+    "word/footer",
+    "word/media/",
+    "word/theme/",
+    "word/settings.xml",
+    "word/numbering.xml",
+    "word/styles.xml",
+    "word/webSettings.xml",
+    "word/fontTable.xml",
+    "docProps/core.xml",
+    "docProps/app.xml",
+];
+
+/// Returns the first free part name like `header1.xml`.
+fn next_part_name<T>(parts: &HashMap<String, T>, prefix: &str) -> String {
+    (1..)
+        .map(|n| format!("{}{}.xml", prefix, n))
+        .find(|name| !parts.contains_key(name))
+        .unwrap_or_default()
+}
+
+/// Serializes the properties part of a custom XML data part, together with
+/// the relationship of the data part to it.
+fn write_custom_xml_properties<F>(
+    name: &str,
+    properties: &DatastoreItem,
+    options: &WriteOptions,
+    f: &mut F,
+) -> DocxResult<()>
+where
+    F: FnMut(&str, &[u8]) -> DocxResult<()>,
+{
+    let properties_name = properties_name(name);
+    let mut rels = Relationships::default();
+    rels.add_rel(SCHEMA_CUSTOM_XML_PROPS, properties_name.clone());
+    f(
+        &format!("customXml/{}", properties_name),
+        &options.serialize(properties)?,
+    )?;
+    f(
+        &format!("customXml/_rels/{}.rels", name),
+        &options.serialize(&rels)?,
+    )
+}
+
+/// Returns the name of the relationships part of a part, like
+/// `word/charts/_rels/chart1.xml.rels`.
+fn rels_name(name: &str) -> String {
+    match name.rsplit_once('/') {
+        Some((folder, file)) => format!("{}/_rels/{}.rels", folder, file),
+        None => format!("_rels/{}.rels", name),
+    }
+}
+
+/// Returns whether a relationship of a part in the folder `base` targets a
+/// part kept as is.
+fn kept_as_is<T>(other_parts: &HashMap<String, T>, base: &str, rel: &Relationship) -> bool {
+    rel.target_mode != Some(TargetMode::External)
+        && other_parts.contains_key(&resolve(base, &rel.target)[1..])
+}
+
+/// Serializes the parts stored under `word/`, e.g. headers, footers and themes.
+///
+/// With the `rayon` feature the parts are serialized in parallel, then handed
+/// to `f` one after another, so that they can be written to the package.
+fn write_part_map<T, F>(
+    parts: &HashMap<String, T>,
+    options: &WriteOptions,
+    f: &mut F,
+) -> DocxResult<()>
+where
+    T: XmlWrite + Sync,
+    F: FnMut(&str, &[u8]) -> DocxResult<()>,
+{
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+
+        let buffers = sorted(parts)
+            .into_par_iter()
+            .map(|(name, xml)| Ok((name, options.serialize(xml)?)))
+            .collect::<DocxResult<Vec<_>>>()?;
+        for (name, buffer) in buffers {
+            f(&format!("word/{}", name), &buffer)?;
+        }
+    }
+
+    #[cfg(not(feature = "rayon"))]
+    for (name, xml) in sorted(parts) {
+        f(&format!("word/{}", name), &options.serialize(xml)?)?;
+    }
+
+    Ok(())
+}
+
+/// Returns the entries of a map ordered by key, so that parts are always
+/// written and related in the same order.
+pub(crate) fn sorted<T>(map: &HashMap<String, T>) -> Vec<(&String, &T)> {
+    let mut entries: Vec<_> = map.iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+    entries
+}
+
+/// How an entry is stored in the package
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    /// Stores the entry as is
+    Stored,
+    /// Compresses the entry with deflate
+    #[default]
+    Deflated,
+}
+
+impl From<Compression> for CompressionMethod {
+    fn from(compression: Compression) -> Self {
+        match compression {
+            Compression::Stored => CompressionMethod::Stored,
+            Compression::Deflated => CompressionMethod::Deflated,
+        }
+    }
+}
+
+/// The bytes of a package parts are borrowed from, e.g. a memory map
+type Package = Arc<dyn AsRef<[u8]> + Send + Sync>;
+
+/// The XML of a part, decompressed, or borrowed from a memory-mapped package
+/// if it was stored uncompressed
+enum PartXml {
+    Owned(String),
+    /// The package and the range of the part in it, checked to be UTF-8
+    Mapped(Package, Range<usize>),
+}
+
+impl Deref for PartXml {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        match self {
+            PartXml::Owned(xml) => xml,
+            PartXml::Mapped(package, range) => {
+                std::str::from_utf8(&(**package).as_ref()[range.clone()]).unwrap_or_default()
+            }
+        }
+    }
+}
+
+/// Reads the XML of a part, in place if it is stored in `mapped`.
+fn read_xml(mut file: ZipFile, mapped: Option<&Package>) -> DocxResult<PartXml> {
+    if let Some(package) = mapped {
+        let start = file.data_start() as usize;
+        let end = start.saturating_add(file.compressed_size() as usize);
+        if file.compression() == CompressionMethod::Stored && start > 0 {
+            let bytes = (**package).as_ref();
+            if let Some(Ok(xml)) = bytes.get(start..end).map(std::str::from_utf8) {
+                let content = skip_prolog(xml);
+                let xml = match to_transitional(content) {
+                    Some(xml) => PartXml::Owned(xml),
+                    None => PartXml::Mapped(package.clone(), end - content.len()..end),
+                };
+                check_well_formed(&xml)?;
+                return Ok(xml);
+            }
+        }
+    }
+    // the size is read from the package, deflate doesn't expand more than
+    // about a thousand times
+    let size = file.size().min(file.compressed_size().saturating_mul(1032));
+    let mut buffer = Vec::with_capacity(size as usize);
+    file.read_to_end(&mut buffer)?;
+    let mut xml = decode(buffer)?;
+    strip_prolog(&mut xml);
+    let xml = to_transitional(&xml).unwrap_or(xml);
+    check_well_formed(&xml)?;
+    Ok(PartXml::Owned(xml))
+}
+
+/// Checks that the elements of a part are closed, in order, as the derived
+/// readers expect well-formed XML.
+fn check_well_formed(xml: &str) -> XmlResult<()> {
+    let name = |prefix: &str, local: &str| match prefix {
+        "" => local.to_string(),
+        prefix => format!("{}:{}", prefix, local),
+    };
+    let mut open = Vec::new();
+    for token in Tokenizer::from(xml) {
+        match token? {
+            Token::ElementStart { prefix, local, .. } => {
+                open.push((prefix.as_str(), local.as_str()))
+            }
+            Token::ElementEnd {
+                end: ElementEnd::Empty,
+                ..
+            } => {
+                open.pop();
+            }
+            Token::ElementEnd {
+                end: ElementEnd::Close(prefix, local),
+                ..
+            } => match open.pop() {
+                Some(start) if start == (prefix.as_str(), local.as_str()) => (),
+                start => {
+                    return Err(XmlError::TagMismatch {
+                        expected: start.map_or_else(String::new, |(p, l)| name(p, l)),
+                        found: name(&prefix, &local),
+                    })
+                }
+            },
+            _ => (),
+        }
+    }
+    if open.is_empty() {
+        Ok(())
+    } else {
+        Err(XmlError::UnexpectedEof)
     }
 }
 
 /// An extracted docx file
 pub struct DocxFile {
-    app: Option<String>,
-    content_types: String,
-    core: Option<String>,
-    document: String,
-    document_rels: Option<String>,
-    font_table: Option<String>,
-    rels: String,
-    styles: Option<String>,
-    settings: Option<String>,
-    web_settings: Option<String>,
-    headers: Vec<(String, String)>,
-    footers: Vec<(String, String)>,
-    themes: Vec<(String, String)>,
+    app: Option<PartXml>,
+    content_types: PartXml,
+    core: Option<PartXml>,
+    document: PartXml,
+    document_rels: Option<PartXml>,
+    font_table: Option<PartXml>,
+    rels: PartXml,
+    styles: Option<PartXml>,
+    settings: Option<PartXml>,
+    settings_rels: Option<PartXml>,
+    web_settings: Option<PartXml>,
+    headers: Vec<(String, PartXml)>,
+    footers: Vec<(String, PartXml)>,
+    themes: Vec<(String, PartXml)>,
     medias: Vec<(String, Vec<u8>)>,
-    footnotes: Option<String>,
-    endnotes: Option<String>,
-    comments: Option<String>,
-    numbering: Option<String>,
+    charts: Vec<(String, PartXml)>,
+    chart_rels: Vec<(String, PartXml)>,
+    embeddings: Vec<(String, Vec<u8>)>,
+    other_parts: Vec<(String, Vec<u8>)>,
+    footnotes: Option<PartXml>,
+    endnotes: Option<PartXml>,
+    comments: Option<PartXml>,
+    comments_extended: Option<PartXml>,
+    people: Option<PartXml>,
+    numbering: Option<PartXml>,
+    bibliography: Option<(String, PartXml)>,
+    custom_xml: Vec<(String, PartXml)>,
+    custom_xml_properties: Vec<(String, PartXml)>,
 }
 
 impl DocxFile {
     /// Extracts from reader
     pub fn from_reader<T: Read + Seek>(reader: T) -> DocxResult<Self> {
-        let mut zip = ZipArchive::new(reader)?;
+        Self::extract(ZipArchive::new(reader)?, None)
+    }
+
+    /// Extracts from a memory-mapped file, which avoids reading the package
+    /// into memory.
+    ///
+    /// Parts stored without compression are parsed in place, the others are
+    /// decompressed. The file must not be modified while the `DocxFile` or
+    /// the documents parsed from it are alive.
+    ///
+    /// ```no_run
+    /// use docx_rust::DocxFile;
+    ///
+    /// let file = DocxFile::from_mmap("large.docx")?;
+    /// let docx = file.parse()?;
+    /// # Ok::<(), docx_rust::DocxError>(())
+    /// ```
+    #[cfg(feature = "mmap")]
+    pub fn from_mmap<P: AsRef<Path>>(path: P) -> DocxResult<Self> {
+        let file = File::open(path)?;
+        // SAFETY: modifying the file while it is mapped is documented as
+        // unsupported, as with any memory-mapped file
+        let map: Package = Arc::new(unsafe { memmap2::Mmap::map(&file)? });
+        Self::extract(ZipArchive::new(Cursor::new((*map).as_ref()))?, Some(&map))
+    }
+
+    /// Extracts the parts of a package, borrowing the XML of stored parts
+    /// from `mapped`, the bytes of the package, if given.
+    fn extract<T: Read + Seek>(
+        mut zip: ZipArchive<T>,
+        mapped: Option<&Package>,
+    ) -> DocxResult<Self> {
+        // the entries read, the others are kept as is
+        let mut read: Vec<String> = Vec::new();
 
         macro_rules! read {
             ($xml:tt, $name:expr) => {{
-                let mut file = zip.by_name($name)?;
-                let mut buffer = String::new();
-                file.read_to_string(&mut buffer)?;
-                buffer
+                let file = zip.by_name($name)?;
+                read.push($name.to_string());
+                read_xml(file, mapped)?
             }};
         }
 
@@ -273,10 +2138,9 @@ impl DocxFile {
                 match zip.by_name($name) {
                     Err(ZipError::FileNotFound) => None,
                     Err(e) => return Err(e.into()),
-                    Ok(mut file) => {
-                        let mut buffer = String::new();
-                        file.read_to_string(&mut buffer)?;
-                        Some(buffer)
+                    Ok(file) => {
+                        read.push($name.to_string());
+                        Some(read_xml(file, mapped)?)
                     }
                 }
             };
@@ -289,11 +2153,10 @@ impl DocxFile {
                     .iter()
                     .filter(|n| n.contains($name))
                     .filter_map(|f| {
-                        zip.by_name(f).ok().and_then(|mut file| {
-                            let mut buffer = String::new();
-                            file.read_to_string(&mut buffer).ok()?;
-                            Some((f.to_string(), buffer))
-                        })
+                        let file = zip.by_name(f).ok()?;
+                        let xml = read_xml(file, mapped).ok()?;
+                        read.push(f.to_string());
+                        Some((f.to_string(), xml))
                     })
                     .collect();
                 name_and_value
@@ -310,6 +2173,7 @@ impl DocxFile {
                         zip.by_name(f).ok().and_then(|mut file| {
                             let mut buffer = Vec::new();
                             file.read_to_end(&mut buffer).ok()?;
+                            read.push(f.to_string());
                             Some((f.to_string(), buffer))
                         })
                     })
@@ -327,16 +2191,46 @@ impl DocxFile {
         let rels = read!(Relationships, "_rels/.rels");
         let styles = option_read!(Styles, "word/styles.xml");
         let settings = option_read!(Settings, "word/settings.xml");
+        let settings_rels = option_read!(Relationships, "word/_rels/settings.xml.rels");
         let web_settings = option_read!(WebSettings, "word/webSettings.xml");
         let footnotes = option_read!(Footnotes, "word/footnotes.xml");
         let endnotes = option_read!(Endnotes, "word/endnotes.xml");
         let comments = option_read!(Comments, "word/comments.xml");
+        let comments_extended = option_read!(CommentsExtended, "word/commentsExtended.xml");
+        let people = option_read!(People, "word/people.xml");
         let numbering = option_read!(Numbering, "word/numbering.xml");
 
         let headers = option_read_multiple!(Headers, "word/header");
         let footers = option_read_multiple!(Footers, "word/footer");
         let themes = option_read_multiple!(Themes, "word/theme/theme");
         let medias = option_read_multiple_files!(Medias, "word/media");
+        let (chart_rels, charts): (Vec<_>, Vec<_>) = option_read_multiple!(Charts, "word/charts/")
+            .into_iter()
+            .partition(|(name, _)| name.ends_with(".rels"));
+        let embeddings = option_read_multiple_files!(Embeddings, "word/embeddings/");
+        let (custom_xml_properties, mut custom_xml): (Vec<_>, Vec<_>) =
+            option_read_multiple!(CustomXml, "customXml/item")
+                .into_iter()
+                .partition(|(name, _)| name.starts_with("customXml/itemProps"));
+        let bibliography = custom_xml
+            .iter()
+            .position(|(_, xml)| is_bibliography(xml))
+            .map(|index| custom_xml.remove(index));
+
+        // the relationships of custom XML parts are written from their
+        // properties
+        let names: Vec<_> = zip
+            .file_names()
+            .filter(|name| !name.ends_with('/') && !name.starts_with("customXml/_rels/"))
+            .filter(|name| !read.iter().any(|read| read == name))
+            .map(str::to_string)
+            .collect();
+        let mut other_parts = Vec::new();
+        for name in names {
+            let mut buffer = Vec::new();
+            zip.by_name(&name)?.read_to_end(&mut buffer)?;
+            other_parts.push((name, buffer));
+        }
 
         Ok(DocxFile {
             app,
@@ -348,25 +2242,136 @@ impl DocxFile {
             rels,
             styles,
             settings,
+            settings_rels,
             web_settings,
             headers,
             footers,
             themes,
             medias,
+            charts,
+            chart_rels,
+            embeddings,
+            other_parts,
             footnotes,
             endnotes,
             comments,
+            comments_extended,
+            people,
             numbering,
+            bibliography,
+            custom_xml,
+            custom_xml_properties,
         })
     }
 
+    /// Extracts from an in-memory package
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> DocxResult<Self> {
+        Self::from_reader(Cursor::new(bytes))
+    }
+
     /// Extracts from file
+    #[cfg(feature = "fs")]
     #[inline]
     pub fn from_file<P: AsRef<Path>>(path: P) -> DocxResult<Self> {
         Self::from_reader(File::open(path)?)
     }
 
+    /// Returns a view that parses parts on first access.
+    ///
+    /// Useful when only a few parts are needed, e.g. the core properties or the
+    /// text of the document, as [`Self::parse`] parses every part up front.
+    ///
+    /// ```no_run
+    /// use docx_rust::DocxFile;
+    ///
+    /// let file = DocxFile::from_file("origin.docx")?;
+    /// let lazy = file.lazy();
+    ///
+    /// let text = lazy.parse_document()?.body.text();
+    /// let title = lazy.parse_core()?.and_then(|core| core.title.clone());
+    /// # Ok::<(), docx_rust::DocxError>(())
+    /// ```
+    pub fn lazy(&self) -> LazyDocx<'_> {
+        LazyDocx {
+            file: self,
+            app: OnceCell::new(),
+            core: OnceCell::new(),
+            document: OnceCell::new(),
+            styles: OnceCell::new(),
+            font_table: OnceCell::new(),
+            numbering: OnceCell::new(),
+            settings: OnceCell::new(),
+            comments: OnceCell::new(),
+            footnotes: OnceCell::new(),
+            endnotes: OnceCell::new(),
+        }
+    }
+
+    /// Returns a streaming reader over the body of the main document part,
+    /// see [`DocumentReader::from_package`] to read it without extracting it.
+    pub fn document_reader(&self) -> DocumentReader<&[u8]> {
+        DocumentReader::new(self.document.as_bytes())
+    }
+
+    /// Returns the attributes of the root element of the WordprocessingML
+    /// parts, keyed by part name.
+    fn extra_attributes(&self) -> HashMap<String, ExtraAttributes> {
+        let parts = [
+            ("word/document.xml", Some(&self.document)),
+            ("word/styles.xml", self.styles.as_ref()),
+            ("word/fontTable.xml", self.font_table.as_ref()),
+            ("word/settings.xml", self.settings.as_ref()),
+            ("word/footnotes.xml", self.footnotes.as_ref()),
+            ("word/endnotes.xml", self.endnotes.as_ref()),
+            ("word/comments.xml", self.comments.as_ref()),
+            ("word/numbering.xml", self.numbering.as_ref()),
+        ];
+        parts
+            .into_iter()
+            .filter_map(|(name, xml)| Some((name, xml?)))
+            .chain(self.headers.iter().map(|(name, xml)| (name.as_str(), xml)))
+            .chain(self.footers.iter().map(|(name, xml)| (name.as_str(), xml)))
+            .map(|(name, xml)| {
+                let mut attrs = root_attributes(xml);
+                attrs.retain(|(name, _)| name != CONFORMANCE);
+                (name.to_string(), attrs)
+            })
+            .filter(|(_, attrs)| !attrs.is_empty())
+            .collect()
+    }
+
+    /// Returns the conformance the main document part declares.
+    fn conformance(&self) -> Conformance {
+        let strict = root_attributes(&self.document)
+            .iter()
+            .any(|(name, value)| name == CONFORMANCE && value == "strict");
+        if strict {
+            Conformance::Strict
+        } else {
+            Conformance::Transitional
+        }
+    }
+
+    /// Parses the properties of the custom XML data part with the given
+    /// name, like `customXml/item1.xml`.
+    fn custom_xml_properties(&self, name: &str) -> DocxResult<Option<DatastoreItem<'_>>> {
+        let properties_name = properties_name(name);
+        let properties = self
+            .custom_xml_properties
+            .iter()
+            .find(|(name, _)| *name == properties_name)
+            .map(|(_, xml)| DatastoreItem::from_str(xml))
+            .transpose()?;
+        Ok(properties)
+    }
+
     /// Parses content into `Docx` struct
+    ///
+    /// Text and attribute values borrow from the extracted parts instead of
+    /// being copied, so the returned `Docx` adds little memory on top of the
+    /// `DocxFile`. Only values containing escaped characters, e.g. `&amp;`,
+    /// are unescaped into owned strings.
     pub fn parse(&self) -> DocxResult<Docx<'_>> {
         let app = if let Some(content) = &self.app {
             Some(App::from_str(content)?)
@@ -390,6 +2395,11 @@ impl DocxFile {
             footers.insert(name, ft);
         }
 
+        let mut other_parts = HashMap::new();
+        for (name, data) in self.other_parts.iter() {
+            other_parts.insert(name.clone(), Cow::Borrowed(&data[..]));
+        }
+
         let mut media = HashMap::new();
         for m in self.medias.iter() {
             let mt = crate::media::get_media_type(&m.0);
@@ -397,9 +2407,29 @@ impl DocxFile {
                 let name = m.0.replace("word/", "");
                 let m = (mt, &m.1);
                 media.insert(name, m);
+            } else {
+                // kept as is, like pictures in formats that aren't read
+                other_parts.insert(m.0.clone(), Cow::Borrowed(&m.1[..]));
             }
         }
 
+        let mut charts = HashMap::new();
+        for (name, xml) in self.charts.iter() {
+            let rels = self
+                .chart_rels
+                .iter()
+                .find(|(rels, _)| *rels == rels_name(name))
+                .map(|(_, xml)| Relationships::from_str(xml))
+                .transpose()?;
+            let xml = Cow::Borrowed(&**xml);
+            charts.insert(name.replace("word/", ""), ChartPart { xml, rels });
+        }
+
+        let mut embeddings = HashMap::new();
+        for (name, data) in self.embeddings.iter() {
+            embeddings.insert(name.replace("word/", ""), Cow::Borrowed(&data[..]));
+        }
+
         let mut themes = HashMap::new();
         // turn off for now
         for t in self.themes.iter() {
@@ -438,10 +2468,16 @@ impl DocxFile {
                             | crate::schema::SCHEMA_SETTINGS
                             | crate::schema::SCHEMA_WEB_SETTINGS
                             | crate::schema::SCHEMA_COMMENTS
+                            | crate::schema::SCHEMA_COMMENTS_EXTENDED
+                            | crate::schema::SCHEMA_PEOPLE
                             | crate::schema::SCHEMA_IMAGE
                             | crate::schema::SCHEMA_HYPERLINK
                             | crate::schema::SCHEMA_NUMBERING
-                    )
+                            | crate::schema::SCHEMA_CHART
+                            | crate::schema::SCHEMA_PACKAGE
+                            | crate::schema::SCHEMA_OLE_OBJECT
+                            | crate::schema::SCHEMA_SUBDOCUMENT
+                    ) || kept_as_is(&other_parts, "/word/", r2)
                 })
                 .map(|d| d.to_owned())
                 .collect();
@@ -472,6 +2508,12 @@ impl DocxFile {
             None
         };
 
+        let settings_rels = if let Some(content) = &self.settings_rels {
+            Some(Relationships::from_str(content)?)
+        } else {
+            None
+        };
+
         let web_settings = if let Some(content) = &self.web_settings {
             Some(WebSettings::from_str(
                 &content.replace("ns0:", "w:").to_string(),
@@ -486,14 +2528,47 @@ impl DocxFile {
             None
         };
 
+        let comments_extended = if let Some(content) = &self.comments_extended {
+            Some(CommentsExtended::from_str(content)?)
+        } else {
+            None
+        };
+
+        let people = if let Some(content) = &self.people {
+            Some(People::from_str(content)?)
+        } else {
+            None
+        };
+
         let numbering = if let Some(content) = &self.numbering {
             Some(Numbering::from_str(content)?)
         } else {
             None
         };
 
+        let bibliography = self
+            .bibliography
+            .as_ref()
+            .map(|(_, content)| Sources::from_str(content))
+            .transpose()?;
+        let bibliography_item = match &self.bibliography {
+            Some((name, _)) => Some((
+                name.replace("customXml/", ""),
+                self.custom_xml_properties(name)?,
+            )),
+            None => None,
+        };
+
+        let mut custom_xml = HashMap::new();
+        for (name, data) in self.custom_xml.iter() {
+            let properties = self.custom_xml_properties(name)?;
+            let name = name.replace("customXml/", "");
+            let data = Cow::Borrowed(&**data);
+            custom_xml.insert(name, CustomXmlPart { data, properties });
+        }
+
         let rels = Relationships::from_str(&self.rels)?;
-        let rels = {
+        let mut rels = {
             let rrr: Vec<_> = rels
                 .relationships
                 .iter()
@@ -503,13 +2578,36 @@ impl DocxFile {
                         crate::schema::SCHEMA_CORE
                             | crate::schema::SCHEMA_REL_EXTENDED
                             | crate::schema::SCHEMA_OFFICE_DOCUMENT
-                    )
+                    ) || kept_as_is(&other_parts, "/", r2)
                 })
                 .map(|d| d.to_owned())
                 .collect();
             Relationships { relationships: rrr }
         };
 
+        // the relationship to the thumbnail is added back when writing
+        let mut thumbnail = None;
+        rels.relationships.retain(|rel| {
+            if rel.ty != SCHEMA_THUMBNAIL || thumbnail.is_some() {
+                return true;
+            }
+            let name = resolve("/", &rel.target)[1..].to_string();
+            let format = match name
+                .rsplit_once('.')
+                .and_then(|(_, ext)| ThumbnailFormat::from_extension(ext))
+            {
+                Some(format) => format,
+                None => return true,
+            };
+            match other_parts.remove(&name) {
+                Some(data) => {
+                    thumbnail = Some(Thumbnail::new(format, data));
+                    false
+                }
+                None => true,
+            }
+        });
+
         let styles = self
             .styles
             .as_ref()
@@ -521,6 +2619,7 @@ impl DocxFile {
             app,
             content_types,
             core,
+            thumbnail,
             document,
             document_rels,
             font_table,
@@ -530,12 +2629,118 @@ impl DocxFile {
             footers,
             themes,
             media,
+            charts,
+            embeddings,
+            other_parts,
             footnotes,
             endnotes,
             settings,
+            settings_rels,
             web_settings,
             comments,
+            comments_extended,
+            people,
             numbering,
+            bibliography,
+            bibliography_item,
+            custom_xml,
+            typography: None,
+            plain_hyperlinks: false,
+            list_indent: None,
+            extra_attributes: self.extra_attributes(),
+            conformance: self.conformance(),
         })
     }
 }
+
+/// Parts of a [`DocxFile`], parsed on first access and cached
+///
+/// Created by [`DocxFile::lazy`].
+pub struct LazyDocx<'a> {
+    file: &'a DocxFile,
+    app: OnceCell<Option<App<'a>>>,
+    core: OnceCell<Option<Core<'a>>>,
+    document: OnceCell<Document<'a>>,
+    styles: OnceCell<Styles<'a>>,
+    font_table: OnceCell<Option<FontTable<'a>>>,
+    numbering: OnceCell<Option<Numbering<'a>>>,
+    settings: OnceCell<Option<Settings<'a>>>,
+    comments: OnceCell<Option<Comments<'a>>>,
+    footnotes: OnceCell<Option<FootNotes<'a>>>,
+    endnotes: OnceCell<Option<EndNotes<'a>>>,
+}
+
+macro_rules! lazy_part {
+    ($(#[$attr:meta])* $name:ident, $field:ident, $ty:ident) => {
+        $(#[$attr])*
+        pub fn $name(&self) -> DocxResult<Option<&$ty<'a>>> {
+            if let Some(part) = self.$field.get() {
+                return Ok(part.as_ref());
+            }
+            let part = match &self.file.$field {
+                Some(content) => Some($ty::from_str(content)?),
+                None => None,
+            };
+            Ok(self.$field.get_or_init(|| part).as_ref())
+        }
+    };
+}
+
+impl<'a> LazyDocx<'a> {
+    /// Parses the main document part.
+    pub fn parse_document(&self) -> DocxResult<&Document<'a>> {
+        if let Some(document) = self.document.get() {
+            return Ok(document);
+        }
+        let document = Document::from_str(&self.file.document)?;
+        Ok(self.document.get_or_init(|| document))
+    }
+
+    /// Parses the styles part, or returns empty styles if there is none.
+    pub fn parse_styles(&self) -> DocxResult<&Styles<'a>> {
+        if let Some(styles) = self.styles.get() {
+            return Ok(styles);
+        }
+        let styles = self
+            .file
+            .styles
+            .as_ref()
+            .map(|content| Styles::from_str(content))
+            .transpose()?
+            .unwrap_or_default();
+        Ok(self.styles.get_or_init(|| styles))
+    }
+
+    lazy_part!(
+        /// Parses the extended properties part.
+        parse_app, app, App
+    );
+    lazy_part!(
+        /// Parses the core properties part.
+        parse_core, core, Core
+    );
+    lazy_part!(
+        /// Parses the font table part.
+        parse_font_table, font_table, FontTable
+    );
+    lazy_part!(
+        /// Parses the numbering part.
+        parse_numbering, numbering, Numbering
+    );
+    lazy_part!(
+        /// Parses the settings part.
+        parse_settings, settings, Settings
+    );
+    lazy_part!(
+        /// Parses the comments part.
+        parse_comments, comments, Comments
+    );
+    lazy_part!(
+        /// Parses the footnotes part.
+        parse_footnotes, footnotes, FootNotes
+    );
+    lazy_part!(
+        /// Parses the endnotes part.
+        parse_endnotes, endnotes, EndNotes
+    );
+}
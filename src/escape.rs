@@ -0,0 +1,158 @@
+//! Fast serialization of text
+//!
+//! Text makes up most of a large document, e.g. one `w:t` per table cell, so
+//! text elements are written with pre-computed tags and escaped in place,
+//! copying the runs of characters that need no escaping as they are.
+//! [`unescape`] reverses it for text read with a tokenizer. The parts are
+//! written through a [`BufferedWriter`], so the many small writes of the
+//! serializer are plain copies.
+
+use std::borrow::Cow;
+use std::io::{Result, Write};
+
+/// The size of the chunks a [`BufferedWriter`] passes on
+const CHUNK_SIZE: usize = 8 * 1024;
+
+/// Collects the small writes of the serializer, like a tag or the unescaped
+/// run of a text, and passes them on to the inner writer in large chunks.
+///
+/// Unlike [`std::io::BufWriter`], flushing doesn't flush the inner writer,
+/// it only passes on what was collected.
+#[derive(Debug)]
+pub(crate) struct BufferedWriter<W: Write> {
+    pub(crate) inner: W,
+    buffer: Vec<u8>,
+}
+
+impl<W: Write> BufferedWriter<W> {
+    pub(crate) fn new(inner: W) -> Self {
+        BufferedWriter {
+            inner,
+            buffer: Vec::with_capacity(CHUNK_SIZE),
+        }
+    }
+}
+
+impl<W: Write> Write for BufferedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        if self.buffer.len() + buf.len() > CHUNK_SIZE {
+            self.flush()?;
+        }
+        if buf.len() >= CHUNK_SIZE {
+            self.inner.write_all(buf)?;
+        } else {
+            self.buffer.extend_from_slice(buf);
+        }
+        Ok(buf.len())
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> Result<()> {
+        self.write(buf).map(|_| ())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        if !self.buffer.is_empty() {
+            self.inner.write_all(&self.buffer)?;
+            self.buffer.clear();
+        }
+        Ok(())
+    }
+}
+
+/// Writes text with `&`, `<`, `>`, `'` and `"` escaped, as
+/// [`hard_xml::XmlWriter::write_text`] does.
+pub(crate) fn write_escaped<W: Write>(out: &mut W, text: &str) -> Result<()> {
+    let bytes = text.as_bytes();
+    let mut start = 0;
+    for (index, byte) in bytes.iter().enumerate() {
+        let entity: &[u8] = match byte {
+            b'&' => b"&amp;",
+            b'<' => b"&lt;",
+            b'>' => b"&gt;",
+            b'\'' => b"&apos;",
+            b'"' => b"&quot;",
+            _ => continue,
+        };
+        out.write_all(&bytes[start..index])?;
+        out.write_all(entity)?;
+        start = index + 1;
+    }
+    out.write_all(&bytes[start..])
+}
+
+/// Replaces the predefined entities and character references.
+pub(crate) fn unescape(text: &str) -> Cow<'_, str> {
+    if !text.contains('&') {
+        return Cow::Borrowed(text);
+    }
+    let mut unescaped = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        unescaped.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let end = match rest.find(';') {
+            Some(end) => end,
+            None => break,
+        };
+        let c = match &rest[1..end] {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            entity => entity
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(str::parse))
+                .and_then(|code| code.ok())
+                .and_then(char::from_u32),
+        };
+        match c {
+            Some(c) => {
+                unescaped.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                unescaped.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    unescaped.push_str(rest);
+    Cow::Owned(unescaped)
+}
+
+#[test]
+fn escaping() {
+    let escape = |text: &str| {
+        let mut out = Vec::new();
+        write_escaped(&mut out, text).unwrap();
+        String::from_utf8(out).unwrap()
+    };
+
+    assert_eq!(escape("plain"), "plain");
+    assert_eq!(escape(""), "");
+    assert_eq!(
+        escape(r#"<a href="x">R&D's</a>"#),
+        "&lt;a href=&quot;x&quot;&gt;R&amp;D&apos;s&lt;/a&gt;"
+    );
+    assert_eq!(escape("café & crème"), "café &amp; crème");
+}
+
+#[test]
+fn buffering() {
+    let mut writer = BufferedWriter::new(Vec::new());
+    writer.write_all(b"<w:t>").unwrap();
+    assert!(writer.inner.is_empty());
+
+    let text = "a&b".repeat(CHUNK_SIZE);
+    write_escaped(&mut writer, &text).unwrap();
+    writer.write_all(b"</w:t>").unwrap();
+    assert!(!writer.inner.is_empty());
+
+    writer.flush().unwrap();
+    assert_eq!(
+        String::from_utf8(writer.inner).unwrap(),
+        format!("<w:t>{}</w:t>", "a&amp;b".repeat(CHUNK_SIZE))
+    );
+}
@@ -0,0 +1,142 @@
+//! Attributes of part roots that aren't modelled
+//!
+//! Elements are read leniently: attributes this crate doesn't model, like
+//! `mc:Ignorable` or namespace declarations on any element, are skipped
+//! instead of failing the parse. Those of the root element of a part are
+//! kept in [`Docx::extra_attributes`] and written back, so that the
+//! namespaces of extensions and the prefixes declared ignorable survive a
+//! round trip.
+//!
+//! [`Docx::extra_attributes`]: crate::Docx::extra_attributes
+
+use xmlparser::{Token, Tokenizer};
+
+use crate::{DocxError, DocxResult};
+
+/// The attributes of a root element, by qualified name, with their values
+/// escaped as in the XML
+pub type ExtraAttributes = Vec<(String, String)>;
+
+const IGNORABLE: &str = "mc:Ignorable";
+
+/// Returns the attributes of the root element.
+pub(crate) fn root_attributes(xml: &str) -> ExtraAttributes {
+    let mut attrs = Vec::new();
+    for token in Tokenizer::from(xml) {
+        match token {
+            Ok(Token::Attribute { span, value, .. }) => {
+                let name = &span.as_str()[..span.as_str().find('=').unwrap_or(0)];
+                attrs.push((name.trim().to_string(), value.as_str().to_string()));
+            }
+            Ok(Token::ElementEnd { .. }) | Err(_) => break,
+            _ => (),
+        }
+    }
+    attrs
+}
+
+/// Adds the attributes missing from the root element of serialized XML.
+///
+/// The prefixes of `mc:Ignorable` are merged, keeping only those declared
+/// on the root.
+pub(crate) fn merge_root_attributes(
+    xml: &[u8],
+    extras: &[(String, String)],
+) -> DocxResult<Vec<u8>> {
+    let text = match std::str::from_utf8(xml) {
+        Ok(text) => text,
+        Err(_) => return Ok(xml.to_vec()),
+    };
+
+    let mut written = Vec::new();
+    let mut ignorable = None;
+    let mut end = None;
+    for token in Tokenizer::from(text) {
+        let token = token.map_err(|e| DocxError::Xml(hard_xml::XmlError::from(e)))?;
+        match token {
+            Token::Attribute { span, value, .. } => {
+                let name = &span.as_str()[..span.as_str().find('=').unwrap_or(0)];
+                let name = name.trim();
+                if name == IGNORABLE {
+                    ignorable = Some((span.start(), span.end(), value.as_str()));
+                }
+                written.push(name);
+            }
+            Token::ElementEnd { span, .. } => {
+                end = Some(span.start());
+                break;
+            }
+            _ => (),
+        }
+    }
+    let end = match end {
+        Some(end) => end,
+        None => return Ok(xml.to_vec()),
+    };
+
+    let mut added = String::new();
+    for (name, value) in extras {
+        if name != IGNORABLE && !written.contains(&name.as_str()) {
+            let quote = if value.contains('"') { '\'' } else { '"' };
+            added.push_str(&format!(" {}={}{}{}", name, quote, value, quote));
+        }
+    }
+
+    let declared = |prefix: &str| {
+        let declaration = format!("xmlns:{}", prefix);
+        written.contains(&declaration.as_str())
+            || extras.iter().any(|(name, _)| *name == declaration)
+    };
+    let mut prefixes: Vec<&str> = ignorable
+        .map(|(_, _, value)| value.split_whitespace().collect())
+        .unwrap_or_default();
+    for (_, value) in extras.iter().filter(|(name, _)| name == IGNORABLE) {
+        for prefix in value.split_whitespace() {
+            if !prefixes.contains(&prefix) && declared(prefix) && declared("mc") {
+                prefixes.push(prefix);
+            }
+        }
+    }
+    let merged = format!(r#" {}="{}""#, IGNORABLE, prefixes.join(" "));
+
+    let mut out = String::with_capacity(text.len() + added.len() + merged.len());
+    match ignorable {
+        Some((start, stop, _)) => {
+            out.push_str(&text[..start]);
+            out.push_str(merged.trim_start());
+            out.push_str(&text[stop..end]);
+        }
+        None if !prefixes.is_empty() => {
+            out.push_str(&text[..end]);
+            out.push_str(&merged);
+        }
+        None => out.push_str(&text[..end]),
+    }
+    out.push_str(&added);
+    out.push_str(&text[end..]);
+
+    Ok(out.into_bytes())
+}
+
+#[test]
+fn round_trip_root_attributes() {
+    let original = r#"<?xml version="1.0"?><w:document xmlns:w="w" xmlns:w15="w15" xmlns:mc="mc" mc:Ignorable="w14 w15" w:conformance='x"y'><w:body/></w:document>"#;
+    let extras = root_attributes(original);
+    assert_eq!(extras.len(), 5);
+    assert_eq!(extras[3], (IGNORABLE.to_string(), "w14 w15".to_string()));
+
+    let written = r#"<?xml version="1.0"?><w:document xmlns:w="w" xmlns:w14="w14" xmlns:mc="mc" mc:Ignorable="w14"><w:body/></w:document>"#;
+    let merged = merge_root_attributes(written.as_bytes(), &extras).unwrap();
+    assert_eq!(
+        String::from_utf8(merged).unwrap(),
+        r#"<?xml version="1.0"?><w:document xmlns:w="w" xmlns:w14="w14" xmlns:mc="mc" mc:Ignorable="w14 w15" xmlns:w15="w15" w:conformance='x"y'><w:body/></w:document>"#
+    );
+
+    let bare = r#"<w:hdr xmlns:w="w"/>"#;
+    let merged = merge_root_attributes(
+        bare.as_bytes(),
+        &root_attributes(r#"<w:hdr xmlns:w="w" mc:Ignorable="w15"/>"#),
+    )
+    .unwrap();
+    assert_eq!(String::from_utf8(merged).unwrap(), bare);
+}
@@ -0,0 +1,321 @@
+//! Flat OPC, the single XML file representation of a package
+//!
+//! Word saves it as "Word XML Document", and it is what Word interop and
+//! SharePoint hand over when a document is passed as a string. Every part is
+//! a `pkg:part` of one `pkg:package` element: XML parts are embedded as is,
+//! other parts as base64.
+
+use std::io::{Cursor, Write};
+
+use hard_xml::{XmlError, XmlWrite};
+use xmlparser::{ElementEnd, Token, Tokenizer};
+use zip::{write::SimpleFileOptions, CompressionMethod, ZipWriter};
+
+use crate::{
+    content_type::{ContentTypes, CONTENT_TYPE_XML},
+    error::DocxResult,
+    prolog::skip_prolog,
+    Docx, DocxFile, WriteOptions,
+};
+
+const PACKAGE_START: &str = concat!(
+    r#"<?xml version="1.0" standalone="yes"?>"#,
+    r#"<?mso-application progid="Word.Document"?>"#,
+    r#"<pkg:package xmlns:pkg="http://schemas.microsoft.com/office/2006/xmlPackage">"#,
+);
+const PACKAGE_END: &str = "</pkg:package>";
+
+impl<'a> Docx<'a> {
+    /// Writes the package as Flat OPC.
+    pub fn write_flat_opc<W: Write>(&mut self, mut writer: W) -> DocxResult<W> {
+        self.add_relationships();
+        let parts = self.package_parts();
+        let content_type = |name: &str| {
+            parts
+                .iter()
+                .find(|(n, _)| n == name)
+                .and_then(|(_, ty)| ty.content_type())
+                .or_else(|| self.content_types.get(name))
+                .unwrap_or(CONTENT_TYPE_XML)
+        };
+
+        writer.write_all(PACKAGE_START.as_bytes())?;
+        self.write_parts(&WriteOptions::default(), |name, xml| {
+            if name == "[Content_Types].xml" {
+                return Ok(());
+            }
+            let name = format!("/{}", name);
+            let xml = String::from_utf8_lossy(xml);
+            write!(
+                writer,
+                r#"<pkg:part pkg:name="{}" pkg:contentType="{}"><pkg:xmlData>{}</pkg:xmlData></pkg:part>"#,
+                name,
+                content_type(&name),
+                strip_declaration(&xml)
+            )?;
+            Ok(())
+        })?;
+        for (name, data) in self.binary_parts() {
+            let name = format!("/{}", name);
+            write!(
+                writer,
+                r#"<pkg:part pkg:name="{}" pkg:contentType="{}" pkg:compression="store"><pkg:binaryData>{}</pkg:binaryData></pkg:part>"#,
+                name,
+                content_type(&name),
+                base64_encode(data)
+            )?;
+        }
+        writer.write_all(PACKAGE_END.as_bytes())?;
+
+        Ok(writer)
+    }
+
+    /// Writes the package as a Flat OPC string.
+    ///
+    /// ```rust
+    /// use docx_rust::{Docx, DocxFile};
+    ///
+    /// let mut docx = Docx::default();
+    /// docx.add_paragraph("Hello");
+    /// let xml = docx.to_flat_opc()?;
+    ///
+    /// let file = DocxFile::from_flat_opc(&xml)?;
+    /// let docx = file.parse()?;
+    /// assert_eq!(docx.document.body.content.len(), 1);
+    /// # Ok::<(), docx_rust::DocxError>(())
+    /// ```
+    pub fn to_flat_opc(&mut self) -> DocxResult<String> {
+        let xml = self.write_flat_opc(Vec::new())?;
+        Ok(String::from_utf8_lossy(&xml).into_owned())
+    }
+}
+
+impl DocxFile {
+    /// Extracts from a Flat OPC string, like the files Word saves as "Word
+    /// XML Document".
+    pub fn from_flat_opc(xml: &str) -> DocxResult<Self> {
+        let parts = flat_parts(skip_prolog(xml))?;
+
+        let mut content_types: ContentTypes = ContentTypes::default();
+        content_types.overrides.clear();
+        for part in &parts {
+            match &part.data {
+                PartData::Xml(_) if part.name.ends_with(".rels") => (),
+                PartData::Xml(_) => {
+                    content_types.add_override(part.name, part.content_type);
+                }
+                PartData::Binary(_) => {
+                    if let Some((_, ext)) = part.name.rsplit_once('.') {
+                        content_types.add_default(ext.to_ascii_lowercase(), part.content_type);
+                    }
+                }
+            }
+        }
+
+        let options = SimpleFileOptions::default().compression_method(CompressionMethod::Stored);
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        zip.start_file("[Content_Types].xml", options)?;
+        zip.write_all(content_types.to_string()?.as_bytes())?;
+        for part in parts {
+            zip.start_file(part.name.trim_start_matches('/'), options)?;
+            match part.data {
+                PartData::Xml(xml) => zip.write_all(xml.as_bytes())?,
+                PartData::Binary(data) => zip.write_all(&data)?,
+            }
+        }
+
+        DocxFile::from_reader(zip.finish()?)
+    }
+}
+
+struct FlatPart<'a> {
+    name: &'a str,
+    content_type: &'a str,
+    data: PartData<'a>,
+}
+
+enum PartData<'a> {
+    Xml(&'a str),
+    Binary(Vec<u8>),
+}
+
+/// Splits a Flat OPC package into its parts.
+fn flat_parts(xml: &str) -> DocxResult<Vec<FlatPart<'_>>> {
+    let mut parts = Vec::new();
+    let mut name = None;
+    let mut content_type = None;
+    let mut tag = None;
+    let mut xml_start = None;
+    let mut binary: Option<String> = None;
+
+    for token in Tokenizer::from(xml) {
+        match token.map_err(XmlError::Parser)? {
+            Token::ElementStart { prefix, local, .. } => {
+                tag = (prefix.as_str() == "pkg").then(|| local.as_str());
+                match tag {
+                    Some("part") => {
+                        name = None;
+                        content_type = None;
+                    }
+                    Some("binaryData") => binary = Some(String::new()),
+                    _ => (),
+                }
+            }
+            Token::Attribute {
+                prefix,
+                local,
+                value,
+                ..
+            } if tag == Some("part") && prefix.as_str() == "pkg" => match local.as_str() {
+                "name" => name = Some(value.as_str()),
+                "contentType" => content_type = Some(value.as_str()),
+                _ => (),
+            },
+            Token::ElementEnd {
+                end: ElementEnd::Open,
+                span,
+            } => {
+                let opened = tag.take();
+                if opened == Some("xmlData") {
+                    xml_start = Some(span.end());
+                }
+            }
+            Token::ElementEnd {
+                end: ElementEnd::Close(prefix, local),
+                span,
+            } if prefix.as_str() == "pkg" => {
+                let data = match local.as_str() {
+                    "xmlData" => xml_start
+                        .take()
+                        .map(|start| PartData::Xml(xml[start..span.start()].trim())),
+                    "binaryData" => binary
+                        .take()
+                        .map(|text| base64_decode(&text).map(PartData::Binary))
+                        .transpose()?,
+                    _ => None,
+                };
+                if let Some(data) = data {
+                    parts.push(FlatPart {
+                        name: name.ok_or_else(|| missing_field("pkg:name"))?,
+                        content_type: content_type
+                            .ok_or_else(|| missing_field("pkg:contentType"))?,
+                        data,
+                    });
+                }
+            }
+            Token::Text { text } => {
+                if let Some(binary) = &mut binary {
+                    binary.push_str(text.as_str());
+                }
+            }
+            _ => (),
+        }
+    }
+
+    Ok(parts)
+}
+
+fn missing_field(field: &str) -> XmlError {
+    XmlError::MissingField {
+        name: "pkg:part".to_string(),
+        field: field.to_string(),
+    }
+}
+
+/// Removes the XML declaration, which isn't allowed inside `pkg:xmlData`.
+fn strip_declaration(xml: &str) -> &str {
+    if xml.starts_with("<?xml") {
+        if let Some(end) = xml.find("?>") {
+            return xml[end + 2..].trim_start();
+        }
+    }
+    xml
+}
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+pub(crate) fn base64_encode(data: &[u8]) -> String {
+    let mut encoded = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let bytes = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let n = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(BASE64[(n >> (18 - 6 * i)) as usize & 0x3F] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+/// Decodes base64, ignoring whitespace as Word wraps the lines.
+fn base64_decode(text: &str) -> Result<Vec<u8>, XmlError> {
+    let mut data = Vec::with_capacity(text.len() / 4 * 3);
+    let mut n = 0u32;
+    let mut bits = 0;
+    for c in text.bytes() {
+        let value = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            b'=' => break,
+            c if c.is_ascii_whitespace() => continue,
+            c => {
+                return Err(XmlError::UnrecognizedSymbol {
+                    symbol: (c as char).to_string(),
+                })
+            }
+        };
+        n = n << 6 | value as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            data.push((n >> bits) as u8);
+        }
+    }
+    Ok(data)
+}
+
+#[test]
+fn flat_opc() {
+    for data in [
+        &b""[..],
+        b"f",
+        b"fo",
+        b"foo",
+        b"foob",
+        b"\x89PNG\r\n\x1a\n\xff",
+    ] {
+        assert_eq!(base64_decode(&base64_encode(data)).unwrap(), data);
+    }
+    assert_eq!(base64_encode(b"foob"), "Zm9vYg==");
+    assert_eq!(base64_decode("Zm9v\r\n  YmFy").unwrap(), b"foobar");
+
+    let xml = concat!(
+        r#"<?xml version="1.0" standalone="yes"?>"#,
+        r#"<pkg:package xmlns:pkg="http://schemas.microsoft.com/office/2006/xmlPackage">"#,
+        r#"<pkg:part pkg:name="/word/document.xml" pkg:contentType="application/xml">"#,
+        r#"<pkg:xmlData><w:document xmlns:w="ns"><w:body/></w:document></pkg:xmlData></pkg:part>"#,
+        r#"<pkg:part pkg:name="/word/media/image1.png" pkg:contentType="image/png" pkg:compression="store">"#,
+        r#"<pkg:binaryData>Zm9v</pkg:binaryData></pkg:part>"#,
+        r#"</pkg:package>"#,
+    );
+    let parts = flat_parts(xml).unwrap();
+    assert_eq!(parts.len(), 2);
+    assert_eq!(parts[0].name, "/word/document.xml");
+    assert!(matches!(
+        parts[0].data,
+        PartData::Xml(r#"<w:document xmlns:w="ns"><w:body/></w:document>"#)
+    ));
+    assert!(matches!(&parts[1].data, PartData::Binary(data) if data == b"foo"));
+
+    assert_eq!(strip_declaration("<?xml version=\"1.0\"?>\n<a/>"), "<a/>");
+}
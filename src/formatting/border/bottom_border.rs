@@ -3,8 +3,7 @@ use std::borrow::Cow;
 
 use crate::{__setter, __xml_test_suites, formatting::BorderStyle};
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:bottom")]
 pub struct BottomBorder<'a> {
     #[xml(attr = "w:val")]
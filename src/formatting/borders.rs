@@ -6,8 +6,7 @@ use crate::{
 };
 
 /// Borders
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:pBdr")]
 pub struct Borders<'a> {
     #[xml(child = "w:top")]
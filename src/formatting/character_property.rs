@@ -2,7 +2,7 @@ use hard_xml::{XmlRead, XmlWrite};
 use std::borrow::Cow;
 
 use crate::{
-    __setter, __string_enum, __xml_test_suites,
+    __merge, __setter, __string_enum, __xml_test_suites,
     formatting::{Bold, Color, Dstrike, Fonts, Italics, Lang, Outline, Size, Strike, Underline},
 };
 
@@ -27,8 +27,7 @@ use super::{BoldComplex, Caps, Highlight, ItalicsComplex, Position, SmallCaps, V
 ///     .underline("00ff00")
 ///     .underline(("ff0000", UnderlineStyle::Dash));
 /// ```
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:rPr")]
 pub struct CharacterProperty<'a> {
     /// Specifies the style ID of the character style.
@@ -160,10 +159,69 @@ impl<'a> CharacterProperty<'a> {
     __setter!(size: Option<Size>);
     __setter!(underline: Option<Underline<'a>>);
     __setter!(fonts: Option<Fonts>);
+
+    /// Overrides these properties with those set in `other`, the attributes
+    /// of the fonts and the languages one by one.
+    ///
+    /// ```rust
+    /// use docx_rust::formatting::CharacterProperty;
+    ///
+    /// let base = CharacterProperty::default().bold(true).size(24isize);
+    /// let prop = base.merge(&CharacterProperty::default().size(32isize));
+    ///
+    /// assert!(prop.bold.is_some());
+    /// assert_eq!(prop.size.unwrap().value, 32);
+    /// ```
+    pub fn merge(mut self, other: &CharacterProperty<'a>) -> Self {
+        __merge!(
+            self,
+            other,
+            style_id,
+            bold,
+            bold_complex,
+            italics,
+            italics_complex,
+            caps,
+            small_caps,
+            strike,
+            dstrike,
+            outline,
+            shadow,
+            emboss,
+            imprint,
+            no_proof,
+            snap_to_grid,
+            vanish,
+            web_hidden,
+            color,
+            spacing,
+            scale,
+            kern,
+            position,
+            size,
+            size_complex,
+            highlight,
+            underline,
+            effect,
+            border,
+            shading,
+            fit_text,
+            vertical_align,
+            rtl,
+            complex_script,
+            emphasis,
+            east_asian_layout,
+            spec_vanish,
+            o_math,
+            ;
+            fonts,
+            lang,
+        );
+        self
+    }
 }
 
-#[derive(Debug, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:rStyle")]
 pub struct CharacterStyleId<'a> {
     #[xml(attr = "w:val")]
@@ -176,32 +234,28 @@ impl<'a, T: Into<Cow<'a, str>>> From<T> for CharacterStyleId<'a> {
     }
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:shadow")]
 pub struct Shadow {
     #[xml(attr = "w:val")]
     pub value: Option<bool>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:emboss")]
 pub struct Emboss {
     #[xml(attr = "w:val")]
     pub value: Option<bool>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:imprint")]
 pub struct Imprint {
     #[xml(attr = "w:val")]
     pub value: Option<bool>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:noProof")]
 pub struct NoProof {
     #[xml(attr = "w:val")]
@@ -216,48 +270,42 @@ pub struct NoProof {
 //     pub value: Option<bool>,
 // }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:vanish")]
 pub struct Vanish {
     #[xml(attr = "w:val")]
     pub value: Option<bool>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:webHidden")]
 pub struct WebHidden {
     #[xml(attr = "w:val")]
     pub value: Option<bool>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:rtl")]
 pub struct RightToLeftText {
     #[xml(attr = "w:val")]
     pub value: Option<bool>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:cs")]
 pub struct ComplexScript {
     #[xml(attr = "w:val")]
     pub value: Option<bool>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:specVanish")]
 pub struct SpecVanish {
     #[xml(attr = "w:val")]
     pub value: Option<bool>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:oMath")]
 pub struct OMath {
     #[xml(attr = "w:val")]
@@ -265,8 +313,7 @@ pub struct OMath {
 }
 
 /// Positive or Negative Value in Twentieths of a Point
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:spacing")]
 pub struct TextSpacing {
     #[xml(attr = "w:val")]
@@ -274,8 +321,7 @@ pub struct TextSpacing {
 }
 
 /// Text Expansion/Compression Percentage, 0..=600.
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:w")]
 pub struct Scale {
     #[xml(attr = "w:val")]
@@ -283,8 +329,7 @@ pub struct Scale {
 }
 
 /// Measurement in Half-Points
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:szCs")]
 pub struct SizeComplex {
     #[xml(attr = "w:val")]
@@ -292,24 +337,21 @@ pub struct SizeComplex {
 }
 
 /// Measurement in Half-Points
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:kern")]
 pub struct Kern {
     #[xml(attr = "w:val")]
     pub value: Option<isize>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:effect")]
 pub struct Effect {
     #[xml(attr = "w:val")]
     pub value: Option<EffectType>,
 }
 
-#[derive(Debug, Clone, Default)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub enum EffectType {
     BlinkBackground, //Blinking Background Animation
     Lights,          //Colored Lights Animation
@@ -333,8 +375,7 @@ __string_enum! {
     }
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:eastAsianLayout")]
 pub struct EastAsianLayout {
     #[xml(attr = "w:id")]
@@ -349,8 +390,7 @@ pub struct EastAsianLayout {
     pub vert_compress: Option<bool>,
 }
 
-#[derive(Debug, Clone, Default)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub enum CombineBracketsType {
     #[default]
     None, //	No Enclosing Brackets
@@ -370,8 +410,7 @@ __string_enum! {
     }
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:fitText")]
 pub struct FitText {
     // Measurement in Twentieths of a Point
@@ -381,8 +420,7 @@ pub struct FitText {
     pub id: Option<isize>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:bdr")]
 pub struct TextBorder<'a> {
     #[xml(attr = "w:val")]
@@ -405,8 +443,7 @@ pub struct TextBorder<'a> {
     pub frame: Option<bool>,
 }
 
-#[derive(Debug, Clone, Default)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub enum ThemeColor {
     #[default]
     Dark1, //Dark 1 Theme Color.
@@ -450,8 +487,7 @@ __string_enum! {
     }
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:shd")]
 pub struct Shading<'a> {
     #[xml(attr = "w:val")]
@@ -474,16 +510,14 @@ pub struct Shading<'a> {
     pub theme_fill_shade: Option<Cow<'a, str>>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:em")]
 pub struct Emphasis {
     #[xml(attr = "w:val")]
     pub value: Option<EmphasisType>,
 }
 
-#[derive(Debug, Default, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub enum ShadingStyle {
     #[default]
     Nil, //No Pattern.
@@ -569,8 +603,7 @@ __string_enum! {
     }
 }
 
-#[derive(Debug, Default, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub enum EmphasisType {
     #[default]
     None, //	No Emphasis Mark
@@ -1,6 +1,6 @@
 use hard_xml::{XmlRead, XmlWrite};
 
-use crate::{__define_enum, __define_struct, __xml_test_suites};
+use crate::{__define_enum, __define_struct, __merge, __xml_test_suites};
 
 // __define_struct!{
 //     (Fontss, "w:rFonts", 'a) {
@@ -24,6 +24,23 @@ __define_struct! {
     }
 }
 
+impl Fonts {
+    /// Overrides these fonts with those set in `other`, a theme font
+    /// together with the font it replaces.
+    pub fn merge(mut self, other: &Fonts) -> Self {
+        __merge!(
+            self,
+            other,
+            (hint),
+            (ascii, ascii_theme),
+            (east_asia, east_asia_theme),
+            (h_ansi, h_ansi_theme),
+            (custom, custom_theme),
+        );
+        self
+    }
+}
+
 // #[derive(Debug, Clone)]
 // #[cfg_attr(test, derive(PartialEq))]
 // pub enum FontHint {
@@ -1,21 +1,32 @@
-#![allow(dead_code)]
-
 use hard_xml::{XmlRead, XmlWrite};
 
-use crate::__xml_test_suites;
+use crate::{__merge, __xml_test_suites};
 
-/// indent
-/// percent to one character.
+/// Indentation
+///
+/// Lengths are in twentieths of a point, and the `*_chars` ones in
+/// hundredths of a character, which take precedence when both are given.
+/// `start` and `end` are the leading and trailing edges, the left and right
+/// ones in left-to-right paragraphs, and replace `left` and `right`.
 ///
 /// ```rust
 /// use docx_rust::formatting::*;
 ///
-/// let sz = Size::from(42isize);
+/// let indent = Indent::default().start(720).hanging(360);
+/// assert_eq!(indent.first_line_indent(), Some(FirstLineIndent::Hanging(360)));
+/// assert_eq!(indent.first_line_offset(), Some(-360));
 /// ```
-#[derive(Debug, XmlRead, XmlWrite, Clone, Default)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, XmlRead, XmlWrite, Clone, Default, PartialEq)]
 #[xml(tag = "w:ind")]
 pub struct Indent {
+    #[xml(attr = "w:start")]
+    pub start: Option<isize>,
+    #[xml(attr = "w:startChars")]
+    pub start_chars: Option<isize>,
+    #[xml(attr = "w:end")]
+    pub end: Option<isize>,
+    #[xml(attr = "w:endChars")]
+    pub end_chars: Option<isize>,
     #[xml(attr = "w:leftChars")]
     pub left_chars: Option<isize>,
     #[xml(attr = "w:left")]
@@ -28,41 +39,172 @@ pub struct Indent {
     pub first_line_chars: Option<isize>,
     #[xml(attr = "w:firstLine")]
     pub first_line: Option<isize>,
+    #[xml(attr = "w:hangingChars")]
+    pub hanging_chars: Option<isize>,
     #[xml(attr = "w:hanging")]
     pub hanging: Option<isize>,
 }
 
+/// The indentation of the first line of a paragraph, relative to the others
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FirstLineIndent {
+    /// Indents the first line further, in twentieths of a point
+    FirstLine(isize),
+    /// Indents the first line further, in hundredths of a character
+    FirstLineChars(isize),
+    /// Indents the first line less, in twentieths of a point
+    Hanging(isize),
+    /// Indents the first line less, in hundredths of a character
+    HangingChars(isize),
+}
+
 impl Indent {
-    fn left<T>(mut self, val: T) -> Self
-    where
-        isize: From<T>,
-    {
-        self.left_chars = Some(val.into());
-        self.left = self.left_chars;
+    /// Returns the indentation Word gives the list level `level`, counting
+    /// from 0: the text starts at half an inch per level, 720 twentieths of
+    /// a point, and the number hangs a quarter inch before it.
+    ///
+    /// ```rust
+    /// use docx_rust::formatting::Indent;
+    ///
+    /// let indent = Indent::list(1);
+    /// assert_eq!((indent.left, indent.hanging), (Some(1440), Some(360)));
+    /// ```
+    pub fn list(level: usize) -> Self {
+        Indent {
+            left: Some(720 * (level as isize + 1)),
+            hanging: Some(360),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the indentation of the leading edge.
+    pub fn start(mut self, val: isize) -> Self {
+        self.start = Some(val);
+        self
+    }
+
+    /// Sets the indentation of the leading edge, in hundredths of a character.
+    pub fn start_chars(mut self, val: isize) -> Self {
+        self.start_chars = Some(val);
+        self
+    }
+
+    /// Sets the indentation of the trailing edge.
+    pub fn end(mut self, val: isize) -> Self {
+        self.end = Some(val);
+        self
+    }
+
+    /// Sets the indentation of the trailing edge, in hundredths of a character.
+    pub fn end_chars(mut self, val: isize) -> Self {
+        self.end_chars = Some(val);
         self
     }
 
-    fn right<T>(mut self, val: T) -> Self
-    where
-        isize: From<T>,
-    {
-        self.right_chars = Some(val.into());
-        self.right = self.right_chars;
+    /// Sets the indentation of the left edge.
+    pub fn left(mut self, val: isize) -> Self {
+        self.left = Some(val);
         self
     }
 
-    fn first_line<T>(mut self, val: T) -> Self
-    where
-        isize: From<T>,
-    {
-        self.first_line = Some(val.into());
-        self.first_line_chars = self.first_line;
+    /// Sets the indentation of the left edge, in hundredths of a character.
+    pub fn left_chars(mut self, val: isize) -> Self {
+        self.left_chars = Some(val);
         self
     }
+
+    /// Sets the indentation of the right edge.
+    pub fn right(mut self, val: isize) -> Self {
+        self.right = Some(val);
+        self
+    }
+
+    /// Sets the indentation of the right edge, in hundredths of a character.
+    pub fn right_chars(mut self, val: isize) -> Self {
+        self.right_chars = Some(val);
+        self
+    }
+
+    /// Indents the first line further than the others, removing any hanging
+    /// indentation.
+    pub fn first_line(self, val: isize) -> Self {
+        self.with_first_line(FirstLineIndent::FirstLine(val))
+    }
+
+    /// Indents the first line less than the others, removing any first line
+    /// indentation.
+    pub fn hanging(self, val: isize) -> Self {
+        self.with_first_line(FirstLineIndent::Hanging(val))
+    }
+
+    /// Sets the indentation of the first line, clearing the other three
+    /// attributes, as first line and hanging indentation exclude each other.
+    pub fn with_first_line(mut self, indent: FirstLineIndent) -> Self {
+        self.first_line = None;
+        self.first_line_chars = None;
+        self.hanging = None;
+        self.hanging_chars = None;
+        match indent {
+            FirstLineIndent::FirstLine(val) => self.first_line = Some(val),
+            FirstLineIndent::FirstLineChars(val) => self.first_line_chars = Some(val),
+            FirstLineIndent::Hanging(val) => self.hanging = Some(val),
+            FirstLineIndent::HangingChars(val) => self.hanging_chars = Some(val),
+        }
+        self
+    }
+
+    /// Overrides this indentation with the edges set in `other`, keeping
+    /// e.g. the leading edge when `other` only sets the first line.
+    pub fn merge(mut self, other: &Indent) -> Self {
+        __merge!(
+            self,
+            other,
+            (start, start_chars, left, left_chars),
+            (end, end_chars, right, right_chars),
+            (first_line, first_line_chars, hanging, hanging_chars),
+        );
+        self
+    }
+
+    /// Returns the indentation of the first line that applies: hanging
+    /// indentation wins over first line indentation, and the `*_chars`
+    /// attributes over the lengths.
+    pub fn first_line_indent(&self) -> Option<FirstLineIndent> {
+        self.hanging_chars
+            .map(FirstLineIndent::HangingChars)
+            .or(self.hanging.map(FirstLineIndent::Hanging))
+            .or(self.first_line_chars.map(FirstLineIndent::FirstLineChars))
+            .or(self.first_line.map(FirstLineIndent::FirstLine))
+    }
+
+    /// Returns the indentation of the first line in twentieths of a point,
+    /// negative when hanging, ignoring the `*_chars` attributes.
+    pub fn first_line_offset(&self) -> Option<isize> {
+        self.hanging.map(|hanging| -hanging).or(self.first_line)
+    }
+
+    /// Returns the indentation of the leading edge, `start` or else `left`.
+    pub fn leading(&self) -> Option<isize> {
+        self.start.or(self.left)
+    }
+
+    /// Returns the indentation of the trailing edge, `end` or else `right`.
+    pub fn trailing(&self) -> Option<isize> {
+        self.end.or(self.right)
+    }
 }
 
 __xml_test_suites!(
     Indent,
-    Indent::default().first_line(200isize),
-    r#"<w:ind w:firstLineChars="200" w:firstLine="200"/>"#,
+    Indent::default().first_line(200),
+    r#"<w:ind w:firstLine="200"/>"#,
+    Indent::default().first_line(200).hanging(100),
+    r#"<w:ind w:hanging="100"/>"#,
+    Indent::default()
+        .start(720)
+        .end_chars(100)
+        .with_first_line(FirstLineIndent::HangingChars(50)),
+    r#"<w:ind w:start="720" w:endChars="100" w:hangingChars="50"/>"#,
+    Indent::list(0),
+    r#"<w:ind w:left="720" w:hanging="360"/>"#,
 );
@@ -9,8 +9,7 @@ use crate::__xml_test_suites;
 ///
 /// let lvl = IndentLevel::from(42isize);
 /// ```
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:ilvl")]
 pub struct IndentLevel {
     #[xml(attr = "w:val")]
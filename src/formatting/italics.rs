@@ -10,8 +10,7 @@ use crate::__xml_test_suites;
 /// let i = Italics::from(false);
 /// let i = Italics::from(true);
 /// ```
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:i")]
 pub struct Italics {
     #[xml(attr = "w:val")]
@@ -24,8 +23,7 @@ impl<T: Into<Option<bool>>> From<T> for Italics {
     }
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:iCs")]
 pub struct ItalicsComplex {
     #[xml(attr = "w:val")]
@@ -38,8 +36,7 @@ impl<T: Into<Option<bool>>> From<T> for ItalicsComplex {
     }
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:caps")]
 pub struct Caps {
     #[xml(attr = "w:val")]
@@ -52,8 +49,7 @@ impl<T: Into<Option<bool>>> From<T> for Caps {
     }
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:smallCaps")]
 pub struct SmallCaps {
     #[xml(attr = "w:val")]
@@ -1,6 +1,6 @@
 use hard_xml::{XmlRead, XmlWrite};
 
-use crate::{__string_enum, __xml_test_suites};
+use crate::__xml_test_suites;
 
 /// Justification
 ///
@@ -9,8 +9,7 @@ use crate::{__string_enum, __xml_test_suites};
 ///
 /// let jc = Justification::from(JustificationVal::Start);
 /// ```
-#[derive(Debug, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:jc")]
 pub struct Justification {
     #[xml(attr = "w:val")]
@@ -23,27 +22,77 @@ impl From<JustificationVal> for Justification {
     }
 }
 
-#[derive(Debug, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+/// Horizontal alignment of paragraphs, tables, table rows and list levels
+///
+/// Values this crate doesn't know, e.g. from newer versions of Word, are kept
+/// as [`JustificationVal::Other`] and written back unchanged.
+///
+/// ```rust
+/// use docx_rust::formatting::JustificationVal;
+///
+/// let value: JustificationVal = "thaiDistribute".parse().unwrap();
+/// assert_eq!(value.to_string(), "thaiDistribute");
+///
+/// let value: JustificationVal = "futureValue".parse().unwrap();
+/// assert_eq!(value.to_string(), "futureValue");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
 pub enum JustificationVal {
     Start,
     End,
     Center,
     Both,
+    MediumKashida,
     Distribute,
+    NumTab,
+    HighKashida,
+    LowKashida,
+    ThaiDistribute,
     Right,
     Left,
+    /// A value not listed above
+    Other(String),
 }
 
-__string_enum! {
-    JustificationVal {
-        Start = "start",
-        End = "end",
-        Center = "center",
-        Both = "both",
-        Distribute = "distribute",
-        Right = "right",
-        Left = "left",
+impl std::fmt::Display for JustificationVal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            JustificationVal::Start => "start",
+            JustificationVal::End => "end",
+            JustificationVal::Center => "center",
+            JustificationVal::Both => "both",
+            JustificationVal::MediumKashida => "mediumKashida",
+            JustificationVal::Distribute => "distribute",
+            JustificationVal::NumTab => "numTab",
+            JustificationVal::HighKashida => "highKashida",
+            JustificationVal::LowKashida => "lowKashida",
+            JustificationVal::ThaiDistribute => "thaiDistribute",
+            JustificationVal::Right => "right",
+            JustificationVal::Left => "left",
+            JustificationVal::Other(value) => value,
+        })
+    }
+}
+
+impl std::str::FromStr for JustificationVal {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "start" => JustificationVal::Start,
+            "end" => JustificationVal::End,
+            "center" => JustificationVal::Center,
+            "both" => JustificationVal::Both,
+            "mediumKashida" => JustificationVal::MediumKashida,
+            "distribute" => JustificationVal::Distribute,
+            "numTab" => JustificationVal::NumTab,
+            "highKashida" => JustificationVal::HighKashida,
+            "lowKashida" => JustificationVal::LowKashida,
+            "thaiDistribute" => JustificationVal::ThaiDistribute,
+            "right" => JustificationVal::Right,
+            "left" => JustificationVal::Left,
+            s => JustificationVal::Other(s.to_string()),
+        })
     }
 }
 
@@ -51,4 +100,8 @@ __xml_test_suites!(
     Justification,
     Justification::from(JustificationVal::Start),
     r#"<w:jc w:val="start"/>"#,
+    Justification::from(JustificationVal::ThaiDistribute),
+    r#"<w:jc w:val="thaiDistribute"/>"#,
+    Justification::from(JustificationVal::Other("futureValue".to_string())),
+    r#"<w:jc w:val="futureValue"/>"#,
 );
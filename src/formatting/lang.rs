@@ -2,7 +2,7 @@ use std::borrow::Cow;
 
 use hard_xml::{XmlRead, XmlWrite};
 
-use crate::{__setter, __xml_test_suites};
+use crate::{__merge, __setter, __xml_test_suites};
 
 /// Size
 ///
@@ -11,8 +11,7 @@ use crate::{__setter, __xml_test_suites};
 ///
 /// let sz = Size::from(42isize);
 /// ```
-#[derive(Debug, XmlRead, XmlWrite, Clone, Default)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, XmlRead, XmlWrite, Clone, Default, PartialEq)]
 #[xml(tag = "w:lang")]
 pub struct Lang<'a> {
     #[xml(attr = "w:val")]
@@ -27,6 +26,12 @@ impl<'a> Lang<'a> {
     __setter!(east_asia: Option<Cow<'a, str>>);
     __setter!(bidi: Option<Cow<'a, str>>);
     __setter!(val: Option<Cow<'a, str>>);
+
+    /// Overrides these languages with those set in `other`.
+    pub fn merge(mut self, other: &Lang<'a>) -> Self {
+        __merge!(self, other, val, east_asia, bidi,);
+        self
+    }
 }
 
 __xml_test_suites!(
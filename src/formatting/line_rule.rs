@@ -1,7 +1,6 @@
 use crate::__string_enum;
 
-#[derive(Debug, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Clone, PartialEq)]
 pub enum LineRule {
     Auto,
     Exact,
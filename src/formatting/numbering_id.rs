@@ -9,8 +9,7 @@ use crate::__xml_test_suites;
 ///
 /// let id = NumberingId::from(42isize);
 /// ```
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:numId")]
 pub struct NumberingId {
     #[xml(attr = "w:val")]
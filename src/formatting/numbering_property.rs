@@ -13,8 +13,7 @@ use crate::formatting::{IndentLevel, NumberingId};
 ///
 /// let prop = NumberingProperty::from((20, 40));
 /// ```
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:numPr")]
 pub struct NumberingProperty<'a> {
     /// Specifies the numbering level of the numbering definition to use for the paragraph.
@@ -31,8 +30,7 @@ pub struct NumberingProperty<'a> {
     pub ins: Option<InsertedProperties<'a>>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:ins")]
 pub struct InsertedProperties<'a> {
     #[xml(attr = "w:id")]
@@ -43,8 +41,7 @@ pub struct InsertedProperties<'a> {
     pub date: Option<Cow<'a, str>>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:numberingChange")]
 pub struct NumberingChange<'a> {
     #[xml(attr = "w:id")]
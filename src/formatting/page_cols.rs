@@ -1,28 +1,100 @@
 use hard_xml::{XmlRead, XmlWrite};
 
-/// Numbering Id
+use crate::{__setter, __xml_test_suites};
+
+/// Column Definitions
+///
+/// Lengths are in twips. Text flows to the next column when the current one
+/// is full, or after a column break.
 ///
 /// ```rust
+/// use docx_rust::document::{BodyContent, Break, Paragraph, Run};
 /// use docx_rust::formatting::*;
+/// use docx_rust::Docx;
+///
+/// let mut docx = Docx::default();
+/// docx.document.push(
+///     Paragraph::default()
+///         .push_text("Left column")
+///         .push(Run::default().push_break(Break::column())),
+/// );
+/// docx.add_paragraph("Right column");
+/// docx.document.push(BodyContent::SectionProperty(
+///     SectionProperty::default().cols(PageCols::equal(2, 720).separator(true)),
+/// ));
 ///
-/// let id = NumberingId::from(42isize);
+/// let cols = PageCols::widths([(3000, 360), (6000, 0)]);
 /// ```
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:cols")]
 pub struct PageCols {
+    /// Specifies whether the columns have the same width
+    #[xml(attr = "w:equalWidth")]
+    pub equal_width: Option<bool>,
+    /// Specifies the space between equal columns
     #[xml(attr = "w:space")]
     pub space: Option<isize>,
+    /// Specifies the number of equal columns
+    #[xml(attr = "w:num")]
+    pub num: Option<isize>,
+    /// Specifies whether a line is drawn between the columns
+    #[xml(attr = "w:sep")]
+    pub separator: Option<bool>,
+    /// Specifies the columns of unequal width
+    #[xml(child = "w:col")]
+    pub columns: Vec<PageCol>,
+}
+
+impl PageCols {
+    __setter!(space: Option<isize>);
+    __setter!(num: Option<isize>);
+    __setter!(separator: Option<bool>);
+
+    /// Creates `num` columns of the same width, `space` apart.
+    pub fn equal(num: isize, space: isize) -> Self {
+        PageCols {
+            num: Some(num),
+            space: Some(space),
+            ..Default::default()
+        }
+    }
+
+    /// Creates columns of the given widths, each with the space after it.
+    pub fn widths<I: IntoIterator<Item = (isize, isize)>>(columns: I) -> Self {
+        let columns: Vec<PageCol> = columns
+            .into_iter()
+            .map(|(width, space)| PageCol {
+                width: Some(width),
+                space: Some(space),
+            })
+            .collect();
+        PageCols {
+            equal_width: Some(false),
+            num: Some(columns.len() as isize),
+            columns,
+            ..Default::default()
+        }
+    }
 }
 
-// impl<T: Into<isize>> From<T> for NumberingId {
-//     fn from(val: T) -> Self {
-//         NumberingId { value: val.into() }
-//     }
-// }
+/// Single Column Definition
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
+#[xml(tag = "w:col")]
+pub struct PageCol {
+    /// Specifies the width of the column
+    #[xml(attr = "w:w")]
+    pub width: Option<isize>,
+    /// Specifies the space after the column
+    #[xml(attr = "w:space")]
+    pub space: Option<isize>,
+}
 
-// __xml_test_suites!(
-//     NumberingId,
-//     NumberingId::from(40isize),
-//     r#"<w:numId w:val="40"/>"#,
-// );
+__xml_test_suites!(
+    PageCols,
+    PageCols::default(),
+    r#"<w:cols/>"#,
+    PageCols::equal(2, 720).separator(true),
+    r#"<w:cols w:space="720" w:num="2" w:sep="true"/>"#,
+    PageCols::widths([(3000, 360), (6000, 0)]),
+    r#"<w:cols w:equalWidth="false" w:num="2"><w:col w:w="3000" w:space="360"/><w:col w:w="6000" w:space="0"/></w:cols>"#,
+);
@@ -9,8 +9,7 @@ use crate::__string_enum;
 ///
 /// let id = NumberingId::from(42isize);
 /// ```
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:docGrid")]
 pub struct PageGrid {
     #[xml(attr = "w:type")]
@@ -21,8 +20,7 @@ pub struct PageGrid {
     pub char_space: Option<isize>,
 }
 
-#[derive(Debug, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Clone, PartialEq)]
 pub enum GridType {
     Default,       //	No Document Grid
     Lines,         //	Line Grid Only
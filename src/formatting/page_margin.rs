@@ -1,14 +1,23 @@
 use hard_xml::{XmlRead, XmlWrite};
 
-/// Numbering Id
+use crate::__setter;
+
+/// Page Margins
+///
+/// Lengths are in twips. The gutter is added to the left margin, or the
+/// inside margin with mirrored margins, to leave room for the binding.
 ///
 /// ```rust
 /// use docx_rust::formatting::*;
 ///
-/// let id = NumberingId::from(42isize);
+/// let margin = PageMargin::default()
+///     .top(1440isize)
+///     .bottom(1440isize)
+///     .left(1080isize)
+///     .right(1080isize)
+///     .gutter(360isize);
 /// ```
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:pgMar")]
 pub struct PageMargin {
     #[xml(attr = "w:top")]
@@ -27,6 +36,16 @@ pub struct PageMargin {
     pub gutter: Option<isize>,
 }
 
+impl PageMargin {
+    __setter!(top: Option<isize>);
+    __setter!(right: Option<isize>);
+    __setter!(bottom: Option<isize>);
+    __setter!(left: Option<isize>);
+    __setter!(header: Option<isize>);
+    __setter!(footer: Option<isize>);
+    __setter!(gutter: Option<isize>);
+}
+
 // impl<T: Into<isize>> From<T> for NumberingId {
 //     fn from(val: T) -> Self {
 //         NumberingId { value: val.into() }
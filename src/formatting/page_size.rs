@@ -7,8 +7,7 @@ use hard_xml::{XmlRead, XmlWrite};
 ///
 /// let id = NumberingId::from(42isize);
 /// ```
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:pgSz")]
 pub struct PageSize {
     #[xml(attr = "w:w")]
@@ -2,8 +2,9 @@ use hard_xml::{XmlRead, XmlWrite};
 use std::borrow::Cow;
 
 use crate::{
-    __define_enum, __define_struct, __define_struct_vec, __setter, __xml_test_suites,
+    __define_enum, __define_struct, __define_struct_vec, __merge, __setter, __xml_test_suites,
     formatting::{Borders, Indent, Justification, NumberingProperty, Spacing, WidowControl},
+    styles::Styles,
 };
 
 /// Paragraph Property
@@ -16,8 +17,7 @@ use crate::{
 ///     .justification(JustificationVal::Start)
 ///     .numbering((10isize, 20isize));
 /// ```
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:pPr")]
 pub struct ParagraphProperty<'a> {
     /// Specifies the style ID of the paragraph style.
@@ -135,10 +135,102 @@ impl<'a> ParagraphProperty<'a> {
     __setter!(numbering: Option<NumberingProperty<'a>>);
     __setter!(spacing: Option<Spacing>);
     __setter!(indent: Option<Indent>);
+    __setter!(outline_lvl: Option<OutlineLvl>);
+    __setter!(suppress_line_numbers: Option<SuppressLineNumbers>);
+    __setter!(contextual_spacing: Option<ContextualSpacing>);
+    __setter!(mirror_indents: Option<MirrorIndents>);
+    __setter!(word_wrap: Option<WordWrap>);
+
+    /// Returns the paragraph properties of a style, including those of the
+    /// styles it is based on, to apply them as direct formatting.
+    ///
+    /// The properties start from the document defaults, and are merged
+    /// attribute by attribute, e.g. a style setting the spacing after keeps
+    /// the spacing before of its base. The style id isn't set, set it to keep
+    /// the paragraph in the style. Returns the document defaults if there is
+    /// no such style.
+    ///
+    /// ```rust
+    /// use docx_rust::formatting::{JustificationVal, ParagraphProperty};
+    /// use docx_rust::styles::Styles;
+    ///
+    /// let mut styles = Styles::new();
+    /// styles.ensure_heading(1);
+    ///
+    /// let prop = ParagraphProperty::from_style(&styles, "Heading1")
+    ///     .merge(&ParagraphProperty::default().justification(JustificationVal::Center));
+    /// assert!(prop.keep_next.is_some());
+    /// assert!(prop.justification.is_some());
+    /// ```
+    pub fn from_style(styles: &Styles<'a>, style_id: &str) -> Self {
+        let defaults = styles
+            .default
+            .as_ref()
+            .and_then(|default| default.paragraph.inner.as_ref());
+        let mut property = styles
+            .chain(Some(style_id))
+            .into_iter()
+            .filter_map(|style| style.paragraph.as_ref())
+            .fold(defaults.cloned().unwrap_or_default(), |property, other| {
+                property.merge(other)
+            });
+        property.style_id = None;
+        property
+    }
+
+    /// Overrides these properties with those set in `other`, the attributes
+    /// of the spacing and the indentation one by one.
+    ///
+    /// The run properties of the paragraph mark are replaced if `other` has
+    /// any.
+    pub fn merge(mut self, other: &ParagraphProperty<'a>) -> Self {
+        __merge!(
+            self,
+            other,
+            style_id,
+            keep_next,
+            keep_lines,
+            page_break_before,
+            widow_control,
+            numbering,
+            suppress_line_numbers,
+            border,
+            shading,
+            tabs,
+            suppress_auto_hyphens,
+            kinsoku,
+            word_wrap,
+            overflow_punct,
+            top_line_punct,
+            auto_space_de,
+            auto_space_dn,
+            bidi,
+            adjust_right_ind,
+            snap_to_grid,
+            contextual_spacing,
+            mirror_indents,
+            suppress_overlap,
+            justification,
+            text_direction,
+            text_alignment,
+            textbox_tight_wrap,
+            outline_lvl,
+            div_id,
+            cnf_style,
+            section_property,
+            p_pr_change,
+            ;
+            spacing,
+            indent,
+        );
+        if !other.r_pr.is_empty() {
+            self.r_pr = other.r_pr.clone();
+        }
+        self
+    }
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:pStyle")]
 pub struct ParagraphStyleId<'a> {
     #[xml(attr = "w:val")]
@@ -151,168 +243,187 @@ impl<'a, T: Into<Cow<'a, str>>> From<T> for ParagraphStyleId<'a> {
     }
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:keepNext")]
 pub struct KeepNext {
     #[xml(attr = "w:val")]
     pub value: Option<bool>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:keepLines")]
 pub struct KeepLines {
     #[xml(attr = "w:val")]
     pub value: Option<bool>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:pageBreakBefore")]
 pub struct PageBreakBefore {
     #[xml(attr = "w:val")]
     pub value: Option<bool>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:suppressLineNumbers")]
 pub struct SuppressLineNumbers {
     #[xml(attr = "w:val")]
     pub value: Option<bool>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+impl<T: Into<Option<bool>>> From<T> for SuppressLineNumbers {
+    fn from(value: T) -> Self {
+        SuppressLineNumbers {
+            value: value.into(),
+        }
+    }
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:suppressAutoHyphens")]
 pub struct SuppressAutoHyphens {
     #[xml(attr = "w:val")]
     pub value: Option<bool>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:kinsoku")]
 pub struct Kinsoku {
     #[xml(attr = "w:val")]
     pub value: Option<bool>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:wordWrap")]
 pub struct WordWrap {
     #[xml(attr = "w:val")]
     pub value: Option<bool>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+impl<T: Into<Option<bool>>> From<T> for WordWrap {
+    fn from(value: T) -> Self {
+        WordWrap {
+            value: value.into(),
+        }
+    }
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:overflowPunct")]
 pub struct OverflowPunct {
     #[xml(attr = "w:val")]
     pub value: Option<bool>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:topLinePunct")]
 pub struct TopLinePunct {
     #[xml(attr = "w:val")]
     pub value: Option<bool>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:autoSpaceDE")]
 pub struct AutoSpaceDE {
     #[xml(attr = "w:val")]
     pub value: Option<bool>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:autoSpaceDN")]
 pub struct AutoSpaceDN {
     #[xml(attr = "w:val")]
     pub value: Option<bool>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:bidi")]
 pub struct Bidi {
     #[xml(attr = "w:val")]
     pub value: Option<bool>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:adjustRightInd")]
 pub struct AdjustRightInd {
     #[xml(attr = "w:val")]
     pub value: Option<bool>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:snapToGrid")]
 pub struct SnapToGrid {
     #[xml(attr = "w:val")]
     pub value: Option<bool>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:contextualSpacing")]
 pub struct ContextualSpacing {
     #[xml(attr = "w:val")]
     pub value: Option<bool>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+impl<T: Into<Option<bool>>> From<T> for ContextualSpacing {
+    fn from(value: T) -> Self {
+        ContextualSpacing {
+            value: value.into(),
+        }
+    }
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:mirrorIndents")]
 pub struct MirrorIndents {
     #[xml(attr = "w:val")]
     pub value: Option<bool>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+impl<T: Into<Option<bool>>> From<T> for MirrorIndents {
+    fn from(value: T) -> Self {
+        MirrorIndents {
+            value: value.into(),
+        }
+    }
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:suppressOverlap")]
 pub struct SuppressOverlap {
     #[xml(attr = "w:val")]
     pub value: Option<bool>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:outlineLvl")]
 pub struct OutlineLvl {
+    /// Specifies the outline level, from 0 for the top level to 8, or 9 for
+    /// body text
     #[xml(attr = "w:val")]
     pub value: isize,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+impl From<isize> for OutlineLvl {
+    fn from(value: isize) -> Self {
+        OutlineLvl { value }
+    }
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:divId")]
 pub struct DivId {
     #[xml(attr = "w:val")]
     pub value: isize,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:cnfStyle")]
 pub struct CnfStyle<'a> {
     #[xml(attr = "w:val")]
     pub value: Cow<'a, str>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:pPrChange")]
 pub struct RevisionParagraphProperty<'a> {
     #[xml(attr = "w:id")]
@@ -326,8 +437,7 @@ pub struct RevisionParagraphProperty<'a> {
     pub previous_property: Option<PreviousParagraphProperty<'a>>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:pPr")]
 pub struct PreviousParagraphProperty<'a> {
     /// Specifies the style ID of the paragraph style.
@@ -486,4 +596,53 @@ __xml_test_suites!(
     r#"<w:pPr><w:pBdr/></w:pPr>"#,
     ParagraphProperty::default().numbering(NumberingProperty::default()),
     r#"<w:pPr><w:numPr/></w:pPr>"#,
+    ParagraphProperty::default()
+        .suppress_line_numbers(true)
+        .word_wrap(false)
+        .contextual_spacing(None)
+        .mirror_indents(true),
+    r#"<w:pPr><w:suppressLineNumbers w:val="true"/><w:wordWrap w:val="false"/><w:contextualSpacing/><w:mirrorIndents w:val="true"/></w:pPr>"#,
 );
+
+#[test]
+fn from_style() {
+    use crate::styles::{DefaultStyle, Style, StyleType, Styles};
+
+    let mut styles = Styles::new();
+    styles
+        .default(DefaultStyle::default().paragraph(
+            ParagraphProperty::default().spacing(Spacing::default().after(160isize).line(259isize)),
+        ))
+        .push(
+            Style::new(StyleType::Paragraph, "Base").paragraph(
+                ParagraphProperty::default()
+                    .spacing(Spacing::default().before(240isize))
+                    .indent(Indent::default().left(720).first_line(200)),
+            ),
+        )
+        .push(
+            Style::new(StyleType::Paragraph, "Quote")
+                .base("Base")
+                .paragraph(
+                    ParagraphProperty::default()
+                        .spacing(Spacing::default().after(0isize))
+                        .indent(Indent::default().hanging(360)),
+                ),
+        );
+
+    let prop = ParagraphProperty::from_style(&styles, "Quote");
+    assert_eq!(
+        prop.spacing,
+        Some(
+            Spacing::default()
+                .before(240isize)
+                .after(0isize)
+                .line(259isize)
+        )
+    );
+    assert_eq!(prop.indent, Some(Indent::default().left(720).hanging(360)));
+    assert_eq!(
+        ParagraphProperty::from_style(&styles, "Missing").spacing,
+        Some(Spacing::default().after(160isize).line(259isize))
+    );
+}
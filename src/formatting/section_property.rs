@@ -3,17 +3,18 @@ use hard_xml::{XmlRead, XmlWrite};
 use std::borrow::Cow;
 
 use crate::{
-    __define_enum, __define_struct, __string_enum,
-    document::HeaderFooterReference,
-    formatting::{PageCols, PageGrid, PageMargin, PageSize},
+    __define_enum, __define_struct, __setter, __string_enum, __xml_test_suites,
+    document::{
+        FooterReference, HeaderFooterReference, HeaderFooterReferenceType, HeaderReference,
+    },
+    formatting::{BorderStyle, PageCols, PageGrid, PageMargin, PageSize},
 };
 
 use super::Bidi;
 
 /// Section Properties
 ///
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:sectPr")]
 pub struct SectionProperty<'a> {
     //#[xml(attr = "w14:paraId")]
@@ -59,7 +60,7 @@ pub struct SectionProperty<'a> {
     ///  Only Allow Editing of Form Fields
     #[xml(child = "w:formProt")]
     pub form_prot: Option<FormProt>,
-    /////  Vertical Text Alignment on Page
+    ///  Vertical Text Alignment on Page
     #[xml(child = "w:vAlign")]
     pub v_align: Option<VAlign>,
     ///  Suppress Endnotes In Document
@@ -80,9 +81,9 @@ pub struct SectionProperty<'a> {
     ///  Document Grid
     #[xml(child = "w:docGrid")]
     pub grid: Option<PageGrid>,
-    /////  Reference to Printer Settings Data
-    //#[xml(child = "w:printerSettings")]
-    //pub printer_settings: Option<PrinterSettings>,
+    ///  Reference to Printer Settings Data
+    #[xml(child = "w:printerSettings")]
+    pub printer_settings: Option<PrinterSettings<'a>>,
     /// Revision Information for Section Properties
     #[xml(child = "w:sectPrChange")]
     pub revision: Option<Revision<'a>>,
@@ -90,8 +91,7 @@ pub struct SectionProperty<'a> {
 
 /// Previous Section Properties
 ///
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:sectPr")]
 pub struct PreviousSectionProperty<'a> {
     //#[xml(attr = "w14:paraId")]
@@ -134,7 +134,7 @@ pub struct PreviousSectionProperty<'a> {
     ///  Only Allow Editing of Form Fields
     #[xml(child = "w:formProt")]
     pub form_prot: Option<FormProt>,
-    /////  Vertical Text Alignment on Page
+    ///  Vertical Text Alignment on Page
     #[xml(child = "w:vAlign")]
     pub v_align: Option<VAlign>,
     ///  Suppress Endnotes In Document
@@ -173,6 +173,49 @@ __define_struct! {
     }
 }
 
+impl PgBorders {
+    /// Creates the same border on all edges of the page, `size` in eighths
+    /// of a point and `color` as `RRGGBB`.
+    ///
+    /// ```rust
+    /// use docx_rust::formatting::{BorderStyle, PageBorderOffset, PgBorders, SectionProperty};
+    ///
+    /// let section = SectionProperty::default().page_borders(
+    ///     PgBorders::all(BorderStyle::Double, 12, "1F3864").offset_from(PageBorderOffset::Page),
+    /// );
+    /// ```
+    pub fn all<T: Into<String>>(style: BorderStyle, size: isize, color: T) -> Self {
+        let color = color.into();
+        PgBorders {
+            top: Some(
+                PgTopBorder::default()
+                    .style(style.clone())
+                    .size(size)
+                    .color(color.clone()),
+            ),
+            left: Some(
+                PgLeftBorder::default()
+                    .style(style.clone())
+                    .size(size)
+                    .color(color.clone()),
+            ),
+            bottom: Some(
+                PgBottomBorder::default()
+                    .style(style.clone())
+                    .size(size)
+                    .color(color.clone()),
+            ),
+            right: Some(
+                PgRightBorder::default()
+                    .style(style)
+                    .size(size)
+                    .color(color),
+            ),
+            ..Default::default()
+        }
+    }
+}
+
 __define_enum! {
     PageBorderZOrder {
         Front = "front", // Page Border Ahead of Text
@@ -278,68 +321,213 @@ __define_struct! {
     }
 }
 
-__define_enum! {
+/// Number Format
+///
+/// The format of page numbers, footnote and endnote numbers, and list
+/// levels, see [`crate::document::NumFmt`].
+///
+/// ```rust
+/// use docx_rust::formatting::NumberFormat;
+///
+/// let format: NumberFormat = "upperRoman".parse().unwrap();
+/// assert_eq!(format.to_string(), "upperRoman");
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub enum NumberFormat {
+    /// Decimal Numbers
+    #[default]
+    Decimal,
+    /// Uppercase Roman Numerals
+    UpperRoman,
+    /// Lowercase Roman Numerals
+    LowerRoman,
+    /// Uppercase Latin Alphabet
+    UpperLetter,
+    /// Lowercase Latin Alphabet
+    LowerLetter,
+    /// Ordinal
+    Ordinal,
+    /// Cardinal Text
+    CardinalText,
+    /// Ordinal Text
+    OrdinalText,
+    /// Hexadecimal Numbering
+    Hex,
+    /// Chicago Manual of Style
+    Chicago,
+    /// Ideographs
+    IdeographDigital,
+    /// Japanese Counting System
+    JapaneseCounting,
+    /// AIUEO Order Hiragana
+    Aiueo,
+    /// Iroha Ordered Katakana
+    Iroha,
+    /// Double Byte Arabic Numerals
+    DecimalFullWidth,
+    /// Single Byte Arabic Numerals
+    DecimalHalfWidth,
+    /// Japanese Legal Numbering
+    JapaneseLegal,
+    /// Japanese Digital Ten Thousand Counting System
+    JapaneseDigitalTenThousand,
+    /// Decimal Numbers Enclosed in a Circle
+    DecimalEnclosedCircle,
+    /// Double Byte Arabic Numerals Alternate
+    DecimalFullWidth2,
+    /// Full-Width AIUEO Order Hiragana
+    AiueoFullWidth,
+    /// Full-Width Iroha Ordered Katakana
+    IrohaFullWidth,
+    /// Initial Zero Arabic Numerals
+    DecimalZero,
+    /// Bullet
+    Bullet,
+    /// Korean Ganada Numbering
+    Ganada,
+    /// Korean Chosung Numbering
+    Chosung,
+    /// Decimal Numbers Followed by a Period
+    DecimalEnclosedFullstop,
+    /// Decimal Numbers Enclosed in Parenthesis
+    DecimalEnclosedParen,
+    /// Decimal Numbers Enclosed in a Circle
+    DecimalEnclosedCircleChinese,
+    /// Ideographs Enclosed in a Circle
+    IdeographEnclosedCircle,
+    /// Traditional Ideograph Format
+    IdeographTraditional,
+    /// Zodiac Ideograph Format
+    IdeographZodiac,
+    /// Traditional Zodiac Ideograph Format
+    IdeographZodiacTraditional,
+    /// Taiwanese Counting System
+    TaiwaneseCounting,
+    /// Traditional Legal Ideograph Format
+    IdeographLegalTraditional,
+    /// Taiwanese Counting Thousand System
+    TaiwaneseCountingThousand,
+    /// Taiwanese Digital Counting System
+    TaiwaneseDigital,
+    /// Chinese Counting System
+    ChineseCounting,
+    /// Chinese Legal Simplified Format
+    ChineseLegalSimplified,
+    /// Chinese Counting Thousand System
+    ChineseCountingThousand,
+    /// Korean Digital Counting System
+    KoreanDigital,
+    /// Korean Counting System
+    KoreanCounting,
+    /// Korean Legal Numbering
+    KoreanLegal,
+    /// Korean Digital Counting System Alternate
+    KoreanDigital2,
+    /// Vietnamese Numerals
+    VietnameseCounting,
+    /// Lowercase Russian Alphabet
+    RussianLower,
+    /// Uppercase Russian Alphabet
+    RussianUpper,
+    /// No Numbering
+    None,
+    /// Number With Dashes
+    NumberInDash,
+    /// Hebrew Numerals
+    Hebrew1,
+    /// Hebrew Alphabet
+    Hebrew2,
+    /// Arabic Alphabet
+    ArabicAlpha,
+    /// Arabic Abjad Numerals
+    ArabicAbjad,
+    /// Hindi Vowels
+    HindiVowels,
+    /// Hindi Consonants
+    HindiConsonants,
+    /// Hindi Numbers
+    HindiNumbers,
+    /// Hindi Counting System
+    HindiCounting,
+    /// Thai Letters
+    ThaiLetters,
+    /// Thai Numerals
+    ThaiNumbers,
+    /// Thai Counting System
+    ThaiCounting,
+    /// Thai Baht Text
+    BahtText,
+    /// Dollar Text
+    DollarText,
+    /// Custom Format, given by the w14:format attribute
+    Custom,
+}
+
+__string_enum! {
     NumberFormat {
-        Decimal = "decimal", // Decimal Numbers
-        UpperRoman = "upperRoman", // Uppercase Roman Numerals
-        LowerRoman = "lowerRoman", // Lowercase Roman Numerals
-        UpperLetter = "upperLetter", // Uppercase Latin Alphabet
-        LowerLetter = "lowerLetter", // Lowercase Latin Alphabet
-        Ordinal = "ordinal", // Ordinal
-        CardinalText = "cardinalText", // Cardinal Text
-        OrdinalText = "ordinalText", // Ordinal Text
-        Hex = "hex", // Hexadecimal Numbering
-        Chicago = "chicago", // Chicago Manual of Style
-        IdeographDigital = "ideographDigital", // Ideographs
-        JapaneseCounting = "japaneseCounting", // Japanese Counting System
-        Aiueo = "aiueo", // AIUEO Order Hiragana
-        Iroha = "iroha", // Iroha Ordered Katakana
-        DecimalFullWidth = "decimalFullWidth", // Double Byte Arabic Numerals
-        DecimalHalfWidth = "decimalHalfWidth", // Single Byte Arabic Numerals
-        JapaneseLegal = "japaneseLegal", // Japanese Legal Numbering
-        JapaneseDigitalTenThousand = "japaneseDigitalTenThousand", // Japanese Digital Ten Thousand Counting System
-        DecimalEnclosedCircle = "decimalEnclosedCircle", // Decimal Numbers Enclosed in a Circle
-        DecimalFullWidth2 = "decimalFullWidth2", // Double Byte Arabic Numerals Alternate
-        AiueoFullWidth = "aiueoFullWidth", // Full-Width AIUEO Order Hiragana
-        IrohaFullWidth = "irohaFullWidth", // Full-Width Iroha Ordered Katakana
-        DecimalZero = "decimalZero", // Initial Zero Arabic Numerals
-        Bullet = "bullet", // Bullet
-        Ganada = "ganada", // Korean Ganada Numbering
-        Chosung = "chosung", // Korean Chosung Numbering
-        DecimalEnclosedFullstop = "decimalEnclosedFullstop", // Decimal Numbers Followed by a Period
-        DecimalEnclosedParen = "decimalEnclosedParen", // Decimal Numbers Enclosed in Parenthesis
-        DecimalEnclosedCircleChinese = "decimalEnclosedCircleChinese", // Decimal Numbers Enclosed in a Circle
-        IdeographEnclosedCircle = "ideographEnclosedCircle", // Ideographs Enclosed in a Circle
-        IdeographTraditional = "ideographTraditional", // Traditional Ideograph Format
-        IdeographZodiac = "ideographZodiac", // Zodiac Ideograph Format
-        IdeographZodiacTraditional = "ideographZodiacTraditional", // Traditional Zodiac Ideograph Format
-        TaiwaneseCounting = "taiwaneseCounting", // Taiwanese Counting System
-        IdeographLegalTraditional = "ideographLegalTraditional", // Traditional Legal Ideograph Format
-        TaiwaneseCountingThousand = "taiwaneseCountingThousand", // Taiwanese Counting Thousand System
-        TaiwaneseDigital = "taiwaneseDigital", // Taiwanese Digital Counting System
-        ChineseCounting = "chineseCounting", // Chinese Counting System
-        ChineseLegalSimplified = "chineseLegalSimplified", // Chinese Legal Simplified Format
-        ChineseCountingThousand = "chineseCountingThousand", // Chinese Counting Thousand System
-        KoreanDigital = "koreanDigital", // Korean Digital Counting System
-        KoreanCounting = "koreanCounting", // Korean Counting System
-        KoreanLegal = "koreanLegal", // Korean Legal Numbering
-        KoreanDigital2 = "koreanDigital2", // Korean Digital Counting System Alternate
-        VietnameseCounting = "vietnameseCounting", // Vietnamese Numerals
-        RussianLower = "russianLower", // Lowercase Russian Alphabet
-        RussianUpper = "russianUpper", // Uppercase Russian Alphabet
-        None = "none", // No Numbering
-        NumberInDash = "numberInDash", // Number With Dashes
-        Hebrew1 = "hebrew1", // Hebrew Numerals
-        Hebrew2 = "hebrew2", // Hebrew Alphabet
-        ArabicAlpha = "arabicAlpha", // Arabic Alphabet
-        ArabicAbjad = "arabicAbjad", // Arabic Abjad Numerals
-        HindiVowels = "hindiVowels", // Hindi Vowels
-        HindiConsonants = "hindiConsonants", // Hindi Consonants
-        HindiNumbers = "hindiNumbers", // Hindi Numbers
-        HindiCounting = "hindiCounting", // Hindi Counting System
-        ThaiLetters = "thaiLetters", // Thai Letters
-        ThaiNumbers = "thaiNumbers", // Thai Numerals
-        ThaiCounting = "thaiCounting", // Thai Counting System
+        Decimal = "decimal",
+        UpperRoman = "upperRoman",
+        LowerRoman = "lowerRoman",
+        UpperLetter = "upperLetter",
+        LowerLetter = "lowerLetter",
+        Ordinal = "ordinal",
+        CardinalText = "cardinalText",
+        OrdinalText = "ordinalText",
+        Hex = "hex",
+        Chicago = "chicago",
+        IdeographDigital = "ideographDigital",
+        JapaneseCounting = "japaneseCounting",
+        Aiueo = "aiueo",
+        Iroha = "iroha",
+        DecimalFullWidth = "decimalFullWidth",
+        DecimalHalfWidth = "decimalHalfWidth",
+        JapaneseLegal = "japaneseLegal",
+        JapaneseDigitalTenThousand = "japaneseDigitalTenThousand",
+        DecimalEnclosedCircle = "decimalEnclosedCircle",
+        DecimalFullWidth2 = "decimalFullWidth2",
+        AiueoFullWidth = "aiueoFullWidth",
+        IrohaFullWidth = "irohaFullWidth",
+        DecimalZero = "decimalZero",
+        Bullet = "bullet",
+        Ganada = "ganada",
+        Chosung = "chosung",
+        DecimalEnclosedFullstop = "decimalEnclosedFullstop",
+        DecimalEnclosedParen = "decimalEnclosedParen",
+        DecimalEnclosedCircleChinese = "decimalEnclosedCircleChinese",
+        IdeographEnclosedCircle = "ideographEnclosedCircle",
+        IdeographTraditional = "ideographTraditional",
+        IdeographZodiac = "ideographZodiac",
+        IdeographZodiacTraditional = "ideographZodiacTraditional",
+        TaiwaneseCounting = "taiwaneseCounting",
+        IdeographLegalTraditional = "ideographLegalTraditional",
+        TaiwaneseCountingThousand = "taiwaneseCountingThousand",
+        TaiwaneseDigital = "taiwaneseDigital",
+        ChineseCounting = "chineseCounting",
+        ChineseLegalSimplified = "chineseLegalSimplified",
+        ChineseCountingThousand = "chineseCountingThousand",
+        KoreanDigital = "koreanDigital",
+        KoreanCounting = "koreanCounting",
+        KoreanLegal = "koreanLegal",
+        KoreanDigital2 = "koreanDigital2",
+        VietnameseCounting = "vietnameseCounting",
+        RussianLower = "russianLower",
+        RussianUpper = "russianUpper",
+        None = "none",
+        NumberInDash = "numberInDash",
+        Hebrew1 = "hebrew1",
+        Hebrew2 = "hebrew2",
+        ArabicAlpha = "arabicAlpha",
+        ArabicAbjad = "arabicAbjad",
+        HindiVowels = "hindiVowels",
+        HindiConsonants = "hindiConsonants",
+        HindiNumbers = "hindiNumbers",
+        HindiCounting = "hindiCounting",
+        ThaiLetters = "thaiLetters",
+        ThaiNumbers = "thaiNumbers",
+        ThaiCounting = "thaiCounting",
+        BahtText = "bahtText",
+        DollarText = "dollarText",
+        Custom = "custom",
     }
 }
 
@@ -353,8 +541,7 @@ __define_enum! {
     }
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:sectPrChange")]
 pub struct Revision<'a> {
     #[xml(attr = "w:id")]
@@ -368,24 +555,21 @@ pub struct Revision<'a> {
     pub section_property: Option<PreviousSectionProperty<'a>>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:type")]
 pub struct SectionTypeP {
     #[xml(attr = "w:val")]
     pub ty: Option<SectionType>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:formProt")]
 pub struct FormProt {
     #[xml(attr = "w:val")]
     pub val: Option<bool>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:noEndnote")]
 pub struct NoEndnote {
     #[xml(attr = "w:val")]
@@ -400,8 +584,7 @@ pub struct NoEndnote {
 //     pub val: Option<bool>,
 // }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:paperSrc")]
 pub struct PaperSource {
     #[xml(attr = "w:first")]
@@ -410,6 +593,15 @@ pub struct PaperSource {
     pub other: Option<isize>,
 }
 
+/// The relationship to the printer settings of a section, a binary part
+/// written as is, see [`crate::Docx::other_parts`]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
+#[xml(tag = "w:printerSettings")]
+pub struct PrinterSettings<'a> {
+    #[xml(attr = "r:id")]
+    pub id: Cow<'a, str>,
+}
+
 __define_struct! {
     ("w:textAlignment", TextAlignment) {
         "w:val", val, TextAlignmentType
@@ -442,16 +634,14 @@ __define_enum! {
     }
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:textDirection")]
 pub struct TextDirection {
     #[xml(attr = "w:val")]
     pub val: TextDirectionType,
 }
 
-#[derive(Debug, Default, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub enum TextDirectionType {
     #[default]
     LrTb, //	Left to Right, Top to Bottom
@@ -473,8 +663,17 @@ __string_enum! {
     }
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+/// Vertical Text Alignment on Page
+///
+/// Aligns the text of the pages of a section between the top and bottom
+/// margins, like a vertically centered title page.
+///
+/// ```rust
+/// use docx_rust::formatting::{SectionProperty, VAlignType};
+///
+/// let section = SectionProperty::default().v_align(VAlignType::Center);
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:vAlign")]
 pub struct VAlign {
     #[xml(attr = "w:val")]
@@ -487,14 +686,17 @@ impl From<VAlignType> for VAlign {
     }
 }
 
-#[derive(Debug, Default, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub enum VAlignType {
     #[default]
-    Top, //	Align Top
-    Center, //	Align Center
-    Both,   //	Vertical Justification
-    Bottom, //	Align Bottom
+    /// Align Top
+    Top,
+    /// Align Center
+    Center,
+    /// Vertical Justification, spacing the paragraphs to fill the page
+    Both,
+    /// Align Bottom
+    Bottom,
 }
 
 __string_enum! {
@@ -506,16 +708,14 @@ __string_enum! {
     }
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:rtlGutter")]
 pub struct RtlGutter {
     #[xml(attr = "w:val")]
     pub val: Option<bool>,
 }
 
-#[derive(Debug, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Clone, PartialEq)]
 pub enum SectionType {
     NextPage,
     NextColumn,
@@ -534,13 +734,11 @@ __string_enum! {
     }
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:titlePg")]
 pub struct TitlePage {}
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:footnotePr")]
 pub struct FootnoteProperty {
     #[xml(child = "w:pos")]
@@ -555,10 +753,27 @@ pub struct FootnoteProperty {
     pub num_restart: Option<NumRestart>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+/// Section-Wide Endnote Properties
+///
+/// Word takes the position of the endnotes from the document settings, see
+/// [`crate::settings::Settings::set_endnote_position`], and the numbering
+/// from the sections.
+///
+/// ```rust
+/// use docx_rust::formatting::*;
+///
+/// let mut section = SectionProperty::default().endnote_property(
+///     EndnoteProperty::default()
+///         .position(EndnotePositionType::SectEnd)
+///         .num_fmt(NumFmt { ty: NumFmtType::LowerRoman })
+///         .num_restart(NumRestart { val: NumRestartType::EachSect }),
+/// );
+/// section.set_suppress_endnotes(true);
+/// ```
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:endnotePr")]
 pub struct EndnoteProperty {
+    /// Endnote Placement
     #[xml(child = "w:pos")]
     pub position: Option<EndnotePosition>,
     #[xml(child = "w:numFmt")]
@@ -571,6 +786,13 @@ pub struct EndnoteProperty {
     pub num_restart: Option<NumRestart>,
 }
 
+impl EndnoteProperty {
+    __setter!(position: Option<EndnotePosition>);
+    __setter!(num_fmt: Option<NumFmt>);
+    __setter!(num_start: Option<NumStart>);
+    __setter!(num_restart: Option<NumRestart>);
+}
+
 #[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
 #[cfg_attr(test, derive(PartialEq))]
 #[xml(tag = "w:footnotePr")]
@@ -623,16 +845,14 @@ pub struct Endnote {
     pub id: isize,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:numRestart")]
 pub struct NumRestart {
     #[xml(attr = "w:val")]
     pub val: NumRestartType,
 }
 
-#[derive(Debug, Default, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub enum NumRestartType {
     #[default]
     Continuous, //	Continue Numbering From Previous Section
@@ -648,163 +868,31 @@ __string_enum! {
     }
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:numStart")]
 pub struct NumStart {
     #[xml(attr = "w:val")]
     pub val: Option<isize>,
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:numFmt")]
 pub struct NumFmt {
     #[xml(attr = "w:val")]
     pub ty: NumFmtType,
 }
 
-#[derive(Debug, Default, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
-pub enum NumFmtType {
-    #[default]
-    Decimal, //Decimal Numbers.
-    UpperRoman,                   //Uppercase Roman Numerals.
-    LowerRoman,                   //Lowercase Roman Numerals.
-    UpperLetter,                  //Uppercase Latin Alphabet.
-    LowerLetter,                  //Lowercase Latin Alphabet.
-    Ordinal,                      //Ordinal.
-    CardinalText,                 //Cardinal Text.
-    OrdinalText,                  //Ordinal Text.
-    Hex,                          //Hexadecimal Numbering.
-    Chicago,                      //Chicago Manual of Style.
-    IdeographDigital,             //Ideographs.
-    JapaneseCounting,             //Japanese Counting System.
-    Aiueo,                        //AIUEO Order Hiragana.
-    Iroha,                        //Iroha Ordered Katakana.
-    DecimalFullWidth,             //Double Byte Arabic Numerals.
-    DecimalHalfWidth,             //Single Byte Arabic Numerals.
-    JapaneseLegal,                //Japanese Legal Numbering.
-    JapaneseDigitalTenThousand,   //Japanese Digital Ten Thousand Counting System.
-    DecimalEnclosedCircle,        //Decimal Numbers Enclosed in a Circle.
-    DecimalFullWidth2,            //Double Byte Arabic Numerals Alternate.
-    AiueoFullWidth,               //Full-Width AIUEO Order Hiragana.
-    IrohaFullWidth,               //Full-Width Iroha Ordered Katakana.
-    DecimalZero,                  //Initial Zero Arabic Numerals.
-    Bullet,                       //Bullet.
-    Ganada,                       //Korean Ganada Numbering.
-    Chosung,                      //Korean Chosung Numbering.
-    DecimalEnclosedFullstop,      //Decimal Numbers Followed by a Period.
-    DecimalEnclosedParen,         //Decimal Numbers Enclosed in Parenthesis.
-    DecimalEnclosedCircleChinese, //Decimal Numbers Enclosed in a Circle.
-    IdeographEnclosedCircle,      //Ideographs Enclosed in a Circle.
-    IdeographTraditional,         //Traditional Ideograph Format.
-    IdeographZodiac,              //Zodiac Ideograph Format.
-    IdeographZodiacTraditional,   //Traditional Zodiac Ideograph Format.
-    TaiwaneseCounting,            //Taiwanese Counting System.
-    IdeographLegalTraditional,    //Traditional Legal Ideograph Format.
-    TaiwaneseCountingThousand,    //Taiwanese Counting Thousand System.
-    TaiwaneseDigital,             //Taiwanese Digital Counting System.
-    ChineseCounting,              //Chinese Counting System.
-    ChineseLegalSimplified,       //Chinese Legal Simplified Format.
-    ChineseCountingThousand,      //Chinese Counting Thousand System.
-    KoreanDigital,                //Korean Digital Counting System.
-    KoreanCounting,               //Korean Counting System.
-    KoreanLegal,                  //Korean Legal Numbering.
-    KoreanDigital2,               //Korean Digital Counting System Alternate.
-    VietnameseCounting,           //Vietnamese Numerals.
-    RussianLower,                 //Lowercase Russian Alphabet.
-    RussianUpper,                 //Uppercase Russian Alphabet.
-    None,                         //No Numbering.
-    NumberInDash,                 //Number With Dashes.
-    Hebrew1,                      //Hebrew Numerals.
-    Hebrew2,                      //Hebrew Alphabet.
-    ArabicAlpha,                  //Arabic Alphabet.
-    ArabicAbjad,                  //Arabic Abjad Numerals.
-    HindiVowels,                  //Hindi Vowels.
-    HindiConsonants,              //Hindi Consonants.
-    HindiNumbers,                 //Hindi Numbers.
-    HindiCounting,                //Hindi Counting System.
-    ThaiLetters,                  //Thai Letters.
-    ThaiNumbers,                  //Thai Numerals.
-    ThaiCounting,                 //Thai Counting System.
-}
+/// The number format of footnotes and endnotes
+pub type NumFmtType = NumberFormat;
 
-__string_enum! {
-    NumFmtType {
-        Decimal = "decimal",
-        UpperRoman = "upperRoman",
-        LowerRoman = "lowerRoman",
-        UpperLetter = "upperLetter",
-        LowerLetter = "lowerLetter",
-        Ordinal = "ordinal",
-        CardinalText = "cardinalText",
-        OrdinalText = "ordinalText",
-        Hex = "hex",
-        Chicago = "chicago",
-        IdeographDigital = "ideographDigital",
-        JapaneseCounting = "japaneseCounting",
-        Aiueo = "aiueo",
-        Iroha = "iroha",
-        DecimalFullWidth = "decimalFullWidth",
-        DecimalHalfWidth = "decimalHalfWidth",
-        JapaneseLegal = "japaneseLegal",
-        JapaneseDigitalTenThousand = "japaneseDigitalTenThousand",
-        DecimalEnclosedCircle = "decimalEnclosedCircle",
-        DecimalFullWidth2 = "decimalFullWidth2",
-        AiueoFullWidth = "aiueoFullWidth",
-        IrohaFullWidth = "irohaFullWidth",
-        DecimalZero = "decimalZero",
-        Bullet = "bullet",
-        Ganada = "ganada",
-        Chosung = "chosung",
-        DecimalEnclosedFullstop = "decimalEnclosedFullstop",
-        DecimalEnclosedParen = "decimalEnclosedParen",
-        DecimalEnclosedCircleChinese = "decimalEnclosedCircleChinese",
-        IdeographEnclosedCircle = "ideographEnclosedCircle",
-        IdeographTraditional = "ideographTraditional",
-        IdeographZodiac = "ideographZodiac",
-        IdeographZodiacTraditional = "ideographZodiacTraditional",
-        TaiwaneseCounting = "taiwaneseCounting",
-        IdeographLegalTraditional = "ideographLegalTraditional",
-        TaiwaneseCountingThousand = "taiwaneseCountingThousand",
-        TaiwaneseDigital = "taiwaneseDigital",
-        ChineseCounting = "chineseCounting",
-        ChineseLegalSimplified = "chineseLegalSimplified",
-        ChineseCountingThousand = "chineseCountingThousand",
-        KoreanDigital = "koreanDigital",
-        KoreanCounting = "koreanCounting",
-        KoreanLegal = "koreanLegal",
-        KoreanDigital2 = "koreanDigital2",
-        VietnameseCounting = "vietnameseCounting",
-        RussianLower = "russianLower",
-        RussianUpper = "russianUpper",
-        None = "none",
-        NumberInDash = "numberInDash",
-        Hebrew1 = "hebrew1",
-        Hebrew2 = "hebrew2",
-        ArabicAlpha = "arabicAlpha",
-        ArabicAbjad = "arabicAbjad",
-        HindiVowels = "hindiVowels",
-        HindiConsonants = "hindiConsonants",
-        HindiNumbers = "hindiNumbers",
-        HindiCounting = "hindiCounting",
-        ThaiLetters = "thaiLetters",
-        ThaiNumbers = "thaiNumbers",
-        ThaiCounting = "thaiCounting",
-    }
-}
-
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:pos")]
 pub struct FootnotePosition {
     #[xml(attr = "w:val")]
     pub val: PositionType,
 }
 
-#[derive(Debug, Default, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub enum PositionType {
     #[default]
     PageBottom, //	Footnotes Positioned at Page Bottom
@@ -822,22 +910,26 @@ __string_enum! {
     }
 }
 
-#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:pos")]
 pub struct EndnotePosition {
     #[xml(attr = "w:val")]
     pub val: EndnotePositionType,
 }
 
-#[derive(Debug, Default, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub enum EndnotePositionType {
     #[default]
     SectEnd, //	Footnotes Positioned At End of Section
     DocEnd, //	Footnotes Positioned At End of Document
 }
 
+impl From<EndnotePositionType> for EndnotePosition {
+    fn from(val: EndnotePositionType) -> Self {
+        EndnotePosition { val }
+    }
+}
+
 __string_enum! {
     EndnotePositionType {
         SectEnd = "sectEnd",
@@ -846,22 +938,106 @@ __string_enum! {
 }
 
 impl<'a> SectionProperty<'a> {
+    __setter!(page_size: Option<PageSize>);
+    __setter!(page_margin: Option<PageMargin>);
+    __setter!(page_borders: Option<PgBorders>);
+    __setter!(cols: Option<PageCols>);
+    __setter!(v_align: Option<VAlign>);
+    __setter!(endnote_property: Option<EndnoteProperty>);
     //     __setter!(style_id: Option<SectionStyleId<'a>>);
     //     __setter!(justification: Option<Justification>);
     //     __setter!(border: Option<Borders<'a>>);
     //     __setter!(numbering: Option<NumberingProperty>);
     //     __setter!(spacing: Option<Spacing>);
     //     __setter!(indent: Option<Indent>);
-    fn first_page_has_diffrent_header_and_footer(&mut self, val: bool) -> &mut Self {
-        if val {
-            self.title_page = Some(TitlePage::default());
-        } else {
-            self.title_page = None;
-        }
+    /// Sets whether the first page has its own header and footer, the
+    /// [`HeaderFooterReferenceType::First`] ones.
+    pub fn set_title_page(&mut self, on: bool) -> &mut Self {
+        self.title_page = on.then(TitlePage::default);
+        self
+    }
+
+    /// Sets whether the endnotes of this section are suppressed, being
+    /// placed with the endnotes of the next section instead.
+    pub fn set_suppress_endnotes(&mut self, on: bool) -> &mut Self {
+        self.no_endnote = on.then(NoEndnote::default);
+        self
+    }
+
+    /// Returns the relationship id of the header shown on the given pages.
+    pub fn header_reference(&self, ty: &HeaderFooterReferenceType) -> Option<&str> {
+        self.header_footer_references
+            .iter()
+            .find_map(|reference| match reference {
+                HeaderFooterReference::Header(header) if is_type(&header.ty, ty) => {
+                    header.id.as_deref()
+                }
+                _ => None,
+            })
+    }
+
+    /// Returns the relationship id of the footer shown on the given pages.
+    pub fn footer_reference(&self, ty: &HeaderFooterReferenceType) -> Option<&str> {
+        self.header_footer_references
+            .iter()
+            .find_map(|reference| match reference {
+                HeaderFooterReference::Footer(footer) if is_type(&footer.ty, ty) => {
+                    footer.id.as_deref()
+                }
+                _ => None,
+            })
+    }
+
+    /// Sets the header shown on the given pages, replacing the previous one.
+    pub fn set_header_reference<T: Into<Cow<'a, str>>>(
+        &mut self,
+        ty: HeaderFooterReferenceType,
+        id: T,
+    ) -> &mut Self {
+        self.header_footer_references.retain(|reference| {
+            !matches!(reference, HeaderFooterReference::Header(header) if is_type(&header.ty, &ty))
+        });
+        self.header_footer_references
+            .push(HeaderReference::default().ty(ty).id(id.into()).into());
+        self
+    }
+
+    /// Sets the footer shown on the given pages, replacing the previous one.
+    pub fn set_footer_reference<T: Into<Cow<'a, str>>>(
+        &mut self,
+        ty: HeaderFooterReferenceType,
+        id: T,
+    ) -> &mut Self {
+        self.header_footer_references.retain(|reference| {
+            !matches!(reference, HeaderFooterReference::Footer(footer) if is_type(&footer.ty, &ty))
+        });
+        self.header_footer_references
+            .push(FooterReference::default().ty(ty).id(id.into()).into());
         self
     }
 }
 
+/// Returns whether a reference is of the given type, references without a
+/// type being the default ones.
+fn is_type(ty: &Option<HeaderFooterReferenceType>, expected: &HeaderFooterReferenceType) -> bool {
+    matches!(
+        (
+            ty.as_ref().unwrap_or(&HeaderFooterReferenceType::Default),
+            expected
+        ),
+        (
+            HeaderFooterReferenceType::Default,
+            HeaderFooterReferenceType::Default
+        ) | (
+            HeaderFooterReferenceType::Even,
+            HeaderFooterReferenceType::Even
+        ) | (
+            HeaderFooterReferenceType::First,
+            HeaderFooterReferenceType::First
+        )
+    )
+}
+
 // #[derive(Debug, XmlRead, XmlWrite, Clone)]
 // #[cfg_attr(test, derive(PartialEq))]
 // #[xml(tag = "w:pStyle")]
@@ -876,19 +1052,21 @@ impl<'a> SectionProperty<'a> {
 //     }
 // }
 
-// #[cfg(test)]
-// use crate::formatting::JustificationVal;
-
-// __xml_test_suites!(
-//     SectionProperty,
-//     SectionProperty::default(),
-//     r#"<w:pPr/>"#,
-//     SectionProperty::default().style_id("id"),
-//     r#"<w:pPr><w:pStyle w:val="id"/></w:pPr>"#,
-//     SectionProperty::default().justification(JustificationVal::Start),
-//     r#"<w:pPr><w:jc w:val="start"/></w:pPr>"#,
-//     SectionProperty::default().border(Borders::default()),
-//     r#"<w:pPr><w:pBdr/></w:pPr>"#,
-//     SectionProperty::default().numbering(NumberingProperty::default()),
-//     r#"<w:pPr><w:numPr><w:numId w:val="0"/><w:ilvl w:val="0"/></w:numPr></w:pPr>"#,
-// );
+__xml_test_suites!(
+    SectionProperty,
+    SectionProperty::default(),
+    r#"<w:sectPr/>"#,
+    SectionProperty::default().v_align(VAlignType::Center),
+    r#"<w:sectPr><w:vAlign w:val="center"/></w:sectPr>"#,
+    SectionProperty {
+        no_endnote: Some(NoEndnote::default()),
+        ..Default::default()
+    }
+    .endnote_property(EndnoteProperty::default().position(EndnotePositionType::SectEnd)),
+    r#"<w:sectPr><w:endnotePr><w:pos w:val="sectEnd"/></w:endnotePr><w:noEndnote/></w:sectPr>"#,
+    SectionProperty {
+        printer_settings: Some(PrinterSettings { id: "rId9".into() }),
+        ..Default::default()
+    },
+    r#"<w:sectPr><w:printerSettings r:id="rId9"/></w:sectPr>"#,
+);
@@ -9,8 +9,7 @@ use crate::__xml_test_suites;
 ///
 /// let sz = Size::from(42isize);
 /// ```
-#[derive(Debug, XmlRead, XmlWrite, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, XmlRead, XmlWrite, Clone, PartialEq)]
 #[xml(tag = "w:sz")]
 pub struct Size {
     #[xml(attr = "w:val")]
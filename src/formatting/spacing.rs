@@ -1,22 +1,26 @@
-#![allow(dead_code)]
-
 use hard_xml::{XmlRead, XmlWrite};
 
-use crate::{__setter, __xml_test_suites};
+use crate::{__merge, __setter, __xml_test_suites};
 
 use super::line_rule::LineRule;
 
 /// Spacing
-/// 100 percent.
-/// line is 240-based.
+///
+/// Lengths are in twentieths of a point, the `*_lines` ones in hundredths of
+/// a line. See [`Spacing::with_line_spacing`] for the spacing between lines.
 ///
 /// ```rust
 /// use docx_rust::formatting::*;
 ///
-/// let sz = Size::from(42isize);
+/// let spacing = Spacing::default()
+///     .before_pt(6.0)
+///     .after_auto_spacing(true)
+///     .with_line_spacing(LineSpacing::Multiple(1.5));
+/// assert_eq!(spacing.before, Some(120));
+/// assert_eq!(spacing.line, Some(360));
+/// assert_eq!(spacing.line_spacing(), Some(LineSpacing::Multiple(1.5)));
 /// ```
-#[derive(Debug, XmlRead, XmlWrite, Clone, Default)]
-#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, XmlRead, XmlWrite, Clone, Default, PartialEq)]
 #[xml(tag = "w:spacing")]
 pub struct Spacing {
     /// Spacing Above Paragraph In Line Units
@@ -25,49 +29,123 @@ pub struct Spacing {
     /// Spacing Above Paragraph
     #[xml(attr = "w:before")]
     pub before: Option<isize>,
-    /// Automatically Determine Spacing Above Paragraph
+    /// Automatically Determine Spacing Above Paragraph, ignoring `before`
     #[xml(attr = "w:beforeAutospacing")]
     pub before_auto_spacing: Option<bool>,
+    /// Spacing Below Paragraph In Line Units
     #[xml(attr = "w:afterLines")]
     pub after_lines: Option<isize>,
+    /// Spacing Below Paragraph
     #[xml(attr = "w:after")]
     pub after: Option<isize>,
+    /// Automatically Determine Spacing Below Paragraph, ignoring `after`
     #[xml(attr = "w:afterAutospacing")]
     pub after_auto_spacing: Option<bool>,
+    /// Spacing Between Lines, in 240ths of a line if `line_rule` is `auto`
     #[xml(attr = "w:line")]
     pub line: Option<isize>,
     #[xml(attr = "w:lineRule")]
     pub line_rule: Option<LineRule>,
 }
 
+/// The spacing between the lines of a paragraph
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LineSpacing {
+    /// A multiple of single line spacing, e.g. `2.0` for double spacing
+    Multiple(f64),
+    /// An exact height in twentieths of a point
+    Exact(isize),
+    /// A minimal height in twentieths of a point
+    AtLeast(isize),
+}
+
+impl LineSpacing {
+    /// Creates an exact line height in points.
+    pub fn exact_pt(pt: f64) -> Self {
+        LineSpacing::Exact(twips(pt))
+    }
+
+    /// Creates a minimal line height in points.
+    pub fn at_least_pt(pt: f64) -> Self {
+        LineSpacing::AtLeast(twips(pt))
+    }
+}
+
+fn twips(pt: f64) -> isize {
+    (pt * 20.0).round() as isize
+}
+
 impl Spacing {
-    fn before<T>(mut self, val: T) -> Self
-    where
-        isize: From<T>,
-    {
-        self.before = Some(val.into());
-        self.before_lines = self.before;
+    __setter!(before_lines: Option<isize>);
+    __setter!(before: Option<isize>);
+    __setter!(before_auto_spacing: Option<bool>);
+    __setter!(after_lines: Option<isize>);
+    __setter!(after: Option<isize>);
+    __setter!(after_auto_spacing: Option<bool>);
+    __setter!(line: Option<isize>);
+    __setter!(line_rule: Option<LineRule>);
+
+    /// Overrides this spacing with the attributes set in `other`, keeping
+    /// e.g. the spacing above when `other` only sets the spacing below.
+    pub fn merge(mut self, other: &Spacing) -> Self {
+        __merge!(
+            self,
+            other,
+            (before_lines, before, before_auto_spacing),
+            (after_lines, after, after_auto_spacing),
+            (line, line_rule),
+        );
         self
     }
-    fn after<T>(mut self, val: T) -> Self
-    where
-        isize: From<T>,
-    {
-        self.after = Some(val.into());
-        self.after_lines = self.before;
+
+    /// Sets the spacing above the paragraph in points.
+    pub fn before_pt(self, pt: f64) -> Self {
+        self.before(twips(pt))
+    }
+
+    /// Sets the spacing below the paragraph in points.
+    pub fn after_pt(self, pt: f64) -> Self {
+        self.after(twips(pt))
+    }
+
+    /// Sets the spacing between lines.
+    pub fn with_line_spacing(mut self, spacing: LineSpacing) -> Self {
+        let (line, rule) = match spacing {
+            LineSpacing::Multiple(multiple) => {
+                ((multiple * 240.0).round() as isize, LineRule::Auto)
+            }
+            LineSpacing::Exact(twips) => (twips, LineRule::Exact),
+            LineSpacing::AtLeast(twips) => (twips, LineRule::AtLeast),
+        };
+        self.line = Some(line);
+        self.line_rule = Some(rule);
         self
     }
 
-    __setter!(line: Option<isize>);
-    __setter!(line_rule: Option<LineRule>);
+    /// Returns the spacing between lines, if set.
+    pub fn line_spacing(&self) -> Option<LineSpacing> {
+        let line = self.line?;
+        Some(match self.line_rule {
+            Some(LineRule::Exact) => LineSpacing::Exact(line),
+            Some(LineRule::AtLeast) => LineSpacing::AtLeast(line),
+            Some(LineRule::Auto) | None => LineSpacing::Multiple(line as f64 / 240.0),
+        })
+    }
 }
 
 __xml_test_suites!(
     Spacing,
     Spacing::default()
+        .before_lines(50isize)
         .before(50isize)
+        .after_lines(50isize)
         .after(50isize)
         .line(384isize)
         .line_rule(LineRule::Auto),
     r#"<w:spacing w:beforeLines="50" w:before="50" w:afterLines="50" w:after="50" w:line="384" w:lineRule="auto"/>"#,
+    Spacing::default()
+        .before_auto_spacing(true)
+        .after_pt(12.0)
+        .with_line_spacing(LineSpacing::at_least_pt(14.5)),
+    r#"<w:spacing w:beforeAutospacing="true" w:after="240" w:line="290" w:lineRule="atLeast"/>"#,
 );
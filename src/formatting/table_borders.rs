@@ -3,7 +3,8 @@ use hard_xml::{XmlRead, XmlWrite};
 use crate::{
     __setter, __xml_test_suites,
     formatting::{
-        BottomBorder, InsideHorizonBorder, InsideVerticalBorder, LeftBorder, RightBorder, TopBorder,
+        BorderStyle, BottomBorder, InsideHorizonBorder, InsideVerticalBorder, LeftBorder,
+        RightBorder, TopBorder,
     },
 };
 
@@ -28,6 +29,23 @@ pub struct TableBorders<'a> {
 impl<'a> TableBorders<'a> {
     __setter!(top: Option<TopBorder<'a>>);
     __setter!(bottom: Option<BottomBorder<'a>>);
+
+    /// Creates the same border on all edges and between all cells, `size` in
+    /// eighths of a point.
+    pub fn all(style: BorderStyle, size: isize) -> Self {
+        TableBorders {
+            top: Some(TopBorder::default().style(style.clone()).size(size)),
+            left: Some(LeftBorder::default().style(style.clone()).size(size)),
+            bottom: Some(BottomBorder::default().style(style.clone()).size(size)),
+            right: Some(RightBorder::default().style(style.clone()).size(size)),
+            inside_horizon: Some(
+                InsideHorizonBorder::default()
+                    .style(style.clone())
+                    .size(size),
+            ),
+            inside_vertical: Some(InsideVerticalBorder::default().style(style).size(size)),
+        }
+    }
 }
 
 __xml_test_suites!(
@@ -1,6 +1,7 @@
 use hard_xml::{XmlRead, XmlWrite};
 
-use crate::{__string_enum, __xml_test_suites};
+use crate::__xml_test_suites;
+use crate::formatting::JustificationVal;
 
 /// Table Justification
 ///
@@ -23,25 +24,8 @@ impl From<TableJustificationVal> for TableJustification {
     }
 }
 
-#[derive(Debug, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
-pub enum TableJustificationVal {
-    Start,
-    End,
-    Center,
-    Left,
-    Right,
-}
-
-__string_enum! {
-    TableJustificationVal {
-        Start = "start",
-        End = "end",
-        Center = "center",
-        Left = "left",
-        Right = "right",
-    }
-}
+/// Table alignment, which takes the same values as paragraph alignment
+pub type TableJustificationVal = JustificationVal;
 
 __xml_test_suites!(
     TableJustification,
@@ -49,4 +33,6 @@ __xml_test_suites!(
     "<w:jc/>",
     TableJustification::from(TableJustificationVal::Start),
     r#"<w:jc w:val="start"/>"#,
+    TableJustification::from(TableJustificationVal::Both),
+    r#"<w:jc w:val="both"/>"#,
 );
@@ -35,6 +35,12 @@ pub struct TableProperty<'a> {
     pub borders: Option<TableBorders<'a>>,
     #[xml(child = "w:tblCellMar")]
     pub margins: Option<TableMargins<'a>>,
+    /// Specifies the caption of the table, read by screen readers
+    #[xml(child = "w:tblCaption")]
+    pub caption: Option<TableCaption<'a>>,
+    /// Specifies the description of the table, read by screen readers
+    #[xml(child = "w:tblDescription")]
+    pub description: Option<TableDescription<'a>>,
 }
 
 impl<'a> TableProperty<'a> {
@@ -43,6 +49,8 @@ impl<'a> TableProperty<'a> {
     __setter!(borders: Option<TableBorders<'a>>);
     __setter!(indent: Option<TableIndent>);
     __setter!(width: Option<TableWidth>);
+    __setter!(caption: Option<TableCaption<'a>>);
+    __setter!(description: Option<TableDescription<'a>>);
 }
 
 #[derive(Debug, XmlRead, XmlWrite, Clone)]
@@ -59,6 +67,34 @@ impl<'a, T: Into<Cow<'a, str>>> From<T> for TableStyleId<'a> {
     }
 }
 
+#[derive(Debug, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:tblCaption")]
+pub struct TableCaption<'a> {
+    #[xml(attr = "w:val")]
+    pub value: Cow<'a, str>,
+}
+
+impl<'a, T: Into<Cow<'a, str>>> From<T> for TableCaption<'a> {
+    fn from(val: T) -> Self {
+        TableCaption { value: val.into() }
+    }
+}
+
+#[derive(Debug, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:tblDescription")]
+pub struct TableDescription<'a> {
+    #[xml(attr = "w:val")]
+    pub value: Cow<'a, str>,
+}
+
+impl<'a, T: Into<Cow<'a, str>>> From<T> for TableDescription<'a> {
+    fn from(val: T) -> Self {
+        TableDescription { value: val.into() }
+    }
+}
+
 #[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
 #[cfg_attr(test, derive(PartialEq))]
 #[xml(tag = "w:tblStylePr")]
@@ -130,4 +166,8 @@ __xml_test_suites!(
     r#"<w:tblPr><w:tblInd/></w:tblPr>"#,
     TableProperty::default().width(TableWidth::default()),
     r#"<w:tblPr><w:tblW/></w:tblPr>"#,
+    TableProperty::default()
+        .caption("Sales")
+        .description("Sales per quarter"),
+    r#"<w:tblPr><w:tblCaption w:val="Sales"/><w:tblDescription w:val="Sales per quarter"/></w:tblPr>"#,
 );
@@ -0,0 +1,56 @@
+//! Entry point for fuzzing the parser and the writer
+//!
+//! ```rust
+//! use docx_rust::fuzz::parse_and_rewrite;
+//!
+//! assert!(parse_and_rewrite(b"PK\x03\x04 not a package").is_err());
+//! ```
+//!
+//! The `fuzz` directory of the repository has a `cargo fuzz` target calling
+//! it:
+//!
+//! ```sh
+//! cargo +nightly fuzz run parse_and_rewrite
+//! ```
+
+use crate::{DocxFile, DocxResult};
+
+/// Parses a package and writes it again, returning the written package.
+///
+/// Malformed packages and parts are reported as errors, e.g. XML that isn't
+/// well-formed as [`DocxError::Xml`](crate::DocxError::Xml).
+pub fn parse_and_rewrite(bytes: &[u8]) -> DocxResult<Vec<u8>> {
+    let file = DocxFile::from_bytes(bytes)?;
+    let mut docx = file.parse()?;
+    docx.write_to_vec()
+}
+
+#[test]
+fn malformed_input() {
+    use std::io::{Cursor, Write};
+    use zip::{write::SimpleFileOptions, ZipWriter};
+
+    use crate::DocxError;
+
+    assert!(matches!(parse_and_rewrite(&[]), Err(DocxError::Zip(_))));
+    assert!(parse_and_rewrite(b"PK\x05\x06\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0").is_err());
+
+    for document in [
+        "<w:document><w:body",
+        "<w:document><w:body>",
+        "<w:document></w:body>",
+    ] {
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        let parts = [
+            ("[Content_Types].xml", "<Types/>"),
+            ("_rels/.rels", "<Relationships/>"),
+            ("word/document.xml", document),
+        ];
+        for (name, xml) in parts {
+            zip.start_file(name, SimpleFileOptions::default()).unwrap();
+            zip.write_all(xml.as_bytes()).unwrap();
+        }
+        let bytes = zip.finish().unwrap().into_inner();
+        assert!(matches!(parse_and_rewrite(&bytes), Err(DocxError::Xml(_))));
+    }
+}
@@ -0,0 +1,527 @@
+//! HTML export of the document body
+//!
+//! Headings, lists, tables, links and pictures become their HTML elements.
+//! Formatting is resolved from the document defaults, the paragraph and
+//! character styles and the direct formatting, and written as inline CSS,
+//! bold, italic, underlined, struck, superscript and subscript text using
+//! the matching elements.
+
+use crate::{
+    document::{
+        BodyContent, BreakType, Drawing, ListLabels, Paragraph, ParagraphContent, Run, RunContent,
+        Table, TableCellContent, TableRowContent,
+    },
+    flat_opc::base64_encode,
+    formatting::OnOffOnlyType,
+    resolve::{ParagraphFormat, TextFormat, Vertical},
+    walk::paragraph_content,
+    Docx,
+};
+
+/// English Metric Units per CSS pixel
+const EMU_PER_PIXEL: u64 = 9525;
+
+impl<'a> Docx<'a> {
+    /// Converts the body of the document to an HTML fragment, embedding
+    /// pictures as data URIs.
+    ///
+    /// ```rust
+    /// use docx_rust::Docx;
+    ///
+    /// let mut docx = Docx::default();
+    /// docx.add_heading(1, "Report");
+    /// docx.add_paragraph("Fish & chips").bold();
+    ///
+    /// let html = docx.to_html();
+    /// assert!(html.starts_with("<h1 style=\"margin-top:12pt\">"));
+    /// assert!(html.ends_with("<p><strong>Fish &amp; chips</strong></p>\n"));
+    /// ```
+    pub fn to_html(&self) -> String {
+        self.to_html_with_images(|name, data| {
+            format!("data:{};base64,{}", mime_type(name), base64_encode(data))
+        })
+    }
+
+    /// Converts the body of the document to an HTML fragment, using
+    /// `image_src` to get the `src` of pictures from their name in the
+    /// package, like `media/image1.png`, and their data.
+    ///
+    /// Use it to extract the pictures to files next to the HTML:
+    ///
+    /// ```no_run
+    /// use docx_rust::DocxFile;
+    ///
+    /// let file = DocxFile::from_file("origin.docx")?;
+    /// let docx = file.parse()?;
+    /// let html = docx.to_html_with_images(|name, data| {
+    ///     let path = name.replace('/', "_");
+    ///     std::fs::write(&path, data).unwrap();
+    ///     path
+    /// });
+    /// # Ok::<(), docx_rust::DocxError>(())
+    /// ```
+    pub fn to_html_with_images<F>(&self, image_src: F) -> String
+    where
+        F: FnMut(&str, &[u8]) -> String,
+    {
+        let defaults = self.default_text_format();
+
+        let mut writer = HtmlWriter {
+            docx: self,
+            labels: self.list_labels(),
+            image_src,
+            defaults,
+            html: String::new(),
+            lists: Vec::new(),
+        };
+        writer.content(&self.document.body.content);
+        writer.close_lists(0);
+
+        let css = writer.defaults.css(&TextFormat::default());
+        if css.is_empty() {
+            writer.html
+        } else {
+            format!("<div style=\"{}\">\n{}</div>\n", css, writer.html)
+        }
+    }
+}
+
+struct HtmlWriter<'d, 'a, F> {
+    docx: &'d Docx<'a>,
+    labels: ListLabels<'d, 'a>,
+    image_src: F,
+    /// Formatting of the document defaults, set on the enclosing element
+    defaults: TextFormat,
+    html: String,
+    /// Lists that are open, each with an open `li` element
+    lists: Vec<&'static str>,
+}
+
+impl<'d, 'a, F: FnMut(&str, &[u8]) -> String> HtmlWriter<'d, 'a, F> {
+    fn content(&mut self, content: &[BodyContent]) {
+        for content in content {
+            match content {
+                BodyContent::Paragraph(para) => self.block(para),
+                BodyContent::Table(table) => {
+                    self.close_lists(0);
+                    self.table(table);
+                }
+                BodyContent::TableCell(cell) => {
+                    for TableCellContent::Paragraph(para) in &cell.content {
+                        self.block(para);
+                    }
+                }
+                BodyContent::Sdt(sdt) => {
+                    if let Some(sdt_content) = &sdt.content {
+                        self.content(&sdt_content.content);
+                    }
+                }
+                BodyContent::SectionProperty(_) | BodyContent::Raw(_) => (),
+            }
+        }
+    }
+
+    /// Writes a paragraph as a heading, list item or paragraph.
+    fn block(&mut self, para: &Paragraph) {
+        let label = self.labels.label(para);
+        if let Some(level) = self.docx.heading_level(para) {
+            self.close_lists(0);
+            let tag = format!("h{}", level.min(6));
+            self.paragraph(para, &tag);
+            self.html.push('\n');
+        } else if let Some(label) = label {
+            let level = self.labels.level(para).unwrap_or_default();
+            let list = match self.labels.number_format(para) {
+                Some("bullet") => List::Bulleted,
+                format => List::Numbered {
+                    ty: match format {
+                        Some("lowerLetter") => "a",
+                        Some("upperLetter") => "A",
+                        Some("lowerRoman") => "i",
+                        Some("upperRoman") => "I",
+                        _ => "1",
+                    },
+                    start: label.trim_end_matches(['.', ')']).parse().ok(),
+                },
+            };
+            self.open_item(level, list);
+            self.paragraph(para, "p");
+        } else {
+            self.close_lists(0);
+            self.paragraph(para, "p");
+            self.html.push('\n');
+        }
+    }
+
+    /// Opens a list item at the given level, closing the items and lists
+    /// that are deeper or of another kind.
+    fn open_item(&mut self, level: usize, list: List) {
+        let tag = list.tag();
+        self.close_lists(level + 1);
+        if self.lists.len() == level + 1 {
+            self.html.push_str("</li>\n");
+            if self.lists[level] != tag {
+                self.html.push_str(&format!("</{}>\n", self.lists[level]));
+                self.lists.pop();
+            }
+        }
+        while self.lists.len() < level + 1 {
+            let depth = self.lists.len();
+            match list {
+                List::Numbered { ty, start } if depth == level => {
+                    self.html.push_str("<ol");
+                    if ty != "1" {
+                        self.html.push_str(&format!(" type=\"{}\"", ty));
+                    }
+                    if let Some(start) = start.filter(|start| *start != 1) {
+                        self.html.push_str(&format!(" start=\"{}\"", start));
+                    }
+                    self.html.push_str(">\n");
+                }
+                _ => self.html.push_str(&format!("<{}>\n", tag)),
+            }
+            self.lists.push(tag);
+            if depth < level {
+                self.html.push_str("<li>\n");
+            }
+        }
+        self.html.push_str("<li>");
+    }
+
+    /// Closes the lists deeper than `depth`.
+    fn close_lists(&mut self, depth: usize) {
+        while self.lists.len() > depth {
+            let tag = self.lists.pop().unwrap_or_default();
+            self.html.push_str(&format!("</li>\n</{}>\n", tag));
+        }
+    }
+
+    fn table(&mut self, table: &Table) {
+        self.html
+            .push_str("<table style=\"border-collapse:collapse\">\n");
+        for row in &table.rows {
+            let header = row
+                .property
+                .table_header
+                .as_ref()
+                .is_some_and(|header| !matches!(header.value, Some(OnOffOnlyType::Off)));
+            let tag = if header { "th" } else { "td" };
+
+            self.html.push_str("<tr>");
+            for cell in &row.cells {
+                let cell = match cell {
+                    TableRowContent::TableCell(cell) => cell,
+                    TableRowContent::SDT(_) => continue,
+                };
+                self.html
+                    .push_str(&format!("<{} style=\"border:1px solid\">", tag));
+                for TableCellContent::Paragraph(para) in &cell.content {
+                    self.paragraph(para, "p");
+                }
+                self.html.push_str(&format!("</{}>", tag));
+            }
+            self.html.push_str("</tr>\n");
+        }
+        self.html.push_str("</table>\n");
+    }
+
+    fn paragraph(&mut self, para: &Paragraph, tag: &str) {
+        let (format, text) = self.docx.resolve_paragraph(para);
+
+        let css = format.css();
+        if css.is_empty() {
+            self.html.push_str(&format!("<{}>", tag));
+        } else {
+            self.html.push_str(&format!("<{} style=\"{}\">", tag, css));
+        }
+        for content in paragraph_content(para) {
+            match content {
+                ParagraphContent::Run(run) => self.run(run, &text),
+                ParagraphContent::Link(link) => {
+                    let href = match (&link.anchor, self.docx.hyperlink_target(link)) {
+                        (Some(anchor), _) => Some(format!("#{}", anchor)),
+                        (None, target) => target.map(str::to_string),
+                    };
+                    match href {
+                        Some(href) => {
+                            self.html
+                                .push_str(&format!("<a href=\"{}\"", escape(&href)));
+                            if let Some(tooltip) = &link.tooltip {
+                                self.html
+                                    .push_str(&format!(" title=\"{}\"", escape(tooltip)));
+                            }
+                            if let Some(frame) = &link.target_frame {
+                                self.html
+                                    .push_str(&format!(" target=\"{}\"", escape(frame)));
+                            }
+                            self.html.push('>');
+                            self.run(&link.content, &text);
+                            self.html.push_str("</a>");
+                        }
+                        None => self.run(&link.content, &text),
+                    }
+                }
+                ParagraphContent::BookmarkStart(bookmark) => {
+                    if let Some(name) = &bookmark.name {
+                        self.html
+                            .push_str(&format!("<a id=\"{}\"></a>", escape(name)));
+                    }
+                }
+                _ => (),
+            }
+        }
+        self.html.push_str(&format!("</{}>", tag));
+    }
+
+    /// Writes a run, `base` being the formatting of its paragraph.
+    fn run(&mut self, run: &Run, base: &TextFormat) {
+        let format = self.docx.resolve_run(run, base);
+
+        let mut content = String::new();
+        for c in &run.content {
+            match c {
+                RunContent::Text(text) => content.push_str(&escape(&text.text)),
+                RunContent::Tab(_) => content.push('\t'),
+                RunContent::Break(br) => match br.ty {
+                    Some(BreakType::Page) => {
+                        content.push_str("<br style=\"page-break-before:always\">")
+                    }
+                    _ => content.push_str("<br>"),
+                },
+                RunContent::CarriageReturn(_) => content.push_str("<br>"),
+                RunContent::NoBreakHyphen(_) => content.push('\u{2011}'),
+                RunContent::SoftHyphen(_) => content.push_str("&shy;"),
+                RunContent::Drawing(drawing) => content.push_str(&self.image(drawing)),
+                _ => (),
+            }
+        }
+        if content.is_empty() {
+            return;
+        }
+
+        let tags = [
+            (format.bold, "strong"),
+            (format.italic, "em"),
+            (format.underline, "u"),
+            (format.strike, "s"),
+            (format.vertical == Vertical::Superscript, "sup"),
+            (format.vertical == Vertical::Subscript, "sub"),
+        ];
+        let css = format.css(&self.defaults);
+        if !css.is_empty() {
+            self.html.push_str(&format!("<span style=\"{}\">", css));
+        }
+        for (on, tag) in tags {
+            if on {
+                self.html.push_str(&format!("<{}>", tag));
+            }
+        }
+        self.html.push_str(&content);
+        for (on, tag) in tags.into_iter().rev() {
+            if on {
+                self.html.push_str(&format!("</{}>", tag));
+            }
+        }
+        if !css.is_empty() {
+            self.html.push_str("</span>");
+        }
+    }
+
+    fn image(&mut self, drawing: &Drawing) -> String {
+        let target = match self
+            .docx
+            .document_rels
+            .as_ref()
+            .and_then(|rels| drawing.image_target(rels))
+        {
+            Some(target) => target,
+            None => return String::new(),
+        };
+        let data = match self.docx.image_data(drawing) {
+            Some((_, data)) => data,
+            None => return String::new(),
+        };
+
+        let extent = match (&drawing.inline, &drawing.anchor) {
+            (Some(inline), _) => inline.extent.as_ref(),
+            (None, Some(anchor)) => anchor.extent.as_ref(),
+            (None, None) => None,
+        };
+        let alt = drawing.description_text().unwrap_or_default();
+        let mut img = format!(
+            "<img src=\"{}\" alt=\"{}\"",
+            escape(&(self.image_src)(target, data)),
+            escape(alt)
+        );
+        if let Some(extent) = extent {
+            img.push_str(&format!(
+                " width=\"{}\" height=\"{}\"",
+                extent.cx / EMU_PER_PIXEL,
+                extent.cy / EMU_PER_PIXEL
+            ));
+        }
+        img.push('>');
+        img
+    }
+}
+
+#[derive(Clone, Copy)]
+enum List {
+    Bulleted,
+    Numbered {
+        /// Value of the `type` attribute
+        ty: &'static str,
+        /// Number of the first item, only known for decimal numbers
+        start: Option<usize>,
+    },
+}
+
+impl List {
+    fn tag(self) -> &'static str {
+        match self {
+            List::Bulleted => "ul",
+            List::Numbered { .. } => "ol",
+        }
+    }
+}
+
+impl TextFormat {
+    /// Returns the CSS of the formatting that differs from `base`, the
+    /// formatting that has its own element excepted.
+    fn css(&self, base: &TextFormat) -> String {
+        let mut css = Vec::new();
+        if self.font != base.font {
+            if let Some(font) = &self.font {
+                css.push(format!("font-family:'{}'", font.replace(['\'', '"'], "")));
+            }
+        }
+        if self.size != base.size {
+            if let Some(size) = self.size {
+                css.push(format!("font-size:{}pt", size as f64 / 2.0));
+            }
+        }
+        if self.color != base.color {
+            css.push(format!(
+                "color:{}",
+                self.color.as_deref().unwrap_or("inherit")
+            ));
+        }
+        if self.background != base.background {
+            if let Some(background) = &self.background {
+                css.push(format!("background-color:{}", background));
+            }
+        }
+        if self.caps && !base.caps {
+            css.push("text-transform:uppercase".to_string());
+        }
+        if self.small_caps && !base.small_caps {
+            css.push("font-variant:small-caps".to_string());
+        }
+        if self.hidden && !base.hidden {
+            css.push("display:none".to_string());
+        }
+        css.join(";")
+    }
+}
+
+impl ParagraphFormat {
+    fn css(&self) -> String {
+        let lengths = [
+            ("margin-left", self.left),
+            ("margin-right", self.right),
+            ("text-indent", self.first_line),
+            ("margin-top", self.before),
+            ("margin-bottom", self.after),
+        ];
+        let mut css: Vec<String> = self
+            .align
+            .map(|align| format!("text-align:{}", align))
+            .into_iter()
+            .collect();
+        css.extend(
+            lengths
+                .into_iter()
+                .filter_map(|(name, twips)| Some(format!("{}:{}pt", name, twips? as f64 / 20.0))),
+        );
+        css.join(";")
+    }
+}
+
+/// Returns the MIME type of a picture from its extension.
+fn mime_type(name: &str) -> &'static str {
+    let ext = name
+        .rsplit_once('.')
+        .map(|(_, ext)| ext.to_ascii_lowercase())
+        .unwrap_or_default();
+    match ext.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "bmp" => "image/bmp",
+        "svg" => "image/svg+xml",
+        "tif" | "tiff" => "image/tiff",
+        _ => "application/octet-stream",
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[test]
+fn html() {
+    use crate::{
+        formatting::{
+            CharacterProperty, Color, Justification, JustificationVal, ParagraphProperty, Size,
+        },
+        styles::{DefaultCharacterProperty, DefaultStyle},
+    };
+
+    let mut docx = Docx::default();
+    docx.styles.default = Some(
+        DefaultStyle::default().character(DefaultCharacterProperty::from(
+            CharacterProperty::default().size(Size::from(22isize)),
+        )),
+    );
+    docx.document.push(
+        Paragraph::default()
+            .property(
+                ParagraphProperty::default()
+                    .justification(Justification::from(JustificationVal::Center)),
+            )
+            .push(
+                Run::default()
+                    .property(
+                        CharacterProperty::default()
+                            .size(Size::from(28isize))
+                            .color(Color::from("FF0000")),
+                    )
+                    .push_text("big <red>"),
+            ),
+    );
+    docx.numbered_list(["one", "  nested", "two"]);
+    docx.add_table(["Name"], [["apples"]]);
+
+    assert_eq!(
+        docx.to_html(),
+        "<div style=\"font-size:11pt\">\n\
+         <p style=\"text-align:center\"><span style=\"font-size:14pt;color:#FF0000\">big &lt;red&gt;</span></p>\n\
+         <ol>\n<li><p>one</p><ol type=\"a\">\n<li><p>nested</p></li>\n</ol>\n</li>\n<li><p>two</p></li>\n</ol>\n\
+         <table style=\"border-collapse:collapse\">\n\
+         <tr><th style=\"border:1px solid\"><p><strong>Name</strong></p></th></tr>\n\
+         <tr><td style=\"border:1px solid\"><p>apples</p></td></tr>\n\
+         </table>\n\
+         </div>\n"
+    );
+    assert_eq!(mime_type("media/image1.PNG"), "image/png");
+}
@@ -0,0 +1,664 @@
+//! Layout description of the document body for renderers
+//!
+//! [`Docx::layout`] resolves the formatting of every paragraph and run from
+//! the document defaults, the styles and the direct formatting, and converts
+//! all measurements to points, so that a PDF renderer doesn't have to
+//! implement style inheritance. [`Layout::to_json`] serializes it.
+//!
+//! ```rust
+//! use docx_rust::Docx;
+//!
+//! let mut docx = Docx::default();
+//! docx.add_heading(1, "Report");
+//! docx.add_paragraph("Hello");
+//!
+//! let layout = docx.layout();
+//! assert_eq!(layout.sections[0].page.width, 612.0);
+//! assert!(layout.to_json().starts_with(r#"{"sections":[{"page":{"width":612,"#));
+//! ```
+
+use crate::{
+    document::{
+        BodyContent, ListLabels, Paragraph, ParagraphContent, Run, RunContent, Table,
+        TableCellContent, TableRowContent,
+    },
+    formatting::{LineSpacing, OnOffOnlyType, SectionProperty, TableWidthUnit},
+    resolve::{TextFormat, Vertical},
+    walk::paragraph_content,
+    Docx,
+};
+
+/// Font size when the document defaults don't have one, in half-points
+const DEFAULT_SIZE: isize = 20;
+
+/// Layout of a document, with measurements in points
+#[derive(Debug, Clone, PartialEq)]
+pub struct Layout {
+    pub sections: Vec<LayoutSection>,
+}
+
+/// A part of the document with the same page geometry
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayoutSection {
+    pub page: PageGeometry,
+    pub blocks: Vec<Block>,
+}
+
+/// Page size and margins
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageGeometry {
+    pub width: f64,
+    pub height: f64,
+    pub margin_top: f64,
+    pub margin_right: f64,
+    pub margin_bottom: f64,
+    pub margin_left: f64,
+    /// Distance from the top edge to the header
+    pub header: f64,
+    /// Distance from the bottom edge to the footer
+    pub footer: f64,
+    pub gutter: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Block {
+    Paragraph(ParagraphLayout),
+    Table(TableLayout),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParagraphLayout {
+    /// Id of the paragraph style
+    pub style: Option<String>,
+    /// Heading level, from 1 to 9
+    pub heading_level: Option<usize>,
+    /// Label of a numbered paragraph, like `1.` or its bullet
+    pub list_label: Option<String>,
+    /// List level, from 0 to 8
+    pub list_level: Option<usize>,
+    /// `left`, `center`, `right` or `justify`
+    pub align: &'static str,
+    pub indent_left: f64,
+    pub indent_right: f64,
+    /// Indentation of the first line, negative when hanging
+    pub indent_first_line: f64,
+    pub space_before: f64,
+    pub space_after: f64,
+    pub line_height: LineHeight,
+    pub spans: Vec<Span>,
+}
+
+/// Line spacing of a paragraph
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LineHeight {
+    /// A multiple of the single line height of the font
+    Multiple(f64),
+    /// Exact height in points
+    Exact(f64),
+    /// Minimum height in points
+    AtLeast(f64),
+}
+
+/// Text with the same formatting
+#[derive(Debug, Clone, PartialEq)]
+pub struct Span {
+    /// Text, with tabs and line breaks as `\t` and `\n`
+    pub text: String,
+    pub font: Option<String>,
+    pub size: f64,
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+    pub strike: bool,
+    pub caps: bool,
+    pub small_caps: bool,
+    pub superscript: bool,
+    pub subscript: bool,
+    /// Color as `#RRGGBB`
+    pub color: Option<String>,
+    /// Highlight or shading, as `#RRGGBB` or a CSS color name
+    pub background: Option<String>,
+    /// Address of a link, `#` followed by the bookmark name for internal
+    /// links
+    pub link: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableLayout {
+    /// Widths of the grid columns
+    pub columns: Vec<f64>,
+    pub rows: Vec<RowLayout>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowLayout {
+    /// Whether the row is repeated at the top of every page
+    pub header: bool,
+    pub cells: Vec<CellLayout>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CellLayout {
+    pub width: Option<f64>,
+    pub paragraphs: Vec<ParagraphLayout>,
+}
+
+impl<'a> Docx<'a> {
+    /// Returns the layout description of the body, see [`crate::layout`].
+    pub fn layout(&self) -> Layout {
+        let mut builder = LayoutBuilder {
+            docx: self,
+            labels: self.list_labels(),
+            sections: Vec::new(),
+            blocks: Vec::new(),
+        };
+        builder.content(&self.document.body.content);
+        let last = self
+            .document
+            .body
+            .content
+            .iter()
+            .rev()
+            .find_map(|content| match content {
+                BodyContent::SectionProperty(section) => Some(section),
+                _ => None,
+            });
+        builder.end_section(last);
+        Layout {
+            sections: builder.sections,
+        }
+    }
+}
+
+struct LayoutBuilder<'d, 'a> {
+    docx: &'d Docx<'a>,
+    labels: ListLabels<'d, 'a>,
+    sections: Vec<LayoutSection>,
+    /// Blocks of the current section
+    blocks: Vec<Block>,
+}
+
+impl LayoutBuilder<'_, '_> {
+    fn content(&mut self, content: &[BodyContent]) {
+        for content in content {
+            match content {
+                BodyContent::Paragraph(para) => {
+                    let layout = self.paragraph(para);
+                    self.blocks.push(Block::Paragraph(layout));
+                    let section = para
+                        .property
+                        .as_ref()
+                        .and_then(|property| property.section_property.as_ref());
+                    if section.is_some() {
+                        self.end_section(section);
+                    }
+                }
+                BodyContent::Table(table) => {
+                    let layout = self.table(table);
+                    self.blocks.push(Block::Table(layout));
+                }
+                BodyContent::TableCell(cell) => {
+                    for TableCellContent::Paragraph(para) in &cell.content {
+                        let layout = self.paragraph(para);
+                        self.blocks.push(Block::Paragraph(layout));
+                    }
+                }
+                BodyContent::Sdt(sdt) => {
+                    if let Some(sdt_content) = &sdt.content {
+                        self.content(&sdt_content.content);
+                    }
+                }
+                BodyContent::SectionProperty(_) | BodyContent::Raw(_) => (),
+            }
+        }
+    }
+
+    fn end_section(&mut self, section: Option<&SectionProperty>) {
+        let size = section.and_then(|section| section.page_size.as_ref());
+        let margin = section.and_then(|section| section.page_margin.as_ref());
+        let margin = |get: fn(&crate::formatting::PageMargin) -> Option<isize>, default| {
+            points(margin.and_then(get).unwrap_or(default))
+        };
+        // Word uses the US Letter size and one inch margins by default
+        let page = PageGeometry {
+            width: points(size.map_or(12240, |size| size.weight)),
+            height: points(size.map_or(15840, |size| size.height)),
+            margin_top: margin(|m| m.top, 1440),
+            margin_right: margin(|m| m.right, 1440),
+            margin_bottom: margin(|m| m.bottom, 1440),
+            margin_left: margin(|m| m.left, 1440),
+            header: margin(|m| m.header, 720),
+            footer: margin(|m| m.footer, 720),
+            gutter: margin(|m| m.gutter, 0),
+        };
+        self.sections.push(LayoutSection {
+            page,
+            blocks: std::mem::take(&mut self.blocks),
+        });
+    }
+
+    fn table(&mut self, table: &Table) -> TableLayout {
+        let rows = table
+            .rows
+            .iter()
+            .map(|row| RowLayout {
+                header: row
+                    .property
+                    .table_header
+                    .as_ref()
+                    .is_some_and(|header| !matches!(header.value, Some(OnOffOnlyType::Off))),
+                cells: row
+                    .cells
+                    .iter()
+                    .filter_map(|cell| match cell {
+                        TableRowContent::TableCell(cell) => Some(cell),
+                        TableRowContent::SDT(_) => None,
+                    })
+                    .map(|cell| CellLayout {
+                        width: cell
+                            .property
+                            .wide
+                            .as_ref()
+                            .filter(|wide| matches!(wide.unit, None | Some(TableWidthUnit::Dxa)))
+                            .and_then(|wide| wide.value)
+                            .map(points),
+                        paragraphs: cell
+                            .content
+                            .iter()
+                            .map(|TableCellContent::Paragraph(para)| self.paragraph(para))
+                            .collect(),
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        TableLayout {
+            columns: table
+                .grids
+                .columns
+                .iter()
+                .map(|column| points(column.width))
+                .collect(),
+            rows,
+        }
+    }
+
+    fn paragraph(&mut self, para: &Paragraph) -> ParagraphLayout {
+        let (format, text) = self.docx.resolve_paragraph(para);
+
+        let mut spans: Vec<Span> = Vec::new();
+        for content in paragraph_content(para) {
+            match content {
+                ParagraphContent::Run(run) => self.push_run(&mut spans, run, &text, None),
+                ParagraphContent::Link(link) => {
+                    let target = match (&link.anchor, self.docx.hyperlink_target(link)) {
+                        (Some(anchor), _) => Some(format!("#{}", anchor)),
+                        (None, target) => target.map(str::to_string),
+                    };
+                    self.push_run(&mut spans, &link.content, &text, target);
+                }
+                _ => (),
+            }
+        }
+
+        ParagraphLayout {
+            style: para
+                .property
+                .as_ref()
+                .and_then(|property| property.style_id.as_ref())
+                .map(|style_id| style_id.value.to_string()),
+            heading_level: self.docx.heading_level(para),
+            list_label: self.labels.label(para),
+            list_level: self.labels.level(para),
+            align: format.align.unwrap_or("left"),
+            indent_left: points(format.left.unwrap_or_default()),
+            indent_right: points(format.right.unwrap_or_default()),
+            indent_first_line: points(format.first_line.unwrap_or_default()),
+            space_before: points(format.before.unwrap_or_default()),
+            space_after: points(format.after.unwrap_or_default()),
+            line_height: match format.line {
+                Some(LineSpacing::Multiple(multiple)) => LineHeight::Multiple(multiple),
+                Some(LineSpacing::Exact(twips)) => LineHeight::Exact(points(twips)),
+                Some(LineSpacing::AtLeast(twips)) => LineHeight::AtLeast(points(twips)),
+                None => LineHeight::Multiple(1.0),
+            },
+            spans,
+        }
+    }
+
+    /// Adds the text of a run, merged into the last span if it has the
+    /// same formatting. Hidden text is skipped.
+    fn push_run(&self, spans: &mut Vec<Span>, run: &Run, base: &TextFormat, link: Option<String>) {
+        let format = self.docx.resolve_run(run, base);
+        if format.hidden {
+            return;
+        }
+
+        let mut text = String::new();
+        for content in &run.content {
+            match content {
+                RunContent::Text(t) => text.push_str(&t.text),
+                RunContent::Tab(_) => text.push('\t'),
+                RunContent::Break(_) | RunContent::CarriageReturn(_) => text.push('\n'),
+                RunContent::NoBreakHyphen(_) => text.push('\u{2011}'),
+                RunContent::SoftHyphen(_) => text.push('\u{ad}'),
+                _ => (),
+            }
+        }
+        if text.is_empty() {
+            return;
+        }
+
+        let span = Span {
+            text,
+            font: format.font,
+            size: format.size.unwrap_or(DEFAULT_SIZE) as f64 / 2.0,
+            bold: format.bold,
+            italic: format.italic,
+            underline: format.underline,
+            strike: format.strike,
+            caps: format.caps,
+            small_caps: format.small_caps,
+            superscript: format.vertical == Vertical::Superscript,
+            subscript: format.vertical == Vertical::Subscript,
+            color: format.color,
+            background: format.background,
+            link,
+        };
+        match spans.last_mut() {
+            Some(last)
+                if Span {
+                    text: last.text.clone(),
+                    ..span.clone()
+                } == *last =>
+            {
+                last.text.push_str(&span.text);
+            }
+            _ => spans.push(span),
+        }
+    }
+}
+
+/// Converts twips to points.
+fn points(twips: isize) -> f64 {
+    twips as f64 / 20.0
+}
+
+impl Layout {
+    /// Serializes the layout as JSON.
+    ///
+    /// Objects have the fields of the structs, `null` for missing values.
+    /// Blocks and line heights are objects with a `type` field, like
+    /// `{"type":"paragraph",...}` or `{"type":"exact","value":12}`.
+    pub fn to_json(&self) -> String {
+        let mut out = String::new();
+        self.json(&mut out);
+        out
+    }
+}
+
+/// Writes a value as JSON
+trait Json {
+    fn json(&self, out: &mut String);
+}
+
+fn object(out: &mut String, fields: &[(&str, &dyn Json)]) {
+    out.push('{');
+    for (i, (name, value)) in fields.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        name.json(out);
+        out.push(':');
+        value.json(out);
+    }
+    out.push('}');
+}
+
+impl Json for str {
+    fn json(&self, out: &mut String) {
+        out.push('"');
+        for c in self.chars() {
+            match c {
+                '"' => out.push_str("\\\""),
+                '\\' => out.push_str("\\\\"),
+                '\n' => out.push_str("\\n"),
+                '\t' => out.push_str("\\t"),
+                '\r' => out.push_str("\\r"),
+                c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+                c => out.push(c),
+            }
+        }
+        out.push('"');
+    }
+}
+
+impl Json for &str {
+    fn json(&self, out: &mut String) {
+        (**self).json(out);
+    }
+}
+
+impl Json for String {
+    fn json(&self, out: &mut String) {
+        self.as_str().json(out);
+    }
+}
+
+impl Json for f64 {
+    fn json(&self, out: &mut String) {
+        if self.is_finite() {
+            out.push_str(&self.to_string());
+        } else {
+            out.push_str("null");
+        }
+    }
+}
+
+impl Json for usize {
+    fn json(&self, out: &mut String) {
+        out.push_str(&self.to_string());
+    }
+}
+
+impl Json for bool {
+    fn json(&self, out: &mut String) {
+        out.push_str(if *self { "true" } else { "false" });
+    }
+}
+
+impl<T: Json> Json for Option<T> {
+    fn json(&self, out: &mut String) {
+        match self {
+            Some(value) => value.json(out),
+            None => out.push_str("null"),
+        }
+    }
+}
+
+impl<T: Json> Json for Vec<T> {
+    fn json(&self, out: &mut String) {
+        out.push('[');
+        for (i, value) in self.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            value.json(out);
+        }
+        out.push(']');
+    }
+}
+
+impl Json for Layout {
+    fn json(&self, out: &mut String) {
+        object(out, &[("sections", &self.sections)]);
+    }
+}
+
+impl Json for LayoutSection {
+    fn json(&self, out: &mut String) {
+        object(out, &[("page", &self.page), ("blocks", &self.blocks)]);
+    }
+}
+
+impl Json for PageGeometry {
+    fn json(&self, out: &mut String) {
+        object(
+            out,
+            &[
+                ("width", &self.width),
+                ("height", &self.height),
+                ("margin_top", &self.margin_top),
+                ("margin_right", &self.margin_right),
+                ("margin_bottom", &self.margin_bottom),
+                ("margin_left", &self.margin_left),
+                ("header", &self.header),
+                ("footer", &self.footer),
+                ("gutter", &self.gutter),
+            ],
+        );
+    }
+}
+
+impl Json for Block {
+    fn json(&self, out: &mut String) {
+        match self {
+            Block::Paragraph(para) => para.json(out),
+            Block::Table(table) => object(
+                out,
+                &[
+                    ("type", &"table"),
+                    ("columns", &table.columns),
+                    ("rows", &table.rows),
+                ],
+            ),
+        }
+    }
+}
+
+impl Json for ParagraphLayout {
+    fn json(&self, out: &mut String) {
+        object(
+            out,
+            &[
+                ("type", &"paragraph"),
+                ("style", &self.style),
+                ("heading_level", &self.heading_level),
+                ("list_label", &self.list_label),
+                ("list_level", &self.list_level),
+                ("align", &self.align),
+                ("indent_left", &self.indent_left),
+                ("indent_right", &self.indent_right),
+                ("indent_first_line", &self.indent_first_line),
+                ("space_before", &self.space_before),
+                ("space_after", &self.space_after),
+                ("line_height", &self.line_height),
+                ("spans", &self.spans),
+            ],
+        );
+    }
+}
+
+impl Json for LineHeight {
+    fn json(&self, out: &mut String) {
+        let (ty, value) = match self {
+            LineHeight::Multiple(value) => ("multiple", value),
+            LineHeight::Exact(value) => ("exact", value),
+            LineHeight::AtLeast(value) => ("at_least", value),
+        };
+        object(out, &[("type", &ty), ("value", value)]);
+    }
+}
+
+impl Json for Span {
+    fn json(&self, out: &mut String) {
+        object(
+            out,
+            &[
+                ("text", &self.text),
+                ("font", &self.font),
+                ("size", &self.size),
+                ("bold", &self.bold),
+                ("italic", &self.italic),
+                ("underline", &self.underline),
+                ("strike", &self.strike),
+                ("caps", &self.caps),
+                ("small_caps", &self.small_caps),
+                ("superscript", &self.superscript),
+                ("subscript", &self.subscript),
+                ("color", &self.color),
+                ("background", &self.background),
+                ("link", &self.link),
+            ],
+        );
+    }
+}
+
+impl Json for RowLayout {
+    fn json(&self, out: &mut String) {
+        object(out, &[("header", &self.header), ("cells", &self.cells)]);
+    }
+}
+
+impl Json for CellLayout {
+    fn json(&self, out: &mut String) {
+        object(
+            out,
+            &[("width", &self.width), ("paragraphs", &self.paragraphs)],
+        );
+    }
+}
+
+#[test]
+fn layout() {
+    use crate::formatting::{PageMargin, PageSize, ParagraphProperty, Spacing};
+
+    let mut docx = Docx::default();
+    docx.add_paragraph("A \"quote\"\tand tab").property =
+        Some(ParagraphProperty::default().spacing(Spacing {
+            before: Some(240),
+            line: Some(360),
+            ..Default::default()
+        }));
+    docx.document
+        .push(BodyContent::SectionProperty(SectionProperty {
+            page_size: Some(PageSize {
+                weight: 11906,
+                height: 16838,
+            }),
+            page_margin: Some(PageMargin {
+                top: Some(1134),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }));
+    docx.add_table(["Name"], [["apples"]]);
+
+    let layout = docx.layout();
+    assert_eq!(layout.sections.len(), 1);
+    let section = &layout.sections[0];
+    assert_eq!(section.page.width, 595.3);
+    assert_eq!(section.page.margin_top, 56.7);
+    assert_eq!(section.page.margin_left, 72.0);
+
+    let para = match &section.blocks[0] {
+        Block::Paragraph(para) => para,
+        _ => unreachable!(),
+    };
+    assert_eq!(para.space_before, 12.0);
+    assert_eq!(para.line_height, LineHeight::Multiple(1.5));
+    assert_eq!(para.spans.len(), 1);
+    assert_eq!(para.spans[0].size, 10.0);
+
+    let table = match &section.blocks[1] {
+        Block::Table(table) => table,
+        _ => unreachable!(),
+    };
+    assert!(table.rows[0].header);
+    assert!(table.rows[0].cells[0].paragraphs[0].spans[0].bold);
+
+    let json = layout.to_json();
+    assert!(json.contains(r#""text":"A \"quote\"\tand tab""#));
+    assert!(json.contains(r#""line_height":{"type":"multiple","value":1.5}"#));
+    assert!(json.contains(r#"{"type":"table","columns":["#));
+}
@@ -66,6 +66,20 @@
 //!
 //! [`DocxFile::from_reader`]: struct.DocxFile.html#method.from_reader
 //!
+//! # Targets without a file system
+//!
+//! The file based helpers, [`Docx::write_file`] and [`DocxFile::from_file`],
+//! are behind the default `fs` feature. Disable default features to build for
+//! `wasm32-unknown-unknown` and work with byte buffers instead, using
+//! [`Docx::write_to_vec`] and [`DocxFile::from_bytes`]:
+//!
+//! ```toml
+//! docx-rust = { version = "0.1", default-features = false }
+//! ```
+//!
+//! [`Docx::write_to_vec`]: struct.Docx.html#method.write_to_vec
+//! [`DocxFile::from_bytes`]: struct.DocxFile.html#method.from_bytes
+//!
 //! # Similar Projects
 //!
 //! [`bokuweb/docx-rs`]: A .docx file writer with Rust/WebAssembly.
@@ -80,26 +94,62 @@
 mod macros;
 
 pub mod app;
+mod assemble;
+pub mod bibliography;
+pub mod chart;
+mod clipboard;
+mod compact;
+mod conformance;
 pub mod content_type;
 pub mod core;
+pub mod custom_xml;
 pub mod document;
 mod docx;
 mod error;
+mod escape;
+mod extra_attributes;
+mod flat_opc;
 pub mod font_table;
 pub mod formatting;
+pub mod fuzz;
+mod html;
+pub mod layout;
+mod markdown;
 pub mod media;
+#[cfg(feature = "odt")]
+mod odt;
+mod personal_info;
+mod prolog;
 pub mod rels;
+mod repair;
+mod resolve;
+mod rsid;
+#[cfg(feature = "rtf")]
+mod rtf;
 mod schema;
+mod select;
 pub mod settings;
+mod split;
 pub mod styles;
+#[cfg(feature = "test-support")]
+pub mod test_support;
+pub mod thumbnail;
+pub mod typography;
+pub mod uri;
+mod walk;
 pub mod web_settings;
+mod xml_output;
 
 use std::io::Write;
 
 use hard_xml::{XmlWrite, XmlWriter};
 
-pub use crate::docx::{Docx, DocxFile};
+pub use crate::conformance::Conformance;
+pub use crate::docx::{Compression, Docx, DocxFile, LazyDocx, WriteOptions};
 pub use crate::error::{DocxError, DocxResult};
+pub use crate::extra_attributes::ExtraAttributes;
+pub use crate::schema::NamespaceSet;
+pub use crate::select::Selector;
 
 pub fn write_attr<W: Write, T: XmlWrite>(
     element: &Option<T>,
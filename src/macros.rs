@@ -2,8 +2,7 @@
 #[doc(hidden)]
 macro_rules! __define_struct_vec {
     ( ($tag:expr, $name:ident, $choicename:ident) { $($value2:expr, $variant2:ident, $ty2: ty)* } { $($value:expr, $variant:ident)* }) => {
-        #[derive(Debug, XmlRead, XmlWrite, Clone)]
-        #[cfg_attr(test, derive(PartialEq))]
+        #[derive(Debug, XmlRead, XmlWrite, Clone, PartialEq)]
         pub enum $choicename {
             $(
                 #[xml(tag = $value)]
@@ -11,8 +10,7 @@ macro_rules! __define_struct_vec {
             )*
         }
 
-        #[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
-        #[cfg_attr(test, derive(PartialEq))]
+        #[derive(Debug, Default, XmlRead, XmlWrite, Clone, PartialEq)]
         #[xml(tag = $tag)]
         pub struct $name {
             $(
@@ -44,8 +42,7 @@ macro_rules! __define_struct_vec {
 #[doc(hidden)]
 macro_rules! __define_struct {
     ( ($tag:expr, $name:ident, $a:lifetime) { $($value:expr, $variant:ident, $ty: ty)* }) => {
-        #[derive(Debug, XmlRead, XmlWrite, Clone, Default)]
-        #[cfg_attr(test, derive(PartialEq))]
+        #[derive(Debug, XmlRead, XmlWrite, Clone, Default, PartialEq)]
         #[xml(tag = $tag)]
         pub struct $name<$a> {
             $(
@@ -66,8 +63,7 @@ macro_rules! __define_struct {
     };
 
     ( ($tag:expr, $name:ident) { $($value:expr, $variant:ident, $ty: ty)* }) => {
-        #[derive(Debug, XmlRead, XmlWrite, Clone, Default)]
-        #[cfg_attr(test, derive(PartialEq))]
+        #[derive(Debug, XmlRead, XmlWrite, Clone, Default, PartialEq)]
         #[xml(tag = $tag)]
         pub struct $name {
             $(
@@ -88,8 +84,7 @@ macro_rules! __define_struct {
     };
 
     ( ($tag:expr, $name:ident) { $($value:expr, $variant:ident, $ty: ty)* } { $($value2:expr, $variant2:ident, $ty2: ty)* }) => {
-        #[derive(Debug, XmlRead, XmlWrite, Clone, Default)]
-        #[cfg_attr(test, derive(PartialEq))]
+        #[derive(Debug, XmlRead, XmlWrite, Clone, Default, PartialEq)]
         #[xml(tag = $tag)]
         pub struct $name {
             $(
@@ -126,8 +121,7 @@ macro_rules! __define_struct {
 #[doc(hidden)]
 macro_rules! __define_enum {
     ($name:ident { $($variant:ident = $value:expr, )* }) => {
-        #[derive(Debug, Clone)]
-        #[cfg_attr(test, derive(PartialEq))]
+        #[derive(Debug, Clone, PartialEq)]
         pub enum $name {
             $( $variant, )*
         }
@@ -205,6 +199,38 @@ macro_rules! __setter {
     };
 }
 
+/// Overrides the fields of `$self` with those of `$other` that are set.
+///
+/// The fields after `;` are merged with their own `merge` method, attribute
+/// by attribute. Fields given in parentheses exclude or take precedence over
+/// each other, so they are all overridden if any of them is set.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __merge {
+    ($self:ident, $other:ident, $($field:ident,)* $(; $($nested:ident,)*)?) => {
+        $(
+            if $other.$field.is_some() {
+                $self.$field = $other.$field.clone();
+            }
+        )*
+        $($(
+            if let Some(other) = &$other.$nested {
+                $self.$nested = Some(match $self.$nested.take() {
+                    Some(field) => field.merge(other),
+                    None => other.clone(),
+                });
+            }
+        )*)?
+    };
+    ($self:ident, $other:ident, $(($($field:ident),+),)*) => {
+        $(
+            if $($other.$field.is_some())||+ {
+                $($self.$field = $other.$field.clone();)+
+            }
+        )*
+    };
+}
+
 #[macro_export]
 #[doc(hidden)]
 macro_rules! __xml_test_suites {
@@ -225,3 +251,109 @@ macro_rules! __xml_test_suites {
         }
     };
 }
+
+/// Returns the text between two slices of the same source, e.g. the content
+/// of an element the reader skipped, or an empty string if `after` doesn't
+/// follow `before`.
+///
+/// # Safety
+///
+/// Both slices must borrow from the same string.
+pub(crate) unsafe fn between<'a>(before: &'a str, after: &'a str) -> &'a str {
+    let start = before.as_ptr().add(before.len());
+    let len = match (after.as_ptr() as usize).checked_sub(start as usize) {
+        Some(len) => len,
+        None => return "",
+    };
+    std::str::from_utf8(std::slice::from_raw_parts(start, len)).unwrap_or_default()
+}
+
+/// Implements reading and writing of an element holding a literal text and an
+/// optional `xml:space` attribute, like `w:t`.
+///
+/// When written without a `xml:space`, text whose whitespace would be lost
+/// gets `xml:space="preserve"`. When read, a preserved text of only
+/// whitespace is kept as in the source, even though the reader doesn't yield
+/// it as a token.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __text_element {
+    ($name:ident, $tag:expr) => {
+        impl<'a> XmlRead<'a> for $name<'a> {
+            fn from_reader(reader: &mut hard_xml::XmlReader<'a>) -> hard_xml::XmlResult<Self> {
+                use hard_xml::xmlparser::{ElementEnd, Token};
+
+                reader.read_till_element_start($tag)?;
+
+                let mut space = None;
+                while let Some((key, value)) = reader.find_attribute()? {
+                    if key == "xml:space" {
+                        space = Some(
+                            <$crate::document::TextSpace as std::str::FromStr>::from_str(&value)
+                                .map_err(|e| hard_xml::XmlError::FromStr(e.into()))?,
+                        );
+                    }
+                }
+
+                if let Some(Ok(Token::ElementEnd {
+                    end: ElementEnd::Open,
+                    span,
+                })) = reader.peek()
+                {
+                    let open = span.as_str();
+                    reader.next();
+                    if let Some(Ok(Token::ElementEnd {
+                        end: ElementEnd::Close(..),
+                        span,
+                    })) = reader.peek()
+                    {
+                        let close = span.as_str();
+                        reader.next();
+                        let text = match space {
+                            // SAFETY: the spans of the tokens borrow from the
+                            // same source
+                            Some($crate::document::TextSpace::Preserve) => {
+                                $crate::escape::unescape(unsafe {
+                                    $crate::macros::between(open, close)
+                                })
+                            }
+                            _ => std::borrow::Cow::Borrowed(""),
+                        };
+                        return Ok($name { space, text });
+                    }
+                }
+
+                let text = reader.read_text($tag)?;
+
+                Ok($name { space, text })
+            }
+        }
+
+        impl XmlWrite for $name<'_> {
+            fn to_writer<W: std::io::Write>(
+                &self,
+                writer: &mut hard_xml::XmlWriter<W>,
+            ) -> hard_xml::XmlResult<()> {
+                let start = match &self.space {
+                    Some($crate::document::TextSpace::Preserve) => {
+                        concat!("<", $tag, r#" xml:space="preserve">"#)
+                    }
+                    Some($crate::document::TextSpace::Default) => {
+                        concat!("<", $tag, r#" xml:space="default">"#)
+                    }
+                    None if $crate::document::needs_preserve(&self.text) => {
+                        concat!("<", $tag, r#" xml:space="preserve">"#)
+                    }
+                    None => concat!("<", $tag, ">"),
+                };
+                writer.inner.write_all(start.as_bytes())?;
+                $crate::escape::write_escaped(&mut writer.inner, &self.text)?;
+                writer
+                    .inner
+                    .write_all(concat!("</", $tag, ">").as_bytes())?;
+
+                Ok(())
+            }
+        }
+    };
+}
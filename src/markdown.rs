@@ -0,0 +1,330 @@
+//! Markdown export of the document body
+//!
+//! Headings come from the `Title` and `Heading1` to `Heading9` styles or an
+//! outline level, bold and italic text from direct formatting and character
+//! styles. Numbered paragraphs become list items and tables become pipe
+//! tables, their first row being the header.
+
+use crate::{
+    document::{
+        BodyContent, ListLabels, Paragraph, ParagraphContent, Run, RunContent, Table,
+        TableCellContent, TableRowContent,
+    },
+    formatting::CharacterProperty,
+    walk::paragraph_content,
+    Docx,
+};
+
+impl<'a> Docx<'a> {
+    /// Converts the body of the document to Markdown.
+    ///
+    /// ```rust
+    /// use docx_rust::Docx;
+    ///
+    /// let mut docx = Docx::default();
+    /// docx.add_heading(2, "Fruits");
+    /// docx.add_paragraph("Apples").bold();
+    /// docx.bulleted_list(["pears", "  conference"]);
+    ///
+    /// assert_eq!(
+    ///     docx.to_markdown(),
+    ///     "## Fruits\n\n**Apples**\n\n- pears\n    - conference\n"
+    /// );
+    /// ```
+    pub fn to_markdown(&self) -> String {
+        let mut writer = MarkdownWriter {
+            docx: self,
+            labels: self.list_labels(),
+            blocks: Vec::new(),
+        };
+        writer.content(&self.document.body.content);
+
+        let mut markdown = String::new();
+        for (i, (block, is_item)) in writer.blocks.iter().enumerate() {
+            if i > 0 {
+                let previous_is_item = writer.blocks[i - 1].1;
+                markdown.push_str(if *is_item && previous_is_item {
+                    "\n"
+                } else {
+                    "\n\n"
+                });
+            }
+            markdown.push_str(block);
+        }
+        if !markdown.is_empty() {
+            markdown.push('\n');
+        }
+        markdown
+    }
+}
+
+struct MarkdownWriter<'d, 'a> {
+    docx: &'d Docx<'a>,
+    labels: ListLabels<'d, 'a>,
+    /// Markdown of every block, and whether it is a list item, as list items
+    /// aren't separated by blank lines
+    blocks: Vec<(String, bool)>,
+}
+
+impl MarkdownWriter<'_, '_> {
+    fn content(&mut self, content: &[BodyContent]) {
+        for content in content {
+            match content {
+                BodyContent::Paragraph(para) => self.paragraph(para),
+                BodyContent::Table(table) => {
+                    let table = self.table(table);
+                    self.blocks.push((table, false));
+                }
+                BodyContent::TableCell(cell) => {
+                    for TableCellContent::Paragraph(para) in &cell.content {
+                        self.paragraph(para);
+                    }
+                }
+                BodyContent::Sdt(sdt) => {
+                    if let Some(sdt_content) = &sdt.content {
+                        self.content(&sdt_content.content);
+                    }
+                }
+                BodyContent::SectionProperty(_) | BodyContent::Raw(_) => (),
+            }
+        }
+    }
+
+    fn paragraph(&mut self, para: &Paragraph) {
+        let text = self.inline(para);
+        let label = self.labels.label(para);
+        if text.is_empty() {
+            return;
+        }
+
+        if let Some(level) = self.docx.heading_level(para) {
+            self.blocks
+                .push((format!("{} {}", "#".repeat(level), text), false));
+        } else if let Some(label) = label {
+            let level = self.labels.level(para).unwrap_or_default();
+            let number = label.trim_end_matches(['.', ')']);
+            let marker = if !number.is_empty() && number.bytes().all(|b| b.is_ascii_digit()) {
+                format!("{}.", number)
+            } else {
+                "-".to_string()
+            };
+            let item = format!("{}{} {}", "    ".repeat(level), marker, text);
+            self.blocks.push((item, true));
+        } else {
+            self.blocks.push((escape_start(&text), false));
+        }
+    }
+
+    fn table(&mut self, table: &Table) -> String {
+        let mut markdown = String::new();
+        for (i, row) in table.rows.iter().enumerate() {
+            let cells: Vec<String> = row
+                .cells
+                .iter()
+                .filter_map(|cell| match cell {
+                    TableRowContent::TableCell(cell) => Some(cell),
+                    TableRowContent::SDT(_) => None,
+                })
+                .map(|cell| {
+                    cell.content
+                        .iter()
+                        .map(|TableCellContent::Paragraph(para)| self.inline(para))
+                        .filter(|text| !text.is_empty())
+                        .collect::<Vec<_>>()
+                        .join("<br>")
+                        .replace('|', "\\|")
+                })
+                .collect();
+
+            markdown.push_str(&format!("| {} |\n", cells.join(" | ")));
+            if i == 0 {
+                markdown.push_str(&format!("|{}\n", " --- |".repeat(cells.len())));
+            }
+        }
+        markdown.pop();
+        markdown
+    }
+
+    /// Converts the text of a paragraph, merging the runs that have the same
+    /// emphasis and link.
+    fn inline(&self, para: &Paragraph) -> String {
+        let mut spans: Vec<Span> = Vec::new();
+        for content in paragraph_content(para) {
+            match content {
+                ParagraphContent::Run(run) => self.push_run(&mut spans, run, None),
+                ParagraphContent::Link(link) => {
+                    let target = match (&link.anchor, self.docx.hyperlink_target(link)) {
+                        (Some(anchor), _) => Some(format!("#{}", anchor)),
+                        (None, target) => target.map(str::to_string),
+                    };
+                    self.push_run(&mut spans, &link.content, target);
+                }
+                _ => (),
+            }
+        }
+
+        let mut markdown = String::new();
+        for span in spans {
+            // emphasis can't start or end with whitespace
+            let start = span.text.len() - span.text.trim_start().len();
+            let end = span.text.trim_end().len().max(start);
+            let (leading, text, trailing) = (
+                &span.text[..start],
+                &span.text[start..end],
+                &span.text[end..],
+            );
+            let marker = match (span.bold, span.italic) {
+                _ if text.is_empty() => "",
+                (true, true) => "***",
+                (true, false) => "**",
+                (false, true) => "*",
+                (false, false) => "",
+            };
+            let text = format!("{}{}{}", marker, text, marker);
+            markdown.push_str(leading);
+            match span.link {
+                Some(target) => markdown.push_str(&format!("[{}]({})", text, target)),
+                None => markdown.push_str(&text),
+            }
+            markdown.push_str(trailing);
+        }
+        markdown.trim().to_string()
+    }
+
+    fn push_run(&self, spans: &mut Vec<Span>, run: &Run, link: Option<String>) {
+        let mut text = String::new();
+        for content in &run.content {
+            match content {
+                RunContent::Text(t) => text.push_str(&escape(&t.text)),
+                RunContent::Tab(_) => text.push('\t'),
+                RunContent::Break(_) | RunContent::CarriageReturn(_) => text.push_str("\\\n"),
+                RunContent::NoBreakHyphen(_) => text.push('\u{2011}'),
+                _ => (),
+            }
+        }
+        if text.is_empty() {
+            return;
+        }
+
+        let property = run.property.as_ref();
+        let span = Span {
+            bold: self.toggle(property, |p| p.bold.as_ref().map(|b| b.value)),
+            italic: self.toggle(property, |p| p.italics.as_ref().map(|i| i.value)),
+            link,
+            text,
+        };
+        match spans.last_mut() {
+            Some(last)
+                if last.bold == span.bold
+                    && last.italic == span.italic
+                    && last.link == span.link =>
+            {
+                last.text.push_str(&span.text);
+            }
+            _ => spans.push(span),
+        }
+    }
+
+    /// Returns whether a toggle property is on, set on the run or by its
+    /// character style.
+    fn toggle<F>(&self, property: Option<&CharacterProperty>, get: F) -> bool
+    where
+        F: Fn(&CharacterProperty) -> Option<Option<bool>>,
+    {
+        let property = match property {
+            Some(property) => property,
+            None => return false,
+        };
+        let value = get(property).or_else(|| {
+            let style_id = &property.style_id.as_ref()?.value;
+            let style = self.docx.styles.get(style_id)?;
+            get(style.character.as_ref()?)
+        });
+        // a toggle property without a value is on
+        matches!(value, Some(None) | Some(Some(true)))
+    }
+}
+
+struct Span {
+    bold: bool,
+    italic: bool,
+    link: Option<String>,
+    text: String,
+}
+
+/// Escapes the characters that would be read as Markdown syntax.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Escapes the start of a paragraph that would be read as a heading, list
+/// item or block quote.
+fn escape_start(text: &str) -> String {
+    let digits = text.bytes().take_while(u8::is_ascii_digit).count();
+    let marker = match text[digits..].chars().next() {
+        Some('.' | ')') if digits > 0 => Some(digits),
+        Some('#' | '-' | '+') if digits == 0 => Some(0),
+        _ => None,
+    };
+    match marker {
+        Some(i) => format!("{}\\{}", &text[..i], &text[i..]),
+        None => text.to_string(),
+    }
+}
+
+#[test]
+fn markdown() {
+    use crate::formatting::{Bold, Italics, ParagraphProperty};
+
+    assert_eq!(escape("a*b_[c]"), r"a\*b\_\[c\]");
+    assert_eq!(escape_start("1. not a list"), r"1\. not a list");
+    assert_eq!(escape_start("# not a heading"), r"\# not a heading");
+    assert_eq!(escape_start("2024 was"), "2024 was");
+
+    let mut docx = Docx::default();
+    docx.document.push(
+        Paragraph::default()
+            .property(ParagraphProperty::default().style_id("Title"))
+            .push_text("Report"),
+    );
+    let link = docx.hyperlink("https://example.com", "site");
+    docx.document.push(
+        Paragraph::default()
+            .push(
+                Run::default()
+                    .property(CharacterProperty::default().bold(Bold::from(true)))
+                    .push_text("bold "),
+            )
+            .push(
+                Run::default()
+                    .property(
+                        CharacterProperty::default()
+                            .bold(Bold::from(true))
+                            .italics(Italics::from(true)),
+                    )
+                    .push_text("both"),
+            )
+            .push_text(" see ")
+            .push(link),
+    );
+    docx.numbered_list(["one", "two"]);
+    docx.add_table(["Name", "Qty"], [["a|b", "3"]]);
+
+    assert_eq!(
+        docx.to_markdown(),
+        "# Report\n\n\
+         **bold** ***both*** see [site](https://example.com)\n\n\
+         1. one\n\
+         2. two\n\n\
+         | **Name** | **Qty** |\n\
+         | --- | --- |\n\
+         | a\\|b | 3 |\n"
+    );
+}
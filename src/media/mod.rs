@@ -1,4 +1,8 @@
-use crate::schema::SCHEMA_IMAGE;
+mod replace;
+
+pub use self::replace::*;
+
+use crate::{content_type::image_content_type, schema::SCHEMA_IMAGE};
 
 /// Specifies the type of a media file
 ///
@@ -15,13 +19,6 @@ pub fn get_media_type_relation_type(mt: &MediaType) -> &'static str {
 }
 
 pub fn get_media_type(filename: &str) -> Option<MediaType> {
-    if filename.ends_with("png")
-        | filename.ends_with("jpg")
-        | filename.ends_with("jpeg")
-        | filename.ends_with("bmp")
-    {
-        Some(MediaType::Image)
-    } else {
-        None
-    }
+    let (_, ext) = filename.rsplit_once('.')?;
+    image_content_type(ext).map(|_| MediaType::Image)
 }
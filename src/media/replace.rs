@@ -0,0 +1,377 @@
+//! Replacing the pictures of a document
+//!
+//! Report templates often hold a placeholder picture, like last month's
+//! chart, to be swapped for a new one. [`Docx::replace_image`] finds the
+//! pictures with an [`ImageSelector`] and points them to new data, keeping
+//! their size or rescaling them as the [`ImageFit`] asks.
+
+use std::collections::HashSet;
+
+use crate::{
+    document::{Drawing, RunContent},
+    repair::resolve,
+    schema::SCHEMA_IMAGE,
+    walk::{relationship_ids, runs, runs_mut},
+    Docx,
+};
+
+use super::MediaType;
+
+/// A way to find the pictures of the main document
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageSelector<'s> {
+    /// Selects the pictures showing the image of the given relationship id
+    RelationshipId(&'s str),
+    /// Selects the pictures with the given alternative text
+    Description(&'s str),
+    /// Selects the pictures with the given title
+    Title(&'s str),
+}
+
+impl<'s> ImageSelector<'s> {
+    /// Returns whether the drawing is selected.
+    pub fn matches(&self, drawing: &Drawing) -> bool {
+        match *self {
+            ImageSelector::RelationshipId(id) => drawing.image_id() == Some(id),
+            ImageSelector::Description(descr) => drawing.description_text() == Some(descr),
+            ImageSelector::Title(title) => drawing
+                .doc_property()
+                .is_some_and(|property| property.title.as_deref() == Some(title)),
+        }
+    }
+}
+
+/// How a replaced picture is sized
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFit {
+    /// Keeps the size of the picture, stretching the new image if its aspect
+    /// ratio differs
+    Keep,
+    /// Keeps the width of the picture, and sets the height from the aspect
+    /// ratio of the new image
+    KeepWidth,
+    /// Keeps the height of the picture, and sets the width from the aspect
+    /// ratio of the new image
+    KeepHeight,
+    /// Sets the size of the picture, in EMUs
+    Size(u64, u64),
+}
+
+/// Returns the width and height in pixels of a PNG, JPEG or BMP image, read
+/// from its header.
+///
+/// ```rust
+/// use docx_rust::media::image_size;
+///
+/// let mut png = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR".to_vec();
+/// png.extend([0, 0, 1, 0, 0, 0, 0, 200]);
+/// assert_eq!(image_size(&png), Some((256, 200)));
+/// assert_eq!(image_size(b"GIF89a"), None);
+/// ```
+pub fn image_size(data: &[u8]) -> Option<(u32, u32)> {
+    let be16 = |i: usize| Some(u16::from_be_bytes([*data.get(i)?, *data.get(i + 1)?]) as u32);
+    match image_extension(data)? {
+        "png" => {
+            let be32 = |i: usize| Some(u32::from_be_bytes(data.get(i..i + 4)?.try_into().ok()?));
+            Some((be32(16)?, be32(20)?))
+        }
+        "bmp" => {
+            let le32 = |i: usize| Some(i32::from_le_bytes(data.get(i..i + 4)?.try_into().ok()?));
+            Some((le32(18)?.unsigned_abs(), le32(22)?.unsigned_abs()))
+        }
+        _ => {
+            // walks the JPEG segments up to a start of frame
+            let mut i = 2;
+            while *data.get(i)? == 0xFF {
+                let marker = *data.get(i + 1)?;
+                match marker {
+                    0xFF => i += 1,
+                    0xC0..=0xCF if !matches!(marker, 0xC4 | 0xC8 | 0xCC) => {
+                        return Some((be16(i + 7)?, be16(i + 5)?));
+                    }
+                    _ => i += 2 + be16(i + 2)? as usize,
+                }
+            }
+            None
+        }
+    }
+}
+
+/// Returns the file extension of a PNG, JPEG or BMP image from its magic
+/// bytes.
+pub(crate) fn image_extension(data: &[u8]) -> Option<&'static str> {
+    if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some("png")
+    } else if data.starts_with(&[0xFF, 0xD8]) {
+        Some("jpeg")
+    } else if data.starts_with(b"BM") {
+        Some("bmp")
+    } else {
+        None
+    }
+}
+
+impl<'a> Docx<'a> {
+    /// Returns the pictures of the main document selected, in document order.
+    pub fn find_images(&self, selector: &ImageSelector) -> Vec<&Drawing<'a>> {
+        self.body_paragraphs()
+            .into_iter()
+            .flat_map(runs)
+            .flat_map(|run| &run.content)
+            .filter_map(|content| match content {
+                RunContent::Drawing(drawing) if selector.matches(drawing) => Some(drawing),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns an id for a new drawing, one more than the largest id of the
+    /// drawings of the package, starting at 1.
+    ///
+    /// ```rust
+    /// use docx_rust::document::{Drawing, Paragraph, Run};
+    /// use docx_rust::Docx;
+    ///
+    /// let mut docx = Docx::default();
+    /// let logo = Drawing::inline_picture(docx.next_drawing_id(), "rId1", 914400, 457200);
+    /// docx.document.push(Paragraph::default().push(Run::default().push(logo)));
+    ///
+    /// assert_eq!(docx.next_drawing_id(), 2);
+    /// ```
+    pub fn next_drawing_id(&self) -> isize {
+        self.paragraphs()
+            .into_iter()
+            .flat_map(runs)
+            .flat_map(|run| &run.content)
+            .filter_map(|content| match content {
+                RunContent::Drawing(drawing) => drawing.doc_property()?.id,
+                _ => None,
+            })
+            .max()
+            .map_or(1, |id| id + 1)
+    }
+
+    /// Replaces the image of the pictures of the main document selected,
+    /// returning how many were changed.
+    ///
+    /// The data is added as a new media file, and the media file the
+    /// pictures showed is removed once no picture uses it. The data must be
+    /// a PNG, JPEG or BMP image, the formats read back from packages;
+    /// nothing is changed otherwise.
+    ///
+    /// ```rust
+    /// use docx_rust::document::{Drawing, Paragraph, Run};
+    /// use docx_rust::media::{ImageFit, ImageSelector};
+    /// use docx_rust::Docx;
+    ///
+    /// let mut png = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR".to_vec();
+    /// png.extend([0, 0, 0, 200, 0, 0, 0, 100]);
+    ///
+    /// let mut docx = Docx::default();
+    /// let chart = Drawing::inline_picture(docx.next_drawing_id(), "rId1", 914400, 914400)
+    ///     .description("Sales chart");
+    /// docx.document.push(Paragraph::default().push(Run::default().push(chart)));
+    ///
+    /// let replaced = docx.replace_image(&ImageSelector::Description("Sales chart"), &png, ImageFit::KeepWidth);
+    /// assert_eq!(replaced, 1);
+    /// let chart = docx.find_images(&ImageSelector::Description("Sales chart"))[0];
+    /// assert_eq!(chart.size(), Some((914400, 457200)));
+    /// assert!(docx.image_data(chart).is_some());
+    /// ```
+    pub fn replace_image(
+        &mut self,
+        selector: &ImageSelector,
+        data: &'a Vec<u8>,
+        fit: ImageFit,
+    ) -> usize {
+        let extension = match image_extension(data) {
+            Some(extension) if !self.find_images(selector).is_empty() => extension,
+            _ => return 0,
+        };
+        let pixels = image_size(data);
+
+        let existing = self
+            .media
+            .iter()
+            .find(|(_, (_, media))| *media == data)
+            .map(|(name, _)| name.clone());
+        let name = existing.unwrap_or_else(|| {
+            let name = format!("media/image{}.{}", self.media.len() + 1, extension);
+            self.insert_media(&name, (MediaType::Image, data))
+        });
+        let rels = self.document_rels.get_or_insert_with(Default::default);
+        rels.add_rel(SCHEMA_IMAGE, name.clone());
+        let id = rels.get_id(&name).unwrap_or_default().to_string();
+        self.content_types.add_image_default(extension);
+
+        let mut old_ids = Vec::new();
+        for para in self.body_paragraphs_mut() {
+            for run in runs_mut(para) {
+                for content in run.content.iter_mut() {
+                    let drawing = match content {
+                        RunContent::Drawing(drawing) if selector.matches(drawing) => drawing,
+                        _ => continue,
+                    };
+                    if let Some(embed) = drawing.image_id_mut() {
+                        old_ids.push(std::mem::replace(embed, id.clone().into()).into_owned());
+                    }
+                    resize(drawing, fit, pixels);
+                }
+            }
+        }
+
+        self.remove_unused_images(&old_ids);
+        old_ids.len()
+    }
+
+    /// Removes the relationships of the given relationship ids no paragraph
+    /// refers to anymore, with their media files unless another part, e.g. a
+    /// header or a chart, still shows them.
+    fn remove_unused_images(&mut self, ids: &[String]) {
+        let referenced: HashSet<&str> = self
+            .paragraphs()
+            .into_iter()
+            .flat_map(relationship_ids)
+            .collect();
+        let unused: Vec<String> = ids
+            .iter()
+            .filter(|id| !referenced.contains(id.as_str()))
+            .cloned()
+            .collect();
+        let rels = match &mut self.document_rels {
+            Some(rels) => rels,
+            None => return,
+        };
+        let targets: Vec<String> = unused
+            .iter()
+            .filter_map(|id| rels.get_target(id))
+            .map(|target| resolve("/word/", target))
+            .collect();
+        rels.relationships
+            .retain(|rel| !unused.iter().any(|id| rel.id == id.as_str()));
+
+        let used = match self.used_media() {
+            Some(used) => used,
+            None => return,
+        };
+        for target in targets.iter().filter(|target| !used.contains(*target)) {
+            if let Some(name) = target.strip_prefix("/word/") {
+                self.media.remove(name);
+            }
+        }
+    }
+}
+
+/// Resizes a picture showing an image of the given size in pixels.
+fn resize(drawing: &mut Drawing, fit: ImageFit, pixels: Option<(u32, u32)>) {
+    let (cx, cy) = match (drawing.size(), fit, pixels) {
+        (_, ImageFit::Size(cx, cy), _) => (cx, cy),
+        (Some((cx, _)), ImageFit::KeepWidth, Some((width, height))) if width > 0 => {
+            (cx, cx * height as u64 / width as u64)
+        }
+        (Some((_, cy)), ImageFit::KeepHeight, Some((width, height))) if height > 0 => {
+            (cy * width as u64 / height as u64, cy)
+        }
+        _ => return,
+    };
+    drawing.set_size(cx, cy);
+}
+
+#[test]
+fn replace_image() {
+    use crate::document::{Paragraph, Run};
+
+    let old = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR\0\0\0\x01\0\0\0\x01".to_vec();
+    let truncated = old[..4].to_vec();
+    let jpeg = vec![
+        0xFF, 0xD8, 0xFF, 0xE0, 0, 4, 0, 0, 0xFF, 0xC0, 0, 17, 8, 0, 150, 0, 100,
+    ];
+
+    let mut docx = Docx::default();
+    docx.media
+        .insert("media/image1.png".into(), (MediaType::Image, &old));
+    docx.add_relationships();
+    let id = docx
+        .document_rels
+        .as_ref()
+        .and_then(|rels| rels.get_id("media/image1.png"))
+        .unwrap()
+        .to_string();
+    for title in ["Chart", "Logo"] {
+        let drawing =
+            Drawing::inline_picture(docx.next_drawing_id(), id.clone(), 600, 600).title(title);
+        docx.document
+            .push(Paragraph::default().push(Run::default().push(drawing)));
+    }
+
+    assert_eq!(image_size(&jpeg), Some((100, 150)));
+    assert_eq!(
+        docx.replace_image(&ImageSelector::Title("Chart"), &jpeg, ImageFit::KeepHeight),
+        1
+    );
+    let chart = docx.find_images(&ImageSelector::Title("Chart"))[0];
+    assert_eq!(chart.size(), Some((400, 600)));
+    assert_eq!(
+        docx.image_data(chart).map(|(_, data)| data),
+        Some(&jpeg[..])
+    );
+    // the logo still shows the old image
+    assert!(docx.media.contains_key("media/image1.png"));
+    assert_eq!(
+        docx.content_types.get("/word/media/image2.jpeg"),
+        Some("image/jpeg")
+    );
+
+    let logo = ImageSelector::RelationshipId(&id);
+    assert_eq!(docx.find_images(&logo).len(), 1);
+    assert_eq!(docx.replace_image(&logo, &jpeg, ImageFit::Size(10, 20)), 1);
+    assert!(docx.find_images(&logo).is_empty());
+    assert!(!docx.media.contains_key("media/image1.png"));
+    assert_eq!(docx.media.len(), 1);
+    assert_eq!(
+        docx.replace_image(&ImageSelector::Title("Logo"), &truncated, ImageFit::Keep),
+        0
+    );
+}
+
+#[test]
+fn keep_images_of_other_parts() {
+    use crate::chart::ChartPart;
+    use crate::document::{Paragraph, Run};
+    use crate::rels::Relationships;
+
+    let old = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR\0\0\0\x01\0\0\0\x01".to_vec();
+    let new = vec![
+        0xFF, 0xD8, 0xFF, 0xE0, 0, 4, 0, 0, 0xFF, 0xC0, 0, 17, 8, 0, 150, 0, 100,
+    ];
+
+    let mut docx = Docx::default();
+    docx.media
+        .insert("media/image1.png".into(), (MediaType::Image, &old));
+    docx.add_relationships();
+    let id = docx
+        .document_rels
+        .as_ref()
+        .and_then(|rels| rels.get_id("media/image1.png"))
+        .unwrap()
+        .to_string();
+    let drawing = Drawing::inline_picture(docx.next_drawing_id(), id.clone(), 600, 600);
+    docx.document
+        .push(Paragraph::default().push(Run::default().push(drawing)));
+    let mut rels = Relationships::default();
+    rels.add_rel(SCHEMA_IMAGE, "../media/image1.png");
+    docx.charts.insert(
+        "charts/chart1.xml".into(),
+        ChartPart {
+            xml: "<c:chartSpace/>".into(),
+            rels: Some(rels),
+        },
+    );
+
+    let selector = ImageSelector::RelationshipId(&id);
+    assert_eq!(docx.replace_image(&selector, &new, ImageFit::Keep), 1);
+    // the chart still shows the old image
+    assert!(docx.media.contains_key("media/image1.png"));
+    let rels = docx.document_rels.as_ref().unwrap();
+    assert!(rels.get_target(&id).is_none());
+}
@@ -0,0 +1,698 @@
+//! OpenDocument text import
+//!
+//! Reads the headings, paragraphs, spans, links, lists and tables of an ODT
+//! package. The formatting of the ODT styles is converted to direct
+//! formatting: bold, italic, underline, strike, font, size, color and
+//! alignment. Headings get the `Heading1` to `Heading9` styles, lists are
+//! bulleted or numbered like their list style. Frames, images, fields other
+//! than their text, notes and annotations are not imported.
+
+use std::{
+    collections::HashMap,
+    io::{Read, Seek},
+};
+
+use hard_xml::XmlError;
+use xmlparser::{ElementEnd, Token, Tokenizer};
+use zip::{result::ZipError, ZipArchive};
+
+use crate::{
+    document::{
+        AbstractNum, BodyContent, Break, Hyperlink, Numbering, Paragraph, Run, RunContent, Tab,
+        Table, TableCellContent, TableRowContent,
+    },
+    escape::unescape,
+    formatting::{
+        CharacterProperty, Fonts, JustificationVal, OnOffOnlyType, ParagraphProperty,
+        UnderlineStyle,
+    },
+    prolog::{decode, strip_prolog},
+    Docx, DocxResult,
+};
+
+impl Docx<'static> {
+    /// Imports an ODT package.
+    ///
+    /// ```rust,no_run
+    /// use docx_rust::Docx;
+    ///
+    /// let file = std::fs::File::open("report.odt")?;
+    /// let mut docx = Docx::from_odt(file)?;
+    /// docx.write_file("report.docx")?;
+    /// # Ok::<(), docx_rust::DocxError>(())
+    /// ```
+    pub fn from_odt<R: Read + Seek>(reader: R) -> DocxResult<Self> {
+        let mut archive = ZipArchive::new(reader)?;
+        let styles = match read_part(&mut archive, "styles.xml") {
+            Ok(xml) => Some(xml),
+            Err(ZipError::FileNotFound) => None,
+            Err(err) => return Err(err.into()),
+        };
+        let content = read_part(&mut archive, "content.xml")?;
+
+        let mut reader = OdtReader::default();
+        // automatic styles of the content come last, as they can have the
+        // same names as the automatic styles of styles.xml
+        if let Some(styles) = styles {
+            reader.styles(&parse(&styles)?);
+        }
+        let content = parse(&content)?;
+        reader.styles(&content);
+        if let Some(text) = content
+            .child("office:document-content")
+            .and_then(|root| root.child("office:body"))
+            .and_then(|body| body.child("office:text"))
+        {
+            reader.blocks(text, None);
+        }
+        Ok(reader.docx)
+    }
+}
+
+fn read_part<R: Read + Seek>(archive: &mut ZipArchive<R>, name: &str) -> Result<String, ZipError> {
+    let mut bytes = Vec::new();
+    archive.by_name(name)?.read_to_end(&mut bytes)?;
+    let mut xml = decode(bytes)?;
+    strip_prolog(&mut xml);
+    Ok(xml)
+}
+
+#[derive(Default)]
+struct OdtReader {
+    docx: Docx<'static>,
+    styles: HashMap<String, OdtStyle>,
+    /// Whether the list styles are numbered
+    list_styles: HashMap<String, bool>,
+}
+
+/// Formatting of a text or paragraph style, unset values being inherited
+#[derive(Clone, Default)]
+struct OdtStyle {
+    parent: Option<String>,
+    bold: Option<bool>,
+    italic: Option<bool>,
+    underline: Option<bool>,
+    strike: Option<bool>,
+    font: Option<String>,
+    /// Size in half-points
+    size: Option<isize>,
+    /// Color as `RRGGBB`
+    color: Option<String>,
+    align: Option<JustificationVal>,
+}
+
+impl OdtStyle {
+    fn new(element: &Element) -> Self {
+        let mut style = OdtStyle {
+            parent: element.attr("style:parent-style-name").map(str::to_string),
+            ..Default::default()
+        };
+        if let Some(text) = element.child("style:text-properties") {
+            style.bold = text.attr("fo:font-weight").map(|w| w != "normal");
+            style.italic = text.attr("fo:font-style").map(|s| s != "normal");
+            style.underline = text.attr("style:text-underline-style").map(|s| s != "none");
+            style.strike = text
+                .attr("style:text-line-through-style")
+                .map(|s| s != "none");
+            style.font = text
+                .attr("style:font-name")
+                .or_else(|| text.attr("fo:font-family"))
+                .map(|font| font.trim_matches('\'').to_string());
+            style.size = text
+                .attr("fo:font-size")
+                .and_then(|size| size.strip_suffix("pt"))
+                .and_then(|size| size.parse::<f64>().ok())
+                .map(|size| (size * 2.0).round() as isize);
+            style.color = text
+                .attr("fo:color")
+                .and_then(|color| color.strip_prefix('#'))
+                .map(|color| color.to_ascii_uppercase());
+        }
+        if let Some(para) = element.child("style:paragraph-properties") {
+            style.align = para.attr("fo:text-align").and_then(|align| match align {
+                "start" | "left" => Some(JustificationVal::Left),
+                "center" => Some(JustificationVal::Center),
+                "end" | "right" => Some(JustificationVal::Right),
+                "justify" => Some(JustificationVal::Both),
+                _ => None,
+            });
+        }
+        style
+    }
+
+    /// Overrides the formatting with the values that are set in `style`.
+    fn apply(&mut self, style: &OdtStyle) {
+        self.bold = style.bold.or(self.bold);
+        self.italic = style.italic.or(self.italic);
+        self.underline = style.underline.or(self.underline);
+        self.strike = style.strike.or(self.strike);
+        self.font = style.font.clone().or(self.font.take());
+        self.size = style.size.or(self.size);
+        self.color = style.color.clone().or(self.color.take());
+        self.align = style.align.clone().or(self.align.take());
+    }
+
+    fn character_property(&self) -> Option<CharacterProperty<'static>> {
+        let mut property = CharacterProperty::default();
+        if let Some(bold) = self.bold {
+            property = property.bold(bold);
+        }
+        if let Some(italic) = self.italic {
+            property = property.italics(italic);
+        }
+        if let Some(underline) = self.underline {
+            property = property.underline(if underline {
+                UnderlineStyle::Single
+            } else {
+                UnderlineStyle::None
+            });
+        }
+        if let Some(strike) = self.strike {
+            property = property.strike(strike);
+        }
+        if let Some(font) = &self.font {
+            property = property.fonts(Fonts::default().ascii(font.clone()).h_ansi(font.clone()));
+        }
+        if let Some(size) = self.size {
+            property = property.size(size);
+        }
+        if let Some(color) = &self.color {
+            property = property.color(color.clone());
+        }
+
+        let is_empty = self.bold.is_none()
+            && self.italic.is_none()
+            && self.underline.is_none()
+            && self.strike.is_none()
+            && self.font.is_none()
+            && self.size.is_none()
+            && self.color.is_none();
+        (!is_empty).then_some(property)
+    }
+}
+
+impl OdtReader {
+    /// Reads the styles of a `styles.xml` or `content.xml` part.
+    fn styles(&mut self, part: &Element) {
+        let root = match part.children().next() {
+            Some(root) => root,
+            None => return,
+        };
+        for styles in root
+            .children()
+            .filter(|e| e.name == "office:styles" || e.name == "office:automatic-styles")
+        {
+            for style in styles.children() {
+                let name = match style.attr("style:name") {
+                    Some(name) => name.to_string(),
+                    None => continue,
+                };
+                match style.name.as_str() {
+                    "style:style" => {
+                        self.styles.insert(name, OdtStyle::new(style));
+                    }
+                    "text:list-style" => {
+                        let numbered = style
+                            .children()
+                            .next()
+                            .is_some_and(|level| level.name == "text:list-level-style-number");
+                        self.list_styles.insert(name, numbered);
+                    }
+                    _ => (),
+                }
+            }
+        }
+    }
+
+    /// Returns the formatting of a style and the styles it inherits from.
+    fn resolve(&self, name: Option<&str>) -> OdtStyle {
+        let mut chain = Vec::new();
+        let mut name = name;
+        while let Some(style) = name.and_then(|name| self.styles.get(name)) {
+            // styles inheriting from each other would loop forever
+            if chain.len() > 16 {
+                break;
+            }
+            chain.push(style);
+            name = style.parent.as_deref();
+        }
+        let mut resolved = OdtStyle::default();
+        for style in chain.into_iter().rev() {
+            resolved.apply(style);
+        }
+        resolved
+    }
+
+    /// Imports the blocks of `office:text`, a section, a list item or a
+    /// table cell. `list` is the numbering id and level of list items.
+    fn blocks(&mut self, element: &Element, list: Option<(isize, isize)>) {
+        for child in element.children() {
+            let block = match child.name.as_str() {
+                "text:p" => Some(BodyContent::Paragraph(self.paragraph(child, None, list))),
+                "text:h" => {
+                    let level = child
+                        .attr("text:outline-level")
+                        .and_then(|level| level.parse::<usize>().ok())
+                        .unwrap_or(1)
+                        .clamp(1, 9);
+                    self.docx.styles.ensure_heading(level);
+                    let para = self.paragraph(child, Some(level), list);
+                    Some(BodyContent::Paragraph(para))
+                }
+                "text:list" => {
+                    self.list(child, list);
+                    None
+                }
+                "table:table" => Some(BodyContent::Table(self.table(child))),
+                "text:section" => {
+                    self.blocks(child, list);
+                    None
+                }
+                _ => None,
+            };
+            if let Some(block) = block {
+                self.docx.document.push(block);
+            }
+        }
+    }
+
+    fn list(&mut self, element: &Element, parent: Option<(isize, isize)>) {
+        let list = match parent {
+            Some((num_id, level)) => (num_id, (level + 1).min(8)),
+            None => {
+                let numbered = element
+                    .attr("text:style-name")
+                    .and_then(|name| self.list_styles.get(name))
+                    .copied()
+                    .unwrap_or_default();
+                let abstract_num = if numbered {
+                    AbstractNum::decimal()
+                } else {
+                    AbstractNum::bullet()
+                };
+                let numbering = self.docx.numbering.get_or_insert_with(Numbering::default);
+                (numbering.push_list(abstract_num), 0)
+            }
+        };
+        for item in element
+            .children()
+            .filter(|e| e.name == "text:list-item" || e.name == "text:list-header")
+        {
+            self.blocks(item, Some(list));
+        }
+    }
+
+    fn paragraph(
+        &mut self,
+        element: &Element,
+        heading: Option<usize>,
+        list: Option<(isize, isize)>,
+    ) -> Paragraph<'static> {
+        let style = self.resolve(element.attr("text:style-name"));
+
+        let mut property = ParagraphProperty::default();
+        if let Some(level) = heading {
+            property = property.style_id(format!("Heading{}", level));
+        }
+        if let Some(align) = style.align.clone() {
+            property = property.justification(align);
+        }
+        if let Some(list) = list {
+            property = property.numbering(list);
+        }
+
+        let mut para = Paragraph::default().property(property);
+        let mut inline = Inline {
+            content: Vec::new(),
+            // leading whitespace is removed
+            last_is_space: true,
+        };
+        self.inline(element, &style, &mut inline);
+        for content in inline.content {
+            match content {
+                InlineContent::Run(run) => para = para.push(run),
+                InlineContent::Link(href, run) => para = para.push(self.link(&href, run)),
+            }
+        }
+        para
+    }
+
+    fn link(&mut self, href: &str, run: Run<'static>) -> Hyperlink<'static> {
+        if let Some(anchor) = href.strip_prefix('#') {
+            return Hyperlink::default().anchor(anchor.to_string()).content(run);
+        }
+        let mut link = self.docx.hyperlink(href.to_string(), "");
+        // the span formatting goes over the link style
+        let style_id = link.content.property.and_then(|property| property.style_id);
+        let mut run = run;
+        if style_id.is_some() {
+            let property = run.property.take().unwrap_or_default();
+            run.property = Some(CharacterProperty {
+                style_id,
+                ..property
+            });
+        }
+        link.content = run;
+        link
+    }
+
+    /// Imports the text of a paragraph, heading, span or link, collapsing
+    /// whitespace like ODF does.
+    fn inline(&self, element: &Element, style: &OdtStyle, inline: &mut Inline) {
+        for node in &element.children {
+            let child = match node {
+                Node::Text(text) => {
+                    let mut collapsed = String::new();
+                    for c in text.chars() {
+                        if matches!(c, ' ' | '\t' | '\n' | '\r') {
+                            if !inline.last_is_space {
+                                collapsed.push(' ');
+                            }
+                            inline.last_is_space = true;
+                        } else {
+                            collapsed.push(c);
+                            inline.last_is_space = false;
+                        }
+                    }
+                    if !collapsed.is_empty() {
+                        inline.push(style, collapsed, None);
+                    }
+                    continue;
+                }
+                Node::Element(child) => child,
+            };
+            match child.name.as_str() {
+                "text:span" => {
+                    let mut span = style.clone();
+                    span.apply(&self.resolve(child.attr("text:style-name")));
+                    self.inline(child, &span, inline);
+                }
+                "text:a" => {
+                    let mut link = Inline {
+                        content: Vec::new(),
+                        last_is_space: inline.last_is_space,
+                    };
+                    self.inline(child, style, &mut link);
+                    inline.last_is_space = link.last_is_space;
+                    let href = child.attr("xlink:href").unwrap_or_default();
+                    for content in link.content {
+                        match content {
+                            InlineContent::Run(run) => inline
+                                .content
+                                .push(InlineContent::Link(href.to_string(), run)),
+                            link => inline.content.push(link),
+                        }
+                    }
+                }
+                "text:s" => {
+                    let count = child
+                        .attr("text:c")
+                        .and_then(|count| count.parse().ok())
+                        .unwrap_or(1);
+                    inline.push(style, " ".repeat(count), None);
+                    inline.last_is_space = true;
+                }
+                "text:tab" => {
+                    inline.push(style, String::new(), Some(Tab.into()));
+                    inline.last_is_space = true;
+                }
+                "text:line-break" => {
+                    inline.push(style, String::new(), Some(Break::default().into()));
+                    inline.last_is_space = true;
+                }
+                "text:note" | "office:annotation" | "draw:frame" | "text:bookmark-ref" => (),
+                // fields, bookmarks and change marks, keeping their text
+                _ => self.inline(child, style, inline),
+            }
+        }
+    }
+
+    fn table(&mut self, element: &Element) -> Table<'static> {
+        let mut rows: Vec<(bool, Vec<Vec<Paragraph<'static>>>)> = Vec::new();
+        for child in element.children() {
+            let (header, group): (bool, Vec<&Element>) = match child.name.as_str() {
+                "table:table-header-rows" => (true, child.children().collect()),
+                "table:table-rows" => (false, child.children().collect()),
+                "table:table-row" => (false, vec![child]),
+                _ => continue,
+            };
+            for row in group.into_iter().filter(|e| e.name == "table:table-row") {
+                let mut cells = Vec::new();
+                for cell in row.children().filter(|e| {
+                    e.name == "table:table-cell" || e.name == "table:covered-table-cell"
+                }) {
+                    let repeated = cell
+                        .attr("table:number-columns-repeated")
+                        .and_then(|count| count.parse().ok())
+                        .unwrap_or(1usize);
+                    let paras: Vec<_> = cell
+                        .children()
+                        .filter(|e| e.name == "text:p" || e.name == "text:h")
+                        .map(|para| self.paragraph(para, None, None))
+                        .collect();
+                    // spreadsheets repeat empty cells up to the last column
+                    for _ in 0..repeated.min(64) {
+                        cells.push(paras.clone());
+                    }
+                }
+                rows.push((header, cells));
+            }
+        }
+
+        let mut table = Table::from_rows(
+            rows.iter()
+                .map(|(_, cells)| cells.iter().map(|_| "").collect::<Vec<_>>()),
+        );
+        for (row, (header, cells)) in table.rows.iter_mut().zip(rows) {
+            if header {
+                row.property.table_header = Some(OnOffOnlyType::On.into());
+            }
+            for (cell, paras) in row.cells.iter_mut().zip(cells) {
+                if let TableRowContent::TableCell(cell) = cell {
+                    if !paras.is_empty() {
+                        cell.content = paras.into_iter().map(TableCellContent::Paragraph).collect();
+                    }
+                }
+            }
+        }
+        table
+    }
+}
+
+/// Runs of a paragraph being imported
+struct Inline {
+    content: Vec<InlineContent>,
+    /// Whether the text ends with whitespace, as whitespace is collapsed
+    last_is_space: bool,
+}
+
+enum InlineContent {
+    Run(Run<'static>),
+    /// A run of a link to an address or `#` followed by a bookmark name
+    Link(String, Run<'static>),
+}
+
+impl Inline {
+    fn push(&mut self, style: &OdtStyle, text: String, content: Option<RunContent<'static>>) {
+        let mut run = Run::default();
+        if let Some(property) = style.character_property() {
+            run = run.property(property);
+        }
+        if !text.is_empty() {
+            run = run.push_text(text);
+        }
+        if let Some(content) = content {
+            run = run.push(content);
+        }
+        self.content.push(InlineContent::Run(run));
+    }
+}
+
+/// An XML element with its attributes and content
+#[derive(Default)]
+struct Element {
+    /// Qualified name, like `text:p`
+    name: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<Node>,
+}
+
+enum Node {
+    Element(Element),
+    Text(String),
+}
+
+impl Element {
+    fn attr(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn children(&self) -> impl Iterator<Item = &Element> {
+        self.children.iter().filter_map(|node| match node {
+            Node::Element(element) => Some(element),
+            Node::Text(_) => None,
+        })
+    }
+
+    fn child(&self, name: &str) -> Option<&Element> {
+        self.children().find(|e| e.name == name)
+    }
+}
+
+/// Parses a part, returning an element that contains its root element.
+///
+/// ODF documents use the usual prefixes, so elements are matched by their
+/// qualified names.
+fn parse(xml: &str) -> Result<Element, XmlError> {
+    let mut stack = vec![Element::default()];
+    for token in Tokenizer::from(xml) {
+        match token.map_err(XmlError::Parser)? {
+            Token::ElementStart { prefix, local, .. } => stack.push(Element {
+                name: qualified(prefix.as_str(), local.as_str()),
+                ..Default::default()
+            }),
+            Token::Attribute {
+                prefix,
+                local,
+                value,
+                ..
+            } => {
+                if let Some(element) = stack.last_mut() {
+                    element.attributes.push((
+                        qualified(prefix.as_str(), local.as_str()),
+                        unescape(value.as_str()).into_owned(),
+                    ));
+                }
+            }
+            Token::ElementEnd {
+                end: ElementEnd::Empty | ElementEnd::Close(..),
+                ..
+            } if stack.len() > 1 => {
+                let element = stack.pop().unwrap_or_default();
+                if let Some(parent) = stack.last_mut() {
+                    parent.children.push(Node::Element(element));
+                }
+            }
+            Token::Text { text } => {
+                if let Some(element) = stack.last_mut() {
+                    element
+                        .children
+                        .push(Node::Text(unescape(text.as_str()).into_owned()));
+                }
+            }
+            Token::Cdata { text, .. } => {
+                if let Some(element) = stack.last_mut() {
+                    element.children.push(Node::Text(text.as_str().to_string()));
+                }
+            }
+            _ => (),
+        }
+    }
+    Ok(stack.swap_remove(0))
+}
+
+fn qualified(prefix: &str, local: &str) -> String {
+    if prefix.is_empty() {
+        local.to_string()
+    } else {
+        format!("{}:{}", prefix, local)
+    }
+}
+
+#[test]
+fn odt() {
+    use crate::document::ParagraphContent;
+    use std::io::{Cursor, Write};
+    use zip::{write::SimpleFileOptions, ZipWriter};
+
+    assert_eq!(
+        unescape("a &amp; b &#x41;&#66; &unknown;"),
+        "a & b AB &unknown;"
+    );
+
+    let content = r##"<?xml version="1.0" encoding="UTF-8"?>
+<office:document-content xmlns:office="urn:oasis:names:tc:opendocument:xmlns:office:1.0" xmlns:style="urn:oasis:names:tc:opendocument:xmlns:style:1.0" xmlns:text="urn:oasis:names:tc:opendocument:xmlns:text:1.0" xmlns:table="urn:oasis:names:tc:opendocument:xmlns:table:1.0" xmlns:fo="urn:oasis:names:tc:opendocument:xmlns:xsl-fo-compatible:1.0" xmlns:xlink="http://www.w3.org/1999/xlink">
+  <office:automatic-styles>
+    <style:style style:name="P1" style:family="paragraph">
+      <style:paragraph-properties fo:text-align="center"/>
+    </style:style>
+    <style:style style:name="T1" style:family="text">
+      <style:text-properties fo:font-weight="bold" fo:font-size="14pt" fo:color="#ff0000"/>
+    </style:style>
+    <text:list-style style:name="L1">
+      <text:list-level-style-number text:level="1"/>
+    </text:list-style>
+  </office:automatic-styles>
+  <office:body>
+    <office:text>
+      <text:h text:outline-level="2">Fruits &amp; vegetables</text:h>
+      <text:p text:style-name="P1">Some   <text:span text:style-name="T1">bold</text:span><text:s text:c="2"/>text, <text:a xlink:href="https://example.com">a link</text:a></text:p>
+      <text:list text:style-name="L1">
+        <text:list-item><text:p>one</text:p>
+          <text:list><text:list-item><text:p>nested</text:p></text:list-item></text:list>
+        </text:list-item>
+      </text:list>
+      <table:table>
+        <table:table-column table:number-columns-repeated="2"/>
+        <table:table-header-rows>
+          <table:table-row><table:table-cell><text:p>Name</text:p></table:table-cell><table:table-cell><text:p>Qty</text:p></table:table-cell></table:table-row>
+        </table:table-header-rows>
+        <table:table-row><table:table-cell><text:p>apples</text:p></table:table-cell><table:table-cell><text:p>3</text:p></table:table-cell></table:table-row>
+      </table:table>
+    </office:text>
+  </office:body>
+</office:document-content>"##;
+
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    zip.start_file("content.xml", SimpleFileOptions::default())
+        .unwrap();
+    zip.write_all(content.as_bytes()).unwrap();
+    let docx = Docx::from_odt(zip.finish().unwrap()).unwrap();
+
+    let content = &docx.document.body.content;
+    assert_eq!(content.len(), 5);
+    let paras: Vec<&Paragraph> = content
+        .iter()
+        .filter_map(|content| match content {
+            BodyContent::Paragraph(para) => Some(para),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(paras[0].text(), "Fruits & vegetables");
+    assert_eq!(docx.heading_level(paras[0]), Some(2));
+
+    assert_eq!(paras[1].text(), "Some bold  text, a link");
+    let property = paras[1].property.as_ref().unwrap();
+    assert!(matches!(
+        property.justification.as_ref().unwrap().value,
+        JustificationVal::Center
+    ));
+    let bold = match &paras[1].content[1] {
+        ParagraphContent::Run(run) => run.property.as_ref().unwrap(),
+        _ => unreachable!(),
+    };
+    assert_eq!(bold.size.as_ref().unwrap().value, 28);
+    assert_eq!(bold.color.as_ref().unwrap().value, "FF0000");
+    let link = paras[1].content.last().unwrap();
+    assert!(
+        matches!(link, ParagraphContent::Link(link) if docx.hyperlink_target(link) == Some("https://example.com"))
+    );
+
+    let mut labels = docx.list_labels();
+    assert_eq!(labels.label(paras[2]).as_deref(), Some("1."));
+    assert_eq!(labels.level(paras[3]), Some(1));
+
+    let table = match &content[4] {
+        BodyContent::Table(table) => table,
+        _ => unreachable!(),
+    };
+    assert_eq!(table.rows.len(), 2);
+    assert!(table.rows[0].property.table_header.is_some());
+    assert_eq!(
+        table.iter_text().map(|t| t.as_ref()).collect::<Vec<_>>(),
+        ["Name", "Qty", "apples", "3"]
+    );
+}
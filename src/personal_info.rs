@@ -0,0 +1,125 @@
+use std::borrow::Cow;
+
+use crate::{document::Person, settings::RemovePersonalInformation, Docx};
+
+/// The name that replaces the authors of comments and revisions
+const ANONYMOUS: &str = "Author";
+
+impl<'a> Docx<'a> {
+    /// Removes personal information, like the Document Inspector of Word.
+    ///
+    /// - the author, last editor and company are removed from the document
+    ///   properties,
+    /// - comment and revision authors are replaced by `Author`, and the
+    ///   identities of authors are dropped,
+    /// - revision save ids (`rsid`) are removed from paragraphs, runs,
+    ///   sections, styles and settings,
+    /// - Word is told to keep removing personal information on save.
+    ///
+    /// ```rust
+    /// use docx_rust::core::Core;
+    /// use docx_rust::Docx;
+    ///
+    /// let mut docx = Docx::default();
+    /// docx.core = Some(Core {
+    ///     creator: Some("Jane Doe".into()),
+    ///     ..Default::default()
+    /// });
+    /// docx.remove_personal_info();
+    ///
+    /// assert!(docx.core.unwrap().creator.is_none());
+    /// ```
+    pub fn remove_personal_info(&mut self) {
+        if let Some(core) = &mut self.core {
+            core.creator = None;
+            core.last_modified_by = None;
+        }
+        if let Some(app) = &mut self.app {
+            app.company = None;
+        }
+
+        if let Some(comments) = &mut self.comments {
+            for comment in comments.comments.iter_mut() {
+                comment.author = ANONYMOUS.into();
+            }
+        }
+        if let Some(people) = &mut self.people {
+            if !people.persons.is_empty() {
+                people.persons = vec![Person::new(ANONYMOUS)];
+            }
+        }
+
+        for para in self.paragraphs_mut() {
+            if let Some(property) = &mut para.property {
+                if let Some(change) = &mut property.p_pr_change {
+                    change.author = ANONYMOUS.into();
+                }
+                if let Some(numbering) = &mut property.numbering {
+                    if let Some(change) = &mut numbering.numbering_change {
+                        anonymize(&mut change.author);
+                    }
+                    if let Some(ins) = &mut numbering.ins {
+                        anonymize(&mut ins.author);
+                    }
+                }
+            }
+        }
+        for section in self.section_properties_mut() {
+            if let Some(revision) = &mut section.revision {
+                revision.author = ANONYMOUS.into();
+            }
+        }
+
+        self.strip_rsids();
+        let settings = self.settings.get_or_insert_with(Default::default);
+        settings.remove_personal_information = Some(RemovePersonalInformation { val: None });
+    }
+}
+
+fn anonymize(author: &mut Option<Cow<str>>) {
+    if author.is_some() {
+        *author = Some(ANONYMOUS.into());
+    }
+}
+
+#[test]
+fn remove_personal_info() {
+    use crate::{
+        document::{BodyContent, Comment, Comments, Paragraph, ParagraphContent, Run},
+        formatting::{ParagraphProperty, RevisionParagraphProperty},
+    };
+
+    let mut docx = Docx::default();
+    let mut para = Paragraph::default().push(Run {
+        rsid_r: Some("00A1".into()),
+        ..Default::default()
+    });
+    para.rsid_r = Some("00A2".into());
+    para.property = Some(ParagraphProperty {
+        p_pr_change: Some(RevisionParagraphProperty {
+            author: "Jane Doe".into(),
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    docx.document.push(para);
+    docx.comments = Some(Comments {
+        comments: vec![Comment {
+            author: "Jane Doe".into(),
+            ..Default::default()
+        }],
+    });
+
+    docx.remove_personal_info();
+
+    let para = match &docx.document.body.content[0] {
+        BodyContent::Paragraph(para) => para,
+        _ => unreachable!(),
+    };
+    assert_eq!(para.rsid_r, None);
+    assert!(matches!(&para.content[0], ParagraphContent::Run(run) if run.rsid_r.is_none()));
+    let change = para.property.as_ref().unwrap().p_pr_change.as_ref();
+    assert_eq!(change.unwrap().author, ANONYMOUS);
+    assert_eq!(docx.comments.unwrap().comments[0].author, ANONYMOUS);
+    assert!(docx.settings.unwrap().remove_personal_information.is_some());
+}
@@ -0,0 +1,117 @@
+//! Tolerant reading of the start of parts
+//!
+//! Some producers start parts with a byte order mark, save them as UTF-16
+//! or Windows-1252, or write XML declarations the parser doesn't accept, e.g. after
+//! whitespace or with the attributes in another order. Parts are decoded
+//! and their declaration skipped before parsing.
+
+use std::io::{Error, ErrorKind};
+
+/// Decodes a part, UTF-8 unless it starts with a UTF-16 byte order mark or
+/// declares a Latin-1 encoding.
+pub(crate) fn decode(bytes: Vec<u8>) -> Result<String, Error> {
+    let utf16 = |bytes: &[u8], unit: fn([u8; 2]) -> u16| {
+        let units: Vec<u16> = bytes
+            .chunks_exact(2)
+            .map(|pair| unit([pair[0], pair[1]]))
+            .collect();
+        String::from_utf16(&units).map_err(|err| Error::new(ErrorKind::InvalidData, err))
+    };
+
+    match bytes.get(..2) {
+        Some([0xFF, 0xFE]) => utf16(&bytes[2..], u16::from_le_bytes),
+        Some([0xFE, 0xFF]) => utf16(&bytes[2..], u16::from_be_bytes),
+        _ => match String::from_utf8(bytes) {
+            Ok(xml) => Ok(xml),
+            Err(err) if is_single_byte(err.as_bytes()) => {
+                Ok(err.as_bytes().iter().map(|&b| windows_1252(b)).collect())
+            }
+            Err(err) => Err(Error::new(ErrorKind::InvalidData, err)),
+        },
+    }
+}
+
+/// Returns whether the XML declaration names a Latin-1 encoding.
+fn is_single_byte(bytes: &[u8]) -> bool {
+    let declaration = match bytes.iter().position(|&b| b == b'>') {
+        Some(end) => String::from_utf8_lossy(&bytes[..end]).to_ascii_lowercase(),
+        None => return false,
+    };
+    declaration.contains("<?xml")
+        && ["iso-8859-1", "latin1", "windows-1252", "cp1252"]
+            .iter()
+            .any(|encoding| declaration.contains(encoding))
+}
+
+/// Decodes a Windows-1252 byte, a superset of ISO-8859-1 which decoders
+/// commonly use for both.
+fn windows_1252(byte: u8) -> char {
+    const HIGH: [char; 32] = [
+        '€', '\u{81}', '‚', 'ƒ', '„', '…', '†', '‡', 'ˆ', '‰', 'Š', '‹', 'Œ', '\u{8d}', 'Ž',
+        '\u{8f}', '\u{90}', '‘', '’', '“', '”', '•', '–', '—', '˜', '™', 'š', '›', 'œ', '\u{9d}',
+        'ž', 'Ÿ',
+    ];
+    match byte {
+        0x80..=0x9F => HIGH[(byte - 0x80) as usize],
+        _ => byte as char,
+    }
+}
+
+/// Returns the XML after the byte order mark, the XML declaration and the
+/// whitespace around them.
+pub(crate) fn skip_prolog(xml: &str) -> &str {
+    let xml = xml.trim_start_matches('\u{feff}').trim_start();
+    let is_declaration = xml
+        .get(..5)
+        .is_some_and(|start| start.eq_ignore_ascii_case("<?xml"))
+        && xml[5..].starts_with(|c: char| c.is_ascii_whitespace() || c == '?');
+    if !is_declaration {
+        return xml;
+    }
+    match xml.find("?>") {
+        Some(end) => xml[end + 2..].trim_start(),
+        None => xml,
+    }
+}
+
+/// Removes the byte order mark and the XML declaration of a part.
+pub(crate) fn strip_prolog(xml: &mut String) {
+    let start = xml.len() - skip_prolog(xml).len();
+    xml.drain(..start);
+}
+
+#[test]
+fn tolerant_prolog() {
+    let root = "<w:document xmlns:w='urn:w'/>";
+    for xml in [
+        root.to_string(),
+        format!("\u{feff}{}", root),
+        format!("\u{feff}<?xml version='1.0' encoding='utf-8'?>{}", root),
+        format!(
+            "\r\n  <?XML version=\"1.0\" standalone=\"yes\" encoding=\"UTF-8\" ?>\n{}",
+            root
+        ),
+    ] {
+        assert_eq!(skip_prolog(&xml), root);
+        let mut owned = xml.clone();
+        strip_prolog(&mut owned);
+        assert_eq!(owned, root);
+    }
+    assert_eq!(
+        skip_prolog("<?xml-stylesheet href='a'?><a/>"),
+        "<?xml-stylesheet href='a'?><a/>"
+    );
+
+    let mut utf16 = vec![0xFF, 0xFE];
+    utf16.extend("<a/>".encode_utf16().flat_map(u16::to_le_bytes));
+    assert_eq!(decode(utf16).unwrap(), "<a/>");
+    let mut utf16 = vec![0xFE, 0xFF];
+    utf16.extend("<a/>".encode_utf16().flat_map(u16::to_be_bytes));
+    assert_eq!(decode(utf16).unwrap(), "<a/>");
+    assert!(decode(vec![0xC3]).is_err());
+    let latin1 = b"<?xml version='1.0' encoding='windows-1252'?><a>caf\xe9 \x80</a>".to_vec();
+    assert_eq!(
+        decode(latin1).unwrap(),
+        "<?xml version='1.0' encoding='windows-1252'?><a>café €</a>"
+    );
+}
@@ -45,7 +45,8 @@ impl<'a> XmlWrite for Relationships<'a> {
 }
 
 impl<'a> Relationships<'a> {
-    pub fn add_rel(&mut self, schema: &'a str, target: &'a str) {
+    pub fn add_rel<T: Into<Cow<'a, str>>>(&mut self, schema: &'a str, target: T) {
+        let target = target.into();
         let has = self.relationships.iter().find(|r| r.target == target);
         if has.is_none() {
             let ids: Vec<_> = self
@@ -68,19 +69,20 @@ impl<'a> Relationships<'a> {
             //let target = target.replace("jpeg","png");
             self.relationships.push(Relationship {
                 id: format!("rId{}", id).into(),
-                target: target.into(),
+                target,
                 ty: schema.into(),
                 target_mode: None,
             });
         }
     }
 
-    pub fn add_rel_with_target_mode(
+    pub fn add_rel_with_target_mode<T: Into<Cow<'a, str>>>(
         &mut self,
         schema: &'a str,
-        target: &'a str,
+        target: T,
         target_mode: Option<&'a str>,
     ) {
+        let target = target.into();
         let has = self.relationships.iter().find(|r| r.target == target);
         if has.is_none() {
             let ids: Vec<_> = self
@@ -103,13 +105,30 @@ impl<'a> Relationships<'a> {
             //let target = target.replace("jpeg","png");
             self.relationships.push(Relationship {
                 id: format!("rId{}", id).into(),
-                target: target.into(),
+                target,
                 ty: schema.into(),
                 target_mode: TargetMode::from_str(target_mode),
             });
         }
     }
 
+    /// Orders the relationships by id, numerically for ids like `rId12`.
+    pub fn sort_by_id(&mut self) {
+        fn number(id: &str) -> Option<u64> {
+            id.strip_prefix("rId")?.parse().ok()
+        }
+        self.relationships
+            .sort_by(|a, b| (number(&a.id), &a.id).cmp(&(number(&b.id), &b.id)));
+    }
+
+    /// Returns the id of the relationship to the given target.
+    pub fn get_id(&self, target: &str) -> Option<&str> {
+        self.relationships
+            .iter()
+            .find(|r| r.target == target)
+            .map(|r| &*r.id)
+    }
+
     pub fn get_target(&self, id: &str) -> Option<&str> {
         self.relationships
             .iter()
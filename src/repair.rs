@@ -0,0 +1,362 @@
+use std::collections::HashSet;
+
+use crate::{
+    content_type::{
+        chart_content_type, embedding_content_type, image_content_type, CONTENT_TYPE_COMMENTS,
+        CONTENT_TYPE_COMMENTS_EXTENDED, CONTENT_TYPE_CORE, CONTENT_TYPE_CUSTOM_XML_PROPS,
+        CONTENT_TYPE_DOCUMENT, CONTENT_TYPE_ENDNOTES, CONTENT_TYPE_EXTENDED,
+        CONTENT_TYPE_FONT_TABLE, CONTENT_TYPE_FOOTER, CONTENT_TYPE_FOOTNOTES, CONTENT_TYPE_HEADER,
+        CONTENT_TYPE_NUMBERING, CONTENT_TYPE_PEOPLE, CONTENT_TYPE_SETTINGS, CONTENT_TYPE_STYLES,
+        CONTENT_TYPE_THEME, CONTENT_TYPE_WEB_SETTINGS, CONTENT_TYPE_XML,
+    },
+    custom_xml::properties_name,
+    rels::{Relationships, TargetMode},
+    Docx,
+};
+
+impl<'a> Docx<'a> {
+    /// Fixes common inconsistencies of packages written by other tools.
+    ///
+    /// - relationships sharing an id get new ids, except for the first one,
+    /// - relationships to parts that are missing from the package are
+    ///   removed,
+    /// - parts without a proper content type get one.
+    ///
+    /// Returns a description of every fix.
+    ///
+    /// ```rust
+    /// use docx_rust::Docx;
+    ///
+    /// let mut docx = Docx::default();
+    /// docx.settings = Some(Default::default());
+    ///
+    /// let fixes = docx.repair();
+    /// assert_eq!(
+    ///     fixes,
+    ///     ["added the content type of /word/settings.xml"]
+    /// );
+    /// assert!(docx.repair().is_empty());
+    /// ```
+    pub fn repair(&mut self) -> Vec<String> {
+        let mut fixes = Vec::new();
+        let parts = self.package_parts();
+        let names: HashSet<&str> = parts.iter().map(|(name, _)| name.as_str()).collect();
+
+        let mut rels = vec![("/_rels/.rels", "/", &mut self.rels)];
+        if let Some(document_rels) = &mut self.document_rels {
+            rels.push(("/word/_rels/document.xml.rels", "/word/", document_rels));
+        }
+        if let Some(settings_rels) = &mut self.settings_rels {
+            rels.push(("/word/_rels/settings.xml.rels", "/word/", settings_rels));
+        }
+        for (rels_name, base, rels) in rels {
+            renumber_duplicates(rels, rels_name, &mut fixes);
+            rels.relationships.retain(|rel| {
+                if rel.target_mode == Some(TargetMode::External) {
+                    return true;
+                }
+                let target = resolve(base, &rel.target);
+                let found = names.contains(target.as_str());
+                if !found {
+                    fixes.push(format!(
+                        "removed relationship {} of {} to missing part {}",
+                        rel.id, rels_name, target
+                    ));
+                }
+                found
+            });
+        }
+
+        let content_types = &mut self.content_types;
+        for (name, ty) in &parts {
+            match ty {
+                Part::Xml(ty) => {
+                    if content_types.get(name) != Some(*ty) {
+                        content_types.overrides.retain(|o| o.part != *name);
+                        content_types.add_override(name.clone(), ty);
+                        fixes.push(format!("added the content type of {}", name));
+                    }
+                }
+                Part::Media(ty) => {
+                    if content_types.get(name).is_none() {
+                        if let Some((_, ext)) = name.rsplit_once('.') {
+                            content_types.add_default(ext.to_ascii_lowercase(), ty);
+                            fixes.push(format!("added the content type of .{} files", ext));
+                        }
+                    }
+                }
+                Part::Other => (),
+            }
+        }
+
+        fixes
+    }
+
+    /// Returns the name and content type of the parts that are written,
+    /// relationship parts excepted.
+    pub(crate) fn package_parts(&self) -> Vec<(String, Part)> {
+        let xml = [
+            (
+                "docProps/app.xml",
+                self.app.is_some(),
+                CONTENT_TYPE_EXTENDED,
+            ),
+            ("docProps/core.xml", self.core.is_some(), CONTENT_TYPE_CORE),
+            ("word/document.xml", true, CONTENT_TYPE_DOCUMENT),
+            ("word/styles.xml", true, CONTENT_TYPE_STYLES),
+            (
+                "word/fontTable.xml",
+                self.font_table.is_some(),
+                CONTENT_TYPE_FONT_TABLE,
+            ),
+            (
+                "word/footnotes.xml",
+                self.footnotes.is_some(),
+                CONTENT_TYPE_FOOTNOTES,
+            ),
+            (
+                "word/endnotes.xml",
+                self.endnotes.is_some(),
+                CONTENT_TYPE_ENDNOTES,
+            ),
+            (
+                "word/settings.xml",
+                self.settings.is_some(),
+                CONTENT_TYPE_SETTINGS,
+            ),
+            (
+                "word/webSettings.xml",
+                self.web_settings.is_some(),
+                CONTENT_TYPE_WEB_SETTINGS,
+            ),
+            (
+                "word/comments.xml",
+                self.comments.is_some(),
+                CONTENT_TYPE_COMMENTS,
+            ),
+            (
+                "word/commentsExtended.xml",
+                self.comments_extended.is_some(),
+                CONTENT_TYPE_COMMENTS_EXTENDED,
+            ),
+            (
+                "word/people.xml",
+                self.people.is_some(),
+                CONTENT_TYPE_PEOPLE,
+            ),
+            (
+                "word/numbering.xml",
+                self.numbering.is_some(),
+                CONTENT_TYPE_NUMBERING,
+            ),
+        ];
+        let mut parts: Vec<_> = xml
+            .into_iter()
+            .filter(|(_, present, _)| *present)
+            .map(|(name, _, ty)| (format!("/{}", name), Part::Xml(ty)))
+            .collect();
+
+        for (names, ty) in [
+            (self.headers.keys().collect::<Vec<_>>(), CONTENT_TYPE_HEADER),
+            (self.footers.keys().collect::<Vec<_>>(), CONTENT_TYPE_FOOTER),
+            (self.themes.keys().collect::<Vec<_>>(), CONTENT_TYPE_THEME),
+        ] {
+            parts.extend(
+                names
+                    .into_iter()
+                    .map(|name| (format!("/word/{}", name), Part::Xml(ty))),
+            );
+        }
+        for name in self.media.keys() {
+            let ty = name
+                .rsplit_once('.')
+                .and_then(|(_, ext)| image_content_type(ext))
+                .unwrap_or("image/jpeg");
+            parts.push((format!("/word/{}", name), Part::Media(ty)));
+        }
+        for name in self.charts.keys() {
+            let ty = chart_content_type(name);
+            parts.push((format!("/word/{}", name), Part::Xml(ty)));
+        }
+        for name in self.embeddings.keys() {
+            let ty = embedding_content_type(name);
+            parts.push((format!("/word/{}", name), Part::Media(ty)));
+        }
+        for (name, part) in &self.custom_xml {
+            parts.push((
+                format!("/customXml/{}", name),
+                Part::Media(CONTENT_TYPE_XML),
+            ));
+            if part.properties.is_some() {
+                parts.push((
+                    format!("/customXml/{}", properties_name(name)),
+                    Part::Xml(CONTENT_TYPE_CUSTOM_XML_PROPS),
+                ));
+            }
+        }
+        if self.bibliography.is_some() {
+            let (name, _) = self.bibliography_part();
+            parts.push((
+                format!("/customXml/{}", properties_name(&name)),
+                Part::Xml(CONTENT_TYPE_CUSTOM_XML_PROPS),
+            ));
+            parts.push((
+                format!("/customXml/{}", name),
+                Part::Media(CONTENT_TYPE_XML),
+            ));
+        }
+        if let Some(thumbnail) = &self.thumbnail {
+            let ty = thumbnail.format.content_type();
+            parts.push((format!("/{}", thumbnail.name()), Part::Media(ty)));
+        }
+        for name in self.other_parts.keys() {
+            parts.push((format!("/{}", name), Part::Other));
+        }
+
+        parts
+    }
+}
+
+/// Content type of a part
+#[derive(Clone, Copy)]
+pub(crate) enum Part {
+    /// An XML part, which needs an override
+    Xml(&'static str),
+    /// A media file, typed by the default for its extension
+    Media(&'static str),
+    /// A part kept as is, typed by the content types parsed
+    Other,
+}
+
+impl Part {
+    pub(crate) fn content_type(self) -> Option<&'static str> {
+        match self {
+            Part::Xml(ty) | Part::Media(ty) => Some(ty),
+            Part::Other => None,
+        }
+    }
+}
+
+/// Gives new ids to the relationships whose id is already taken.
+fn renumber_duplicates(rels: &mut Relationships, rels_name: &str, fixes: &mut Vec<String>) {
+    let mut ids: HashSet<String> = HashSet::new();
+    let mut duplicates = Vec::new();
+    for (i, rel) in rels.relationships.iter().enumerate() {
+        if !ids.insert(rel.id.to_string()) {
+            duplicates.push(i);
+        }
+    }
+
+    let mut next = 1;
+    for i in duplicates {
+        while ids.contains(&format!("rId{}", next)) {
+            next += 1;
+        }
+        let id = format!("rId{}", next);
+        fixes.push(format!(
+            "renamed duplicate relationship {} of {} to {}",
+            rels.relationships[i].id, rels_name, id
+        ));
+        ids.insert(id.clone());
+        rels.relationships[i].id = id.into();
+    }
+}
+
+/// Resolves a relationship target against the folder of its source part.
+pub(crate) fn resolve(base: &str, target: &str) -> String {
+    let path = if target.starts_with('/') {
+        target.to_string()
+    } else {
+        format!("{}{}", base, target)
+    };
+
+    let mut segments: Vec<&str> = Vec::new();
+    for segment in path.split('/') {
+        match segment {
+            "" | "." => (),
+            ".." => {
+                segments.pop();
+            }
+            segment => segments.push(segment),
+        }
+    }
+    format!("/{}", segments.join("/"))
+}
+
+#[test]
+fn repair() {
+    use crate::rels::Relationship;
+
+    let mut docx = Docx::default();
+    let rels = docx.document_rels.get_or_insert_with(Default::default);
+    rels.add_rel(crate::schema::SCHEMA_STYLES, "styles.xml");
+    rels.add_rel(crate::schema::SCHEMA_FOOTNOTES, "footnotes.xml");
+    rels.relationships.push(Relationship {
+        id: "rId1".into(),
+        target: "../word/styles.xml".into(),
+        ty: crate::schema::SCHEMA_STYLES.into(),
+        target_mode: None,
+    });
+
+    let fixes = docx.repair();
+    assert_eq!(
+        fixes,
+        [
+            "renamed duplicate relationship rId1 of /word/_rels/document.xml.rels to rId3",
+            "removed relationship rId2 of /word/_rels/document.xml.rels to missing part /word/footnotes.xml",
+        ]
+    );
+    assert_eq!(
+        resolve("/word/", "media/../theme/theme1.xml"),
+        "/word/theme/theme1.xml"
+    );
+}
+
+#[test]
+fn repair_embeddings() {
+    use crate::content_type::CONTENT_TYPE_OLE_OBJECT;
+    use crate::schema::SCHEMA_OLE_OBJECT;
+    use std::borrow::Cow;
+
+    let mut docx = Docx::default();
+    docx.embeddings.insert(
+        "embeddings/oleObject1.bin".into(),
+        Cow::Borrowed(&[0xD0, 0xCF][..]),
+    );
+    let rels = docx.document_rels.get_or_insert_with(Default::default);
+    rels.add_rel(SCHEMA_OLE_OBJECT, "embeddings/oleObject1.bin");
+    rels.add_rel(SCHEMA_OLE_OBJECT, "embeddings/oleObject2.bin");
+
+    let fixes = docx.repair();
+    assert_eq!(
+        fixes,
+        [
+            "removed relationship rId2 of /word/_rels/document.xml.rels to missing part /word/embeddings/oleObject2.bin",
+            "added the content type of .bin files",
+        ]
+    );
+    assert_eq!(
+        docx.content_types.get("/word/embeddings/oleObject1.bin"),
+        Some(CONTENT_TYPE_OLE_OBJECT)
+    );
+    assert_eq!(docx.embedding_data("rId1"), Some(&[0xD0, 0xCF][..]));
+}
+
+#[test]
+fn repair_custom_xml() {
+    use crate::bibliography::{Source, SourceType};
+    use crate::custom_xml::DatastoreItem;
+
+    let mut docx = Docx::default();
+    docx.add_custom_xml(
+        "<invoice/>",
+        DatastoreItem::new("{6E1FD3B1-8B4C-4F4B-9C43-A3C2B1F0D5E7}", "urn:invoice"),
+    );
+    docx.add_source(Source::new("a", SourceType::Book, "A"));
+    docx.add_relationships();
+
+    assert!(docx.repair().iter().all(|fix| !fix.contains("customXml")));
+    let rels = docx.document_rels.as_ref().unwrap();
+    assert!(rels.get_id("../customXml/item1.xml").is_some());
+    assert!(rels.get_id("../customXml/item2.xml").is_some());
+}
@@ -0,0 +1,222 @@
+//! Formatting resolved from the document defaults, the styles and the direct
+//! formatting, as used by the HTML and layout exports
+//!
+//! Only the formatting these exports need is resolved.
+
+use crate::{
+    document::{Paragraph, Run},
+    formatting::{
+        CharacterProperty, HighlightType, JustificationVal, LineSpacing, ParagraphProperty,
+        UnderlineStyle, VertAlignType,
+    },
+    styles::Style,
+    Docx,
+};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) enum Vertical {
+    #[default]
+    Baseline,
+    Superscript,
+    Subscript,
+}
+
+/// Resolved character formatting
+#[derive(Clone, Debug, Default)]
+pub(crate) struct TextFormat {
+    pub(crate) bold: bool,
+    pub(crate) italic: bool,
+    pub(crate) underline: bool,
+    pub(crate) strike: bool,
+    pub(crate) caps: bool,
+    pub(crate) small_caps: bool,
+    pub(crate) hidden: bool,
+    pub(crate) vertical: Vertical,
+    /// Size in half-points
+    pub(crate) size: Option<isize>,
+    pub(crate) font: Option<String>,
+    /// Color as `#RRGGBB`
+    pub(crate) color: Option<String>,
+    /// Highlight or shading, as `#RRGGBB` or a CSS color name
+    pub(crate) background: Option<String>,
+}
+
+impl TextFormat {
+    /// Overrides the formatting with the properties that are set.
+    pub(crate) fn apply(&mut self, property: &CharacterProperty) {
+        let toggles = [
+            (&mut self.bold, property.bold.as_ref().map(|b| b.value)),
+            (&mut self.italic, property.italics.as_ref().map(|i| i.value)),
+            (&mut self.caps, property.caps.as_ref().map(|c| c.value)),
+            (
+                &mut self.small_caps,
+                property.small_caps.as_ref().map(|c| c.value),
+            ),
+            (&mut self.hidden, property.vanish.as_ref().map(|v| v.value)),
+            (
+                &mut self.strike,
+                property
+                    .strike
+                    .as_ref()
+                    .map(|s| s.value)
+                    .or_else(|| property.dstrike.as_ref().map(|s| s.value)),
+            ),
+        ];
+        for (field, value) in toggles {
+            if let Some(value) = value {
+                // a toggle property without a value is on
+                *field = value != Some(false);
+            }
+        }
+
+        if let Some(underline) = &property.underline {
+            self.underline = !matches!(underline.val, Some(UnderlineStyle::None));
+        }
+        if let Some(vertical_align) = &property.vertical_align {
+            self.vertical = match vertical_align.value {
+                Some(VertAlignType::Superscript) => Vertical::Superscript,
+                Some(VertAlignType::Subscript) => Vertical::Subscript,
+                _ => Vertical::Baseline,
+            };
+        }
+        if let Some(size) = &property.size {
+            self.size = Some(size.value);
+        }
+        if let Some(font) = property.fonts.as_ref().and_then(|f| f.ascii.as_ref()) {
+            self.font = Some(font.clone());
+        }
+        if let Some(color) = &property.color {
+            self.color = hex_color(&color.value);
+        }
+        if let Some(fill) = property.shading.as_ref().and_then(|s| s.fill.as_ref()) {
+            self.background = hex_color(fill);
+        }
+        if let Some(highlight) = &property.highlight {
+            self.background = match &highlight.value {
+                None | Some(HighlightType::None) => None,
+                Some(HighlightType::DarkYellow) => Some("olive".to_string()),
+                Some(highlight) => Some(highlight.to_string().to_lowercase()),
+            };
+        }
+    }
+}
+
+/// Resolved paragraph formatting, lengths in twips
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ParagraphFormat {
+    /// `left`, `center`, `right` or `justify`
+    pub(crate) align: Option<&'static str>,
+    pub(crate) left: Option<isize>,
+    pub(crate) right: Option<isize>,
+    /// Indentation of the first line, negative when hanging
+    pub(crate) first_line: Option<isize>,
+    pub(crate) before: Option<isize>,
+    pub(crate) after: Option<isize>,
+    pub(crate) line: Option<LineSpacing>,
+}
+
+impl ParagraphFormat {
+    /// Overrides the formatting with the properties that are set.
+    pub(crate) fn apply(&mut self, property: &ParagraphProperty) {
+        if let Some(justification) = &property.justification {
+            self.align = match justification.value {
+                JustificationVal::Start | JustificationVal::Left => Some("left"),
+                JustificationVal::Center => Some("center"),
+                JustificationVal::End | JustificationVal::Right => Some("right"),
+                JustificationVal::Both
+                | JustificationVal::Distribute
+                | JustificationVal::MediumKashida
+                | JustificationVal::HighKashida
+                | JustificationVal::LowKashida
+                | JustificationVal::ThaiDistribute => Some("justify"),
+                JustificationVal::NumTab | JustificationVal::Other(_) => self.align,
+            };
+        }
+        if let Some(indent) = &property.indent {
+            self.left = indent.leading().or(self.left);
+            self.right = indent.trailing().or(self.right);
+            self.first_line = indent.first_line_offset().or(self.first_line);
+        }
+        if let Some(spacing) = &property.spacing {
+            self.before = spacing.before.or(self.before);
+            self.after = spacing.after.or(self.after);
+            self.line = spacing.line_spacing().or(self.line);
+        }
+    }
+}
+
+impl<'a> Docx<'a> {
+    /// Returns the character formatting of the document defaults.
+    pub(crate) fn default_text_format(&self) -> TextFormat {
+        let mut format = TextFormat::default();
+        if let Some(property) = self
+            .styles
+            .default
+            .as_ref()
+            .and_then(|default| default.character.inner.as_ref())
+        {
+            format.apply(property);
+        }
+        format
+    }
+
+    /// Returns the formatting of a paragraph, and the character formatting
+    /// its runs start from.
+    pub(crate) fn resolve_paragraph(&self, para: &Paragraph) -> (ParagraphFormat, TextFormat) {
+        let mut format = ParagraphFormat::default();
+        let mut text = self.default_text_format();
+        if let Some(default) = self
+            .styles
+            .default
+            .as_ref()
+            .and_then(|default| default.paragraph.inner.as_ref())
+        {
+            format.apply(default);
+        }
+        let style_id = para
+            .property
+            .as_ref()
+            .and_then(|property| property.style_id.as_ref())
+            .map(|style_id| style_id.value.as_ref());
+        for style in self.style_chain(style_id) {
+            if let Some(property) = &style.paragraph {
+                format.apply(property);
+            }
+            if let Some(property) = &style.character {
+                text.apply(property);
+            }
+        }
+        if let Some(property) = &para.property {
+            format.apply(property);
+        }
+        (format, text)
+    }
+
+    /// Returns the formatting of a run, `base` being the character
+    /// formatting of its paragraph.
+    pub(crate) fn resolve_run(&self, run: &Run, base: &TextFormat) -> TextFormat {
+        let mut format = base.clone();
+        if let Some(property) = &run.property {
+            let style_id = property.style_id.as_ref().map(|id| id.value.as_ref());
+            for style in self.style_chain(style_id) {
+                if let Some(property) = &style.character {
+                    format.apply(property);
+                }
+            }
+            format.apply(property);
+        }
+        format
+    }
+
+    /// Returns a style and the styles it is based on, the base first.
+    fn style_chain(&self, style_id: Option<&str>) -> Vec<&Style<'a>> {
+        self.styles.chain(style_id)
+    }
+}
+
+/// Converts a `RRGGBB` color to `#RRGGBB`, `auto` and invalid colors give
+/// `None`.
+fn hex_color(color: &str) -> Option<String> {
+    (color.len() == 6 && color.bytes().all(|b| b.is_ascii_hexdigit()))
+        .then(|| format!("#{}", color))
+}
@@ -0,0 +1,245 @@
+use std::collections::{hash_map::RandomState, HashSet};
+use std::hash::{BuildHasher, Hasher};
+use std::time::SystemTime;
+
+use crate::{
+    settings::{Rsid, RsidRoot, Rsids},
+    walk::runs_mut,
+    Docx,
+};
+
+impl<'a> Docx<'a> {
+    /// Removes every revision save id (`rsid`) from paragraphs, runs,
+    /// sections, styles and settings.
+    ///
+    /// Word adds a new rsid every time a document is saved, so stripping
+    /// them keeps the output of different runs comparable. Word adds them
+    /// back the next time the document is edited.
+    pub fn strip_rsids(&mut self) {
+        for para in self.paragraphs_mut() {
+            para.rsid_r = None;
+            para.rsid_r_default = None;
+            for run in runs_mut(para) {
+                run.rsid_r = None;
+                run.rsid_r_default = None;
+                run.rsid_r_pr = None;
+                run.rsid_del = None;
+            }
+        }
+        for section in self.section_properties_mut() {
+            section.rsid_r = None;
+            section.rsid_r_default = None;
+            if let Some(previous) = section
+                .revision
+                .as_mut()
+                .and_then(|revision| revision.section_property.as_mut())
+            {
+                previous.rsid_r = None;
+                previous.rsid_r_default = None;
+            }
+        }
+        for style in self.styles.styles.iter_mut() {
+            style.rsid = None;
+        }
+        if let Some(settings) = &mut self.settings {
+            settings.rsids = None;
+        }
+    }
+
+    /// Generates a revision save id that isn't used yet, and adds it to the
+    /// rsids of the settings, as the root if it is the first one.
+    ///
+    /// Pass it to [`Docx::stamp_rsid`] to mark the content added in this
+    /// editing session.
+    ///
+    /// ```rust
+    /// use docx_rust::Docx;
+    ///
+    /// let mut docx = Docx::default();
+    /// docx.add_paragraph("Hello");
+    ///
+    /// let rsid = docx.new_rsid();
+    /// docx.stamp_rsid(&rsid);
+    ///
+    /// assert_eq!(rsid.len(), 8);
+    /// ```
+    pub fn new_rsid(&mut self) -> String {
+        let rsids = self
+            .settings
+            .get_or_insert_with(Default::default)
+            .rsids
+            .get_or_insert_with(Default::default);
+
+        let rsid = loop {
+            let rsid = format!("{:08X}", random() & 0x00FF_FFFF);
+            if !rsids.contains(&rsid) {
+                break rsid;
+            }
+        };
+
+        if rsids.ro.is_none() {
+            rsids.ro = Some(RsidRoot {
+                val: rsid.clone().into(),
+            });
+        }
+        rsids.rsids.push(Rsid {
+            val: rsid.clone().into(),
+        });
+        rsid
+    }
+
+    /// Sets the given revision save id on the paragraphs and runs that don't
+    /// have one yet.
+    pub fn stamp_rsid(&mut self, rsid: &str) {
+        for para in self.paragraphs_mut() {
+            if para.rsid_r.is_none() {
+                para.rsid_r = Some(rsid.to_owned().into());
+            }
+            if para.rsid_r_default.is_none() {
+                para.rsid_r_default = Some(rsid.to_owned().into());
+            }
+            for run in runs_mut(para) {
+                if run.rsid_r.is_none() {
+                    run.rsid_r = Some(rsid.to_owned().into());
+                }
+            }
+        }
+    }
+}
+
+impl<'a> Docx<'a> {
+    /// Gives every paragraph without one a unique paragraph id
+    /// (`w14:paraId`) and text id (`w14:textId`), as Word does.
+    ///
+    /// Comments extended and co-authoring refer to paragraphs by these ids,
+    /// which Word keeps as long as the paragraph exists. Existing ids are
+    /// kept. See [`WriteOptions::para_ids`] to assign them when writing.
+    ///
+    /// ```rust
+    /// use docx_rust::document::BodyContent;
+    /// use docx_rust::Docx;
+    ///
+    /// let mut docx = Docx::default();
+    /// docx.add_paragraph("Hello");
+    /// docx.assign_para_ids();
+    ///
+    /// match &docx.document.body.content[0] {
+    ///     BodyContent::Paragraph(para) => assert_eq!(para.id.as_ref().unwrap().len(), 8),
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    ///
+    /// [`WriteOptions::para_ids`]: crate::WriteOptions::para_ids
+    pub fn assign_para_ids(&mut self) {
+        self.assign_para_ids_with(random);
+    }
+
+    /// Assigns paragraph ids, taking candidates from `next` until one is
+    /// valid and unused.
+    pub(crate) fn assign_para_ids_with<F: FnMut() -> u32>(&mut self, mut next: F) {
+        let mut used: HashSet<String> = self
+            .paragraphs()
+            .into_iter()
+            .flat_map(|para| [&para.id, &para.text_id])
+            .flatten()
+            .map(|id| id.to_string())
+            .collect();
+
+        for para in self.paragraphs_mut() {
+            if para.id.is_none() {
+                para.id = Some(new_para_id(&mut used, &mut next).into());
+            }
+            if para.text_id.is_none() {
+                para.text_id = Some(new_para_id(&mut used, &mut next).into());
+            }
+        }
+    }
+}
+
+/// Returns a paragraph or text id that isn't in `used`, taking candidates
+/// from `next`, and adds it to `used`.
+pub(crate) fn new_para_id<F: FnMut() -> u32>(used: &mut HashSet<String>, mut next: F) -> String {
+    loop {
+        // ids must be below 0x80000000, and 0 is reserved
+        let id = format!("{:08X}", next() & 0x7FFF_FFFF);
+        if id != "00000000" && used.insert(id.clone()) {
+            break id;
+        }
+    }
+}
+
+impl Rsids<'_> {
+    /// Returns whether the rsid is listed.
+    pub fn contains(&self, rsid: &str) -> bool {
+        self.ro.as_ref().is_some_and(|r| r.val == rsid) || self.rsids.iter().any(|r| r.val == rsid)
+    }
+}
+
+pub(crate) fn random() -> u32 {
+    let mut hasher = RandomState::new().build_hasher();
+    if let Ok(elapsed) = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
+        hasher.write_u128(elapsed.as_nanos());
+    }
+    hasher.finish() as u32
+}
+
+#[test]
+fn rsids() {
+    use crate::document::BodyContent;
+
+    let mut docx = Docx::default();
+    docx.add_paragraph("Hello");
+
+    let first = docx.new_rsid();
+    let second = docx.new_rsid();
+    assert_ne!(first, second);
+    assert!(first.starts_with("00"));
+
+    let rsids = docx.settings.as_ref().unwrap().rsids.as_ref().unwrap();
+    assert_eq!(rsids.ro.as_ref().unwrap().val, first);
+    assert!(rsids.contains(&second));
+
+    docx.stamp_rsid(&second);
+    match &docx.document.body.content[0] {
+        BodyContent::Paragraph(para) => assert_eq!(para.rsid_r.as_deref(), Some(&*second)),
+        _ => unreachable!(),
+    }
+
+    docx.strip_rsids();
+    match &docx.document.body.content[0] {
+        BodyContent::Paragraph(para) => assert_eq!(para.rsid_r, None),
+        _ => unreachable!(),
+    }
+    assert!(docx.settings.unwrap().rsids.is_none());
+}
+
+#[test]
+fn para_ids() {
+    use crate::document::{BodyContent, Paragraph};
+
+    let mut docx = Docx::default();
+    docx.document
+        .push(Paragraph::default().id("00000001").push_text("Kept"));
+    docx.add_paragraph("New");
+
+    let mut counter = 0;
+    docx.assign_para_ids_with(|| {
+        counter += 1;
+        counter
+    });
+
+    let ids: Vec<_> = docx
+        .document
+        .body
+        .content
+        .iter()
+        .map(|content| match content {
+            BodyContent::Paragraph(para) => (para.id.clone(), para.text_id.clone()),
+            _ => unreachable!(),
+        })
+        .collect();
+    assert_eq!(ids[0].0.as_deref(), Some("00000001"));
+    assert_eq!(ids[0].1.as_deref(), Some("00000002"));
+    assert_eq!(ids[1].0.as_deref(), Some("00000003"));
+    assert_eq!(ids[1].1.as_deref(), Some("00000004"));
+}
@@ -0,0 +1,335 @@
+//! RTF export of the document body
+//!
+//! Covers paragraphs with their alignment, runs with their direct
+//! formatting, links and tables. Styles, numbering, images and fields other
+//! than links are not exported.
+
+use crate::{
+    document::{
+        BodyContent, BreakType, Paragraph, ParagraphContent, Run, RunContent, Table,
+        TableCellContent, TableRowContent,
+    },
+    formatting::{
+        CharacterProperty, JustificationVal, TableWidthUnit, UnderlineStyle, VertAlignType,
+    },
+    walk::paragraph_content,
+    Docx,
+};
+
+/// Width of table columns without a width, in twips
+const DEFAULT_COLUMN_WIDTH: isize = 2000;
+
+impl<'a> Docx<'a> {
+    /// Converts the body of the document to RTF.
+    ///
+    /// ```rust
+    /// use docx_rust::Docx;
+    ///
+    /// let mut docx = Docx::default();
+    /// docx.add_paragraph("Hello, {world}");
+    ///
+    /// let rtf = docx.to_rtf();
+    /// assert!(rtf.starts_with(r"{\rtf1\ansi"));
+    /// assert!(rtf.contains(r"{Hello, \{world\}}\par"));
+    /// ```
+    pub fn to_rtf(&self) -> String {
+        let mut writer = RtfWriter {
+            docx: self,
+            body: String::new(),
+            fonts: Vec::new(),
+            colors: Vec::new(),
+        };
+        writer.content(&self.document.body.content);
+
+        let mut rtf = String::from(r"{\rtf1\ansi\deff0{\fonttbl{\f0 Calibri;}");
+        for (i, font) in writer.fonts.iter().enumerate() {
+            rtf.push_str(&format!(r"{{\f{} {};}}", i + 1, escape(font)));
+        }
+        rtf.push_str(r"}{\colortbl ;");
+        for color in &writer.colors {
+            rtf.push_str(&format!(
+                r"\red{}\green{}\blue{};",
+                color[0], color[1], color[2]
+            ));
+        }
+        rtf.push_str("}\n");
+        rtf.push_str(&writer.body);
+        rtf.push('}');
+        rtf
+    }
+}
+
+struct RtfWriter<'d, 'a> {
+    docx: &'d Docx<'a>,
+    body: String,
+    /// Fonts of the font table, after the default font `\f0`
+    fonts: Vec<String>,
+    /// Colors of the color table, after the automatic color `\cf0`
+    colors: Vec<[u8; 3]>,
+}
+
+impl RtfWriter<'_, '_> {
+    fn content(&mut self, content: &[BodyContent]) {
+        for content in content {
+            match content {
+                BodyContent::Paragraph(para) => {
+                    self.paragraph(para, false);
+                    self.body.push_str("\\par\n");
+                }
+                BodyContent::Table(table) => self.table(table),
+                BodyContent::TableCell(cell) => {
+                    for TableCellContent::Paragraph(para) in &cell.content {
+                        self.paragraph(para, false);
+                        self.body.push_str("\\par\n");
+                    }
+                }
+                BodyContent::Sdt(sdt) => {
+                    if let Some(sdt_content) = &sdt.content {
+                        self.content(&sdt_content.content);
+                    }
+                }
+                BodyContent::SectionProperty(_) | BodyContent::Raw(_) => (),
+            }
+        }
+    }
+
+    fn table(&mut self, table: &Table) {
+        for row in &table.rows {
+            let cells: Vec<_> = row
+                .cells
+                .iter()
+                .filter_map(|cell| match cell {
+                    TableRowContent::TableCell(cell) => Some(cell),
+                    TableRowContent::SDT(_) => None,
+                })
+                .collect();
+
+            self.body.push_str(r"\trowd\trgaph108");
+            let mut right = 0;
+            for (i, cell) in cells.iter().enumerate() {
+                let width = cell
+                    .property
+                    .wide
+                    .as_ref()
+                    .filter(|wide| matches!(wide.unit, None | Some(TableWidthUnit::Dxa)))
+                    .and_then(|wide| wide.value)
+                    .or_else(|| table.grids.columns.get(i).map(|column| column.width))
+                    .unwrap_or(DEFAULT_COLUMN_WIDTH);
+                right += width;
+                self.body.push_str(&format!(r"\cellx{}", right));
+            }
+            self.body.push('\n');
+
+            for cell in cells {
+                let paras: Vec<_> = cell
+                    .content
+                    .iter()
+                    .map(|TableCellContent::Paragraph(para)| para)
+                    .collect();
+                if paras.is_empty() {
+                    self.body.push_str(r"\pard\intbl");
+                }
+                for (i, para) in paras.iter().enumerate() {
+                    self.paragraph(para, true);
+                    if i + 1 < paras.len() {
+                        self.body.push_str(r"\par ");
+                    }
+                }
+                self.body.push_str("\\cell\n");
+            }
+            self.body.push_str("\\row\n");
+        }
+        self.body.push_str(r"\pard");
+    }
+
+    /// Writes a paragraph, without the control word that ends it.
+    fn paragraph(&mut self, para: &Paragraph, in_table: bool) {
+        self.body.push_str(r"\pard");
+        if in_table {
+            self.body.push_str(r"\intbl");
+        }
+        let justification = para
+            .property
+            .as_ref()
+            .and_then(|property| property.justification.as_ref());
+        if let Some(justification) = justification {
+            self.body.push_str(match justification.value {
+                JustificationVal::Start | JustificationVal::Left => r"\ql",
+                JustificationVal::Center => r"\qc",
+                JustificationVal::End | JustificationVal::Right => r"\qr",
+                JustificationVal::Both => r"\qj",
+                JustificationVal::Distribute
+                | JustificationVal::MediumKashida
+                | JustificationVal::HighKashida
+                | JustificationVal::LowKashida => r"\qd",
+                JustificationVal::ThaiDistribute => r"\qt",
+                JustificationVal::NumTab | JustificationVal::Other(_) => "",
+            });
+        }
+        self.body.push(' ');
+
+        for content in paragraph_content(para) {
+            match content {
+                ParagraphContent::Run(run) => self.run(run),
+                ParagraphContent::Link(link) => match self.docx.hyperlink_target(link) {
+                    Some(target) => {
+                        self.body.push_str(&format!(
+                            r#"{{\field{{\*\fldinst HYPERLINK "{}"}}{{\fldrslt "#,
+                            escape(target)
+                        ));
+                        self.run(&link.content);
+                        self.body.push_str("}}");
+                    }
+                    None => self.run(&link.content),
+                },
+                _ => (),
+            }
+        }
+    }
+
+    fn run(&mut self, run: &Run) {
+        self.body.push('{');
+        if let Some(property) = &run.property {
+            self.character_property(property);
+            if !self.body.ends_with('{') {
+                self.body.push(' ');
+            }
+        }
+        for content in &run.content {
+            match content {
+                RunContent::Text(text) => self.body.push_str(&escape(&text.text)),
+                RunContent::Tab(_) => self.body.push_str(r"\tab "),
+                RunContent::Break(br) => match br.ty {
+                    Some(BreakType::Page) => self.body.push_str(r"\page "),
+                    Some(BreakType::Column) => self.body.push_str(r"\column "),
+                    _ => self.body.push_str(r"\line "),
+                },
+                RunContent::CarriageReturn(_) => self.body.push_str(r"\line "),
+                RunContent::NoBreakHyphen(_) => self.body.push_str(r"\_"),
+                RunContent::SoftHyphen(_) => self.body.push_str(r"\-"),
+                _ => (),
+            }
+        }
+        self.body.push('}');
+    }
+
+    fn character_property(&mut self, property: &CharacterProperty) {
+        let toggles = [
+            (property.bold.as_ref().map(|b| b.value), r"\b"),
+            (property.italics.as_ref().map(|i| i.value), r"\i"),
+            (property.strike.as_ref().map(|s| s.value), r"\strike"),
+            (property.dstrike.as_ref().map(|s| s.value), r"\striked1"),
+            (property.caps.as_ref().map(|c| c.value), r"\caps"),
+            (property.small_caps.as_ref().map(|c| c.value), r"\scaps"),
+            (property.vanish.as_ref().map(|v| v.value), r"\v"),
+        ];
+        for (value, word) in toggles {
+            if let Some(value) = value {
+                self.body.push_str(word);
+                if value == Some(false) {
+                    self.body.push('0');
+                }
+            }
+        }
+
+        if let Some(underline) = &property.underline {
+            self.body.push_str(match underline.val {
+                Some(UnderlineStyle::None) => r"\ulnone",
+                Some(UnderlineStyle::Double) => r"\uldb",
+                _ => r"\ul",
+            });
+        }
+        if let Some(vertical_align) = &property.vertical_align {
+            self.body.push_str(match vertical_align.value {
+                Some(VertAlignType::Superscript) => r"\super",
+                Some(VertAlignType::Subscript) => r"\sub",
+                _ => r"\nosupersub",
+            });
+        }
+        if let Some(size) = &property.size {
+            self.body.push_str(&format!(r"\fs{}", size.value));
+        }
+        if let Some(font) = property.fonts.as_ref().and_then(|f| f.ascii.as_ref()) {
+            let index = match self.fonts.iter().position(|f| f == font) {
+                Some(index) => index,
+                None => {
+                    self.fonts.push(font.clone());
+                    self.fonts.len() - 1
+                }
+            };
+            self.body.push_str(&format!(r"\f{}", index + 1));
+        }
+        if let Some(color) = property.color.as_ref().and_then(|c| parse_color(&c.value)) {
+            let index = match self.colors.iter().position(|c| *c == color) {
+                Some(index) => index,
+                None => {
+                    self.colors.push(color);
+                    self.colors.len() - 1
+                }
+            };
+            self.body.push_str(&format!(r"\cf{}", index + 1));
+        }
+    }
+}
+
+/// Parses a `RRGGBB` color, `auto` and invalid colors give `None`.
+fn parse_color(color: &str) -> Option<[u8; 3]> {
+    if color.len() != 6 {
+        return None;
+    }
+    let value = u32::from_str_radix(color, 16).ok()?;
+    Some([(value >> 16) as u8, (value >> 8) as u8, value as u8])
+}
+
+/// Escapes the special characters of RTF, and encodes non-ASCII characters
+/// as `\u` control words with `?` as the fallback.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | '{' | '}' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str(r"\line "),
+            '\t' => escaped.push_str(r"\tab "),
+            c if c.is_ascii() => escaped.push(c),
+            c => {
+                let mut units = [0; 2];
+                for unit in c.encode_utf16(&mut units) {
+                    escaped.push_str(&format!(r"\u{}?", *unit as i16));
+                }
+            }
+        }
+    }
+    escaped
+}
+
+#[test]
+fn rtf() {
+    use crate::formatting::{Bold, Color, Italics, Size};
+
+    assert_eq!(escape(r"a\b{c}"), r"a\\b\{c\}");
+    assert_eq!(escape("é€😀"), r"\u233?\u8364?\u-10179?\u-8704?");
+    assert_eq!(parse_color("FF8000"), Some([255, 128, 0]));
+    assert_eq!(parse_color("auto"), None);
+
+    let mut docx = Docx::default();
+    let run = Run::default()
+        .property(
+            CharacterProperty::default()
+                .bold(Bold::from(true))
+                .italics(Italics::from(false))
+                .size(Size::from(28isize))
+                .color(Color::from("FF0000")),
+        )
+        .push_text("Bold");
+    let link = docx.hyperlink("https://example.com", "link");
+    docx.document
+        .push(Paragraph::default().push(run).push(link));
+
+    let rtf = docx.to_rtf();
+    assert!(rtf.contains(r"{\colortbl ;\red255\green0\blue0;}"));
+    assert!(rtf.contains(r"{\b\i0\fs28\cf1 Bold}"));
+    assert!(rtf.contains(r#"{\field{\*\fldinst HYPERLINK "https://example.com"}{\fldrslt "#));
+}
@@ -1,5 +1,14 @@
 #![allow(dead_code)]
 
+use hard_xml::{XmlResult, XmlWriter};
+use std::{
+    cell::RefCell,
+    io::{self, Write},
+};
+use xmlparser::{Token, Tokenizer};
+
+use crate::escape::BufferedWriter;
+
 pub const SCHEMA_XML: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\r\n";
 pub const SCHEMA_DC: &str = "http://purl.org/dc/elements/1.1/";
 pub const SCHEMA_CORE_2: &str =
@@ -10,11 +19,28 @@ pub const SCHEMA_CONTENT_TYPES: &str =
     "http://schemas.openxmlformats.org/package/2006/content-types";
 pub const SCHEMA_MAIN: &str = "http://schemas.openxmlformats.org/wordprocessingml/2006/main";
 pub const SCHEMA_WORDML_14: &str = "http://schemas.microsoft.com/office/word/2010/wordml";
+pub const SCHEMA_WORDML_15: &str = "http://schemas.microsoft.com/office/word/2012/wordml";
 pub const SCHEMA_DRAWINGML: &str = "http://schemas.openxmlformats.org/drawingml/2006/main";
 pub const SCHEMA_WP: &str =
     "http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing";
+pub const SCHEMA_WP_14: &str =
+    "http://schemas.microsoft.com/office/word/2010/wordprocessingDrawing";
+pub const SCHEMA_WPS: &str = "http://schemas.microsoft.com/office/word/2010/wordprocessingShape";
+pub const SCHEMA_WPG: &str = "http://schemas.microsoft.com/office/word/2010/wordprocessingGroup";
+pub const SCHEMA_MC: &str = "http://schemas.openxmlformats.org/markup-compatibility/2006";
+pub const SCHEMA_PICTURE: &str = "http://schemas.openxmlformats.org/drawingml/2006/picture";
+pub const SCHEMA_DECORATIVE: &str = "http://schemas.microsoft.com/office/drawing/2017/decorative";
+pub const SCHEMA_DRAWINGML_CHART: &str = "http://schemas.openxmlformats.org/drawingml/2006/chart";
+pub const SCHEMA_MATH: &str = "http://schemas.openxmlformats.org/officeDocument/2006/math";
+pub const SCHEMA_VML: &str = "urn:schemas-microsoft-com:vml";
+pub const SCHEMA_OFFICE: &str = "urn:schemas-microsoft-com:office:office";
+pub const SCHEMA_WORD_10: &str = "urn:schemas-microsoft-com:office:word";
 pub const SCHEMA_RELATIONSHIPS_DOCUMENT: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships";
+pub const SCHEMA_BIBLIOGRAPHY: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/bibliography";
+pub const SCHEMA_CUSTOM_XML_DATA: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/customXml";
 pub const SCHEMAS_EXTENDED: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/extended-properties";
 
@@ -55,6 +81,22 @@ pub const SCHEMA_NUMBERING: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/numbering";
 pub const SCHEMA_HYPERLINK: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/hyperlink";
+pub const SCHEMA_SUBDOCUMENT: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/subDocument";
+pub const SCHEMA_MAIL_MERGE_SOURCE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/mailMergeSource";
+pub const SCHEMA_CHART: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/chart";
+pub const SCHEMA_PACKAGE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/package";
+pub const SCHEMA_THUMBNAIL: &str =
+    "http://schemas.openxmlformats.org/package/2006/relationships/metadata/thumbnail";
+pub const SCHEMA_OLE_OBJECT: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/oleObject";
+pub const SCHEMA_CUSTOM_XML: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/customXml";
+pub const SCHEMA_CUSTOM_XML_PROPS: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/customXmlProps";
 
 pub const SCHEMA_COMMENTS_EXT: &str =
     "http://schemas.microsoft.com/office/2018/08/relationships/commentsExtensible";
@@ -62,3 +104,393 @@ pub const SCHEMA_COMMENTS_IDS: &str =
     "http://schemas.microsoft.com/office/2016/09/relationships/commentsIds";
 pub const SCHEMA_COMMENTS_EXTENDED: &str =
     "http://schemas.microsoft.com/office/2011/relationships/commentsExtended";
+pub const SCHEMA_PEOPLE: &str = "http://schemas.microsoft.com/office/2011/relationships/people";
+
+/// Namespaces declared on demand, when their prefix is used in the content of a part.
+const KNOWN_NAMESPACES: &[(&str, &str)] = &[
+    ("w", SCHEMA_MAIN),
+    ("r", SCHEMA_RELATIONSHIPS_DOCUMENT),
+    ("w14", SCHEMA_WORDML_14),
+    ("w15", SCHEMA_WORDML_15),
+    ("wp", SCHEMA_WP),
+    ("wp14", SCHEMA_WP_14),
+    ("wps", SCHEMA_WPS),
+    ("wpg", SCHEMA_WPG),
+    ("a", SCHEMA_DRAWINGML),
+    ("pic", SCHEMA_PICTURE),
+    ("c", SCHEMA_DRAWINGML_CHART),
+    ("m", SCHEMA_MATH),
+    ("v", SCHEMA_VML),
+    ("o", SCHEMA_OFFICE),
+    ("w10", SCHEMA_WORD_10),
+    ("mc", SCHEMA_MC),
+];
+
+/// Extension namespaces older consumers are allowed to ignore.
+const IGNORABLE_NAMESPACES: &[&str] = &["w14", "w15", "wp14"];
+
+/// The namespace declarations of the root element of a part.
+///
+/// Every root part starts from a base set and, while writing, adds the known
+/// namespaces whose prefix is used by the elements and attributes of its
+/// content, so features such as
+/// drawings or `mc:AlternateContent` don't need per-part declarations.
+/// Declaring an extension namespace also declares `mc`, and the declared
+/// extension namespaces are listed in `mc:Ignorable`.
+///
+/// ```rust
+/// use docx_rust::NamespaceSet;
+///
+/// let mut ns = NamespaceSet::wordprocessing();
+/// ns.detect(r#"<w:p><w:r><w:drawing><wp:inline/></w:drawing></w:r></w:p>"#);
+///
+/// assert!(ns.contains("wp"));
+/// assert!(!ns.contains("pic"));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct NamespaceSet {
+    namespaces: Vec<(&'static str, &'static str)>,
+}
+
+impl NamespaceSet {
+    /// Declares the WordprocessingML main and Word 2010 namespaces, and the
+    /// markup compatibility namespace marking the latter as ignorable.
+    pub fn wordprocessing() -> Self {
+        NamespaceSet::default()
+            .with("w", SCHEMA_MAIN)
+            .with("w14", SCHEMA_WORDML_14)
+    }
+
+    pub fn with(mut self, prefix: &'static str, uri: &'static str) -> Self {
+        self.insert(prefix, uri);
+        self
+    }
+
+    /// Declares a namespace, replacing the uri of an already declared prefix.
+    pub fn insert(&mut self, prefix: &'static str, uri: &'static str) -> &mut Self {
+        match self.namespaces.iter_mut().find(|(p, _)| *p == prefix) {
+            Some(ns) => ns.1 = uri,
+            None => self.push(prefix, uri),
+        }
+        self
+    }
+
+    /// Declares a new namespace, and `mc` along with an extension namespace.
+    fn push(&mut self, prefix: &'static str, uri: &'static str) {
+        self.namespaces.push((prefix, uri));
+        if IGNORABLE_NAMESPACES.contains(&prefix) && !self.contains("mc") {
+            self.namespaces.push(("mc", SCHEMA_MC));
+        }
+    }
+
+    pub fn contains(&self, prefix: &str) -> bool {
+        self.namespaces.iter().any(|(p, _)| *p == prefix)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &(&'static str, &'static str)> {
+        self.namespaces.iter()
+    }
+
+    /// Declares every known namespace whose prefix is used by an element or
+    /// attribute of the given serialized content.
+    pub fn detect(&mut self, xml: &str) -> &mut Self {
+        self.declare_used(&prefixes(xml))
+    }
+
+    /// Declares every known namespace whose prefix is in `used`.
+    fn declare_used<S: AsRef<str>>(&mut self, used: &[S]) -> &mut Self {
+        for (prefix, uri) in KNOWN_NAMESPACES {
+            if !self.contains(prefix) && used.iter().any(|p| p.as_ref() == *prefix) {
+                self.push(prefix, uri);
+            }
+        }
+        self
+    }
+
+    fn ignorable(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.namespaces
+            .iter()
+            .map(|(p, _)| *p)
+            .filter(|p| IGNORABLE_NAMESPACES.contains(p))
+    }
+
+    /// Writes the declarations as attributes of the element being started.
+    pub fn write<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
+        for (prefix, uri) in &self.namespaces {
+            writer.write_attribute(&format!("xmlns:{}", prefix), uri)?;
+        }
+        if self.contains("mc") {
+            let ignorable: Vec<_> = self.ignorable().collect();
+            if !ignorable.is_empty() {
+                writer.write_attribute("mc:Ignorable", &ignorable.join(" "))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Writes the root element of a part, declaring `namespaces` together with
+/// the known namespaces used by the content.
+pub(crate) fn write_root<W, F>(
+    writer: &mut XmlWriter<W>,
+    tag: &str,
+    mut namespaces: NamespaceSet,
+    content: F,
+) -> XmlResult<()>
+where
+    W: Write,
+    F: FnOnce(&mut XmlWriter<BufferedWriter<PrefixTracker>>) -> XmlResult<()>,
+{
+    let previous = USED_PREFIXES.with(|used| used.replace(Some(UsedPrefixes::default())));
+    let mut buffer = XmlWriter::new(BufferedWriter::new(PrefixTracker::default()));
+    let result = content(&mut buffer).and_then(|()| Ok(buffer.inner.flush()?));
+    let used = USED_PREFIXES
+        .with(|used| used.replace(previous))
+        .unwrap_or_default();
+    result?;
+
+    namespaces.declare_used(&used.prefixes);
+
+    writer.write_element_start(tag)?;
+    namespaces.write(writer)?;
+    writer.write_element_end_open()?;
+    writer.inner.write_all(&buffer.inner.inner.buffer)?;
+    writer.write_element_end_close(tag)?;
+
+    Ok(())
+}
+
+/// Writes raw XML, e.g. an element kept as it was read, recording the
+/// prefixes of its elements and attributes for [`write_root`].
+pub(crate) fn write_raw<W: Write>(writer: &mut XmlWriter<W>, xml: &str) -> XmlResult<()> {
+    // the markup written so far is scanned before the flag is set, and the
+    // raw XML before it is reset
+    writer.inner.flush()?;
+    let collecting = USED_PREFIXES.with(|used| match used.borrow_mut().as_mut() {
+        Some(used) => {
+            used.raw = true;
+            true
+        }
+        None => false,
+    });
+    if collecting {
+        for prefix in prefixes(xml) {
+            use_prefix(prefix);
+        }
+    }
+    let result = writer
+        .inner
+        .write_all(xml.as_bytes())
+        .and_then(|_| writer.inner.flush());
+    if collecting {
+        USED_PREFIXES.with(|used| {
+            if let Some(used) = used.borrow_mut().as_mut() {
+                used.raw = false;
+            }
+        });
+    }
+    result?;
+    Ok(())
+}
+
+/// The namespace prefixes used by the part being written, see [`write_root`]
+#[derive(Debug, Default)]
+struct UsedPrefixes {
+    prefixes: Vec<String>,
+    /// Set while raw XML is written, whose prefixes are read by
+    /// [`write_raw`] instead
+    raw: bool,
+}
+
+thread_local! {
+    static USED_PREFIXES: RefCell<Option<UsedPrefixes>> = const { RefCell::new(None) };
+}
+
+/// Records a prefix used by the part being written.
+fn use_prefix(prefix: &str) {
+    if prefix.is_empty() || prefix == "xmlns" {
+        return;
+    }
+    USED_PREFIXES.with(|used| {
+        if let Some(used) = used.borrow_mut().as_mut() {
+            if !used.prefixes.iter().any(|p| p == prefix) {
+                used.prefixes.push(prefix.to_string());
+            }
+        }
+    });
+}
+
+/// Returns the prefixes of the elements and attributes of some XML.
+fn prefixes(xml: &str) -> Vec<&str> {
+    let mut prefixes = Vec::new();
+    for token in Tokenizer::from(xml) {
+        let prefix = match token {
+            Ok(Token::ElementStart { prefix, .. }) | Ok(Token::Attribute { prefix, .. }) => prefix,
+            Ok(_) => continue,
+            Err(_) => break,
+        };
+        if !prefix.is_empty() && !prefixes.contains(&prefix.as_str()) {
+            prefixes.push(prefix.as_str());
+        }
+    }
+    prefixes
+}
+
+/// The content of a part being written, which records the prefixes of the
+/// elements and attributes the [`XmlWriter`] writes as they go through.
+#[derive(Debug, Default)]
+pub(crate) struct PrefixTracker {
+    buffer: Vec<u8>,
+    markup: Markup,
+    /// The element or attribute name being written
+    name: Vec<u8>,
+}
+
+/// Where the writer is in the markup
+#[derive(Debug, Default, Clone, Copy)]
+enum Markup {
+    #[default]
+    Text,
+    /// In the name of a start or end tag
+    Element,
+    /// Between the attributes of a start tag
+    Tag,
+    Attribute,
+    /// Between an attribute name and its value
+    Equals,
+    /// In an attribute value, delimited by the quote
+    Value(u8),
+    /// In a comment, a processing instruction or CDATA
+    Other,
+}
+
+impl PrefixTracker {
+    fn scan(&mut self, byte: u8) {
+        self.markup = match (self.markup, byte) {
+            (Markup::Text, b'<') => Markup::Element,
+            (Markup::Element, b'/') if self.name.is_empty() => Markup::Element,
+            (Markup::Element, b'!' | b'?') if self.name.is_empty() => Markup::Other,
+            (Markup::Element | Markup::Attribute, b'>') => {
+                self.use_name();
+                Markup::Text
+            }
+            (Markup::Element | Markup::Attribute, b'/' | b' ' | b'\t' | b'\r' | b'\n') => {
+                self.use_name();
+                Markup::Tag
+            }
+            (Markup::Attribute, b'=') => {
+                self.use_name();
+                Markup::Equals
+            }
+            (Markup::Element | Markup::Attribute, _) => {
+                self.name.push(byte);
+                self.markup
+            }
+            (Markup::Tag, b'>') => Markup::Text,
+            (Markup::Tag, b'=') => Markup::Equals,
+            (Markup::Tag, b'/' | b' ' | b'\t' | b'\r' | b'\n') => Markup::Tag,
+            (Markup::Tag, _) => {
+                self.name.push(byte);
+                Markup::Attribute
+            }
+            (Markup::Equals, b'"' | b'\'') => Markup::Value(byte),
+            (Markup::Value(quote), _) if byte == quote => Markup::Tag,
+            (Markup::Other, b'>') => Markup::Text,
+            (markup, _) => markup,
+        };
+    }
+
+    fn use_name(&mut self) {
+        if let Some(colon) = self.name.iter().position(|&b| b == b':') {
+            if let Ok(prefix) = std::str::from_utf8(&self.name[..colon]) {
+                use_prefix(prefix);
+            }
+        }
+        self.name.clear();
+    }
+}
+
+impl Write for PrefixTracker {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let raw = USED_PREFIXES.with(|used| used.borrow().as_ref().is_some_and(|used| used.raw));
+        if !raw {
+            for &byte in buf {
+                self.scan(byte);
+            }
+        }
+        self.buffer.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn namespace_declarations() -> XmlResult<()> {
+    let mut writer = XmlWriter::new(Vec::new());
+    write_root(
+        &mut writer,
+        "w:hdr",
+        NamespaceSet::wordprocessing(),
+        |writer| {
+            write!(
+                writer.inner,
+                r#"<w:p w14:paraId="1"><mc:AlternateContent/></w:p>"#
+            )?;
+            Ok(())
+        },
+    )?;
+
+    assert_eq!(
+        String::from_utf8(writer.inner).unwrap(),
+        format!(
+            r#"<w:hdr xmlns:w="{}" xmlns:w14="{}" xmlns:mc="{}" mc:Ignorable="w14"><w:p w14:paraId="1"><mc:AlternateContent/></w:p></w:hdr>"#,
+            SCHEMA_MAIN, SCHEMA_WORDML_14, SCHEMA_MC
+        )
+    );
+
+    let mut writer = XmlWriter::new(Vec::new());
+    write_root(
+        &mut writer,
+        "w:ftr",
+        NamespaceSet::wordprocessing(),
+        |writer| {
+            write!(writer.inner, "<w:p/>")?;
+            Ok(())
+        },
+    )?;
+
+    assert_eq!(
+        String::from_utf8(writer.inner).unwrap(),
+        format!(
+            r#"<w:ftr xmlns:w="{}" xmlns:w14="{}" xmlns:mc="{}" mc:Ignorable="w14"><w:p/></w:ftr>"#,
+            SCHEMA_MAIN, SCHEMA_WORDML_14, SCHEMA_MC
+        )
+    );
+
+    Ok(())
+}
+
+#[test]
+fn used_prefixes() -> XmlResult<()> {
+    let mut writer = XmlWriter::new(Vec::new());
+    write_root(&mut writer, "w:ftr", NamespaceSet::default(), |writer| {
+        writer.write_element_start("w:p")?;
+        writer.write_attribute("w:rsidR", "a:b c:d")?;
+        writer.write_element_end_open()?;
+        writer.write_text("see pic: 3 > m:")?;
+        write_raw(writer, r#"<v:shape o:ole=""><![CDATA[<a:x/>]]></v:shape>"#)?;
+        writer.write_element_end_close("w:p")?;
+        Ok(())
+    })?;
+
+    let xml = String::from_utf8(writer.inner).unwrap();
+    assert!(xml.starts_with(&format!(
+        r#"<w:ftr xmlns:w="{}" xmlns:v="{}" xmlns:o="{}">"#,
+        SCHEMA_MAIN, SCHEMA_VML, SCHEMA_OFFICE
+    )));
+
+    Ok(())
+}
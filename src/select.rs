@@ -0,0 +1,153 @@
+//! Inserting content at a location of the body
+//!
+//! Template filling code can find a paragraph of the body with a
+//! [`Selector`], and insert generated paragraphs before or after it, rather
+//! than only append them.
+
+use crate::{
+    document::{BodyContent, Paragraph, ParagraphContent},
+    Docx,
+};
+
+/// A way to find a paragraph of the body
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Selector<'s> {
+    /// Selects the paragraph where the bookmark with the given name starts
+    Bookmark(&'s str),
+    /// Selects the paragraphs with the given paragraph style id
+    Style(&'s str),
+    /// Selects the paragraphs whose text contains the given text
+    Text(&'s str),
+}
+
+impl<'s> Selector<'s> {
+    /// Returns whether the paragraph is selected.
+    pub fn matches(&self, para: &Paragraph) -> bool {
+        match *self {
+            Selector::Bookmark(name) => para.content.iter().any(|content| {
+                matches!(
+                    content,
+                    ParagraphContent::BookmarkStart(start) if start.name.as_deref() == Some(name)
+                )
+            }),
+            Selector::Style(style_id) => para
+                .property
+                .as_ref()
+                .and_then(|property| property.style_id.as_ref())
+                .is_some_and(|id| id.value == style_id),
+            Selector::Text(text) => para.text().contains(text),
+        }
+    }
+}
+
+impl<'a> Docx<'a> {
+    /// Returns the index in the body of the first paragraph selected.
+    ///
+    /// Only the paragraphs of the body are searched, not those of tables or
+    /// content controls.
+    pub fn position(&self, selector: &Selector) -> Option<usize> {
+        self.document
+            .body
+            .content
+            .iter()
+            .position(|content| match content {
+                BodyContent::Paragraph(para) => selector.matches(para),
+                _ => false,
+            })
+    }
+
+    /// Inserts a paragraph at the given index of the body, or before the
+    /// properties of the last section if the index is past them.
+    ///
+    /// The text is typeset like [`Docx::add_paragraph`] does.
+    pub fn insert_paragraph_at(
+        &mut self,
+        index: usize,
+        mut para: Paragraph<'a>,
+    ) -> &mut Paragraph<'a> {
+        self.typeset(para.iter_text_mut());
+        let body = &mut self.document.body.content;
+        let end = match body.last() {
+            Some(BodyContent::SectionProperty(_)) => body.len() - 1,
+            _ => body.len(),
+        };
+        let index = index.min(end);
+        body.insert(index, BodyContent::Paragraph(para));
+        match &mut body[index] {
+            BodyContent::Paragraph(para) => para,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Inserts a paragraph before the first paragraph selected, returning
+    /// `None` if there's none.
+    ///
+    /// ```rust
+    /// use docx_rust::document::Paragraph;
+    /// use docx_rust::{Docx, Selector};
+    ///
+    /// let mut docx = Docx::default();
+    /// docx.add_paragraph("Dear customer,");
+    /// docx.add_paragraph("{{items}}");
+    /// docx.add_paragraph("Regards");
+    ///
+    /// docx.insert_before(&Selector::Text("{{items}}"), Paragraph::default().push_text("Your order:"));
+    /// docx.insert_after(&Selector::Text("{{items}}"), Paragraph::default().push_text("- Book"));
+    /// assert_eq!(docx.position(&Selector::Text("Book")), Some(3));
+    /// assert!(docx.insert_after(&Selector::Bookmark("missing"), Paragraph::default()).is_none());
+    /// ```
+    pub fn insert_before(
+        &mut self,
+        selector: &Selector,
+        para: Paragraph<'a>,
+    ) -> Option<&mut Paragraph<'a>> {
+        let index = self.position(selector)?;
+        Some(self.insert_paragraph_at(index, para))
+    }
+
+    /// Inserts a paragraph after the first paragraph selected, returning
+    /// `None` if there's none.
+    pub fn insert_after(
+        &mut self,
+        selector: &Selector,
+        para: Paragraph<'a>,
+    ) -> Option<&mut Paragraph<'a>> {
+        let index = self.position(selector)?;
+        Some(self.insert_paragraph_at(index + 1, para))
+    }
+}
+
+#[test]
+fn selectors() {
+    use crate::document::BookmarkStart;
+    use crate::formatting::ParagraphProperty;
+
+    let mut docx = Docx::default();
+    docx.add_paragraph("Intro");
+    docx.add_heading(1, "Results");
+    docx.document.push(
+        Paragraph::default()
+            .push(BookmarkStart::default().id("0").name("table"))
+            .push_text("Table goes here"),
+    );
+    docx.document
+        .body
+        .content
+        .push(BodyContent::SectionProperty(Default::default()));
+
+    assert_eq!(docx.position(&Selector::Style("Heading1")), Some(1));
+    assert_eq!(docx.position(&Selector::Bookmark("table")), Some(2));
+    assert_eq!(docx.position(&Selector::Text("goes")), Some(2));
+    assert_eq!(docx.position(&Selector::Style("Title")), None);
+
+    let para = Paragraph::default().property(ParagraphProperty::default().style_id("Caption"));
+    docx.insert_after(&Selector::Bookmark("table"), para);
+    assert_eq!(docx.position(&Selector::Style("Caption")), Some(3));
+
+    docx.insert_paragraph_at(100, Paragraph::default().push_text("Last"));
+    assert_eq!(docx.position(&Selector::Text("Last")), Some(4));
+    assert!(matches!(
+        docx.document.body.content.last(),
+        Some(BodyContent::SectionProperty(_))
+    ));
+}
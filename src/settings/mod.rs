@@ -7,7 +7,10 @@ use hard_xml::{XmlRead, XmlResult, XmlWrite, XmlWriter};
 use std::borrow::Cow;
 use std::io::Write;
 
-use crate::schema::{SCHEMA_MAIN, SCHEMA_WORDML_14};
+use crate::formatting::EndnotePositionType;
+use crate::schema::{
+    write_root, NamespaceSet, SCHEMA_MAIN, SCHEMA_RELATIONSHIPS_DOCUMENT, SCHEMA_WORDML_14,
+};
 use crate::{__string_enum, __xml_test_suites, write_attr};
 
 /// The root element of the main document part.
@@ -104,7 +107,7 @@ pub struct Settings<'a> {
     pub document_type: Option<DocumentType>,
     ///  Mail Merge Settings
     #[xml(child = "w:mailMerge")]
-    pub mail_merge: Option<MailMerge>,
+    pub mail_merge: Option<MailMerge<'a>>,
     ///  Visibility of Annotation Types
     #[xml(child = "w:revisionView")]
     pub revision_view: Option<RevisionView>,
@@ -600,55 +603,499 @@ pub struct DocumentType {}
 #[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
 #[cfg_attr(test, derive(PartialEq))]
 #[xml(tag = "w:mailMerge")]
-pub struct MailMerge {
-    // ///required    Source Document Type
-    // #[xml(child = "w:mainDocumentType")]
-    // pub main_document_type: Option<mainDocumentType>,
-    // ///required    Query Contains Link to External Query File
-    // #[xml(child = "w:linkToQuery")]
-    // pub link_to_query: Option<linkToQuery>,
-    // ///required    Data Source Type
-    // #[xml(child = "w:dataType")]
-    // pub data_type: Option<dataType>,
-    // ///required    Data Source Connection String
-    // #[xml(child = "w:connectString")]
-    // pub connect_string: Option<connectString>,
-    // ///required    Query For Data Source Records To Merge
-    // #[xml(child = "w:query")]
-    // pub query: Option<query>,
-    // ///required    Data Source File Path
-    // #[xml(child = "w:dataSource")]
-    // pub data_source: Option<dataSource>,
-    // ///required    Header Definition File Path
-    // #[xml(child = "w:headerSource")]
-    // pub header_source: Option<headerSource>,
-    // ///required    Remove Blank Lines from Merged Documents
-    // #[xml(child = "w:doNotSuppressBlankLines")]
-    // pub do_not_suppress_blank_lines: Option<doNotSuppressBlankLines>,
-    // ///required    Merged Document Destination
-    // #[xml(child = "w:destination")]
-    // pub destination: Option<destination>,
-    // ///required    Column Containing E-mail Address
-    // #[xml(child = "w:addressFieldName")]
-    // pub address_field_name: Option<addressFieldName>,
-    // ///required    Merged E-mail or Fax Subject Line
-    // #[xml(child = "w:mailSubject")]
-    // pub mail_subject: Option<mailSubject>,
-    // ///required    Merged Document To E-Mail Attachment
-    // #[xml(child = "w:mailAsAttachment")]
-    // pub mail_as_attachment: Option<mailAsAttachment>,
-    // ///required    View Merged Data Within Document
-    // #[xml(child = "w:viewMergedData")]
-    // pub view_merged_data: Option<viewMergedData>,
-    // ///required    Record Currently Displayed In Merged Document
-    // #[xml(child = "w:activeRecord")]
-    // pub active_record: Option<activeRecord>,
-    // ///required    Mail Merge Error Reporting Setting
-    // #[xml(child = "w:checkErrors")]
-    // pub check_errors: Option<checkErrors>,
-    // ///required    Office Data Source Object Settings
-    // #[xml(child = "w:odso")]
-    // pub odso: Option<odso>,
+pub struct MailMerge<'a> {
+    ///  Source Document Type
+    #[xml(child = "w:mainDocumentType")]
+    pub main_document_type: MainDocumentType,
+    ///  Query Contains Link to External Query File
+    #[xml(child = "w:linkToQuery")]
+    pub link_to_query: Option<LinkToQuery>,
+    ///  Data Source Type
+    #[xml(child = "w:dataType")]
+    pub data_type: DataType,
+    ///  Data Source Connection String
+    #[xml(child = "w:connectString")]
+    pub connect_string: Option<ConnectString<'a>>,
+    ///  Query For Data Source Records To Merge
+    #[xml(child = "w:query")]
+    pub query: Option<Query<'a>>,
+    ///  Data Source File Path
+    #[xml(child = "w:dataSource")]
+    pub data_source: Option<DataSource<'a>>,
+    ///  Header Definition File Path
+    #[xml(child = "w:headerSource")]
+    pub header_source: Option<HeaderSource<'a>>,
+    ///  Remove Blank Lines from Merged Documents
+    #[xml(child = "w:doNotSuppressBlankLines")]
+    pub do_not_suppress_blank_lines: Option<DoNotSuppressBlankLines>,
+    ///  Merged Document Destination
+    #[xml(child = "w:destination")]
+    pub destination: Option<Destination>,
+    ///  Column Containing E-mail Address
+    #[xml(child = "w:addressFieldName")]
+    pub address_field_name: Option<AddressFieldName<'a>>,
+    ///  Merged E-mail or Fax Subject Line
+    #[xml(child = "w:mailSubject")]
+    pub mail_subject: Option<MailSubject<'a>>,
+    ///  Merged Document To E-Mail Attachment
+    #[xml(child = "w:mailAsAttachment")]
+    pub mail_as_attachment: Option<MailAsAttachment>,
+    ///  View Merged Data Within Document
+    #[xml(child = "w:viewMergedData")]
+    pub view_merged_data: Option<ViewMergedData>,
+    ///  Record Currently Displayed In Merged Document
+    #[xml(child = "w:activeRecord")]
+    pub active_record: Option<ActiveRecord>,
+    ///  Mail Merge Error Reporting Setting
+    #[xml(child = "w:checkErrors")]
+    pub check_errors: Option<CheckErrors>,
+    ///  Office Data Source Object Settings
+    #[xml(child = "w:odso")]
+    pub odso: Option<Odso<'a>>,
+}
+
+impl<'a> MailMerge<'a> {
+    /// Creates the settings of a mail merge main document.
+    ///
+    /// ```rust
+    /// use docx_rust::settings::*;
+    ///
+    /// let merge = MailMerge::new(MailMergeDocType::FormLetters, MailMergeDataType::TextFile)
+    ///     .query("SELECT * FROM recipients.csv")
+    ///     .field_map(0, "First Name", "FirstName");
+    ///
+    /// assert_eq!(merge.odso.unwrap().field_map_data.len(), 1);
+    /// ```
+    pub fn new(doc_type: MailMergeDocType, data_type: MailMergeDataType) -> Self {
+        MailMerge {
+            main_document_type: MainDocumentType { val: doc_type },
+            data_type: DataType { val: data_type },
+            ..Default::default()
+        }
+    }
+
+    /// Sets the query selecting the records to merge.
+    pub fn query<T: Into<Cow<'a, str>>>(mut self, query: T) -> Self {
+        self.query = Some(Query { val: query.into() });
+        self
+    }
+
+    /// Sets the connection string of the data source.
+    pub fn connect_string<T: Into<Cow<'a, str>>>(mut self, connect_string: T) -> Self {
+        self.connect_string = Some(ConnectString {
+            val: connect_string.into(),
+        });
+        self
+    }
+
+    /// References the data source by the id of an external relationship in
+    /// the settings part.
+    pub fn data_source<T: Into<Cow<'a, str>>>(mut self, id: T) -> Self {
+        self.data_source = Some(DataSource { id: id.into() });
+        self
+    }
+
+    /// Maps a column of the data source to a predefined merge field.
+    pub fn field_map<N: Into<Cow<'a, str>>, M: Into<Cow<'a, str>>>(
+        mut self,
+        column: usize,
+        name: N,
+        mapped_name: M,
+    ) -> Self {
+        self.odso
+            .get_or_insert_with(Default::default)
+            .field_map_data
+            .push(FieldMapData {
+                ty: Some(MailMergeOdsoFmdFieldType {
+                    val: MailMergeOdsoFmdFieldTypeVal::DbColumn,
+                }),
+                name: Some(OdsoName { val: name.into() }),
+                mapped_name: Some(MappedName {
+                    val: mapped_name.into(),
+                }),
+                column: Some(OdsoColumn { val: column }),
+                ..Default::default()
+            });
+        self
+    }
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:mainDocumentType")]
+pub struct MainDocumentType {
+    #[xml(attr = "w:val")]
+    pub val: MailMergeDocType,
+}
+
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+pub enum MailMergeDocType {
+    Catalog,       //	Catalog Source Document
+    Envelopes,     //	Envelope Source Document
+    MailingLabels, //	Mailing Label Source Document
+    #[default]
+    FormLetters, //	Form Letter Source Document
+    Email,         //	E-Mail Source Document
+    Fax,           //	Fax Source Document
+}
+
+__string_enum! {
+    MailMergeDocType {
+        Catalog = "catalog",
+        Envelopes = "envelopes",
+        MailingLabels = "mailingLabels",
+        FormLetters = "formLetters",
+        Email = "email",
+        Fax = "fax",
+    }
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:linkToQuery")]
+pub struct LinkToQuery {
+    #[xml(attr = "w:val")]
+    pub val: Option<bool>,
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:dataType")]
+pub struct DataType {
+    #[xml(attr = "w:val")]
+    pub val: MailMergeDataType,
+}
+
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+pub enum MailMergeDataType {
+    #[default]
+    TextFile, //	Text File Data Source
+    Database,    //	Database Data Source
+    Spreadsheet, //	Spreadsheet Data Source
+    Query,       //	Query Data Source
+    Odbc,        //	Open Database Connectivity Data Source
+    Native,      //	Office Data Source Object Data Source
+}
+
+__string_enum! {
+    MailMergeDataType {
+        TextFile = "textFile",
+        Database = "database",
+        Spreadsheet = "spreadsheet",
+        Query = "query",
+        Odbc = "odbc",
+        Native = "native",
+    }
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:connectString")]
+pub struct ConnectString<'a> {
+    #[xml(attr = "w:val")]
+    pub val: Cow<'a, str>,
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:query")]
+pub struct Query<'a> {
+    #[xml(attr = "w:val")]
+    pub val: Cow<'a, str>,
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:dataSource")]
+pub struct DataSource<'a> {
+    #[xml(attr = "r:id")]
+    pub id: Cow<'a, str>,
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:headerSource")]
+pub struct HeaderSource<'a> {
+    #[xml(attr = "r:id")]
+    pub id: Cow<'a, str>,
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:doNotSuppressBlankLines")]
+pub struct DoNotSuppressBlankLines {
+    #[xml(attr = "w:val")]
+    pub val: Option<bool>,
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:destination")]
+pub struct Destination {
+    #[xml(attr = "w:val")]
+    pub val: MailMergeDest,
+}
+
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+pub enum MailMergeDest {
+    #[default]
+    NewDocument, //	Send Merged Documents to New Documents
+    Printer, //	Send Merged Documents to Printer
+    Email,   //	Send Merged Documents as E-mail Messages
+    Fax,     //	Send Merged Documents as Faxes
+}
+
+__string_enum! {
+    MailMergeDest {
+        NewDocument = "newDocument",
+        Printer = "printer",
+        Email = "email",
+        Fax = "fax",
+    }
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:addressFieldName")]
+pub struct AddressFieldName<'a> {
+    #[xml(attr = "w:val")]
+    pub val: Cow<'a, str>,
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:mailSubject")]
+pub struct MailSubject<'a> {
+    #[xml(attr = "w:val")]
+    pub val: Cow<'a, str>,
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:mailAsAttachment")]
+pub struct MailAsAttachment {
+    #[xml(attr = "w:val")]
+    pub val: Option<bool>,
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:viewMergedData")]
+pub struct ViewMergedData {
+    #[xml(attr = "w:val")]
+    pub val: Option<bool>,
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:activeRecord")]
+pub struct ActiveRecord {
+    #[xml(attr = "w:val")]
+    pub val: isize,
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:checkErrors")]
+pub struct CheckErrors {
+    #[xml(attr = "w:val")]
+    pub val: isize,
+}
+
+/// Office Data Source Object settings, holding the field mapping.
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:odso")]
+pub struct Odso<'a> {
+    ///  UDL Connection String
+    #[xml(child = "w:udl")]
+    pub udl: Option<Udl<'a>>,
+    ///  Data Source Table Name
+    #[xml(child = "w:table")]
+    pub table: Option<OdsoTable<'a>>,
+    ///  ODSO Data Source File Path
+    #[xml(child = "w:src")]
+    pub src: Option<OdsoSource<'a>>,
+    ///  Column Delimiter for Data Source
+    #[xml(child = "w:colDelim")]
+    pub col_delim: Option<ColDelim>,
+    ///  ODSO Data Source Type
+    #[xml(child = "w:type")]
+    pub ty: Option<OdsoType>,
+    ///  First Row of Data Source Contains Column Names
+    #[xml(child = "w:fHdr")]
+    pub first_row_header: Option<FirstRowHeader>,
+    ///  External Data Source to Merge Field Mapping
+    #[xml(child = "w:fieldMapData")]
+    pub field_map_data: Vec<FieldMapData<'a>>,
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:udl")]
+pub struct Udl<'a> {
+    #[xml(attr = "w:val")]
+    pub val: Cow<'a, str>,
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:table")]
+pub struct OdsoTable<'a> {
+    #[xml(attr = "w:val")]
+    pub val: Cow<'a, str>,
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:src")]
+pub struct OdsoSource<'a> {
+    #[xml(attr = "r:id")]
+    pub id: Cow<'a, str>,
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:colDelim")]
+pub struct ColDelim {
+    #[xml(attr = "w:val")]
+    pub val: isize,
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:type")]
+pub struct OdsoType {
+    #[xml(attr = "w:val")]
+    pub val: MailMergeSourceType,
+}
+
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+pub enum MailMergeSourceType {
+    Database,    //	Database Data Source
+    AddressBook, //	Address Book Data Source
+    Document1,   //	Alternate Document Format Data Source
+    Document2,   //	Alternate Document Format Data Source Two
+    #[default]
+    Text, //	Text File Data Source
+    Email,       //	E-Mail Program Data Source
+    Native,      //	Native Data Source
+    Legacy,      //	Legacy Document Format Data Source
+    Master,      //	Aggregate Data Source
+}
+
+__string_enum! {
+    MailMergeSourceType {
+        Database = "database",
+        AddressBook = "addressBook",
+        Document1 = "document1",
+        Document2 = "document2",
+        Text = "text",
+        Email = "email",
+        Native = "native",
+        Legacy = "legacy",
+        Master = "master",
+    }
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:fHdr")]
+pub struct FirstRowHeader {
+    #[xml(attr = "w:val")]
+    pub val: Option<bool>,
+}
+
+/// Maps a column of the data source to a predefined merge field.
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:fieldMapData")]
+pub struct FieldMapData<'a> {
+    ///  Merge Field Mapping
+    #[xml(child = "w:type")]
+    pub ty: Option<MailMergeOdsoFmdFieldType>,
+    ///  Data Source Name for Column
+    #[xml(child = "w:name")]
+    pub name: Option<OdsoName<'a>>,
+    ///  Predefined Merge Field Name
+    #[xml(child = "w:mappedName")]
+    pub mapped_name: Option<MappedName<'a>>,
+    ///  Index of Column Being Mapped
+    #[xml(child = "w:column")]
+    pub column: Option<OdsoColumn>,
+    ///  Merge Field Name Language ID
+    #[xml(child = "w:lid")]
+    pub lid: Option<OdsoLid<'a>>,
+    ///  Use Country/Region-Based Address Field Ordering
+    #[xml(child = "w:dynamicAddress")]
+    pub dynamic_address: Option<DynamicAddress>,
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:type")]
+pub struct MailMergeOdsoFmdFieldType {
+    #[xml(attr = "w:val")]
+    pub val: MailMergeOdsoFmdFieldTypeVal,
+}
+
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+pub enum MailMergeOdsoFmdFieldTypeVal {
+    #[default]
+    Null, //	Field Not Mapped
+    DbColumn, //	Field Mapping to Data Source Column
+}
+
+__string_enum! {
+    MailMergeOdsoFmdFieldTypeVal {
+        Null = "null",
+        DbColumn = "dbColumn",
+    }
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:name")]
+pub struct OdsoName<'a> {
+    #[xml(attr = "w:val")]
+    pub val: Cow<'a, str>,
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:mappedName")]
+pub struct MappedName<'a> {
+    #[xml(attr = "w:val")]
+    pub val: Cow<'a, str>,
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:column")]
+pub struct OdsoColumn {
+    #[xml(attr = "w:val")]
+    pub val: usize,
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:lid")]
+pub struct OdsoLid<'a> {
+    #[xml(attr = "w:val")]
+    pub val: Cow<'a, str>,
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+#[xml(tag = "w:dynamicAddress")]
+pub struct DynamicAddress {
+    #[xml(attr = "w:val")]
+    pub val: Option<bool>,
 }
 
 #[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
@@ -782,7 +1229,11 @@ pub struct BookFoldPrinting {
 #[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
 #[cfg_attr(test, derive(PartialEq))]
 #[xml(tag = "w:bookFoldPrintingSheets")]
-pub struct BookFoldPrintingSheets {}
+pub struct BookFoldPrintingSheets {
+    /// Number of pages per booklet, a multiple of 4
+    #[xml(attr = "w:val")]
+    pub val: Option<isize>,
+}
 
 #[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
 #[cfg_attr(test, derive(PartialEq))]
@@ -1022,6 +1473,37 @@ pub struct DocVar<'a> {
     pub val: Cow<'a, str>,
 }
 
+impl<'a> DocVars<'a> {
+    /// Returns the value of the document variable with the given name.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.vars
+            .iter()
+            .find(|v| v.name == name)
+            .map(|v| v.val.as_ref())
+    }
+
+    /// Sets a document variable, replacing the value of an existing one.
+    pub fn set<N: Into<Cow<'a, str>>, V: Into<Cow<'a, str>>>(
+        &mut self,
+        name: N,
+        val: V,
+    ) -> &mut Self {
+        let name = name.into();
+        let val = val.into();
+        match self.vars.iter_mut().find(|v| v.name == name) {
+            Some(var) => var.val = val,
+            None => self.vars.push(DocVar { name, val }),
+        }
+        self
+    }
+
+    /// Removes a document variable, returning its value.
+    pub fn remove(&mut self, name: &str) -> Option<Cow<'a, str>> {
+        let index = self.vars.iter().position(|v| v.name == name)?;
+        Some(self.vars.remove(index).val)
+    }
+}
+
 #[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
 #[cfg_attr(test, derive(PartialEq))]
 #[xml(tag = "w:rsids")]
@@ -1136,6 +1618,117 @@ pub struct ThemeFontLang<'a> {
     pub east_asia: Option<Cow<'a, str>>,
 }
 
+impl<'a> Settings<'a> {
+    /// Returns the value of a document variable.
+    ///
+    /// ```rust
+    /// use docx_rust::settings::Settings;
+    ///
+    /// let mut settings = Settings::default();
+    /// settings.set_doc_var("client", "ACME");
+    /// settings.set_doc_var("client", "Contoso");
+    ///
+    /// assert_eq!(settings.doc_var("client"), Some("Contoso"));
+    /// assert_eq!(settings.doc_vars.as_ref().unwrap().vars.len(), 1);
+    /// ```
+    pub fn doc_var(&self, name: &str) -> Option<&str> {
+        self.doc_vars.as_ref()?.get(name)
+    }
+
+    /// Sets a document variable, creating `w:docVars` if needed.
+    pub fn set_doc_var<N: Into<Cow<'a, str>>, V: Into<Cow<'a, str>>>(
+        &mut self,
+        name: N,
+        val: V,
+    ) -> &mut Self {
+        self.doc_vars
+            .get_or_insert_with(Default::default)
+            .set(name, val);
+        self
+    }
+
+    /// Sets whether the inside and outside margins of facing pages are
+    /// mirrored, the gutter being on the inside.
+    pub fn set_mirror_margins(&mut self, on: bool) -> &mut Self {
+        self.mirror_margins = on.then(MirrorMargins::default);
+        self
+    }
+
+    /// Sets whether the gutter is at the top of the pages rather than on
+    /// their left side.
+    pub fn set_gutter_at_top(&mut self, on: bool) -> &mut Self {
+        self.gutter_at_top = on.then(GutterAtTop::default);
+        self
+    }
+
+    /// Prints the pages as booklets of two pages per side of a sheet,
+    /// folded in the middle. `sheets` is the number of pages per booklet, a
+    /// multiple of 4, all pages making one booklet if `None`. `reverse` is
+    /// for right-to-left booklets.
+    ///
+    /// ```rust
+    /// use docx_rust::settings::Settings;
+    ///
+    /// let mut settings = Settings::default();
+    /// settings.set_book_fold(Some(16), false).set_mirror_margins(true);
+    ///
+    /// assert!(settings.book_fold_printing.is_some());
+    /// assert_eq!(settings.book_fold_printing_sheets.unwrap().val, Some(16));
+    /// ```
+    pub fn set_book_fold(&mut self, sheets: Option<isize>, reverse: bool) -> &mut Self {
+        if reverse {
+            self.book_fold_printing = None;
+            self.book_fold_rev_printing = Some(BookFoldRevPrinting::default());
+        } else {
+            self.book_fold_printing = Some(BookFoldPrinting::default());
+            self.book_fold_rev_printing = None;
+        }
+        self.book_fold_printing_sheets =
+            sheets.map(|val| BookFoldPrintingSheets { val: Some(val) });
+        self
+    }
+
+    /// Turns off book fold printing.
+    pub fn clear_book_fold(&mut self) -> &mut Self {
+        self.book_fold_printing = None;
+        self.book_fold_rev_printing = None;
+        self.book_fold_printing_sheets = None;
+        self
+    }
+
+    /// Places the endnotes at the end of each section or of the document.
+    ///
+    /// Sections can suppress their endnotes to have them printed with the
+    /// ones of the next section, see
+    /// [`SectionProperty::set_suppress_endnotes`](crate::formatting::SectionProperty::set_suppress_endnotes).
+    ///
+    /// ```rust
+    /// use docx_rust::formatting::EndnotePositionType;
+    /// use docx_rust::settings::Settings;
+    ///
+    /// let mut settings = Settings::default();
+    /// settings.set_endnote_position(EndnotePositionType::SectEnd);
+    ///
+    /// assert!(settings.endnote_pr.unwrap().position.is_some());
+    /// ```
+    pub fn set_endnote_position(&mut self, position: EndnotePositionType) -> &mut Self {
+        self.endnote_pr
+            .get_or_insert_with(Default::default)
+            .position = Some(position.into());
+        self
+    }
+
+    /// Removes a document variable, dropping `w:docVars` once it is empty.
+    pub fn remove_doc_var(&mut self, name: &str) -> Option<Cow<'a, str>> {
+        let doc_vars = self.doc_vars.as_mut()?;
+        let val = doc_vars.remove(name);
+        if doc_vars.vars.is_empty() {
+            self.doc_vars = None;
+        }
+        val
+    }
+}
+
 impl<'a> XmlWrite for Settings<'a> {
     fn to_writer<W: Write>(&self, writer: &mut XmlWriter<W>) -> XmlResult<()> {
         let Settings {
@@ -1239,111 +1832,113 @@ impl<'a> XmlWrite for Settings<'a> {
         log::debug!("[Settings] Started writing.");
         let _ = write!(writer.inner, "{}", crate::schema::SCHEMA_XML);
 
-        writer.write_element_start("w:settings")?;
-
-        writer.write_attribute("xmlns:w", SCHEMA_MAIN)?;
-
-        writer.write_attribute("xmlns:w14", SCHEMA_WORDML_14)?;
-
-        writer.write_element_end_open()?;
-
-        write_attr(write_protection, writer)?;
-        write_attr(view, writer)?;
-        write_attr(zoom, writer)?;
-        write_attr(remove_personal_information, writer)?;
-        write_attr(remove_date_and_time, writer)?;
-        write_attr(do_not_display_page_boundaries, writer)?;
-        write_attr(display_background_shape, writer)?;
-        write_attr(print_post_script_over_text, writer)?;
-        write_attr(print_fractional_character_width, writer)?;
-        write_attr(print_forms_data, writer)?;
-        write_attr(embed_true_type_fonts, writer)?;
-        write_attr(embed_system_fonts, writer)?;
-        write_attr(save_subset_fonts, writer)?;
-        write_attr(save_forms_data, writer)?;
-        write_attr(mirror_margins, writer)?;
-        write_attr(align_borders_and_edges, writer)?;
-        write_attr(borders_do_not_surround_header, writer)?;
-        write_attr(borders_do_not_surround_footer, writer)?;
-        write_attr(gutter_at_top, writer)?;
-        write_attr(hide_spelling_errors, writer)?;
-        write_attr(hide_grammatical_errors, writer)?;
-        write_attr(active_writing_style, writer)?;
-        write_attr(proof_state, writer)?;
-        write_attr(forms_design, writer)?;
-        write_attr(attached_template, writer)?;
-        write_attr(link_styles, writer)?;
-        write_attr(style_pane_format_filter, writer)?;
-        write_attr(style_pane_sort_method, writer)?;
-        write_attr(document_type, writer)?;
-        write_attr(mail_merge, writer)?;
-        write_attr(revision_view, writer)?;
-        write_attr(track_revisions, writer)?;
-        write_attr(do_not_track_moves, writer)?;
-        write_attr(do_not_track_formatting, writer)?;
-        write_attr(document_protection, writer)?;
-        write_attr(auto_format_override, writer)?;
-        write_attr(style_lock_theme, writer)?;
-        write_attr(style_lock_qfset, writer)?;
-        write_attr(default_tab_stop, writer)?;
-        write_attr(auto_hyphenation, writer)?;
-        write_attr(consecutive_hyphen_limit, writer)?;
-        write_attr(hyphenation_zone, writer)?;
-        write_attr(do_not_hyphenate_caps, writer)?;
-        write_attr(show_envelope, writer)?;
-        write_attr(summary_length, writer)?;
-        write_attr(click_and_type_style, writer)?;
-        write_attr(default_table_style, writer)?;
-        write_attr(even_and_odd_headers, writer)?;
-        write_attr(book_fold_rev_printing, writer)?;
-        write_attr(book_fold_printing, writer)?;
-        write_attr(book_fold_printing_sheets, writer)?;
-        write_attr(drawing_grid_horizontal_spacing, writer)?;
-        write_attr(drawing_grid_vertical_spacing, writer)?;
-        write_attr(display_horizontal_drawing_grid_every, writer)?;
-        write_attr(display_vertical_drawing_grid_every, writer)?;
-        write_attr(do_not_use_margins_for_drawing_grid_origin, writer)?;
-        write_attr(drawing_grid_horizontal_origin, writer)?;
-        write_attr(drawing_grid_vertical_origin, writer)?;
-        write_attr(do_not_shade_form_data, writer)?;
-        write_attr(no_punctuation_kerning, writer)?;
-        write_attr(character_spacing_control, writer)?;
-        write_attr(print_two_on_one, writer)?;
-        write_attr(strict_first_and_last_chars, writer)?;
-        write_attr(no_line_breaks_after, writer)?;
-        write_attr(no_line_breaks_before, writer)?;
-        write_attr(save_preview_picture, writer)?;
-        write_attr(do_not_validate_against_schema, writer)?;
-        write_attr(save_invalid_xml, writer)?;
-        write_attr(ignore_mixed_content, writer)?;
-        write_attr(always_show_placeholder_text, writer)?;
-        write_attr(do_not_demarcate_invalid_xml, writer)?;
-        write_attr(save_xml_data_only, writer)?;
-        write_attr(use_xsltwhen_saving, writer)?;
-        write_attr(save_through_xslt, writer)?;
-        write_attr(show_xmltags, writer)?;
-        write_attr(always_merge_empty_namespace, writer)?;
-        write_attr(update_fields, writer)?;
-        write_attr(hdr_shape_defaults, writer)?;
-        write_attr(footnote_pr, writer)?;
-        write_attr(endnote_pr, writer)?;
-        write_attr(compat, writer)?;
-        write_attr(doc_vars, writer)?;
-        write_attr(rsids, writer)?;
-        write_attr(ui_compat97_to2003, writer)?;
-        write_attr(theme_font_lang, writer)?;
-        write_attr(clr_scheme_mapping, writer)?;
-        write_attr(do_not_include_subdocs_in_stats, writer)?;
-        write_attr(do_not_auto_compress_pictures, writer)?;
-        write_attr(force_upgrade, writer)?;
-        write_attr(captions, writer)?;
-        write_attr(read_mode_ink_lock_down, writer)?;
-        write_attr(shape_defaults, writer)?;
-        write_attr(do_not_embed_smart_tags, writer)?;
-        write_attr(decimal_symbol, writer)?;
-        write_attr(list_separator, writer)?;
-
-        writer.write_element_end_close("w:settings")?;
+        write_root(
+            writer,
+            "w:settings",
+            NamespaceSet::default()
+                .with("w", SCHEMA_MAIN)
+                .with("r", SCHEMA_RELATIONSHIPS_DOCUMENT)
+                .with("w14", SCHEMA_WORDML_14),
+            |writer| {
+                write_attr(write_protection, writer)?;
+                write_attr(view, writer)?;
+                write_attr(zoom, writer)?;
+                write_attr(remove_personal_information, writer)?;
+                write_attr(remove_date_and_time, writer)?;
+                write_attr(do_not_display_page_boundaries, writer)?;
+                write_attr(display_background_shape, writer)?;
+                write_attr(print_post_script_over_text, writer)?;
+                write_attr(print_fractional_character_width, writer)?;
+                write_attr(print_forms_data, writer)?;
+                write_attr(embed_true_type_fonts, writer)?;
+                write_attr(embed_system_fonts, writer)?;
+                write_attr(save_subset_fonts, writer)?;
+                write_attr(save_forms_data, writer)?;
+                write_attr(mirror_margins, writer)?;
+                write_attr(align_borders_and_edges, writer)?;
+                write_attr(borders_do_not_surround_header, writer)?;
+                write_attr(borders_do_not_surround_footer, writer)?;
+                write_attr(gutter_at_top, writer)?;
+                write_attr(hide_spelling_errors, writer)?;
+                write_attr(hide_grammatical_errors, writer)?;
+                write_attr(active_writing_style, writer)?;
+                write_attr(proof_state, writer)?;
+                write_attr(forms_design, writer)?;
+                write_attr(attached_template, writer)?;
+                write_attr(link_styles, writer)?;
+                write_attr(style_pane_format_filter, writer)?;
+                write_attr(style_pane_sort_method, writer)?;
+                write_attr(document_type, writer)?;
+                write_attr(mail_merge, writer)?;
+                write_attr(revision_view, writer)?;
+                write_attr(track_revisions, writer)?;
+                write_attr(do_not_track_moves, writer)?;
+                write_attr(do_not_track_formatting, writer)?;
+                write_attr(document_protection, writer)?;
+                write_attr(auto_format_override, writer)?;
+                write_attr(style_lock_theme, writer)?;
+                write_attr(style_lock_qfset, writer)?;
+                write_attr(default_tab_stop, writer)?;
+                write_attr(auto_hyphenation, writer)?;
+                write_attr(consecutive_hyphen_limit, writer)?;
+                write_attr(hyphenation_zone, writer)?;
+                write_attr(do_not_hyphenate_caps, writer)?;
+                write_attr(show_envelope, writer)?;
+                write_attr(summary_length, writer)?;
+                write_attr(click_and_type_style, writer)?;
+                write_attr(default_table_style, writer)?;
+                write_attr(even_and_odd_headers, writer)?;
+                write_attr(book_fold_rev_printing, writer)?;
+                write_attr(book_fold_printing, writer)?;
+                write_attr(book_fold_printing_sheets, writer)?;
+                write_attr(drawing_grid_horizontal_spacing, writer)?;
+                write_attr(drawing_grid_vertical_spacing, writer)?;
+                write_attr(display_horizontal_drawing_grid_every, writer)?;
+                write_attr(display_vertical_drawing_grid_every, writer)?;
+                write_attr(do_not_use_margins_for_drawing_grid_origin, writer)?;
+                write_attr(drawing_grid_horizontal_origin, writer)?;
+                write_attr(drawing_grid_vertical_origin, writer)?;
+                write_attr(do_not_shade_form_data, writer)?;
+                write_attr(no_punctuation_kerning, writer)?;
+                write_attr(character_spacing_control, writer)?;
+                write_attr(print_two_on_one, writer)?;
+                write_attr(strict_first_and_last_chars, writer)?;
+                write_attr(no_line_breaks_after, writer)?;
+                write_attr(no_line_breaks_before, writer)?;
+                write_attr(save_preview_picture, writer)?;
+                write_attr(do_not_validate_against_schema, writer)?;
+                write_attr(save_invalid_xml, writer)?;
+                write_attr(ignore_mixed_content, writer)?;
+                write_attr(always_show_placeholder_text, writer)?;
+                write_attr(do_not_demarcate_invalid_xml, writer)?;
+                write_attr(save_xml_data_only, writer)?;
+                write_attr(use_xsltwhen_saving, writer)?;
+                write_attr(save_through_xslt, writer)?;
+                write_attr(show_xmltags, writer)?;
+                write_attr(always_merge_empty_namespace, writer)?;
+                write_attr(update_fields, writer)?;
+                write_attr(hdr_shape_defaults, writer)?;
+                write_attr(footnote_pr, writer)?;
+                write_attr(endnote_pr, writer)?;
+                write_attr(compat, writer)?;
+                write_attr(doc_vars, writer)?;
+                write_attr(rsids, writer)?;
+                write_attr(ui_compat97_to2003, writer)?;
+                write_attr(theme_font_lang, writer)?;
+                write_attr(clr_scheme_mapping, writer)?;
+                write_attr(do_not_include_subdocs_in_stats, writer)?;
+                write_attr(do_not_auto_compress_pictures, writer)?;
+                write_attr(force_upgrade, writer)?;
+                write_attr(captions, writer)?;
+                write_attr(read_mode_ink_lock_down, writer)?;
+                write_attr(shape_defaults, writer)?;
+                write_attr(do_not_embed_smart_tags, writer)?;
+                write_attr(decimal_symbol, writer)?;
+                write_attr(list_separator, writer)?;
+
+                Ok(())
+            },
+        )?;
 
         log::debug!("[Settings] Finished writing.");
 
@@ -1355,10 +1950,45 @@ __xml_test_suites!(
     Settings,
     Settings::default(),
     format!(
-        r#"{}<w:settings xmlns:w="{}" xmlns:w14="{}"></w:settings>"#,
+        r#"{}<w:settings xmlns:w="{}" xmlns:r="{}" xmlns:w14="{}" xmlns:mc="{}" mc:Ignorable="w14"></w:settings>"#,
+        crate::schema::SCHEMA_XML,
+        SCHEMA_MAIN,
+        SCHEMA_RELATIONSHIPS_DOCUMENT,
+        SCHEMA_WORDML_14,
+        crate::schema::SCHEMA_MC
+    )
+    .as_str(),
+    Settings {
+        doc_vars: Some(DocVars {
+            vars: vec![DocVar {
+                name: "client".into(),
+                val: "ACME".into(),
+            }],
+        }),
+        ..Default::default()
+    },
+    format!(
+        r#"{}<w:settings xmlns:w="{}" xmlns:r="{}" xmlns:w14="{}" xmlns:mc="{}" mc:Ignorable="w14"><w:docVars><w:docVar w:name="client" w:val="ACME"/></w:docVars></w:settings>"#,
+        crate::schema::SCHEMA_XML,
+        SCHEMA_MAIN,
+        SCHEMA_RELATIONSHIPS_DOCUMENT,
+        SCHEMA_WORDML_14,
+        crate::schema::SCHEMA_MC
+    )
+    .as_str(),
+    Settings {
+        mirror_margins: Some(MirrorMargins::default()),
+        book_fold_printing: Some(BookFoldPrinting::default()),
+        book_fold_printing_sheets: Some(BookFoldPrintingSheets { val: Some(16) }),
+        ..Default::default()
+    },
+    format!(
+        r#"{}<w:settings xmlns:w="{}" xmlns:r="{}" xmlns:w14="{}" xmlns:mc="{}" mc:Ignorable="w14"><w:mirrorMargins/><w:bookFoldPrinting/><w:bookFoldPrintingSheets w:val="16"/></w:settings>"#,
         crate::schema::SCHEMA_XML,
         SCHEMA_MAIN,
-        SCHEMA_WORDML_14
+        SCHEMA_RELATIONSHIPS_DOCUMENT,
+        SCHEMA_WORDML_14,
+        crate::schema::SCHEMA_MC
     )
     .as_str(),
 );
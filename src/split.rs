@@ -0,0 +1,248 @@
+//! Splitting a document into chapters
+
+use std::collections::HashSet;
+
+use hard_xml::XmlRead;
+
+use crate::{
+    document::BodyContent, rels::Relationships, repair::resolve, walk::relationship_ids, Docx,
+};
+
+impl<'a> Docx<'a> {
+    /// Splits the document into one document per heading of the given level
+    /// or above, e.g. one per chapter for level 1.
+    ///
+    /// Each part starts at its heading and ends before the next one; the
+    /// content before the first heading, if any, makes the first part. The
+    /// parts keep the section properties at the end of the body, and drop the
+    /// styles, numbering definitions and media they don't use.
+    ///
+    /// ```rust
+    /// use docx_rust::Docx;
+    ///
+    /// let mut docx = Docx::default();
+    /// docx.add_paragraph("Preface");
+    /// docx.add_heading(1, "Installation");
+    /// docx.add_heading(2, "Requirements");
+    /// docx.add_heading(1, "Usage");
+    ///
+    /// let chapters = docx.split_by_heading(1);
+    /// assert_eq!(chapters.len(), 3);
+    /// assert_eq!(chapters[1].document.body.content.len(), 2);
+    /// assert!(chapters[2].styles.get("Heading2").is_none());
+    /// ```
+    pub fn split_by_heading(&self, level: usize) -> Vec<Docx<'a>> {
+        let content = &self.document.body.content;
+        let end = match content.last() {
+            Some(BodyContent::SectionProperty(_)) => content.len() - 1,
+            _ => content.len(),
+        };
+
+        let mut starts: Vec<usize> = content[..end]
+            .iter()
+            .enumerate()
+            .filter_map(|(index, content)| match content {
+                BodyContent::Paragraph(para) => self
+                    .heading_level(para)
+                    .filter(|heading| *heading <= level)
+                    .map(|_| index),
+                _ => None,
+            })
+            .collect();
+        if end > 0 && starts.first() != Some(&0) {
+            starts.insert(0, 0);
+        }
+
+        starts
+            .iter()
+            .enumerate()
+            .map(|(index, &start)| {
+                let stop = starts.get(index + 1).copied().unwrap_or(end);
+                let mut part = self.fragment(start..stop);
+                part.prune_unused_styles();
+                part.prune_unused_numbering();
+                part.prune_unused_media();
+                part
+            })
+            .collect()
+    }
+
+    /// Removes the numbering definitions no paragraph or style refers to.
+    pub(crate) fn prune_unused_numbering(&mut self) {
+        let styles = self
+            .styles
+            .styles
+            .iter()
+            .filter_map(|style| style.paragraph.as_ref());
+        let used: HashSet<isize> = self
+            .paragraphs()
+            .into_iter()
+            .filter_map(|para| para.property.as_ref())
+            .chain(styles)
+            .filter_map(|property| Some(property.numbering.as_ref()?.id.as_ref()?.value))
+            .collect();
+
+        let numbering = match &mut self.numbering {
+            Some(numbering) => numbering,
+            None => return,
+        };
+        numbering
+            .numberings
+            .retain(|num| num.num_id.is_some_and(|id| used.contains(&id)));
+        let abstract_ids: HashSet<isize> = numbering
+            .numberings
+            .iter()
+            .filter_map(|num| num.abstract_num_id.as_ref()?.value)
+            .collect();
+        numbering.abstract_numberings.retain(|abstract_num| {
+            abstract_num
+                .abstract_num_id
+                .is_some_and(|id| abstract_ids.contains(&id))
+        });
+    }
+
+    /// Removes the media nothing refers to, with their relationships.
+    pub(crate) fn prune_unused_media(&mut self) {
+        let used = match self.used_media() {
+            Some(used) => used,
+            None => return,
+        };
+
+        let unused: Vec<String> = self
+            .media
+            .keys()
+            .filter(|name| !used.contains(&format!("/word/{}", name)))
+            .cloned()
+            .collect();
+        for name in &unused {
+            self.media.remove(name);
+        }
+        if let Some(rels) = &mut self.document_rels {
+            rels.relationships
+                .retain(|rel| !unused.iter().any(|name| rel.target == name.as_str()));
+        }
+    }
+
+    /// Returns the names of the media something refers to, like
+    /// `/word/media/image1.png`, or `None` if it can't be known.
+    ///
+    /// The paragraphs refer to media by the relationships of the main
+    /// document part, e.g. in pictures and VML. The media of headers,
+    /// footers, charts and other parts are referred to by their own
+    /// relationships, and are used while these parts are.
+    pub(crate) fn used_media(&self) -> Option<HashSet<String>> {
+        let mut used = HashSet::new();
+        if let Some(rels) = &self.document_rels {
+            for para in self.paragraphs() {
+                for id in relationship_ids(para) {
+                    used.extend(rels.get_target(id).map(|target| resolve("/word/", target)));
+                }
+            }
+        }
+        for chart in self.charts.values() {
+            for rel in chart.rels.iter().flat_map(|rels| &rels.relationships) {
+                used.insert(resolve("/word/charts/", &rel.target));
+            }
+        }
+        for (name, data) in &self.other_parts {
+            let base = match name
+                .strip_suffix(".rels")
+                .and_then(|n| n.split_once("_rels/"))
+            {
+                Some((folder, _)) => format!("/{}", folder),
+                None => continue,
+            };
+            let xml = std::str::from_utf8(data).ok()?;
+            let rels = Relationships::from_str(xml).ok()?;
+            used.extend(
+                rels.relationships
+                    .iter()
+                    .map(|rel| resolve(&base, &rel.target)),
+            );
+        }
+        Some(used)
+    }
+}
+
+#[test]
+fn split_by_heading() {
+    use crate::document::{AbstractNum, Numbering, Paragraph};
+    use crate::formatting::ParagraphProperty;
+
+    let mut docx = Docx::default();
+    let mut numbering = Numbering::default();
+    let bullets = numbering.push_list(AbstractNum::bullet());
+    let numbers = numbering.push_list(AbstractNum::decimal());
+    docx.numbering = Some(numbering);
+
+    docx.add_heading(1, "One");
+    docx.document.push(
+        Paragraph::default()
+            .property(ParagraphProperty::default().numbering((bullets, 0)))
+            .push_text("Item"),
+    );
+    docx.add_heading(1, "Two");
+    docx.document.push(
+        Paragraph::default()
+            .property(ParagraphProperty::default().numbering((numbers, 0)))
+            .push_text("Step"),
+    );
+
+    let parts = docx.split_by_heading(1);
+    assert_eq!(parts.len(), 2);
+    assert_eq!(parts[0].document.body.content.len(), 2);
+
+    let numbering = parts[1].numbering.as_ref().unwrap();
+    assert_eq!(numbering.numberings.len(), 1);
+    assert_eq!(numbering.numberings[0].num_id, Some(numbers));
+    assert_eq!(numbering.abstract_numberings.len(), 1);
+}
+
+#[test]
+fn prune_unused_media() {
+    use crate::chart::ChartPart;
+    use crate::document::{Paragraph, Pict, Run, RunContent};
+    use crate::media::MediaType;
+    use crate::schema::SCHEMA_IMAGE;
+
+    let data = vec![0x89, 0x50, 0x4E, 0x47];
+    let mut docx = Docx::default();
+    for name in ["vml.png", "chart.png", "header.png", "unused.png"] {
+        docx.media
+            .insert(format!("media/{}", name), (MediaType::Image, &data));
+    }
+    let rels = docx.document_rels.get_or_insert_with(Default::default);
+    rels.add_rel(SCHEMA_IMAGE, "media/vml.png");
+    rels.add_rel(SCHEMA_IMAGE, "media/unused.png");
+    let id = rels.get_id("media/vml.png").unwrap();
+    let pict = format!(r#"<w:pict><v:shape><v:imagedata r:id="{id}"/></v:shape></w:pict>"#);
+    docx.document.push(
+        Paragraph::default().push(Run::default().push(RunContent::Pict(Pict { xml: pict.into() }))),
+    );
+    let mut chart_rels = Relationships::default();
+    chart_rels.add_rel(SCHEMA_IMAGE, "../media/chart.png");
+    docx.charts.insert(
+        "charts/chart1.xml".to_string(),
+        ChartPart {
+            xml: "<c:chartSpace/>".into(),
+            rels: Some(chart_rels),
+        },
+    );
+    docx.other_parts.insert(
+        "word/_rels/header1.xml.rels".to_string(),
+        br#"<Relationships><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/image" Target="media/header.png"/></Relationships>"#[..].into(),
+    );
+
+    docx.prune_unused_media();
+    let mut media: Vec<_> = docx.media.keys().cloned().collect();
+    media.sort();
+    assert_eq!(
+        media,
+        ["media/chart.png", "media/header.png", "media/vml.png"]
+    );
+    assert!(docx
+        .document_rels
+        .unwrap()
+        .get_id("media/unused.png")
+        .is_none());
+}
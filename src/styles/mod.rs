@@ -9,14 +9,19 @@ mod priority;
 mod semi_hidden;
 mod style;
 mod unhidden_when_used;
+mod usage;
 
 use self::latent_styles::LatentStyles;
-pub use self::{default_style::*, style::*};
+pub use self::{default_style::*, priority::*, semi_hidden::*, style::*, unhidden_when_used::*};
 
 use hard_xml::{XmlRead, XmlResult, XmlWrite, XmlWriter};
 use std::io::Write;
 
-use crate::schema::{SCHEMA_MAIN, SCHEMA_XML};
+use crate::formatting::{
+    BorderStyle, CharacterProperty, ContextualSpacing, Fonts, Indent, JustificationVal, Kern,
+    LineRule, ParagraphProperty, Spacing, TableBorders, TableProperty, ThemeFont,
+};
+use crate::schema::{write_root, NamespaceSet, SCHEMA_MAIN, SCHEMA_XML};
 use crate::{__xml_test_suites, write_attr};
 
 /// Styles of the document
@@ -55,20 +60,21 @@ impl<'a> XmlWrite for Styles<'a> {
         log::debug!("[Styles] Started writing.");
 
         let _ = write!(writer.inner, "{}", SCHEMA_XML);
-        writer.write_element_start("w:styles")?;
+        write_root(
+            writer,
+            "w:styles",
+            NamespaceSet::default().with("w", SCHEMA_MAIN),
+            |writer| {
+                write_attr(default, writer)?;
+                write_attr(latent_styles, writer)?;
 
-        writer.write_attribute("xmlns:w", SCHEMA_MAIN)?;
+                for ele in styles {
+                    ele.to_writer(writer)?;
+                }
 
-        writer.write_element_end_open()?;
-
-        write_attr(default, writer)?;
-        write_attr(latent_styles, writer)?;
-
-        for ele in styles {
-            ele.to_writer(writer)?;
-        }
-
-        writer.write_element_end_close("w:styles")?;
+                Ok(())
+            },
+        )?;
 
         log::debug!("[Styles] Finished writing.");
 
@@ -81,6 +87,121 @@ impl<'a> Styles<'a> {
         <Styles as Default>::default()
     }
 
+    /// Creates styles resembling those of a new Word document: the document
+    /// defaults, `Normal`, `Heading1` to `Heading6`, `Title`, `Subtitle`,
+    /// `Quote`, `ListParagraph`, `Hyperlink`, the footnote and endnote styles
+    /// and `TableGrid`.
+    ///
+    /// ```rust
+    /// use docx_rust::styles::Styles;
+    ///
+    /// let styles = Styles::with_defaults();
+    /// assert!(styles.get("Normal").is_some());
+    /// assert!(styles.get("TableGrid").is_some());
+    /// ```
+    pub fn with_defaults() -> Self {
+        let mut styles = Styles::new();
+
+        styles.default(
+            DefaultStyle::default()
+                .character(
+                    CharacterProperty::default()
+                        .fonts(
+                            Fonts::default()
+                                .ascii_theme(ThemeFont::MinorHansi)
+                                .east_asia_theme(ThemeFont::MinorEastAsia)
+                                .h_ansi_theme(ThemeFont::MinorHansi),
+                        )
+                        .size(22isize),
+                )
+                .paragraph(ParagraphProperty::default().spacing(Spacing {
+                    after: Some(160),
+                    line: Some(259),
+                    line_rule: Some(LineRule::Auto),
+                    ..Default::default()
+                })),
+        );
+
+        let mut normal = Style::new(StyleType::Paragraph, "Normal").name("Normal");
+        normal.default = Some(true);
+        normal.q_format = Some(QFormat { value: None });
+        styles.push(normal);
+
+        for level in 1..=6 {
+            styles.push(Style::heading(level));
+        }
+
+        let title = Style::new(StyleType::Paragraph, "Title")
+            .name("Title")
+            .paragraph(ParagraphProperty {
+                spacing: Some(Spacing {
+                    after: Some(0),
+                    line: Some(240),
+                    line_rule: Some(LineRule::Auto),
+                    ..Default::default()
+                }),
+                contextual_spacing: Some(ContextualSpacing::default()),
+                ..Default::default()
+            })
+            .character(CharacterProperty {
+                kern: Some(Kern { value: Some(28) }),
+                ..CharacterProperty::default().size(56isize)
+            });
+        styles.push(based_on_normal(title));
+
+        let subtitle = Style::new(StyleType::Paragraph, "Subtitle")
+            .name("Subtitle")
+            .character(CharacterProperty::default().color(0x5a5a5a));
+        styles.push(based_on_normal(subtitle));
+
+        let quote = Style::new(StyleType::Paragraph, "Quote")
+            .name("Quote")
+            .paragraph(
+                ParagraphProperty::default()
+                    .spacing(Spacing {
+                        before: Some(200),
+                        ..Default::default()
+                    })
+                    .indent(Indent {
+                        left: Some(864),
+                        right: Some(864),
+                        ..Default::default()
+                    })
+                    .justification(JustificationVal::Center),
+            )
+            .character(CharacterProperty::default().italics(true).color(0x404040));
+        styles.push(based_on_normal(quote));
+
+        let list_paragraph = Style::new(StyleType::Paragraph, "ListParagraph")
+            .name("List Paragraph")
+            .paragraph(ParagraphProperty {
+                indent: Some(Indent {
+                    left: Some(720),
+                    ..Default::default()
+                }),
+                contextual_spacing: Some(ContextualSpacing::default()),
+                ..Default::default()
+            });
+        styles.push(based_on_normal(list_paragraph));
+
+        styles.push(Style::hyperlink());
+        styles.ensure_notes();
+
+        styles.push(
+            Style::new(StyleType::Table, "TableGrid")
+                .name("Table Grid")
+                .paragraph(ParagraphProperty::default().spacing(Spacing {
+                    after: Some(0),
+                    line: Some(240),
+                    line_rule: Some(LineRule::Auto),
+                    ..Default::default()
+                }))
+                .table(TableProperty::default().borders(TableBorders::all(BorderStyle::Single, 4))),
+        );
+
+        styles
+    }
+
     pub fn default(&mut self, style: DefaultStyle<'a>) -> &mut Self {
         self.default = Some(style);
         self
@@ -90,6 +211,185 @@ impl<'a> Styles<'a> {
         self.styles.push(style);
         self
     }
+
+    /// Returns the style with the given id.
+    pub fn get(&self, style_id: &str) -> Option<&Style<'a>> {
+        self.styles.iter().find(|s| s.style_id == style_id)
+    }
+
+    /// Returns a style and the styles it is based on, the base first.
+    pub(crate) fn chain(&self, style_id: Option<&str>) -> Vec<&Style<'a>> {
+        let mut chain: Vec<&Style> = Vec::new();
+        let mut style_id = style_id;
+        while let Some(style) = style_id.and_then(|id| self.get(id)) {
+            // styles based on each other would loop forever
+            if chain.iter().any(|s| s.style_id == style.style_id) {
+                break;
+            }
+            chain.push(style);
+            style_id = style.base.as_ref().map(|base| base.value.as_ref());
+        }
+        chain.reverse();
+        chain
+    }
+
+    /// Adds the built-in `Heading1` to `Heading9` paragraph style for the
+    /// given level, unless a style with that id already exists.
+    ///
+    /// The heading is based on, and followed by, the default paragraph
+    /// style, or stands alone if there's none.
+    ///
+    /// `level` is clamped to `1..=9`.
+    ///
+    /// ```rust
+    /// use docx_rust::styles::Styles;
+    ///
+    /// let mut styles = Styles::new();
+    /// styles.ensure_heading(2);
+    /// assert!(styles.get("Heading2").unwrap().base.is_none());
+    /// ```
+    pub fn ensure_heading(&mut self, level: usize) -> &mut Self {
+        let level = level.clamp(1, 9);
+        if self.get(&format!("Heading{}", level)).is_none() {
+            let normal = self
+                .default_style(StyleType::Paragraph)
+                .map(|style| style.style_id.to_string());
+            let mut style = Style::heading(level);
+            style.base = normal.clone().map(|value| BasedOn {
+                value: value.into(),
+            });
+            style.next = normal.map(|value| Next {
+                value: value.into(),
+            });
+            self.styles.push(style);
+        }
+        self
+    }
+
+    /// Adds the built-in `Caption` paragraph style, unless a style with that
+    /// id already exists.
+    pub fn ensure_caption(&mut self) -> &mut Self {
+        if self.get("Caption").is_none() {
+            self.styles.push(Style::caption());
+        }
+        self
+    }
+
+    /// Adds the built-in `FootnoteText`, `FootnoteReference`, `EndnoteText`
+    /// and `EndnoteReference` styles, unless styles with those ids already
+    /// exist.
+    ///
+    /// ```rust
+    /// use docx_rust::styles::Styles;
+    ///
+    /// let mut styles = Styles::new();
+    /// styles.ensure_notes();
+    /// assert!(styles.get("FootnoteReference").is_some());
+    /// ```
+    pub fn ensure_notes(&mut self) -> &mut Self {
+        for style in [
+            Style::footnote_text(),
+            Style::footnote_reference(),
+            Style::endnote_text(),
+            Style::endnote_reference(),
+        ] {
+            if self.get(&style.style_id).is_none() {
+                self.styles.push(style);
+            }
+        }
+        self
+    }
+
+    /// Adds the built-in `Hyperlink` character style, unless a style with
+    /// that id already exists.
+    pub fn ensure_hyperlink(&mut self) -> &mut Self {
+        if self.get("Hyperlink").is_none() {
+            self.styles.push(Style::hyperlink());
+        }
+        self
+    }
+
+    /// Returns an id for a style with the given display name, made of its
+    /// letters and digits like Word does, with a number appended if another
+    /// style has that id.
+    ///
+    /// ```rust
+    /// use docx_rust::styles::{Style, StyleType, Styles};
+    ///
+    /// let mut styles = Styles::new();
+    /// assert_eq!(styles.unique_style_id("Block Quote"), "BlockQuote");
+    /// styles.push(Style::new(StyleType::Paragraph, "BlockQuote"));
+    /// assert_eq!(styles.unique_style_id("Block-Quote"), "BlockQuote1");
+    /// ```
+    pub fn unique_style_id(&self, name: &str) -> String {
+        let base = style_id(name);
+        let mut id = base.clone();
+        let mut n = 0;
+        while self.get(&id).is_some() {
+            n += 1;
+            id = format!("{}{}", base, n);
+        }
+        id
+    }
+
+    /// Adds a custom style with the given display name, and an id given by
+    /// [`Styles::unique_style_id`].
+    pub fn add_style(&mut self, ty: StyleType, name: &str) -> &mut Style<'a> {
+        let id = self.unique_style_id(name);
+        let style = Style::new(ty, id).name(name.to_string()).custom_style(true);
+        self.styles.push(style);
+        self.styles.last_mut().unwrap()
+    }
+
+    /// Returns the default style of a type, the one applying to paragraphs,
+    /// runs or tables without a style.
+    pub fn default_style(&self, ty: StyleType) -> Option<&Style<'a>> {
+        self.styles
+            .iter()
+            .find(|style| style.ty == Some(ty) && style.default == Some(true))
+    }
+
+    /// Makes a style the default of its type, removing the flag from the
+    /// other styles of that type. Returns `false` if there's no such style.
+    ///
+    /// ```rust
+    /// use docx_rust::styles::{StyleType, Styles};
+    ///
+    /// let mut styles = Styles::with_defaults();
+    /// assert!(styles.set_default_style("TableGrid"));
+    /// assert_eq!(styles.default_style(StyleType::Table).unwrap().style_id, "TableGrid");
+    /// ```
+    pub fn set_default_style(&mut self, style_id: &str) -> bool {
+        let ty = match self.get(style_id) {
+            Some(style) => style.ty,
+            None => return false,
+        };
+        for style in self.styles.iter_mut().filter(|style| style.ty == ty) {
+            style.default = if style.style_id == style_id {
+                Some(true)
+            } else {
+                None
+            };
+        }
+        true
+    }
+}
+
+/// Returns the letters and digits of a style name, or `Style` if it has none.
+pub(crate) fn style_id(name: &str) -> String {
+    let id: String = name.chars().filter(|c| c.is_alphanumeric()).collect();
+    if id.is_empty() {
+        "Style".to_string()
+    } else {
+        id
+    }
+}
+
+fn based_on_normal(mut style: Style<'_>) -> Style<'_> {
+    style.base = Some(BasedOn {
+        value: "Normal".into(),
+    });
+    style
 }
 
 __xml_test_suites!(
@@ -1,12 +1,13 @@
 use hard_xml::{XmlRead, XmlWrite};
 
-/// WidowControl
+/// UI Priority
+///
+/// Orders the styles in the user interface, lowest first.
 ///
 /// ```rust
-/// use docx_rust::formatting::*;
+/// use docx_rust::styles::*;
 ///
-/// let WidowControl = WidowControl::from(false);
-/// let WidowControl = WidowControl::from(true);
+/// let priority = Priority::from(9isize);
 /// ```
 #[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
 #[cfg_attr(test, derive(PartialEq))]
@@ -15,3 +16,9 @@ pub struct Priority {
     #[xml(attr = "w:val")]
     pub value: Option<isize>,
 }
+
+impl From<isize> for Priority {
+    fn from(value: isize) -> Self {
+        Priority { value: Some(value) }
+    }
+}
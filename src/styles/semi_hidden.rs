@@ -1,12 +1,13 @@
 use hard_xml::{XmlRead, XmlWrite};
 
-/// WidowControl
+/// Semi Hidden
+///
+/// Hides a style from the main user interface, e.g. the style gallery.
 ///
 /// ```rust
-/// use docx_rust::formatting::*;
+/// use docx_rust::styles::*;
 ///
-/// let WidowControl = WidowControl::from(false);
-/// let WidowControl = WidowControl::from(true);
+/// let semi_hidden = SemiHidden::from(true);
 /// ```
 #[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
 #[cfg_attr(test, derive(PartialEq))]
@@ -15,3 +16,11 @@ pub struct SemiHidden {
     #[xml(attr = "w:val")]
     pub value: Option<bool>,
 }
+
+impl<T: Into<Option<bool>>> From<T> for SemiHidden {
+    fn from(value: T) -> Self {
+        SemiHidden {
+            value: value.into(),
+        }
+    }
+}
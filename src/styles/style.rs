@@ -4,10 +4,13 @@ use std::borrow::Cow;
 
 use crate::{
     __setter, __string_enum, __xml_test_suites,
-    formatting::{CharacterProperty, ParagraphProperty, TableProperty},
+    formatting::{
+        CharacterProperty, KeepLines, KeepNext, LineRule, OutlineLvl, ParagraphProperty, Spacing,
+        TableProperty, UnderlineStyle, VertAlign, VertAlignType,
+    },
 };
 
-use crate::styles::priority::Priority;
+use crate::styles::{style_id, Priority, SemiHidden, UnhideWhenUsed};
 
 /// Style
 ///
@@ -34,6 +37,8 @@ pub struct Style<'a> {
     /// This identifier is used throughout the document to apply style in content.
     #[xml(attr = "w:styleId")]
     pub style_id: Cow<'a, str>,
+    /// Specifies that the style applies to the paragraphs, runs or tables of
+    /// its type without a style, see [`crate::styles::Styles::set_default_style`]
     #[xml(attr = "w:default")]
     pub default: Option<bool>,
     #[xml(attr = "w:customStyle")]
@@ -42,6 +47,7 @@ pub struct Style<'a> {
     /// Specifies the primary name
     #[xml(child = "w:name")]
     pub name: Option<StyleName<'a>>,
+    /// Specifies alternate names, separated by commas
     #[xml(child = "w:aliases")]
     pub aliases: Option<Aliases<'a>>,
     #[xml(child = "w:basedOn")]
@@ -49,19 +55,24 @@ pub struct Style<'a> {
     /// Style For Next Paragraph
     #[xml(child = "w:next")]
     pub next: Option<Next<'a>>,
+    /// Specifies the linked paragraph or character style
     #[xml(child = "w:link")]
     pub link: Option<Link<'a>>,
     #[xml(child = "w:autoRedefine")]
     pub auto_redefine: Option<AutoRedefine>,
+    /// Hides the style from the user interface
     #[xml(child = "w:hidden")]
     pub hidden: Option<Hidden>,
     /// Specifies the priority.
     #[xml(child = "w:uiPriority")]
     pub priority: Option<Priority>,
+    /// Hides the style from the main user interface
     #[xml(child = "w:semiHidden")]
-    pub semi_hidden: Option<super::semi_hidden::SemiHidden>,
+    pub semi_hidden: Option<SemiHidden>,
+    /// Removes `semi_hidden` once the style is used
     #[xml(child = "w:unhideWhenUsed")]
-    pub unhide_when_used: Option<super::unhidden_when_used::UnhideWhenUsed>,
+    pub unhide_when_used: Option<UnhideWhenUsed>,
+    /// Shows the style in the style gallery
     #[xml(child = "w:qFormat")]
     pub q_format: Option<QFormat>,
     /// Style Cannot Be Applied
@@ -126,10 +137,191 @@ impl<'a> Style<'a> {
         }
     }
 
+    /// Creates the built-in `Heading{level}` paragraph style, with the size
+    /// decreasing by level and the matching outline level, based on and
+    /// followed by `Normal`, see [`crate::styles::Styles::ensure_heading`].
+    ///
+    /// `level` is clamped to `1..=9`.
+    pub fn heading(level: usize) -> Self {
+        const SIZES: [isize; 9] = [32, 26, 24, 22, 22, 22, 22, 22, 22];
+
+        let level = level.clamp(1, 9);
+        let mut style = Style::new(StyleType::Paragraph, format!("Heading{}", level))
+            .name(format!("heading {}", level))
+            .paragraph(ParagraphProperty {
+                keep_next: Some(KeepNext::default()),
+                keep_lines: Some(KeepLines::default()),
+                spacing: Some(Spacing {
+                    before: Some(if level == 1 { 240 } else { 40 }),
+                    ..Default::default()
+                }),
+                outline_lvl: Some(OutlineLvl {
+                    value: level as isize - 1,
+                }),
+                ..Default::default()
+            })
+            .character(
+                CharacterProperty::default()
+                    .bold(true)
+                    .size(SIZES[level - 1]),
+            );
+        style.base = Some(BasedOn {
+            value: "Normal".into(),
+        });
+        style.next = Some(Next {
+            value: "Normal".into(),
+        });
+        style.priority = Some(Priority { value: Some(9) });
+        style.q_format = Some(QFormat { value: None });
+        style
+    }
+
+    /// Creates the built-in `Caption` paragraph style, small italic text
+    /// used for the labels of figures and tables.
+    pub fn caption() -> Self {
+        let mut style = Style::new(StyleType::Paragraph, "Caption")
+            .name("caption")
+            .paragraph(ParagraphProperty::default().spacing(Spacing {
+                after: Some(200),
+                line: Some(240),
+                line_rule: Some(LineRule::Auto),
+                ..Default::default()
+            }))
+            .character(
+                CharacterProperty::default()
+                    .italics(true)
+                    .color(0x44546a)
+                    .size(18isize),
+            );
+        style.base = Some(BasedOn {
+            value: "Normal".into(),
+        });
+        style.next = Some(Next {
+            value: "Normal".into(),
+        });
+        style.priority = Some(Priority { value: Some(35) });
+        style.unhide_when_used = Some(Default::default());
+        style.q_format = Some(QFormat { value: None });
+        style
+    }
+
+    /// Creates the built-in `Hyperlink` character style, blue and underlined.
+    pub fn hyperlink() -> Self {
+        let mut style = Style::new(StyleType::Character, "Hyperlink")
+            .name("Hyperlink")
+            .character(
+                CharacterProperty::default()
+                    .color(0x0563c1)
+                    .underline(UnderlineStyle::Single),
+            );
+        style.priority = Some(Priority { value: Some(99) });
+        style.unhide_when_used = Some(Default::default());
+        style
+    }
+
+    /// Creates the built-in `FootnoteText` paragraph style, the smaller text
+    /// of footnotes.
+    pub fn footnote_text() -> Self {
+        Style::note_text("FootnoteText", "footnote text")
+    }
+
+    /// Creates the built-in `FootnoteReference` character style, the
+    /// superscript mark of footnotes.
+    pub fn footnote_reference() -> Self {
+        Style::note_reference("FootnoteReference", "footnote reference")
+    }
+
+    /// Creates the built-in `EndnoteText` paragraph style, the smaller text
+    /// of endnotes.
+    pub fn endnote_text() -> Self {
+        Style::note_text("EndnoteText", "endnote text")
+    }
+
+    /// Creates the built-in `EndnoteReference` character style, the
+    /// superscript mark of endnotes.
+    pub fn endnote_reference() -> Self {
+        Style::note_reference("EndnoteReference", "endnote reference")
+    }
+
+    /// Creates a custom paragraph style and its linked character style, the
+    /// way Word does: `My Quote` gives the `MyQuote` paragraph style and the
+    /// `MyQuoteChar` character style named `My Quote Char`, both with the
+    /// given character properties.
+    ///
+    /// Applying the paragraph style to a run, rather than to a paragraph,
+    /// applies the character style instead.
+    pub fn linked(name: &str, character: CharacterProperty<'a>) -> (Self, Self) {
+        let style_id = style_id(name);
+        let char_id = format!("{}Char", style_id);
+
+        let paragraph = Style::new(StyleType::Paragraph, style_id.clone())
+            .name(name.to_string())
+            .base("Normal")
+            .next("Normal")
+            .link(char_id.clone())
+            .q_format(None)
+            .character(character.clone());
+        let run = Style::new(StyleType::Character, char_id)
+            .name(format!("{} Char", name))
+            .base("DefaultParagraphFont")
+            .link(style_id)
+            .character(character);
+
+        (paragraph.custom_style(true), run.custom_style(true))
+    }
+
+    fn note_text(style_id: &'static str, name: &'static str) -> Self {
+        let mut style = Style::new(StyleType::Paragraph, style_id)
+            .name(name)
+            .paragraph(ParagraphProperty::default().spacing(Spacing {
+                after: Some(0),
+                line: Some(240),
+                line_rule: Some(LineRule::Auto),
+                ..Default::default()
+            }))
+            .character(CharacterProperty::default().size(20isize));
+        style.base = Some(BasedOn {
+            value: "Normal".into(),
+        });
+        style.priority = Some(Priority { value: Some(99) });
+        style.semi_hidden = Some(Default::default());
+        style.unhide_when_used = Some(Default::default());
+        style
+    }
+
+    fn note_reference(style_id: &'static str, name: &'static str) -> Self {
+        let mut style = Style::new(StyleType::Character, style_id)
+            .name(name)
+            .character(CharacterProperty {
+                vertical_align: Some(VertAlign {
+                    value: Some(VertAlignType::Superscript),
+                }),
+                ..Default::default()
+            });
+        style.priority = Some(Priority { value: Some(99) });
+        style.semi_hidden = Some(Default::default());
+        style.unhide_when_used = Some(Default::default());
+        style
+    }
+
+    __setter!(default: Option<bool>);
+    __setter!(custom_style: Option<bool>);
     __setter!(ty: Option<StyleType>);
     __setter!(name: Option<StyleName<'a>>);
+    __setter!(aliases: Option<Aliases<'a>>);
+    __setter!(base: Option<BasedOn<'a>>);
+    __setter!(next: Option<Next<'a>>);
+    __setter!(link: Option<Link<'a>>);
+    __setter!(auto_redefine: Option<AutoRedefine>);
+    __setter!(hidden: Option<Hidden>);
+    __setter!(priority: Option<Priority>);
+    __setter!(semi_hidden: Option<SemiHidden>);
+    __setter!(unhide_when_used: Option<UnhideWhenUsed>);
+    __setter!(q_format: Option<QFormat>);
+    __setter!(locked: Option<Locked>);
     __setter!(paragraph: Option<ParagraphProperty<'a>>);
     __setter!(character: Option<CharacterProperty<'a>>);
+    __setter!(table: Option<TableProperty<'a>>);
 }
 
 #[derive(Debug, XmlRead, XmlWrite, Clone)]
@@ -154,6 +346,12 @@ pub struct Aliases<'a> {
     pub value: Cow<'a, str>,
 }
 
+impl<'a, S: Into<Cow<'a, str>>> From<S> for Aliases<'a> {
+    fn from(val: S) -> Self {
+        Aliases { value: val.into() }
+    }
+}
+
 #[derive(Debug, XmlRead, XmlWrite, Clone)]
 #[cfg_attr(test, derive(PartialEq))]
 #[xml(tag = "w:next")]
@@ -162,6 +360,12 @@ pub struct Next<'a> {
     pub value: Cow<'a, str>,
 }
 
+impl<'a, S: Into<Cow<'a, str>>> From<S> for Next<'a> {
+    fn from(val: S) -> Self {
+        Next { value: val.into() }
+    }
+}
+
 #[derive(Debug, XmlRead, XmlWrite, Clone)]
 #[cfg_attr(test, derive(PartialEq))]
 #[xml(tag = "w:link")]
@@ -170,7 +374,13 @@ pub struct Link<'a> {
     pub value: Cow<'a, str>,
 }
 
-#[derive(Debug, XmlRead, XmlWrite, Clone)]
+impl<'a, S: Into<Cow<'a, str>>> From<S> for Link<'a> {
+    fn from(val: S) -> Self {
+        Link { value: val.into() }
+    }
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
 #[cfg_attr(test, derive(PartialEq))]
 #[xml(tag = "w:autoRedefine")]
 pub struct AutoRedefine {
@@ -178,7 +388,15 @@ pub struct AutoRedefine {
     pub value: Option<bool>,
 }
 
-#[derive(Debug, XmlRead, XmlWrite, Clone)]
+impl<T: Into<Option<bool>>> From<T> for AutoRedefine {
+    fn from(value: T) -> Self {
+        AutoRedefine {
+            value: value.into(),
+        }
+    }
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
 #[cfg_attr(test, derive(PartialEq))]
 #[xml(tag = "w:hidden")]
 pub struct Hidden {
@@ -186,7 +404,15 @@ pub struct Hidden {
     pub value: Option<bool>,
 }
 
-#[derive(Debug, XmlRead, XmlWrite, Clone)]
+impl<T: Into<Option<bool>>> From<T> for Hidden {
+    fn from(value: T) -> Self {
+        Hidden {
+            value: value.into(),
+        }
+    }
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
 #[cfg_attr(test, derive(PartialEq))]
 #[xml(tag = "w:locked")]
 pub struct Locked {
@@ -194,6 +420,14 @@ pub struct Locked {
     pub value: Option<bool>,
 }
 
+impl<T: Into<Option<bool>>> From<T> for Locked {
+    fn from(value: T) -> Self {
+        Locked {
+            value: value.into(),
+        }
+    }
+}
+
 #[derive(Debug, XmlRead, XmlWrite, Clone)]
 #[cfg_attr(test, derive(PartialEq))]
 #[xml(tag = "w:personal")]
@@ -234,7 +468,13 @@ pub struct BasedOn<'a> {
     pub value: Cow<'a, str>,
 }
 
-#[derive(Debug, XmlRead, XmlWrite, Clone)]
+impl<'a, S: Into<Cow<'a, str>>> From<S> for BasedOn<'a> {
+    fn from(val: S) -> Self {
+        BasedOn { value: val.into() }
+    }
+}
+
+#[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
 #[cfg_attr(test, derive(PartialEq))]
 #[xml(tag = "w:qFormat")]
 pub struct QFormat {
@@ -242,8 +482,15 @@ pub struct QFormat {
     pub value: Option<bool>,
 }
 
-#[derive(Debug, Clone)]
-#[cfg_attr(test, derive(PartialEq))]
+impl<T: Into<Option<bool>>> From<T> for QFormat {
+    fn from(value: T) -> Self {
+        QFormat {
+            value: value.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum StyleType {
     Character,
     Paragraph,
@@ -270,4 +517,14 @@ __xml_test_suites!(
     r#"<w:style w:type="paragraph" w:styleId="id"/>"#,
     Style::new(StyleType::Character, "id"),
     r#"<w:style w:type="character" w:styleId="id"/>"#,
+    Style::new(StyleType::Paragraph, "Quote")
+        .aliases("Citation")
+        .link("QuoteChar")
+        .hidden(true)
+        .priority(29isize)
+        .semi_hidden(None)
+        .unhide_when_used(None)
+        .q_format(None)
+        .locked(false),
+    r#"<w:style w:type="paragraph" w:styleId="Quote"><w:aliases w:val="Citation"/><w:link w:val="QuoteChar"/><w:hidden w:val="true"/><w:uiPriority w:val="29"/><w:semiHidden/><w:unhideWhenUsed/><w:qFormat/><w:locked w:val="false"/></w:style>"#,
 );
@@ -1,12 +1,13 @@
 use hard_xml::{XmlRead, XmlWrite};
 
-/// WidowControl
+/// Unhide When Used
+///
+/// Removes the semi hidden property of a style once it is used.
 ///
 /// ```rust
-/// use docx_rust::formatting::*;
+/// use docx_rust::styles::*;
 ///
-/// let WidowControl = WidowControl::from(false);
-/// let WidowControl = WidowControl::from(true);
+/// let unhidden_when_used = UnhideWhenUsed::from(true);
 /// ```
 #[derive(Debug, Default, XmlRead, XmlWrite, Clone)]
 #[cfg_attr(test, derive(PartialEq))]
@@ -15,3 +16,11 @@ pub struct UnhideWhenUsed {
     #[xml(attr = "w:val")]
     pub value: Option<bool>,
 }
+
+impl<T: Into<Option<bool>>> From<T> for UnhideWhenUsed {
+    fn from(value: T) -> Self {
+        UnhideWhenUsed {
+            value: value.into(),
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{formatting::CharacterProperty, styles::StyleType, walk::runs, Docx};
+
+impl<'a> Docx<'a> {
+    /// Counts how often each style is referenced by paragraphs, runs and
+    /// tables of every part, and by the levels of the numbering definitions,
+    /// both the paragraph style a level numbers and its run properties.
+    ///
+    /// References between styles, like `w:basedOn`, are not counted.
+    ///
+    /// ```rust
+    /// use docx_rust::Docx;
+    ///
+    /// let mut docx = Docx::default();
+    /// docx.add_heading(1, "Introduction");
+    /// docx.add_heading(1, "Conclusion");
+    ///
+    /// assert_eq!(docx.style_usage()["Heading1"], 2);
+    /// ```
+    pub fn style_usage(&self) -> HashMap<String, usize> {
+        let mut ids: Vec<&str> = Vec::new();
+        for para in self.paragraphs() {
+            if let Some(property) = &para.property {
+                ids.extend(property.style_id.as_ref().map(|id| id.value.as_ref()));
+                ids.extend(property.r_pr.iter().filter_map(character_style));
+            }
+            ids.extend(
+                runs(para)
+                    .into_iter()
+                    .filter_map(|run| character_style(run.property.as_ref()?)),
+            );
+        }
+        for table in self.tables() {
+            ids.extend(table.property.style_id.as_ref().map(|id| id.value.as_ref()));
+        }
+        if let Some(numbering) = &self.numbering {
+            for abstract_num in &numbering.abstract_numberings {
+                for level in &abstract_num.levels {
+                    ids.extend(level.style_id.as_ref().map(|id| id.value.as_ref()));
+                    ids.extend(level.r_pr.iter().filter_map(character_style));
+                }
+            }
+        }
+
+        let mut usage = HashMap::new();
+        for id in ids {
+            *usage.entry(id.to_string()).or_insert(0) += 1;
+        }
+        usage
+    }
+
+    /// Removes the styles that are neither used, see [`Docx::style_usage`],
+    /// nor needed by a used style as its base, next or linked style.
+    ///
+    /// Default styles and numbering styles are always kept. Returns the ids
+    /// of the removed styles.
+    ///
+    /// ```rust
+    /// use docx_rust::styles::Styles;
+    /// use docx_rust::Docx;
+    ///
+    /// let mut docx = Docx::default();
+    /// docx.styles = Styles::with_defaults();
+    /// docx.add_heading(2, "Background");
+    ///
+    /// let removed = docx.prune_unused_styles();
+    /// assert!(removed.contains(&"Title".to_string()));
+    /// assert!(docx.styles.get("Heading2").is_some());
+    /// ```
+    pub fn prune_unused_styles(&mut self) -> Vec<String> {
+        let mut keep: Vec<String> = self.style_usage().into_keys().collect();
+        keep.extend(
+            self.styles
+                .styles
+                .iter()
+                .filter(|style| {
+                    style.default == Some(true) || matches!(style.ty, Some(StyleType::Numbering))
+                })
+                .map(|style| style.style_id.to_string()),
+        );
+
+        let mut kept = HashSet::new();
+        while let Some(id) = keep.pop() {
+            if !kept.insert(id.clone()) {
+                continue;
+            }
+            if let Some(style) = self.styles.get(&id) {
+                let related = [
+                    style.base.as_ref().map(|base| &base.value),
+                    style.next.as_ref().map(|next| &next.value),
+                    style.link.as_ref().map(|link| &link.value),
+                ];
+                keep.extend(related.into_iter().flatten().map(|id| id.to_string()));
+            }
+        }
+
+        let mut removed = Vec::new();
+        self.styles.styles.retain(|style| {
+            let used = kept.contains(style.style_id.as_ref());
+            if !used {
+                removed.push(style.style_id.to_string());
+            }
+            used
+        });
+        removed
+    }
+}
+
+fn character_style<'b>(property: &'b CharacterProperty) -> Option<&'b str> {
+    property.style_id.as_ref().map(|id| id.value.as_ref())
+}
+
+#[test]
+fn numbering_styles() {
+    use crate::document::{AbstractNum, Level, LevelStyleId, Numbering};
+    use crate::styles::{Style, Styles};
+
+    let mut docx = Docx {
+        styles: Styles::with_defaults(),
+        numbering: Some(Numbering {
+            abstract_numberings: vec![AbstractNum {
+                levels: vec![Level {
+                    style_id: Some(LevelStyleId {
+                        value: "ListHeading".into(),
+                    }),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    docx.styles
+        .push(Style::new(StyleType::Paragraph, "ListHeading"));
+
+    assert_eq!(docx.style_usage()["ListHeading"], 1);
+    assert!(!docx
+        .prune_unused_styles()
+        .contains(&"ListHeading".to_string()));
+}
@@ -0,0 +1,358 @@
+//! Round-trip checks for your own documents
+//!
+//! Behind the `test-support` feature. [`check_fixtures`] loads every `.docx`
+//! file of a directory, parses and writes it again, and reports how the
+//! written package differs from the original, so a test suite can make sure
+//! its documents survive this crate.
+//!
+//! ```no_run
+//! use docx_rust::test_support::check_fixtures;
+//!
+//! for fixture in check_fixtures("tests/fixtures").unwrap() {
+//!     let differences = fixture.differences.unwrap();
+//!     let structural: Vec<_> = differences.iter().filter(|d| d.is_structural()).collect();
+//!     assert!(structural.is_empty(), "{}: {:?}", fixture.path.display(), structural);
+//! }
+//! ```
+//!
+//! XML parts are compared by structure: the order of elements and text must
+//! be the same, while the order of attributes, namespace declarations,
+//! whitespace between elements and the escaping of characters may differ.
+//! Other parts are compared byte by byte.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::{Cursor, Read};
+use std::path::{Path, PathBuf};
+
+use xmlparser::{ElementEnd, Token, Tokenizer};
+use zip::ZipArchive;
+
+use crate::{DocxError, DocxFile, DocxResult};
+
+/// A difference between a package and the package written after parsing it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Difference {
+    /// The part isn't written
+    Missing { part: String },
+    /// The part is written, but isn't in the original
+    Added { part: String },
+    /// The part has the same structure, but different bytes
+    Bytes { part: String },
+    /// The XML of the part differs, first at the element with the given path
+    Structure {
+        part: String,
+        path: String,
+        original: String,
+        written: String,
+    },
+}
+
+impl Difference {
+    /// Returns whether the difference changes the content, i.e. is anything
+    /// but a different serialization of the same XML.
+    pub fn is_structural(&self) -> bool {
+        !matches!(self, Difference::Bytes { part } if is_xml(part))
+    }
+
+    /// Returns the name of the part that differs.
+    pub fn part(&self) -> &str {
+        match self {
+            Difference::Missing { part }
+            | Difference::Added { part }
+            | Difference::Bytes { part }
+            | Difference::Structure { part, .. } => part,
+        }
+    }
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Difference::Missing { part } => write!(f, "{}: missing", part),
+            Difference::Added { part } => write!(f, "{}: added", part),
+            Difference::Bytes { part } => write!(f, "{}: bytes differ", part),
+            Difference::Structure {
+                part,
+                path,
+                original,
+                written,
+            } => write!(
+                f,
+                "{} at {}: expected `{}`, found `{}`",
+                part, path, original, written
+            ),
+        }
+    }
+}
+
+/// The result of checking a fixture
+#[derive(Debug)]
+pub struct Fixture {
+    /// Specifies the path of the fixture
+    pub path: PathBuf,
+    /// Specifies the differences found, or why the fixture couldn't be
+    /// parsed or written
+    pub differences: DocxResult<Vec<Difference>>,
+}
+
+/// Loads the `.docx` files of a directory, sorted by path.
+pub fn load_fixtures<P: AsRef<Path>>(dir: P) -> DocxResult<Vec<(PathBuf, Vec<u8>)>> {
+    let mut paths = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        let is_docx = path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("docx"));
+        if is_docx && path.is_file() {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let bytes = std::fs::read(&path)?;
+            Ok((path, bytes))
+        })
+        .collect()
+}
+
+/// Round-trips every `.docx` file of a directory, see [`round_trip`].
+pub fn check_fixtures<P: AsRef<Path>>(dir: P) -> DocxResult<Vec<Fixture>> {
+    Ok(load_fixtures(dir)?
+        .into_iter()
+        .map(|(path, bytes)| Fixture {
+            differences: round_trip(&bytes),
+            path,
+        })
+        .collect())
+}
+
+/// Parses and writes a package, returning how the written package differs
+/// from the original.
+pub fn round_trip(bytes: &[u8]) -> DocxResult<Vec<Difference>> {
+    let file = DocxFile::from_bytes(bytes)?;
+    let written = file.parse()?.write_to_vec()?;
+    compare_packages(bytes, &written)
+}
+
+/// Compares two packages part by part.
+pub fn compare_packages(original: &[u8], written: &[u8]) -> DocxResult<Vec<Difference>> {
+    let original = read_parts(original)?;
+    let written = read_parts(written)?;
+
+    let mut differences = Vec::new();
+    for (part, data) in &original {
+        let other = match written.get(part) {
+            Some(other) => other,
+            None => {
+                differences.push(Difference::Missing { part: part.clone() });
+                continue;
+            }
+        };
+        if data == other {
+            continue;
+        }
+        let structure = match (
+            is_xml(part),
+            std::str::from_utf8(data),
+            std::str::from_utf8(other),
+        ) {
+            (true, Ok(data), Ok(other)) => compare_xml(data, other)?,
+            _ => None,
+        };
+        differences.push(match structure {
+            Some((path, original, written)) => Difference::Structure {
+                part: part.clone(),
+                path,
+                original,
+                written,
+            },
+            None => Difference::Bytes { part: part.clone() },
+        });
+    }
+    for part in written.keys().filter(|part| !original.contains_key(*part)) {
+        differences.push(Difference::Added { part: part.clone() });
+    }
+
+    Ok(differences)
+}
+
+fn read_parts(bytes: &[u8]) -> DocxResult<BTreeMap<String, Vec<u8>>> {
+    let mut zip = ZipArchive::new(Cursor::new(bytes))?;
+    let mut parts = BTreeMap::new();
+    for index in 0..zip.len() {
+        let mut file = zip.by_index(index)?;
+        if file.is_dir() {
+            continue;
+        }
+        let mut data = Vec::with_capacity(file.size() as usize);
+        file.read_to_end(&mut data)?;
+        parts.insert(file.name().to_string(), data);
+    }
+    Ok(parts)
+}
+
+fn is_xml(part: &str) -> bool {
+    part.ends_with(".xml") || part.ends_with(".rels")
+}
+
+#[derive(PartialEq)]
+enum Node<'x> {
+    Start(&'x str, Vec<(&'x str, String)>),
+    End,
+    Text(String),
+}
+
+impl<'x> fmt::Display for Node<'x> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Node::Start(name, attrs) => {
+                write!(f, "<{}", name)?;
+                for (name, value) in attrs {
+                    write!(f, " {}=\"{}\"", name, value)?;
+                }
+                write!(f, ">")
+            }
+            Node::End => write!(f, "end of element"),
+            Node::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+/// Returns the path of the first element whose content differs, with the
+/// differing nodes, or `None` if the XML has the same structure.
+fn compare_xml(original: &str, written: &str) -> DocxResult<Option<(String, String, String)>> {
+    let original = nodes(original)?;
+    let written = nodes(written)?;
+
+    let mut path: Vec<&str> = Vec::new();
+    for index in 0..original.len().max(written.len()) {
+        match (original.get(index), written.get(index)) {
+            (Some(a), Some(b)) if a == b => match a {
+                Node::Start(name, _) => path.push(name),
+                Node::End => {
+                    path.pop();
+                }
+                Node::Text(_) => (),
+            },
+            (a, b) => {
+                let show = |node: Option<&Node>| match node {
+                    Some(node) => node.to_string(),
+                    None => "end of part".to_string(),
+                };
+                return Ok(Some((format!("/{}", path.join("/")), show(a), show(b))));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Returns the elements and text of the XML, without namespace declarations
+/// and whitespace between elements, attributes sorted by name.
+fn nodes(xml: &str) -> DocxResult<Vec<Node<'_>>> {
+    let mut nodes = Vec::new();
+    let mut attrs = Vec::new();
+    let mut name = "";
+
+    for token in Tokenizer::from(xml) {
+        let token = token.map_err(|e| DocxError::Xml(hard_xml::XmlError::from(e)))?;
+        match token {
+            Token::ElementStart { span, .. } => {
+                name = &span.as_str()[1..];
+                attrs.clear();
+            }
+            Token::Attribute { span, value, .. } => {
+                let attr = span.as_str();
+                let attr = attr[..attr.find('=').unwrap_or(0)].trim();
+                if attr != "xmlns" && !attr.starts_with("xmlns:") {
+                    attrs.push((attr, unescape(value.as_str())));
+                }
+            }
+            Token::ElementEnd { end, .. } => match end {
+                ElementEnd::Open | ElementEnd::Empty => {
+                    attrs.sort();
+                    nodes.push(Node::Start(name, std::mem::take(&mut attrs)));
+                    if let ElementEnd::Empty = end {
+                        nodes.push(Node::End);
+                    }
+                }
+                ElementEnd::Close(..) => nodes.push(Node::End),
+            },
+            Token::Text { text } if !text.as_str().trim().is_empty() => {
+                nodes.push(Node::Text(unescape(text.as_str())))
+            }
+            Token::Cdata { text, .. } => nodes.push(Node::Text(text.as_str().to_string())),
+            _ => (),
+        }
+    }
+
+    Ok(nodes)
+}
+
+fn unescape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let end = match rest.find(';') {
+            Some(end) => end,
+            None => break,
+        };
+        let entity = &rest[1..end];
+        let c = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => entity
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(str::parse))
+                .and_then(Result::ok)
+                .and_then(char::from_u32),
+        };
+        match c {
+            Some(c) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[test]
+fn structural_comparison() {
+    let original = r#"<?xml version="1.0"?>
+<w:p xmlns:w="urn:w" w:a="1" w:b="2">
+    <w:r><w:t>Tom &amp; Jerry's</w:t></w:r>
+</w:p>"#;
+    let reordered = r#"<w:p w:b="2" w:a="1"><w:r><w:t>Tom &amp; Jerry&apos;s</w:t></w:r></w:p>"#;
+    assert_eq!(compare_xml(original, reordered).unwrap(), None);
+
+    let changed = r#"<w:p w:a="1" w:b="2"><w:r><w:t>Tom</w:t></w:r></w:p>"#;
+    assert_eq!(
+        compare_xml(original, changed).unwrap(),
+        Some((
+            "/w:p/w:r/w:t".to_string(),
+            "Tom & Jerry's".to_string(),
+            "Tom".to_string()
+        ))
+    );
+
+    let dropped = r#"<w:p w:a="1" w:b="2"/>"#;
+    assert_eq!(
+        compare_xml(original, dropped).unwrap().unwrap().0,
+        "/w:p".to_string()
+    );
+}
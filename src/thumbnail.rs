@@ -0,0 +1,110 @@
+//! Thumbnail part
+//!
+//! The corresponding ZIP item is `/docProps/thumbnail.png`, or another
+//! extension depending on the format, the preview image file explorers show.
+
+use std::borrow::Cow;
+
+/// The format of a thumbnail
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThumbnailFormat {
+    Png,
+    Jpeg,
+    Emf,
+    Wmf,
+}
+
+impl ThumbnailFormat {
+    /// Returns the format of a thumbnail from its file extension.
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension.to_ascii_lowercase().as_str() {
+            "png" => Some(ThumbnailFormat::Png),
+            "jpeg" | "jpg" => Some(ThumbnailFormat::Jpeg),
+            "emf" => Some(ThumbnailFormat::Emf),
+            "wmf" => Some(ThumbnailFormat::Wmf),
+            _ => None,
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            ThumbnailFormat::Png => "png",
+            ThumbnailFormat::Jpeg => "jpeg",
+            ThumbnailFormat::Emf => "emf",
+            ThumbnailFormat::Wmf => "wmf",
+        }
+    }
+
+    pub fn content_type(self) -> &'static str {
+        match self {
+            ThumbnailFormat::Png => "image/png",
+            ThumbnailFormat::Jpeg => "image/jpeg",
+            ThumbnailFormat::Emf => "image/x-emf",
+            ThumbnailFormat::Wmf => "image/x-wmf",
+        }
+    }
+}
+
+/// The preview image of a package
+///
+/// ```rust
+/// use docx_rust::thumbnail::Thumbnail;
+/// use docx_rust::Docx;
+///
+/// let png = b"\x89PNG\r\n\x1a\n".to_vec();
+/// let mut docx = Docx::default();
+/// docx.thumbnail = Some(Thumbnail::png(&png[..]));
+/// assert_eq!(docx.thumbnail.unwrap().name(), "docProps/thumbnail.png");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Thumbnail<'a> {
+    pub format: ThumbnailFormat,
+    pub data: Cow<'a, [u8]>,
+}
+
+impl<'a> Thumbnail<'a> {
+    pub fn new<T: Into<Cow<'a, [u8]>>>(format: ThumbnailFormat, data: T) -> Self {
+        Thumbnail {
+            format,
+            data: data.into(),
+        }
+    }
+
+    pub fn png<T: Into<Cow<'a, [u8]>>>(data: T) -> Self {
+        Thumbnail::new(ThumbnailFormat::Png, data)
+    }
+
+    pub fn emf<T: Into<Cow<'a, [u8]>>>(data: T) -> Self {
+        Thumbnail::new(ThumbnailFormat::Emf, data)
+    }
+
+    /// Returns the name of the part in the package.
+    pub fn name(&self) -> String {
+        format!("docProps/thumbnail.{}", self.format.extension())
+    }
+}
+
+#[test]
+fn thumbnail() {
+    use crate::Docx;
+
+    let mut docx = Docx {
+        thumbnail: Some(Thumbnail::emf(&[1, 0, 0, 0][..])),
+        ..Default::default()
+    };
+    docx.add_relationships();
+
+    assert!(docx.rels.get_id("docProps/thumbnail.emf").is_some());
+    assert_eq!(
+        docx.content_types.get("/docProps/thumbnail.emf"),
+        Some("image/x-emf")
+    );
+    assert_eq!(
+        docx.binary_parts()[0],
+        ("docProps/thumbnail.emf".to_string(), &[1, 0, 0, 0][..])
+    );
+    assert_eq!(
+        ThumbnailFormat::from_extension("JPG"),
+        Some(ThumbnailFormat::Jpeg)
+    );
+}
@@ -0,0 +1,198 @@
+//! Typographic replacement of plain text
+//!
+//! Converts straight quotes to the curly quotes of a language, double and
+//! triple hyphens to en and em dashes, and three dots to an ellipsis.
+//!
+//! ```rust
+//! use docx_rust::typography::Typography;
+//!
+//! let text = Typography::english().apply("\"It's here\" -- she said...");
+//! assert_eq!(text, "\u{201c}It\u{2019}s here\u{201d} \u{2013} she said\u{2026}");
+//!
+//! let text = Typography::for_language("de-DE").apply("\"Hallo\"");
+//! assert_eq!(text, "\u{201e}Hallo\u{201c}");
+//! ```
+
+use std::borrow::Cow;
+
+/// Rules of a typographic replacement pass
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Typography {
+    /// Opening and closing double quotes
+    pub double_quotes: (&'static str, &'static str),
+    /// Opening and closing single quotes
+    pub single_quotes: (&'static str, &'static str),
+    /// Replaces `--` by an en dash and `---` by an em dash
+    pub dashes: bool,
+    /// Replaces `...` by an ellipsis
+    pub ellipses: bool,
+}
+
+impl Default for Typography {
+    fn default() -> Self {
+        Typography::english()
+    }
+}
+
+impl Typography {
+    /// “Double” and ‘single’ quotes
+    pub fn english() -> Self {
+        Typography {
+            double_quotes: ("\u{201c}", "\u{201d}"),
+            single_quotes: ("\u{2018}", "\u{2019}"),
+            dashes: true,
+            ellipses: true,
+        }
+    }
+
+    /// „Double“ and ‚single‘ quotes
+    pub fn german() -> Self {
+        Typography {
+            double_quotes: ("\u{201e}", "\u{201c}"),
+            single_quotes: ("\u{201a}", "\u{2018}"),
+            ..Typography::english()
+        }
+    }
+
+    /// « Double » and ‹ single › quotes, with no-break spaces inside
+    pub fn french() -> Self {
+        Typography {
+            double_quotes: ("\u{ab}\u{a0}", "\u{a0}\u{bb}"),
+            single_quotes: ("\u{2039}\u{a0}", "\u{a0}\u{203a}"),
+            ..Typography::english()
+        }
+    }
+
+    /// «Double» and “single” quotes, as in Spanish, Italian or Russian
+    pub fn guillemets() -> Self {
+        Typography {
+            double_quotes: ("\u{ab}", "\u{bb}"),
+            single_quotes: ("\u{201c}", "\u{201d}"),
+            ..Typography::english()
+        }
+    }
+
+    /// ”Double” and ’single’ quotes, as in Swedish or Finnish
+    pub fn nordic() -> Self {
+        Typography {
+            double_quotes: ("\u{201d}", "\u{201d}"),
+            single_quotes: ("\u{2019}", "\u{2019}"),
+            ..Typography::english()
+        }
+    }
+
+    /// Picks the rules of a language tag like `de-AT`, English if unknown.
+    pub fn for_language(tag: &str) -> Self {
+        let language = tag.split(['-', '_']).next().unwrap_or_default();
+        match language.to_ascii_lowercase().as_str() {
+            "de" | "cs" | "sk" | "da" => Typography::german(),
+            "fr" => Typography::french(),
+            "es" | "it" | "pt" | "ru" | "uk" | "el" | "ca" => Typography::guillemets(),
+            "sv" | "fi" => Typography::nordic(),
+            _ => Typography::english(),
+        }
+    }
+
+    /// Replaces straight quotes, dashes and dots in `text`.
+    ///
+    /// An apostrophe inside a word becomes a closing single quote. Text that
+    /// doesn't need any replacement is returned as is.
+    pub fn apply<'a, T: Into<Cow<'a, str>>>(&self, text: T) -> Cow<'a, str> {
+        let text = text.into();
+        if !text.contains(['"', '\'', '-', '.']) {
+            return text;
+        }
+
+        let mut out = String::with_capacity(text.len() + 8);
+        let mut prev: Option<char> = None;
+        let mut chars = text.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '"' => {
+                    let (open, close) = self.double_quotes;
+                    out.push_str(if opens(prev) { open } else { close });
+                }
+                '\'' => {
+                    let (open, close) = self.single_quotes;
+                    if prev.is_some_and(char::is_alphanumeric) {
+                        out.push('\u{2019}');
+                    } else {
+                        out.push_str(if opens(prev) { open } else { close });
+                    }
+                }
+                '-' if self.dashes && chars.peek() == Some(&'-') => {
+                    chars.next();
+                    if chars.peek() == Some(&'-') {
+                        chars.next();
+                        out.push('\u{2014}');
+                    } else {
+                        out.push('\u{2013}');
+                    }
+                }
+                c => out.push(c),
+            }
+            prev = out.chars().next_back();
+        }
+
+        if self.ellipses {
+            out = out.replace("...", "\u{2026}");
+        }
+
+        if out == text {
+            text
+        } else {
+            out.into()
+        }
+    }
+}
+
+/// Returns whether a quote following `prev` opens a quotation.
+fn opens(prev: Option<char>) -> bool {
+    match prev {
+        None => true,
+        Some(c) => {
+            c.is_whitespace()
+                || matches!(
+                    c,
+                    '(' | '['
+                        | '{'
+                        | '/'
+                        | '\u{2013}'
+                        | '\u{2014}'
+                        | '\u{201c}'
+                        | '\u{2018}'
+                        | '\u{201e}'
+                        | '\u{201a}'
+                        | '\u{ab}'
+                        | '\u{2039}'
+                )
+        }
+    }
+}
+
+#[test]
+fn replacements() {
+    let en = Typography::english();
+    assert_eq!(en.apply("plain text"), "plain text");
+    assert!(matches!(en.apply("plain text"), Cow::Borrowed(_)));
+    assert_eq!(
+        en.apply("'quoted' (\"a\") rock 'n' roll"),
+        "\u{2018}quoted\u{2019} (\u{201c}a\u{201d}) rock \u{2018}n\u{2019} roll"
+    );
+    assert_eq!(
+        en.apply("a--b---c...d 1.5"),
+        "a\u{2013}b\u{2014}c\u{2026}d 1.5"
+    );
+    assert_eq!(
+        Typography::french().apply("\"oui\""),
+        "\u{ab}\u{a0}oui\u{a0}\u{bb}"
+    );
+    assert_eq!(Typography::for_language("sv_SE"), Typography::nordic());
+
+    let no_dashes = Typography {
+        dashes: false,
+        ..en
+    };
+    assert_eq!(no_dashes.apply("a--b"), "a--b");
+}
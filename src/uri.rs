@@ -0,0 +1,144 @@
+//! Link targets that Word accepts
+//!
+//! Word refuses to open links whose target isn't a well-formed URI, so
+//! spaces, non-ASCII characters and other characters that aren't allowed in
+//! a URI have to be percent-encoded.
+//!
+//! ```rust
+//! use docx_rust::uri;
+//!
+//! assert_eq!(
+//!     uri::mailto("jane@example.com", Some("Q&A today")).unwrap(),
+//!     "mailto:jane@example.com?subject=Q%26A%20today"
+//! );
+//! assert_eq!(uri::tel("+31 (0)20-123 4567").unwrap(), "tel:+31201234567");
+//! assert_eq!(uri::file("C:\\My Files\\a.docx"), "file:///C:/My%20Files/a.docx");
+//! assert!(uri::mailto("not an address", None).is_none());
+//! ```
+
+/// Percent-encodes the characters of `uri` that aren't allowed in a URI.
+///
+/// Reserved characters like `/`, `?` and `#`, as well as existing escapes,
+/// are left as is.
+pub fn encode(uri: &str) -> String {
+    encode_except(uri, |c| {
+        c.is_ascii_alphanumeric() || "-._~:/?#[]@!$&'()*+,;=%".contains(c)
+    })
+}
+
+/// Creates a `mailto:` link to `address`, with an optional subject.
+///
+/// Returns `None` if `address` doesn't look like an email address.
+pub fn mailto(address: &str, subject: Option<&str>) -> Option<String> {
+    let address = address.trim();
+    let (local, domain) = address.rsplit_once('@')?;
+    let valid = !local.is_empty()
+        && !domain.is_empty()
+        && !domain.starts_with('.')
+        && !domain.ends_with('.')
+        && !address
+            .chars()
+            .any(|c| c.is_whitespace() || c.is_control() || "<>()[],;:\"".contains(c));
+    if !valid {
+        return None;
+    }
+
+    let mut uri = format!("mailto:{}", encode_component(address, "@.!$'*+-=^_`{|}~"));
+    if let Some(subject) = subject {
+        uri.push_str("?subject=");
+        uri.push_str(&encode_component(subject, "-._~"));
+    }
+    Some(uri)
+}
+
+/// Creates a `tel:` link to a phone number.
+///
+/// Spaces, dashes, dots and parentheses are dropped, as well as a trunk
+/// prefix written as `(0)` after a country code. Returns `None` if the number
+/// contains other characters or no digits.
+pub fn tel(number: &str) -> Option<String> {
+    let number = number.trim();
+    let number = match number.strip_prefix('+') {
+        Some(rest) => format!("+{}", rest.replacen("(0)", "", 1)),
+        None => number.to_owned(),
+    };
+
+    let mut uri = String::from("tel:");
+    for (i, c) in number.chars().enumerate() {
+        match c {
+            '0'..='9' => uri.push(c),
+            '+' if i == 0 => uri.push(c),
+            ' ' | '-' | '.' | '(' | ')' => (),
+            _ => return None,
+        }
+    }
+    uri.chars().any(|c| c.is_ascii_digit()).then_some(uri)
+}
+
+/// Creates a `file:` link to a local or network path.
+///
+/// Backslashes become slashes, and a UNC path like `\\server\share` gets
+/// `server` as the host.
+pub fn file(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    let path = if let Some(unc) = path.strip_prefix("//") {
+        format!("file://{}", unc)
+    } else if path.starts_with('/') {
+        format!("file://{}", path)
+    } else {
+        format!("file:///{}", path)
+    };
+    encode_except(&path, |c| {
+        c.is_ascii_alphanumeric() || "-._~:/!$&'()*+,;=@".contains(c)
+    })
+}
+
+/// Percent-encodes everything but alphanumerics and the `allowed` characters.
+fn encode_component(text: &str, allowed: &str) -> String {
+    encode_except(text, |c| c.is_ascii_alphanumeric() || allowed.contains(c))
+}
+
+fn encode_except(text: &str, keep: impl Fn(char) -> bool) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        if keep(c) {
+            out.push(c);
+        } else {
+            let mut buf = [0; 4];
+            for byte in c.encode_utf8(&mut buf).bytes() {
+                out.push_str(&format!("%{:02X}", byte));
+            }
+        }
+    }
+    out
+}
+
+#[test]
+fn uris() {
+    assert_eq!(
+        encode("https://example.com/a b/ä?q=1#top"),
+        "https://example.com/a%20b/%C3%A4?q=1#top"
+    );
+    assert_eq!(
+        encode("https://example.com/a%20b"),
+        "https://example.com/a%20b"
+    );
+
+    assert_eq!(
+        mailto(" jane.doe+news@example.com ", None).as_deref(),
+        Some("mailto:jane.doe+news@example.com")
+    );
+    assert_eq!(mailto("jane@", None), None);
+    assert_eq!(mailto("@example.com", None), None);
+    assert_eq!(mailto("jane doe@example.com", None), None);
+
+    assert_eq!(tel("(020) 123-45.67").as_deref(), Some("tel:0201234567"));
+    assert_eq!(tel("call me"), None);
+    assert_eq!(tel("+"), None);
+
+    assert_eq!(file("/tmp/a#1.txt"), "file:///tmp/a%231.txt");
+    assert_eq!(
+        file("\\\\server\\share\\report.docx"),
+        "file://server/share/report.docx"
+    );
+}
@@ -0,0 +1,334 @@
+use crate::{
+    document::{
+        BodyContent, Comments, Paragraph, ParagraphContent, Run, Table, TableCellContent,
+        TableRowContent,
+    },
+    formatting::SectionProperty,
+    Docx,
+};
+
+impl<'a> Docx<'a> {
+    /// Returns the content of the body, headers, footers, footnotes and
+    /// endnotes.
+    pub(crate) fn parts_content(&self) -> Vec<&[BodyContent<'a>]> {
+        let mut parts = vec![&self.document.body.content[..]];
+        parts.extend(self.headers.values().map(|header| &header.content[..]));
+        parts.extend(self.footers.values().map(|footer| &footer.content[..]));
+        if let Some(footnotes) = &self.footnotes {
+            parts.extend(footnotes.content.iter().map(|note| &note.content[..]));
+        }
+        if let Some(endnotes) = &self.endnotes {
+            parts.extend(endnotes.content.iter().map(|note| &note.content[..]));
+        }
+        parts
+    }
+
+    /// Returns every paragraph of the package, including those in tables,
+    /// content controls and comments.
+    pub(crate) fn paragraphs(&self) -> Vec<&Paragraph<'a>> {
+        let mut paras = Vec::new();
+        if let Some(comments) = &self.comments {
+            paras.extend(comments.comments.iter().map(|c| &c.content));
+        }
+        for content in self.parts_content() {
+            visit(content, &mut paras, &mut Vec::new());
+        }
+        paras
+    }
+
+    /// Returns the paragraphs of the main document, including those in
+    /// tables and content controls.
+    pub(crate) fn body_paragraphs(&self) -> Vec<&Paragraph<'a>> {
+        content_paragraphs(&self.document.body.content)
+    }
+
+    /// Returns every table of the package.
+    pub(crate) fn tables(&self) -> Vec<&Table<'a>> {
+        let mut tables = Vec::new();
+        for content in self.parts_content() {
+            visit(content, &mut Vec::new(), &mut tables);
+        }
+        tables
+    }
+
+    /// Returns the content of the body, headers, footers, footnotes and
+    /// endnotes.
+    pub(crate) fn parts_content_mut(&mut self) -> Vec<&mut Vec<BodyContent<'a>>> {
+        self.parts_and_comments_mut().0
+    }
+
+    /// Returns every paragraph of the package, including those in tables,
+    /// content controls and comments.
+    pub(crate) fn paragraphs_mut(&mut self) -> Vec<&mut Paragraph<'a>> {
+        let (parts, comments) = self.parts_and_comments_mut();
+        let mut paras = Vec::new();
+        if let Some(comments) = comments {
+            paras.extend(comments.comments.iter_mut().map(|c| &mut c.content));
+        }
+        for content in parts {
+            visit_content(content, &mut paras, &mut Vec::new());
+        }
+        paras
+    }
+
+    /// Mutable version of [`Docx::body_paragraphs`].
+    pub(crate) fn body_paragraphs_mut(&mut self) -> Vec<&mut Paragraph<'a>> {
+        content_paragraphs_mut(&mut self.document.body.content)
+    }
+
+    /// Returns every section property of the package, either in the body or
+    /// in a paragraph.
+    pub(crate) fn section_properties_mut(&mut self) -> Vec<&mut SectionProperty<'a>> {
+        let mut sections = Vec::new();
+        let mut paras = Vec::new();
+        for content in self.parts_content_mut() {
+            visit_content(content, &mut paras, &mut sections);
+        }
+        for para in paras {
+            if let Some(section) = para
+                .property
+                .as_mut()
+                .and_then(|property| property.section_property.as_mut())
+            {
+                sections.push(section);
+            }
+        }
+        sections
+    }
+}
+
+impl<'a> Docx<'a> {
+    fn parts_and_comments_mut(
+        &mut self,
+    ) -> (Vec<&mut Vec<BodyContent<'a>>>, Option<&mut Comments<'a>>) {
+        let mut parts = vec![&mut self.document.body.content];
+        parts.extend(self.headers.values_mut().map(|header| &mut header.content));
+        parts.extend(self.footers.values_mut().map(|footer| &mut footer.content));
+        if let Some(footnotes) = &mut self.footnotes {
+            parts.extend(footnotes.content.iter_mut().map(|note| &mut note.content));
+        }
+        if let Some(endnotes) = &mut self.endnotes {
+            parts.extend(endnotes.content.iter_mut().map(|note| &mut note.content));
+        }
+        (parts, self.comments.as_mut())
+    }
+}
+
+/// Returns the paragraphs of some content, e.g. of a body, including those
+/// in tables and content controls, in document order.
+pub(crate) fn content_paragraphs<'b, 'a>(content: &'b [BodyContent<'a>]) -> Vec<&'b Paragraph<'a>> {
+    let mut paras = Vec::new();
+    visit(content, &mut paras, &mut Vec::new());
+    paras
+}
+
+/// Mutable version of [`content_paragraphs`].
+pub(crate) fn content_paragraphs_mut<'b, 'a>(
+    content: &'b mut [BodyContent<'a>],
+) -> Vec<&'b mut Paragraph<'a>> {
+    let mut paras = Vec::new();
+    visit_content(content, &mut paras, &mut Vec::new());
+    paras
+}
+
+/// Returns the content of a paragraph, with the content of its content
+/// controls in their place, in document order.
+pub(crate) fn paragraph_content<'b, 'a>(para: &'b Paragraph<'a>) -> Vec<&'b ParagraphContent<'a>> {
+    let mut items = Vec::new();
+    visit_inline(&para.content, &mut items);
+    items
+}
+
+/// Mutable version of [`paragraph_content`].
+pub(crate) fn paragraph_content_mut<'b, 'a>(
+    para: &'b mut Paragraph<'a>,
+) -> Vec<&'b mut ParagraphContent<'a>> {
+    let mut items = Vec::new();
+    visit_inline_mut(&mut para.content, &mut items);
+    items
+}
+
+/// Returns the runs of a paragraph, including those of links and content
+/// controls.
+pub(crate) fn runs<'b, 'a>(para: &'b Paragraph<'a>) -> Vec<&'b Run<'a>> {
+    paragraph_content(para)
+        .into_iter()
+        .filter_map(|content| match content {
+            ParagraphContent::Run(run) => Some(run),
+            ParagraphContent::Link(link) => Some(&link.content),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Mutable version of [`runs`].
+pub(crate) fn runs_mut<'b, 'a>(para: &'b mut Paragraph<'a>) -> Vec<&'b mut Run<'a>> {
+    paragraph_content_mut(para)
+        .into_iter()
+        .filter_map(|content| match content {
+            ParagraphContent::Run(run) => Some(run),
+            ParagraphContent::Link(link) => Some(&mut link.content),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the relationship ids a paragraph refers to, in links,
+/// subdocuments and run content, see
+/// [`RunContent::relationship_ids`](crate::document::RunContent::relationship_ids).
+pub(crate) fn relationship_ids<'b>(para: &'b Paragraph<'_>) -> Vec<&'b str> {
+    let mut ids = Vec::new();
+    for content in paragraph_content(para) {
+        let id = match content {
+            ParagraphContent::Link(link) => link.id.as_deref(),
+            ParagraphContent::SubDocument(sub_doc) => sub_doc.id.as_deref(),
+            _ => None,
+        };
+        ids.extend(id);
+    }
+    for run in runs(para) {
+        for content in &run.content {
+            ids.extend(content.relationship_ids());
+        }
+    }
+    ids
+}
+
+/// Replaces the relationship ids a paragraph refers to, in links,
+/// subdocuments and run content, see
+/// [`RunContent::map_relationship_ids`](crate::document::RunContent::map_relationship_ids),
+/// with the ones `f` returns.
+pub(crate) fn map_relationship_ids<F: FnMut(&str) -> Option<String>>(
+    para: &mut Paragraph<'_>,
+    mut f: F,
+) {
+    for content in paragraph_content_mut(para) {
+        let id = match content {
+            ParagraphContent::Link(link) => link.id.as_mut(),
+            ParagraphContent::SubDocument(sub_doc) => sub_doc.id.as_mut(),
+            _ => None,
+        };
+        if let Some(id) = id {
+            if let Some(new_id) = f(id) {
+                *id = new_id.into();
+            }
+        }
+    }
+    for run in runs_mut(para) {
+        for content in run.content.iter_mut() {
+            content.map_relationship_ids(&mut f);
+        }
+    }
+}
+
+fn visit<'b, 'a>(
+    content: &'b [BodyContent<'a>],
+    paras: &mut Vec<&'b Paragraph<'a>>,
+    tables: &mut Vec<&'b Table<'a>>,
+) {
+    for content in content {
+        match content {
+            BodyContent::Paragraph(para) => paras.push(para),
+            BodyContent::Table(table) => {
+                tables.push(table);
+                for row in &table.rows {
+                    for cell in &row.cells {
+                        if let TableRowContent::TableCell(cell) = cell {
+                            visit_cell_paragraphs(&cell.content, paras);
+                        }
+                    }
+                }
+            }
+            BodyContent::TableCell(cell) => visit_cell_paragraphs(&cell.content, paras),
+            BodyContent::Sdt(sdt) => {
+                if let Some(sdt_content) = &sdt.content {
+                    visit(&sdt_content.content, paras, tables);
+                }
+            }
+            BodyContent::SectionProperty(_) | BodyContent::Raw(_) => (),
+        }
+    }
+}
+
+fn visit_cell_paragraphs<'b, 'a>(
+    content: &'b [TableCellContent<'a>],
+    paras: &mut Vec<&'b Paragraph<'a>>,
+) {
+    for content in content {
+        match content {
+            TableCellContent::Paragraph(para) => paras.push(para),
+        }
+    }
+}
+
+fn visit_content<'b, 'a>(
+    content: &'b mut [BodyContent<'a>],
+    paras: &mut Vec<&'b mut Paragraph<'a>>,
+    sections: &mut Vec<&'b mut SectionProperty<'a>>,
+) {
+    for content in content.iter_mut() {
+        match content {
+            BodyContent::Paragraph(para) => paras.push(para),
+            BodyContent::Table(table) => {
+                for row in table.rows.iter_mut() {
+                    for cell in row.cells.iter_mut() {
+                        if let TableRowContent::TableCell(cell) = cell {
+                            visit_cell(&mut cell.content, paras);
+                        }
+                    }
+                }
+            }
+            BodyContent::TableCell(cell) => visit_cell(&mut cell.content, paras),
+            BodyContent::Sdt(sdt) => {
+                if let Some(sdt_content) = &mut sdt.content {
+                    visit_content(&mut sdt_content.content, paras, sections);
+                }
+            }
+            BodyContent::SectionProperty(section) => sections.push(section),
+            BodyContent::Raw(_) => (),
+        }
+    }
+}
+
+fn visit_cell<'b, 'a>(
+    content: &'b mut [TableCellContent<'a>],
+    paras: &mut Vec<&'b mut Paragraph<'a>>,
+) {
+    for content in content.iter_mut() {
+        match content {
+            TableCellContent::Paragraph(para) => paras.push(para),
+        }
+    }
+}
+
+fn visit_inline<'b, 'a>(
+    content: &'b [ParagraphContent<'a>],
+    items: &mut Vec<&'b ParagraphContent<'a>>,
+) {
+    for content in content {
+        match content {
+            ParagraphContent::Sdt(sdt) => {
+                if let Some(sdt_content) = &sdt.content {
+                    visit_inline(&sdt_content.content, items);
+                }
+            }
+            _ => items.push(content),
+        }
+    }
+}
+
+fn visit_inline_mut<'b, 'a>(
+    content: &'b mut [ParagraphContent<'a>],
+    items: &mut Vec<&'b mut ParagraphContent<'a>>,
+) {
+    for content in content.iter_mut() {
+        match content {
+            ParagraphContent::Sdt(sdt) => {
+                if let Some(sdt_content) = &mut sdt.content {
+                    visit_inline_mut(&mut sdt_content.content, items);
+                }
+            }
+            _ => items.push(content),
+        }
+    }
+}
@@ -7,7 +7,7 @@ use hard_xml::{XmlRead, XmlResult, XmlWrite, XmlWriter};
 use std::io::Write;
 
 use crate::__xml_test_suites;
-use crate::schema::{SCHEMA_MAIN, SCHEMA_WORDML_14};
+use crate::schema::{write_root, NamespaceSet};
 
 /// The root element of the main document part.
 #[derive(Debug, Default, XmlRead, Clone)]
@@ -56,23 +56,22 @@ impl XmlWrite for WebSettings {
         log::debug!("[WebSettings] Started writing.");
         let _ = write!(writer.inner, "{}", crate::schema::SCHEMA_XML);
 
-        writer.write_element_start("w:webSettings")?;
+        write_root(
+            writer,
+            "w:webSettings",
+            NamespaceSet::wordprocessing(),
+            |writer| {
+                write_attr(optimize_for_browser, writer)?;
 
-        writer.write_attribute("xmlns:w", SCHEMA_MAIN)?;
+                write_attr(rely_on_vml, writer)?;
 
-        writer.write_attribute("xmlns:w14", SCHEMA_WORDML_14)?;
+                write_attr(allow_png, writer)?;
 
-        writer.write_element_end_open()?;
+                write_attr(do_not_save_as_single_file, writer)?;
 
-        write_attr(optimize_for_browser, writer)?;
-
-        write_attr(rely_on_vml, writer)?;
-
-        write_attr(allow_png, writer)?;
-
-        write_attr(do_not_save_as_single_file, writer)?;
-
-        writer.write_element_end_close("w:webSettings")?;
+                Ok(())
+            },
+        )?;
 
         log::debug!("[webSettings] Finished writing.");
 
@@ -94,10 +93,11 @@ __xml_test_suites!(
     WebSettings,
     WebSettings::default(),
     format!(
-        r#"{}<w:webSettings xmlns:w="{}" xmlns:w14="{}"></w:webSettings>"#,
+        r#"{}<w:webSettings xmlns:w="{}" xmlns:w14="{}" xmlns:mc="{}" mc:Ignorable="w14"></w:webSettings>"#,
         crate::schema::SCHEMA_XML,
-        SCHEMA_MAIN,
-        SCHEMA_WORDML_14
+        crate::schema::SCHEMA_MAIN,
+        crate::schema::SCHEMA_WORDML_14,
+        crate::schema::SCHEMA_MC
     )
     .as_str(),
 );
@@ -0,0 +1,145 @@
+//! Post-processing of serialized parts
+//!
+//! Used by [`crate::WriteOptions`] to indent the XML of a part or to put its
+//! attributes in a stable order.
+
+use xmlparser::{ElementEnd, Token, Tokenizer};
+
+use crate::{DocxError, DocxResult};
+
+const INDENT: &str = "  ";
+
+/// Rewrites serialized XML.
+///
+/// With `pretty`, every element starts on its own line, indented by its depth,
+/// except where it follows text, so the content of text elements is untouched.
+/// With `canonical`, namespace declarations are sorted by prefix and put before
+/// the other attributes, which are sorted by qualified name.
+pub(crate) fn reformat(xml: &str, pretty: bool, canonical: bool) -> DocxResult<String> {
+    let mut out = String::with_capacity(xml.len() + xml.len() / 4);
+    let mut depth = 0usize;
+    let mut attrs: Vec<(&str, &str)> = Vec::new();
+    let mut after_text = false;
+    let mut has_children = Vec::new();
+
+    let newline = |out: &mut String, depth: usize| {
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        for _ in 0..depth {
+            out.push_str(INDENT);
+        }
+    };
+
+    for token in Tokenizer::from(xml) {
+        let token = token.map_err(|e| DocxError::Xml(hard_xml::XmlError::from(e)))?;
+        match token {
+            Token::Declaration { span, .. } => {
+                out.push_str(span.as_str());
+                out.push_str("\r\n");
+            }
+            Token::ProcessingInstruction { span, .. } | Token::Comment { span, .. } => {
+                if pretty && !after_text {
+                    newline(&mut out, depth);
+                }
+                out.push_str(span.as_str());
+            }
+            Token::ElementStart { span, .. } => {
+                if pretty && !after_text && !out.ends_with('\n') {
+                    newline(&mut out, depth);
+                }
+                if let Some(parent) = has_children.last_mut() {
+                    *parent = true;
+                }
+                out.push_str(span.as_str());
+                attrs.clear();
+            }
+            Token::Attribute { span, value, .. } => {
+                let name = &span.as_str()[..span.as_str().find('=').unwrap_or(0)];
+                attrs.push((name.trim(), value.as_str()));
+            }
+            Token::ElementEnd { end, span } => match end {
+                ElementEnd::Open | ElementEnd::Empty => {
+                    if canonical {
+                        attrs.sort_by(|a, b| attribute_key(a.0).cmp(&attribute_key(b.0)));
+                    }
+                    for (name, value) in attrs.drain(..) {
+                        let quote = if value.contains('"') { '\'' } else { '"' };
+                        out.push(' ');
+                        out.push_str(name);
+                        out.push('=');
+                        out.push(quote);
+                        out.push_str(value);
+                        out.push(quote);
+                    }
+                    if let ElementEnd::Open = end {
+                        out.push('>');
+                        depth += 1;
+                        has_children.push(false);
+                    } else {
+                        out.push_str("/>");
+                    }
+                    after_text = false;
+                }
+                ElementEnd::Close(..) => {
+                    depth = depth.saturating_sub(1);
+                    let had_children = has_children.pop().unwrap_or(false);
+                    if pretty && !after_text && had_children {
+                        newline(&mut out, depth);
+                    }
+                    out.push_str(span.as_str());
+                    after_text = false;
+                }
+            },
+            Token::Text { text } => {
+                out.push_str(text.as_str());
+                after_text = true;
+            }
+            Token::Cdata { span, .. } => {
+                out.push_str(span.as_str());
+                after_text = true;
+            }
+            Token::DtdStart { span, .. }
+            | Token::EmptyDtd { span, .. }
+            | Token::EntityDeclaration { span, .. }
+            | Token::DtdEnd { span } => {
+                out.push_str(span.as_str());
+            }
+        }
+    }
+
+    Ok(out)
+}
+
+/// Sorts namespace declarations first, then by qualified name.
+fn attribute_key(name: &str) -> (bool, &str) {
+    (!(name == "xmlns" || name.starts_with("xmlns:")), name)
+}
+
+#[test]
+fn pretty_output() -> DocxResult<()> {
+    let xml = r#"<w:p><w:pPr><w:jc w:val="left"/></w:pPr><w:r><w:t xml:space="preserve"> a </w:t></w:r></w:p>"#;
+    assert_eq!(
+        reformat(xml, true, false)?,
+        r#"<w:p>
+  <w:pPr>
+    <w:jc w:val="left"/>
+  </w:pPr>
+  <w:r>
+    <w:t xml:space="preserve"> a </w:t>
+  </w:r>
+</w:p>"#
+    );
+    Ok(())
+}
+
+#[test]
+fn canonical_output() -> DocxResult<()> {
+    let xml = r#"<w:document xmlns:w="w" xmlns:a="a"><w:p w:rsidR="1" w14:paraId="2" w:rsidP="3"/></w:document>"#;
+    assert_eq!(
+        reformat(xml, false, true)?,
+        r#"<w:document xmlns:a="a" xmlns:w="w"><w:p w14:paraId="2" w:rsidP="3" w:rsidR="1"/></w:document>"#
+    );
+    assert_eq!(reformat(xml, false, false)?, xml);
+    Ok(())
+}
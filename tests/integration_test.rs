@@ -4,7 +4,7 @@ use std::fs::read_dir;
 use docx_rust::{
     document::{BodyContent, ParagraphContent, RunContent},
     rels::TargetMode,
-    DocxFile,
+    Docx, DocxFile, WriteOptions,
 };
 
 #[test]
@@ -31,6 +31,81 @@ fn read_list() {
     );
 }
 
+#[test]
+fn inspect_parts() {
+    let path = std::path::Path::new("./tests/aaa/aa.docx");
+    let book = DocxFile::from_file(path).unwrap();
+    let docx = book.parse().unwrap();
+
+    let parts = docx.parts().unwrap();
+    assert!(parts.iter().any(|(name, _)| name == "[Content_Types].xml"));
+    assert!(parts
+        .iter()
+        .any(|(name, _)| name == "word/_rels/document.xml.rels"));
+
+    let document = docx.part_xml("/word/document.xml").unwrap().unwrap();
+    assert!(document.contains("<w:body>"));
+    assert!(docx.part_xml("word/missing.xml").unwrap().is_none());
+}
+
+#[test]
+fn in_memory_round_trip() {
+    let bytes = std::fs::read("./tests/aaa/aa.docx").unwrap();
+    let book = DocxFile::from_bytes(&bytes).unwrap();
+    let mut docx = book.parse().unwrap();
+    let text = docx.document.body.text();
+
+    let written = docx.write_to_vec().unwrap();
+    let book = DocxFile::from_bytes(&written).unwrap();
+    let docx = book.parse().unwrap();
+    assert_eq!(docx.document.body.text(), text);
+}
+
+#[test]
+fn deterministic_output() {
+    let bytes = std::fs::read("./tests/aaa/aa.docx").unwrap();
+    let options = WriteOptions::default().deterministic(true);
+
+    let write = || {
+        let book = DocxFile::from_bytes(&bytes).unwrap();
+        let mut docx = book.parse().unwrap();
+        docx.write_to_vec_with_options(&options).unwrap()
+    };
+    assert_eq!(write(), write());
+}
+
+#[test]
+fn lazy_parsing() {
+    let book = DocxFile::from_file("./tests/aaa/aa.docx").unwrap();
+    let docx = book.parse().unwrap();
+
+    let lazy = book.lazy();
+    let document = lazy.parse_document().unwrap();
+    assert_eq!(document.body.text(), docx.document.body.text());
+    assert!(std::ptr::eq(document, lazy.parse_document().unwrap()));
+    assert_eq!(
+        lazy.parse_styles().unwrap().styles.len(),
+        docx.styles.styles.len()
+    );
+    assert_eq!(lazy.parse_core().unwrap().is_some(), docx.core.is_some());
+}
+
+#[test]
+fn import_reference_styles() {
+    let reference = DocxFile::from_file("./tests/aaa/aa.docx").unwrap();
+    let reference = reference.parse().unwrap();
+
+    let mut docx = Docx::default();
+    docx.add_heading(1, "Title");
+    docx.import_styles(&reference);
+
+    for style in &reference.styles.styles {
+        assert!(docx.styles.get(&style.style_id).is_some());
+    }
+    assert_eq!(docx.themes.len(), reference.themes.len());
+    docx.write_to_vec().unwrap();
+}
+
 #[test]
 fn read_external_links() {
     let path = std::path::Path::new("./tests/pandoc/links.docx");
@@ -61,6 +136,29 @@ fn read_external_links() {
     }
 }
 
+#[test]
+fn resolve_link_targets() {
+    let book = DocxFile::from_file("./tests/pandoc/links.docx").unwrap();
+    let docx = book.parse().unwrap();
+
+    let targets: Vec<_> = docx
+        .document
+        .body
+        .content
+        .iter()
+        .filter_map(|content| match content {
+            BodyContent::Paragraph(para) => Some(para),
+            _ => None,
+        })
+        .flat_map(|para| &para.content)
+        .filter_map(|content| match content {
+            ParagraphContent::Link(link) => docx.hyperlink_target(link),
+            _ => None,
+        })
+        .collect();
+    assert!(targets.contains(&"http://pandoc.org/README.html#synopsis"));
+}
+
 #[test]
 fn read_pandocs() {
     if let Ok(dir) = read_dir("./tests/pandoc/") {
@@ -153,3 +251,174 @@ fn read_image() {
         }
     }
 }
+
+#[test]
+fn first_page_and_even_headers() {
+    use docx_rust::document::{Footer, Header, HeaderFooterReferenceType, Paragraph};
+
+    let mut docx = Docx::default();
+    docx.add_paragraph("Body");
+    let mut header = Header::default();
+    header.push(Paragraph::default().push_text("Report"));
+    docx.set_header(HeaderFooterReferenceType::Default, header);
+    docx.set_header(HeaderFooterReferenceType::First, Header::default());
+    docx.set_footer(HeaderFooterReferenceType::Even, Footer::default());
+
+    let written = docx.write_to_vec().unwrap();
+    let book = DocxFile::from_bytes(&written).unwrap();
+    let docx = book.parse().unwrap();
+    assert_eq!(docx.headers.len(), 2);
+    assert_eq!(docx.footers.len(), 1);
+    assert!(docx.settings.unwrap().even_and_odd_headers.is_some());
+    match docx.document.body.content.last() {
+        Some(BodyContent::SectionProperty(section)) => {
+            assert!(section.title_page.is_some());
+            assert!(section
+                .footer_reference(&HeaderFooterReferenceType::Even)
+                .is_some());
+        }
+        _ => panic!("missing section properties"),
+    }
+}
+
+#[test]
+fn save_into_existing_package() {
+    use std::io::Cursor;
+
+    let mut docx = Docx::default();
+    docx.add_paragraph("First");
+    let original = docx.write_to_vec().unwrap();
+
+    let book = DocxFile::from_bytes(&original).unwrap();
+    let mut docx = book.parse().unwrap();
+    docx.add_paragraph("Second");
+    let saved = docx
+        .save_into(Cursor::new(&original), Cursor::new(Vec::new()))
+        .unwrap()
+        .into_inner();
+
+    let book = DocxFile::from_bytes(&saved).unwrap();
+    let docx = book.parse().unwrap();
+    assert_eq!(docx.document.body.content.len(), 2);
+}
+
+#[test]
+fn keep_unknown_parts() {
+    use std::borrow::Cow;
+
+    let settings = [0x01, 0x02, 0x03];
+    let mut docx = Docx::default();
+    docx.add_paragraph("Printed");
+    docx.other_parts.insert(
+        "word/printerSettings/printerSettings1.bin".to_string(),
+        Cow::Borrowed(&settings[..]),
+    );
+    docx.document_rels
+        .get_or_insert_with(Default::default)
+        .add_rel(
+            "http://schemas.openxmlformats.org/officeDocument/2006/relationships/printerSettings",
+            "printerSettings/printerSettings1.bin",
+        );
+    let written = docx.write_to_vec().unwrap();
+
+    let book = DocxFile::from_bytes(&written).unwrap();
+    let docx = book.parse().unwrap();
+    assert_eq!(
+        docx.other_parts["word/printerSettings/printerSettings1.bin"],
+        &settings[..]
+    );
+    let rels = docx.document_rels.as_ref().unwrap();
+    assert!(rels
+        .get_id("printerSettings/printerSettings1.bin")
+        .is_some());
+    assert!(!docx.other_parts.contains_key("word/document.xml"));
+}
+
+#[test]
+fn thumbnail_round_trip() {
+    use docx_rust::thumbnail::{Thumbnail, ThumbnailFormat};
+
+    let png = b"\x89PNG\r\n\x1a\n".to_vec();
+    let mut docx = Docx::default();
+    docx.add_paragraph("Preview");
+    docx.thumbnail = Some(Thumbnail::png(&png[..]));
+    let written = docx.write_to_vec().unwrap();
+
+    let book = DocxFile::from_bytes(&written).unwrap();
+    let mut docx = book.parse().unwrap();
+    let thumbnail = docx.thumbnail.as_ref().unwrap();
+    assert_eq!(thumbnail.format, ThumbnailFormat::Png);
+    assert_eq!(thumbnail.data, &png[..]);
+    assert!(docx.other_parts.is_empty());
+
+    let written = docx.write_to_vec().unwrap();
+    let book = DocxFile::from_bytes(&written).unwrap();
+    assert!(book.parse().unwrap().thumbnail.is_some());
+}
+
+#[test]
+fn keep_ole_objects() {
+    use docx_rust::document::{Object, Paragraph, Run};
+    use hard_xml::XmlRead;
+    use std::borrow::Cow;
+
+    let package = [0x50, 0x4B, 0x03, 0x04];
+    let mut docx = Docx::default();
+    docx.embeddings.insert(
+        "embeddings/oleObject1.bin".to_string(),
+        Cow::Borrowed(&package[..]),
+    );
+    let rels = docx.document_rels.get_or_insert_with(Default::default);
+    rels.add_rel(
+        "http://schemas.openxmlformats.org/officeDocument/2006/relationships/oleObject",
+        "embeddings/oleObject1.bin",
+    );
+    let id = rels
+        .get_id("embeddings/oleObject1.bin")
+        .unwrap()
+        .to_string();
+    let xml = format!(
+        r#"<w:object w:dxaOrig="1440" w:dyaOrig="960"><o:OLEObject Type="Embed" ProgID="Package" ObjectID="_1" r:id="{}"/></w:object>"#,
+        id
+    );
+    let object = Object::from_str(&xml).unwrap();
+    docx.document
+        .push(Paragraph::default().push(Run::default().push(RunContent::Object(object))));
+    let written = docx.write_to_vec().unwrap();
+
+    let book = DocxFile::from_bytes(&written).unwrap();
+    let docx = book.parse().unwrap();
+    let object = match &docx.document.body.content[0] {
+        BodyContent::Paragraph(para) => match &para.content[0] {
+            ParagraphContent::Run(run) => match &run.content[0] {
+                RunContent::Object(object) => object,
+                _ => panic!("not an object"),
+            },
+            _ => panic!("not a run"),
+        },
+        _ => panic!("not a paragraph"),
+    };
+    assert_eq!(object.xml, xml);
+    assert_eq!(docx.embedding_data(&id), Some(&package[..]));
+}
+
+#[test]
+fn subdocument_round_trip() {
+    use docx_rust::document::Paragraph;
+
+    let mut docx = Docx::default();
+    for chapter in ["install.docx", "usage.docx"] {
+        let sub_doc = docx.subdocument(chapter);
+        docx.document.push(Paragraph::default().push(sub_doc));
+    }
+    let written = docx.write_to_vec().unwrap();
+
+    let book = DocxFile::from_bytes(&written).unwrap();
+    let mut docx = book.parse().unwrap();
+    assert_eq!(docx.subdocuments(), ["install.docx", "usage.docx"]);
+
+    let written = docx.write_to_vec().unwrap();
+    let book = DocxFile::from_bytes(&written).unwrap();
+    let docx = book.parse().unwrap();
+    assert_eq!(docx.subdocuments(), ["install.docx", "usage.docx"]);
+}